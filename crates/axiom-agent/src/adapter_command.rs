@@ -4,6 +4,7 @@
 // Команды от адаптеров в tick_loop и ответы tick_loop адаптерам.
 // Единый канал — mpsc::Sender<AdapterCommand>.
 
+use crate::feedback::FeedbackSignal;
 use crate::protocol::ServerMessage;
 use axiom_runtime::GatewayPriority;
 
@@ -37,6 +38,8 @@ pub enum AdapterSource {
 pub enum AdapterPayload {
     /// Текстовый ввод → InjectToken → Engine
     Inject { text: String },
+    /// Предвычисленный вектор-эмбеддинг → InjectToken + grounding к k ближайшим
+    Embedding { vector: Vec<f32>, k: usize },
     /// Мета-команда только для чтения (:status, :domains, ...)
     MetaRead { cmd: String },
     /// Мутирующая мета-команда (:save, :load, :quit, ...)
@@ -47,6 +50,20 @@ pub enum AdapterPayload {
     Unsubscribe { channels: Vec<String> },
     /// Запросить детальный снапшот домена
     DomainSnapshot { domain_id: u16 },
+    /// Пакет вердиктов по advisory → очередь FeedbackLedger
+    FeedbackBatch { key: String, signals: Vec<FeedbackSignal> },
+    /// Запросить статус пакета по ключу идемпотентности
+    FeedbackStatus { key: String },
+    /// Сводки потока advisory по циклам длиной `window` event_id (JSON lines)
+    AdvisoryAnalytics { window: u64 },
+    /// Список Over-Domain компонентов (JSON-массив OverDomainModuleInfo)
+    Modules,
+    /// Структурная важность токенов домена: `top` первых по PageRank (JSON-массив)
+    Centrality { domain_id: u16, top: usize },
+    /// Строка GraphQuery над доменом → JSON-массив sutra_id
+    GraphQuery { domain_id: u16, query: String },
+    /// Соединение источника закрыто → забыть его SessionContext, ответа нет
+    SessionClosed,
 }
 
 impl AdapterCommand {
@@ -4,9 +4,15 @@
 // Конфигурация tick_loop и всех адаптеров.
 // Собирается из CliConfig при старте; в будущем — из axiom-cli.yaml.
 
-use crate::channels::cli::CliConfig;
+use crate::adapter_command::{AdapterPayload, AdapterSource};
+use crate::channels::cli::{CliConfig, GraphPolicies};
 use crate::effectors::message::DetailLevel;
+use crate::perceptors::preprocess::TextPipeline;
+use crate::session_context::SessionContextConfig;
+use axiom_arbiter::OverflowPolicy;
 use axiom_runtime::TickSchedule;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 /// Конфигурация WebSocket-адаптера.
 pub struct WebSocketConfig {
@@ -31,6 +37,50 @@ impl Default for WebSocketConfig {
     }
 }
 
+/// Предобработка текста Inject-команд по источнику (AdapterSource).
+///
+/// По умолчанию все pipeline пустые — текст передаётся в TextPerceptor как есть.
+/// Секция `preprocessing:` в axiom-cli.yaml; отсутствующий источник — пустой pipeline.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct PreprocessingConfig {
+    #[serde(default)]
+    pub cli: TextPipeline,
+    #[serde(default)]
+    pub websocket: TextPipeline,
+    #[serde(default)]
+    pub rest: TextPipeline,
+    #[serde(default)]
+    pub telegram: TextPipeline,
+}
+
+impl PreprocessingConfig {
+    /// Pipeline для данного источника.
+    pub fn for_source(&self, source: &AdapterSource) -> &TextPipeline {
+        match source {
+            AdapterSource::Cli => &self.cli,
+            AdapterSource::WebSocket(_) => &self.websocket,
+            AdapterSource::Rest => &self.rest,
+            AdapterSource::Telegram(_) => &self.telegram,
+        }
+    }
+
+    /// Применить pipeline источника к тексту Inject; прочие payload не меняются.
+    pub fn preprocess(&self, source: &AdapterSource, payload: AdapterPayload) -> AdapterPayload {
+        match payload {
+            AdapterPayload::Inject { text } => {
+                let pipeline = self.for_source(source);
+                if pipeline.is_identity() {
+                    AdapterPayload::Inject { text }
+                } else {
+                    AdapterPayload::Inject { text: pipeline.apply(&text) }
+                }
+            }
+            other => other,
+        }
+    }
+}
+
 /// Конфигурация tick_loop и адаптеров.
 pub struct AdaptersConfig {
     /// Целевая частота тиков
@@ -55,6 +105,14 @@ pub struct AdaptersConfig {
     pub verbose: bool,
     pub detail_level: DetailLevel,
     pub adaptive_tick_rate: bool,
+    /// Предобработка текста по источнику команды
+    pub preprocessing: PreprocessingConfig,
+    /// Контекст разговора по источнику (по умолчанию выключен)
+    pub session_context: SessionContextConfig,
+    /// Политика переполнения Experience
+    pub overflow_policy: OverflowPolicy,
+    /// Политики обслуживания графа
+    pub graph_policies: GraphPolicies,
 }
 
 impl AdaptersConfig {
@@ -77,6 +135,10 @@ impl AdaptersConfig {
             verbose: c.verbose,
             detail_level: c.detail_level,
             adaptive_tick_rate: c.adaptive_tick_rate,
+            preprocessing: c.preprocessing.clone(),
+            session_context: c.session_context.clone(),
+            overflow_policy: c.overflow_policy,
+            graph_policies: c.graph_policies.clone(),
         }
     }
 }
@@ -9,7 +9,7 @@
 use axiom_core::Event;
 use axiom_runtime::{Effector, Perceptor};
 use axiom_ucl::{OpCode, UclCommand, UclResult};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufRead, BufReader, Read, Write};
 
 /// Входящий CLI-адаптер: строки из `BufRead` → `UclCommand`.
@@ -151,9 +151,16 @@ fn format_event_type(et: u16) -> String {
 
 // ─── Async CliChannel (CLI Channel V1.1) ─────────────────────────────────────
 
+use crate::adapters_config::PreprocessingConfig;
 use crate::effectors::message::{DetailLevel, MessageEffector};
 use crate::perceptors::text::TextPerceptor;
+use crate::session_context::SessionContextConfig;
+use axiom_arbiter::OverflowPolicy;
 use axiom_config::{self, AnchorSet, ConfigWatcher};
+use axiom_domain::{
+    ConnectionDecay, ConnectionLearningRule, ConnectionPruner, NormalizationMode, OrphanCriteria,
+    StrengthNormalization, SymmetryPolicy,
+};
 use axiom_persist::{AutoSaver, PersistenceConfig};
 use axiom_runtime::{AxiomEngine, GuardianConfig, TickSchedule};
 use schemars::JsonSchema;
@@ -278,6 +285,20 @@ pub struct TickScheduleConfig {
     /// Число idle-тиков до снижения hz (default: 50)
     #[serde(default)]
     pub adaptive_cooldown: Option<u32>,
+    #[serde(default)]
+    pub strength_norm_interval: Option<u32>,
+    #[serde(default)]
+    pub connection_decay_interval: Option<u32>,
+    #[serde(default)]
+    pub connection_learning_interval: Option<u32>,
+    #[serde(default)]
+    pub connection_ttl_interval: Option<u32>,
+    #[serde(default)]
+    pub connection_prune_interval: Option<u32>,
+    #[serde(default)]
+    pub connection_compact_interval: Option<u32>,
+    #[serde(default)]
+    pub orphan_gc_interval: Option<u32>,
 }
 
 impl TickScheduleConfig {
@@ -323,6 +344,27 @@ impl TickScheduleConfig {
         if let Some(v) = self.adaptive_cooldown {
             s.adaptive_tick.cooldown = v;
         }
+        if let Some(v) = self.strength_norm_interval {
+            s.strength_norm_interval = v;
+        }
+        if let Some(v) = self.connection_decay_interval {
+            s.connection_decay_interval = v;
+        }
+        if let Some(v) = self.connection_learning_interval {
+            s.connection_learning_interval = v;
+        }
+        if let Some(v) = self.connection_ttl_interval {
+            s.connection_ttl_interval = v;
+        }
+        if let Some(v) = self.connection_prune_interval {
+            s.connection_prune_interval = v;
+        }
+        if let Some(v) = self.connection_compact_interval {
+            s.connection_compact_interval = v;
+        }
+        if let Some(v) = self.orphan_gc_interval {
+            s.orphan_gc_interval = v;
+        }
     }
 }
 
@@ -385,6 +427,263 @@ impl GuardianConfigYaml {
     }
 }
 
+// ─── Политики обслуживания графа ─────────────────────────────────────────────
+
+/// Политики периодических проходов по графу, которые Engine применяет по
+/// интервалам TickSchedule (`orphan_gc_interval`, `connection_prune_interval`, ...).
+#[derive(Debug, Clone, Default)]
+pub struct GraphPolicies {
+    pub orphan_criteria: OrphanCriteria,
+    pub connection_pruner: ConnectionPruner,
+    pub connection_decay: ConnectionDecay,
+    pub connection_learning: ConnectionLearningRule,
+    pub strength_normalization: StrengthNormalization,
+    pub bidirectional_symmetry: SymmetryPolicy,
+}
+
+impl GraphPolicies {
+    /// Установить политики в Engine.
+    pub fn apply_to(&self, engine: &mut AxiomEngine) {
+        engine.orphan_criteria = self.orphan_criteria;
+        engine.connection_pruner = self.connection_pruner.clone();
+        engine.connection_decay = self.connection_decay;
+        engine.connection_learning = self.connection_learning;
+        engine.strength_normalization = self.strength_normalization.clone();
+        engine.bidirectional_symmetry = self.bidirectional_symmetry;
+    }
+}
+
+/// YAML-зеркало OrphanCriteria.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct OrphanCriteriaYaml {
+    #[serde(default)]
+    pub max_mass: Option<u8>,
+    #[serde(default)]
+    pub min_bond_strength: Option<f32>,
+    #[serde(default)]
+    pub idle_events: Option<u64>,
+}
+
+/// YAML-зеркало ConnectionPruner.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct ConnectionPrunerYaml {
+    #[serde(default)]
+    pub min_strength: Option<f32>,
+    #[serde(default)]
+    pub max_idle: Option<u64>,
+    #[serde(default)]
+    pub prune_flagged: Option<bool>,
+    /// link_type → лимит исходящих связей на (source_id, link_type)
+    #[serde(default)]
+    pub per_link_type_cap: Option<HashMap<u16, usize>>,
+}
+
+/// YAML-зеркало ConnectionDecay.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct ConnectionDecayYaml {
+    #[serde(default)]
+    pub factor: Option<f32>,
+    #[serde(default)]
+    pub idle_after: Option<u64>,
+    #[serde(default)]
+    pub prune_below: Option<f32>,
+}
+
+/// YAML-зеркало ConnectionLearningRule: `rule` — simple / hebbian / stdp.
+/// simple и hebbian читают rate и window (default: 100), stdp — a_plus,
+/// a_minus (default: a_plus) и tau.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct ConnectionLearningYaml {
+    pub rule: String,
+    #[serde(default)]
+    pub rate: Option<f32>,
+    #[serde(default)]
+    pub window: Option<u64>,
+    #[serde(default)]
+    pub a_plus: Option<f32>,
+    #[serde(default)]
+    pub a_minus: Option<f32>,
+    #[serde(default)]
+    pub tau: Option<f32>,
+}
+
+impl ConnectionLearningYaml {
+    /// Правило из YAML. None — неизвестное правило или не хватает параметров.
+    pub fn to_rule(&self) -> Option<ConnectionLearningRule> {
+        let window = self.window.unwrap_or(100);
+        Some(match self.rule.as_str() {
+            "simple" => ConnectionLearningRule::Simple { rate: self.rate?, window },
+            "hebbian" => ConnectionLearningRule::Hebbian { rate: self.rate?, window },
+            "stdp" => {
+                let a_plus = self.a_plus?;
+                let a_minus = self.a_minus.unwrap_or(a_plus);
+                ConnectionLearningRule::Stdp { a_plus, a_minus, tau: self.tau? }
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// YAML-зеркало NormalizationMode: `mode` — sum_to_cap / softmax
+/// (temperature, default: 1.0).
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct NormalizationModeYaml {
+    pub mode: String,
+    pub cap: f32,
+    #[serde(default)]
+    pub temperature: Option<f32>,
+}
+
+impl NormalizationModeYaml {
+    /// Режим из YAML. None — неизвестный режим.
+    pub fn to_mode(&self) -> Option<NormalizationMode> {
+        match self.mode.as_str() {
+            "sum_to_cap" => Some(NormalizationMode::SumToCap { cap: self.cap }),
+            "softmax" => Some(NormalizationMode::Softmax {
+                cap: self.cap,
+                temperature: self.temperature.unwrap_or(1.0),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// YAML-зеркало StrengthNormalization.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct StrengthNormalizationYaml {
+    #[serde(default)]
+    pub default: Option<NormalizationModeYaml>,
+    #[serde(default)]
+    pub per_link_type: HashMap<u16, NormalizationModeYaml>,
+}
+
+/// YAML-зеркало GraphPolicies. Отсутствующие секции и поля не меняют
+/// значения по умолчанию; неизвестные имена правил — предупреждение в stderr.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct GraphPoliciesYaml {
+    #[serde(default)]
+    pub orphan_criteria: Option<OrphanCriteriaYaml>,
+    #[serde(default)]
+    pub connection_pruner: Option<ConnectionPrunerYaml>,
+    #[serde(default)]
+    pub connection_decay: Option<ConnectionDecayYaml>,
+    #[serde(default)]
+    pub connection_learning: Option<ConnectionLearningYaml>,
+    #[serde(default)]
+    pub strength_normalization: Option<StrengthNormalizationYaml>,
+    /// latest_wins (default) / max / min / mean
+    #[serde(default)]
+    pub bidirectional_symmetry: Option<String>,
+}
+
+impl GraphPoliciesYaml {
+    /// Применить значения из YAML поверх `p`.
+    pub fn apply_to(&self, p: &mut GraphPolicies) {
+        if let Some(ref o) = self.orphan_criteria {
+            let c = &mut p.orphan_criteria;
+            if let Some(v) = o.max_mass {
+                c.max_mass = v;
+            }
+            if let Some(v) = o.min_bond_strength {
+                c.min_bond_strength = v;
+            }
+            if let Some(v) = o.idle_events {
+                c.idle_events = v;
+            }
+        }
+        if let Some(ref y) = self.connection_pruner {
+            let c = &mut p.connection_pruner;
+            if let Some(v) = y.min_strength {
+                c.min_strength = v;
+            }
+            if let Some(v) = y.max_idle {
+                c.max_idle = v;
+            }
+            if let Some(v) = y.prune_flagged {
+                c.prune_flagged = v;
+            }
+            if let Some(ref v) = y.per_link_type_cap {
+                c.per_link_type_cap = v.clone();
+            }
+        }
+        if let Some(ref y) = self.connection_decay {
+            let c = &mut p.connection_decay;
+            if let Some(v) = y.factor {
+                c.factor = v;
+            }
+            if let Some(v) = y.idle_after {
+                c.idle_after = v;
+            }
+            if let Some(v) = y.prune_below {
+                c.prune_below = v;
+            }
+        }
+        if let Some(ref y) = self.connection_learning {
+            match y.to_rule() {
+                Some(rule) => p.connection_learning = rule,
+                None => eprintln!("[axiom-cli] invalid connection_learning rule '{}'", y.rule),
+            }
+        }
+        if let Some(ref y) = self.strength_normalization {
+            let mut norm = StrengthNormalization::default();
+            let modes = y.default.iter().map(|m| (None, m));
+            let modes = modes.chain(y.per_link_type.iter().map(|(&t, m)| (Some(t), m)));
+            for (link_type, m) in modes {
+                let Some(mode) = m.to_mode() else {
+                    eprintln!("[axiom-cli] unknown strength_normalization mode '{}'", m.mode);
+                    continue;
+                };
+                match link_type {
+                    Some(t) => {
+                        norm.per_link_type.insert(t, mode);
+                    }
+                    None => norm.default = Some(mode),
+                }
+            }
+            p.strength_normalization = norm;
+        }
+        if let Some(ref name) = self.bidirectional_symmetry {
+            match name.as_str() {
+                "latest_wins" => p.bidirectional_symmetry = SymmetryPolicy::LatestWins,
+                "max" => p.bidirectional_symmetry = SymmetryPolicy::Max,
+                "min" => p.bidirectional_symmetry = SymmetryPolicy::Min,
+                "mean" => p.bidirectional_symmetry = SymmetryPolicy::Mean,
+                _ => eprintln!("[axiom-cli] unknown bidirectional_symmetry '{name}'"),
+            }
+        }
+    }
+}
+
+// ─── Experience YAML-зеркало ─────────────────────────────────────────────────
+
+/// Параметры Experience в конфиге. Отсутствующие поля не меняют значения по умолчанию.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+pub struct ExperienceConfigYaml {
+    /// Поведение при достижении лимита следов:
+    /// drop_lowest_weight (default) / drop_oldest / reject_new / spill
+    #[serde(default)]
+    pub overflow_policy: Option<String>,
+}
+
+impl ExperienceConfigYaml {
+    /// Применить значения из YAML. Неизвестное имя политики — предупреждение в stderr.
+    pub fn apply_to(&self, policy: &mut OverflowPolicy) {
+        if let Some(ref name) = self.overflow_policy {
+            match OverflowPolicy::from_name(name) {
+                Some(p) => *policy = p,
+                None => eprintln!("[axiom-cli] unknown experience.overflow_policy '{name}'"),
+            }
+        }
+    }
+}
+
 // ─── CliConfigFile — YAML-структура ──────────────────────────────────────────
 
 /// Файл конфигурации CLI Channel (axiom-cli.yaml).
@@ -422,6 +721,18 @@ pub struct CliConfigFile {
     /// Параметры адаптации Guardian (скорость обучения)
     #[serde(default)]
     pub guardian: Option<GuardianConfigYaml>,
+    /// Параметры Experience (политика переполнения)
+    #[serde(default)]
+    pub experience: Option<ExperienceConfigYaml>,
+    /// Политики обслуживания графа (orphan GC, pruner, decay, learning, нормализация)
+    #[serde(default)]
+    pub graph_policies: Option<GraphPoliciesYaml>,
+    /// Предобработка текста Inject по источнику (cli / websocket / rest / telegram)
+    #[serde(default)]
+    pub preprocessing: Option<PreprocessingConfig>,
+    /// Контекст разговора по источнику (enabled / capacity / decay / ...)
+    #[serde(default)]
+    pub session_context: Option<SessionContextConfig>,
 }
 
 impl CliConfigFile {
@@ -487,6 +798,14 @@ pub struct CliConfig {
     pub hot_reload: bool,
     /// Параметры адаптации Guardian (скорость обучения модели)
     pub guardian_config: GuardianConfig,
+    /// Политика переполнения Experience (default: DropLowestWeight)
+    pub overflow_policy: OverflowPolicy,
+    /// Политики обслуживания графа для Engine
+    pub graph_policies: GraphPolicies,
+    /// Предобработка текста Inject по источнику
+    pub preprocessing: PreprocessingConfig,
+    /// Контекст разговора по источнику (default: выключен)
+    pub session_context: SessionContextConfig,
     /// Запустить WebSocket-сервер (Phase 1, default: false)
     pub ws_enabled: bool,
     /// Порт WebSocket-сервера (default: 8765)
@@ -517,6 +836,10 @@ impl Default for CliConfig {
             detail_level: DetailLevel::Min,
             hot_reload: false,
             guardian_config: GuardianConfig::default(),
+            overflow_policy: OverflowPolicy::default(),
+            graph_policies: GraphPolicies::default(),
+            preprocessing: PreprocessingConfig::default(),
+            session_context: SessionContextConfig::default(),
             ws_enabled: false,
             ws_port: 8765,
             telegram_token: None,
@@ -573,6 +896,18 @@ impl CliConfig {
             if let Some(g) = file.guardian {
                 g.apply_to(&mut config.guardian_config);
             }
+            if let Some(e) = file.experience {
+                e.apply_to(&mut config.overflow_policy);
+            }
+            if let Some(g) = file.graph_policies {
+                g.apply_to(&mut config.graph_policies);
+            }
+            if let Some(p) = file.preprocessing {
+                config.preprocessing = p;
+            }
+            if let Some(s) = file.session_context {
+                config.session_context = s;
+            }
         }
 
         // Слой 3: CLI-флаги (перекрывают файл)
@@ -730,6 +1065,8 @@ impl CliChannel {
     pub fn new(mut engine: AxiomEngine, config: CliConfig) -> Self {
         engine.tick_schedule = config.tick_schedule.clone();
         engine.guardian_config = config.guardian_config.clone();
+        engine.ashti.experience_mut().set_overflow_policy(config.overflow_policy);
+        config.graph_policies.apply_to(&mut engine);
         let persist_interval = engine.tick_schedule.persist_check_interval;
         let auto_cfg = PersistenceConfig::new(persist_interval);
 
@@ -0,0 +1,359 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// FeedbackLedger — пакетный приём подтверждений/отклонений advisory.
+//
+// Внешние источники (A/B-системы, разметчики) присылают сотни вердиктов за раз;
+// по одному REST-запросу на вердикт — слишком дорого. Пакет принимается целиком,
+// ставится в очередь и разбирается tick_loop не более FEEDBACK_SIGNALS_PER_TICK
+// вердиктов за тик — горячий путь Engine не блокируется.
+//
+// Идемпотентность: пакет идентифицируется ключом клиента. Повторная отправка
+// с тем же ключом и тем же набором вердиктов не ставит их в очередь второй раз,
+// а возвращает текущий статус исходного пакета; другой набор под тем же ключом
+// отклоняется как конфликт.
+//
+// Backpressure: ledger помнит не больше MAX_TRACKED_BATCHES пакетов и
+// вытесняет только разобранные. Если все отслеживаемые пакеты ещё в работе,
+// новый пакет отклоняется — клиент повторяет позже.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+/// Максимум вердиктов в одном пакете.
+pub const MAX_FEEDBACK_BATCH: usize = 1000;
+
+/// Максимальная длина ключа идемпотентности.
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
+
+/// Сколько вердиктов tick_loop применяет за один тик.
+pub const FEEDBACK_SIGNALS_PER_TICK: usize = 64;
+
+/// Сколько последних пакетов ledger помнит для идемпотентности и статуса.
+pub const MAX_TRACKED_BATCHES: usize = 1024;
+
+/// Вердикт по advisory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackVerdict {
+    Confirm,
+    Reject,
+}
+
+/// Один сигнал обратной связи: вердикт по advisory из очереди Arbiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FeedbackSignal {
+    pub advisory_id: u64,
+    pub verdict: FeedbackVerdict,
+}
+
+/// Ошибка валидации пакета (до постановки в очередь).
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedbackBatchError {
+    EmptyKey,
+    KeyTooLong(usize),
+    EmptyBatch,
+    TooLarge(usize),
+    /// Один advisory_id встречается в пакете дважды
+    DuplicateAdvisory(u64),
+}
+
+impl std::fmt::Display for FeedbackBatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedbackBatchError::EmptyKey => write!(f, "idempotency_key is empty"),
+            FeedbackBatchError::KeyTooLong(n) => {
+                write!(f, "idempotency_key length {n} exceeds {MAX_IDEMPOTENCY_KEY_LEN}")
+            }
+            FeedbackBatchError::EmptyBatch => write!(f, "signals is empty"),
+            FeedbackBatchError::TooLarge(n) => {
+                write!(f, "batch size {n} exceeds {MAX_FEEDBACK_BATCH}")
+            }
+            FeedbackBatchError::DuplicateAdvisory(id) => {
+                write!(f, "advisory {id} appears more than once")
+            }
+        }
+    }
+}
+
+/// Отказ в приёме пакета (проверяется tick_loop по состоянию ledger).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackSubmitError {
+    /// Все MAX_TRACKED_BATCHES отслеживаемых пакетов ещё не разобраны
+    Full,
+    /// Ключ уже принят с другим набором вердиктов
+    KeyConflict,
+}
+
+impl std::fmt::Display for FeedbackSubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedbackSubmitError::Full => {
+                write!(f, "{MAX_TRACKED_BATCHES} feedback batches are still in progress")
+            }
+            FeedbackSubmitError::KeyConflict => {
+                write!(f, "idempotency_key was already used with different signals")
+            }
+        }
+    }
+}
+
+/// Структурная проверка пакета — не требует доступа к Engine.
+pub fn validate_batch(key: &str, signals: &[FeedbackSignal]) -> Result<(), FeedbackBatchError> {
+    if key.is_empty() {
+        return Err(FeedbackBatchError::EmptyKey);
+    }
+    if key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+        return Err(FeedbackBatchError::KeyTooLong(key.len()));
+    }
+    if signals.is_empty() {
+        return Err(FeedbackBatchError::EmptyBatch);
+    }
+    if signals.len() > MAX_FEEDBACK_BATCH {
+        return Err(FeedbackBatchError::TooLarge(signals.len()));
+    }
+    let mut seen = HashSet::with_capacity(signals.len());
+    for s in signals {
+        if !seen.insert(s.advisory_id) {
+            return Err(FeedbackBatchError::DuplicateAdvisory(s.advisory_id));
+        }
+    }
+    Ok(())
+}
+
+/// Стадия обработки пакета.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackBatchState {
+    /// В очереди, ни один вердикт ещё не применён
+    Queued,
+    /// Часть вердиктов применена
+    Processing,
+    /// Все вердикты разобраны
+    Done,
+}
+
+/// Статус пакета — ответ на POST и GET /api/feedback/batch.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FeedbackBatchStatus {
+    pub idempotency_key: String,
+    pub state: FeedbackBatchState,
+    pub total: usize,
+    /// Применено (advisory был в очереди Arbiter)
+    pub applied: usize,
+    /// advisory_id, которых не было в очереди Arbiter (неизвестные или уже разобранные)
+    pub unknown: Vec<u64>,
+    /// True если ответ — повтор уже принятого пакета
+    pub replayed: bool,
+}
+
+impl FeedbackBatchStatus {
+    fn processed(&self) -> usize {
+        self.applied + self.unknown.len()
+    }
+}
+
+/// Очередь пакетов + статусы последних MAX_TRACKED_BATCHES пакетов.
+#[derive(Debug, Default)]
+pub struct FeedbackLedger {
+    statuses: HashMap<String, FeedbackBatchStatus>,
+    /// Хеш набора вердиктов пакета — для сверки при повторной отправке
+    hashes: HashMap<String, u64>,
+    /// Порядок приёма — для вытеснения старейших статусов
+    order: VecDeque<String>,
+    /// Неразобранные вердикты: (ключ пакета, сигнал)
+    queue: VecDeque<(String, FeedbackSignal)>,
+}
+
+impl FeedbackLedger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Принять пакет. Пакет с уже известным ключом и тем же набором вердиктов
+    /// в очередь не ставится — возвращается статус исходного пакета с
+    /// `replayed = true`; с другим набором — `KeyConflict`. Если вытеснить
+    /// нечего (все пакеты в работе) — `Full`.
+    pub fn submit(
+        &mut self,
+        key: String,
+        signals: Vec<FeedbackSignal>,
+    ) -> Result<FeedbackBatchStatus, FeedbackSubmitError> {
+        let hash = signals_hash(&signals);
+        if let Some(existing) = self.statuses.get(&key) {
+            if self.hashes.get(&key) != Some(&hash) {
+                return Err(FeedbackSubmitError::KeyConflict);
+            }
+            return Ok(FeedbackBatchStatus { replayed: true, ..existing.clone() });
+        }
+
+        if self.order.len() >= MAX_TRACKED_BATCHES && !self.evict_oldest() {
+            return Err(FeedbackSubmitError::Full);
+        }
+
+        let status = FeedbackBatchStatus {
+            idempotency_key: key.clone(),
+            state: FeedbackBatchState::Queued,
+            total: signals.len(),
+            applied: 0,
+            unknown: Vec::new(),
+            replayed: false,
+        };
+        self.queue.extend(signals.into_iter().map(|s| (key.clone(), s)));
+        self.statuses.insert(key.clone(), status.clone());
+        self.hashes.insert(key.clone(), hash);
+        self.order.push_back(key);
+        Ok(status)
+    }
+
+    /// Статус пакета по ключу.
+    pub fn status(&self, key: &str) -> Option<&FeedbackBatchStatus> {
+        self.statuses.get(key)
+    }
+
+    /// Число неразобранных вердиктов.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Разобрать до `budget` вердиктов. `is_pending` — есть ли advisory в очереди
+    /// Arbiter; `apply` вызывается только для таких. Возвращает число разобранных.
+    pub fn process(
+        &mut self,
+        budget: usize,
+        mut is_pending: impl FnMut(u64) -> bool,
+        mut apply: impl FnMut(&FeedbackSignal),
+    ) -> usize {
+        let mut done = 0;
+        while done < budget {
+            let Some((key, signal)) = self.queue.pop_front() else {
+                break;
+            };
+            done += 1;
+            let known = is_pending(signal.advisory_id);
+            if known {
+                apply(&signal);
+            }
+            // Статус мог быть вытеснен — вердикт всё равно применяется
+            let Some(status) = self.statuses.get_mut(&key) else {
+                continue;
+            };
+            if known {
+                status.applied += 1;
+            } else {
+                status.unknown.push(signal.advisory_id);
+            }
+            status.state = if status.processed() == status.total {
+                FeedbackBatchState::Done
+            } else {
+                FeedbackBatchState::Processing
+            };
+        }
+        done
+    }
+
+    /// Вытеснить старейший разобранный пакет. False — вытеснять нечего.
+    fn evict_oldest(&mut self) -> bool {
+        // Незавершённые пакеты не вытесняются — иначе клиент потеряет статус
+        let pos = self.order.iter().position(|k| {
+            self.statuses
+                .get(k)
+                .is_none_or(|s| s.state == FeedbackBatchState::Done)
+        });
+        let Some(key) = pos.and_then(|p| self.order.remove(p)) else {
+            return false;
+        };
+        self.statuses.remove(&key);
+        self.hashes.remove(&key);
+        true
+    }
+}
+
+/// Хеш набора вердиктов без учёта порядка (advisory_id в пакете уникальны).
+fn signals_hash(signals: &[FeedbackSignal]) -> u64 {
+    let mut sorted = signals.to_vec();
+    sorted.sort_by_key(|s| s.advisory_id);
+    let mut hasher = DefaultHasher::new();
+    sorted.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sig(id: u64, verdict: FeedbackVerdict) -> FeedbackSignal {
+        FeedbackSignal { advisory_id: id, verdict }
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_batches() {
+        let ok = [sig(1, FeedbackVerdict::Confirm)];
+        assert_eq!(validate_batch("", &ok), Err(FeedbackBatchError::EmptyKey));
+        assert_eq!(validate_batch("k", &[]), Err(FeedbackBatchError::EmptyBatch));
+        let dup = [sig(3, FeedbackVerdict::Confirm), sig(3, FeedbackVerdict::Reject)];
+        assert_eq!(validate_batch("k", &dup), Err(FeedbackBatchError::DuplicateAdvisory(3)));
+        assert!(validate_batch("k", &ok).is_ok());
+    }
+
+    #[test]
+    fn test_submit_is_idempotent() {
+        let mut ledger = FeedbackLedger::new();
+        let first = ledger.submit("k".into(), vec![sig(1, FeedbackVerdict::Confirm)]).unwrap();
+        let again = ledger.submit("k".into(), vec![sig(1, FeedbackVerdict::Confirm)]).unwrap();
+        assert!(!first.replayed);
+        assert!(again.replayed);
+        assert_eq!(ledger.queued(), 1);
+    }
+
+    #[test]
+    fn test_submit_same_key_different_signals_conflicts() {
+        let mut ledger = FeedbackLedger::new();
+        let batch = vec![sig(1, FeedbackVerdict::Confirm), sig(2, FeedbackVerdict::Reject)];
+        ledger.submit("k".into(), batch.clone()).unwrap();
+        let reordered = batch.into_iter().rev().collect();
+        assert!(ledger.submit("k".into(), reordered).unwrap().replayed);
+        assert_eq!(
+            ledger.submit("k".into(), vec![sig(1, FeedbackVerdict::Reject)]),
+            Err(FeedbackSubmitError::KeyConflict)
+        );
+        assert_eq!(ledger.queued(), 2);
+    }
+
+    #[test]
+    fn test_submit_rejects_when_nothing_is_done() {
+        let mut ledger = FeedbackLedger::new();
+        for i in 0..MAX_TRACKED_BATCHES as u64 {
+            ledger.submit(format!("k{i}"), vec![sig(i, FeedbackVerdict::Confirm)]).unwrap();
+        }
+        let extra = || vec![sig(0, FeedbackVerdict::Reject)];
+        assert_eq!(ledger.submit("extra".into(), extra()), Err(FeedbackSubmitError::Full));
+        assert_eq!(ledger.queued(), MAX_TRACKED_BATCHES);
+
+        // Разобранный пакет освобождает место
+        ledger.process(1, |_| true, |_| {});
+        assert!(ledger.submit("extra".into(), extra()).is_ok());
+        assert!(ledger.status("k0").is_none());
+    }
+
+    #[test]
+    fn test_process_respects_budget_and_tracks_unknown() {
+        let mut ledger = FeedbackLedger::new();
+        let signals = (1..=5).map(|i| sig(i, FeedbackVerdict::Reject)).collect();
+        ledger.submit("b".into(), signals).unwrap();
+
+        let mut applied = Vec::new();
+        assert_eq!(ledger.process(3, |id| id % 2 == 1, |s| applied.push(s.advisory_id)), 3);
+        assert_eq!(ledger.status("b").unwrap().state, FeedbackBatchState::Processing);
+
+        ledger.process(10, |id| id % 2 == 1, |s| applied.push(s.advisory_id));
+        let status = ledger.status("b").unwrap();
+        assert_eq!(status.state, FeedbackBatchState::Done);
+        assert_eq!(status.applied, 3);
+        assert_eq!(status.unknown, vec![2, 4]);
+        assert_eq!(applied, vec![1, 3, 5]);
+    }
+}
@@ -38,5 +38,9 @@ pub mod telegram;
 pub mod tick_loop;
 /// WebSocket-адаптер (Phase 1)
 pub mod ws;
+/// FeedbackLedger — пакетный приём вердиктов по advisory с идемпотентностью
+pub mod feedback;
+/// SessionContext — контекст разговора по источнику команды
+pub mod session_context;
 /// FileIngester — загрузка .md и .axiom.yaml в UCL команды (INGEST V1.0)
 pub mod ingester;
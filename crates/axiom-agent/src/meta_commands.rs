@@ -120,6 +120,22 @@ pub fn handle_meta_read(
             writeln!(out, "  traces:        {}", traces).unwrap();
             writeln!(out, "  skills:        {}", skills).unwrap();
             writeln!(out, "  tension:       {}", tension).unwrap();
+            let overflow = exp.overflow_stats();
+            writeln!(out, "  overflow:      {}", exp.overflow_policy().name()).unwrap();
+            writeln!(
+                out,
+                "    evicted: {} lowest / {} oldest, rejected: {}",
+                overflow.evicted_lowest_weight, overflow.evicted_oldest, overflow.rejected
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "    spilled: {} ({} queued, {} lost)",
+                overflow.spilled,
+                exp.spilled_len(),
+                overflow.spill_dropped
+            )
+            .unwrap();
             writeln!(out, "  ── cognitive ──────────────────────────").unwrap();
             writeln!(out, "  max_passes:    {}", max_passes).unwrap();
             writeln!(out, "  min_coherence: {:.2}", min_coh).unwrap();
@@ -186,6 +202,12 @@ pub fn handle_meta_read(
             writeln!(out, "  tension_check: {}", s.tension_check_interval).unwrap();
             writeln!(out, "  goal_check:    {}", s.goal_check_interval).unwrap();
             writeln!(out, "  reconcile:     {}", s.reconcile_interval).unwrap();
+            writeln!(out, "  orphan_gc:     {}", s.orphan_gc_interval).unwrap();
+            writeln!(out, "  conn_decay:    {}", s.connection_decay_interval).unwrap();
+            writeln!(out, "  conn_learn:    {}", s.connection_learning_interval).unwrap();
+            writeln!(out, "  conn_ttl:      {}", s.connection_ttl_interval).unwrap();
+            writeln!(out, "  conn_compact:  {}", s.connection_compact_interval).unwrap();
+            writeln!(out, "  conn_prune:    {}", s.connection_prune_interval).unwrap();
         }
 
         ":traces" => {
@@ -799,6 +821,12 @@ pub fn handle_meta_read(
             writeln!(out, "  dream:            {}", s.dream_interval).unwrap();
             writeln!(out, "  horizon_gc:       {}", s.horizon_gc_interval).unwrap();
             writeln!(out, "  reconcile:        {}", s.reconcile_interval).unwrap();
+            writeln!(out, "  orphan_gc:        {}", s.orphan_gc_interval).unwrap();
+            writeln!(out, "  conn_decay:       {}", s.connection_decay_interval).unwrap();
+            writeln!(out, "  conn_learn:       {}", s.connection_learning_interval).unwrap();
+            writeln!(out, "  conn_ttl:         {}", s.connection_ttl_interval).unwrap();
+            writeln!(out, "  conn_compact:     {}", s.connection_compact_interval).unwrap();
+            writeln!(out, "  conn_prune:       {}", s.connection_prune_interval).unwrap();
             writeln!(out, "  persist_check:    {}", s.persist_check_interval).unwrap();
             writeln!(out, "  ── adaptive tick ──────────────────────").unwrap();
             writeln!(out, "  min_hz:           {}", s.adaptive_tick.min_hz).unwrap();
@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// EmbeddingPerceptor — предвычисленный вектор (CLIP и т.п.) → UclCommand(InjectToken).
+//
+// Проекция в семантические координаты — детерминированная знаковая случайная
+// проекция: ось a получает Σ v_i · s(i, a), где s(i, a) ∈ {-1, +1} задаётся
+// FNV-хэшем (i, a). Для L2-нормированного вектора каждая координата ~ N(0, 1),
+// поэтому близкие векторы попадают в близкие точки пространства, а расстояния
+// приблизительно сохраняются (Johnson–Lindenstrauss для k=3). С обученным
+// PcaReducer (`with_reducer`) проекция идёт по трём главным компонентам
+// корпуса эмбеддингов — в тех же единицах σ × scale.
+//
+// Grounding: новый токен связывается EMBEDDING_GROUNDING_BOND с k ближайшими
+// уже существующими токенами SUTRA — так эмбеддинг встраивается в граф,
+// а не висит изолированной точкой.
+
+use super::text::{build_inject_token_command, fnv1a_hash};
+use axiom_core::{Token, FLAG_ACTIVE, TOKEN_FLAG_EMBEDDING};
+use axiom_shell::link_types;
+use axiom_space::{PcaReducer, PositionBatch};
+use axiom_ucl::{BondTokensPayload, OpCode, UclCommand};
+
+/// SUTRA domain_id на уровне 1
+const SUTRA_DOMAIN_ID: u16 = 100;
+
+/// Масштаб проекции по умолчанию: ±3σ ≈ ±24000 — в пределах i16.
+pub const DEFAULT_PROJECTION_SCALE: f32 = 8000.0;
+
+/// Максимальная размерность входного вектора.
+pub const MAX_EMBEDDING_DIM: usize = 4096;
+
+/// Ошибка валидации входного вектора.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmbeddingError {
+    /// Пустой вектор
+    Empty,
+    /// Размерность больше MAX_EMBEDDING_DIM
+    TooLarge(usize),
+    /// NaN / ±inf в компонентах
+    NonFinite,
+    /// Нулевая норма — направление не определено
+    ZeroNorm,
+}
+
+impl std::fmt::Display for EmbeddingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmbeddingError::Empty => write!(f, "embedding vector is empty"),
+            EmbeddingError::TooLarge(n) => {
+                write!(f, "embedding dim {n} exceeds {MAX_EMBEDDING_DIM}")
+            }
+            EmbeddingError::NonFinite => write!(f, "embedding contains NaN or inf"),
+            EmbeddingError::ZeroNorm => write!(f, "embedding has zero norm"),
+        }
+    }
+}
+
+/// Преобразует вектор-эмбеддинг в InjectToken (+ grounding bonds).
+///
+/// Детерминирован: одинаковый вектор → одинаковая позиция и sutra_id.
+pub struct EmbeddingPerceptor {
+    /// Масштаб проекции (квантов на σ)
+    pub scale: f32,
+    /// PCA-проекция вместо случайной; обучается на нормированных векторах
+    pub reducer: Option<PcaReducer>,
+}
+
+impl EmbeddingPerceptor {
+    pub fn new() -> Self {
+        Self { scale: DEFAULT_PROJECTION_SCALE, reducer: None }
+    }
+
+    /// Проецировать через PCA-модель (размерность — как у входных векторов).
+    pub fn with_reducer(mut self, reducer: PcaReducer) -> Self {
+        self.reducer = Some(reducer);
+        self
+    }
+
+    /// Проверить и L2-нормировать вектор.
+    pub fn normalize(vector: &[f32]) -> Result<Vec<f32>, EmbeddingError> {
+        if vector.is_empty() {
+            return Err(EmbeddingError::Empty);
+        }
+        if vector.len() > MAX_EMBEDDING_DIM {
+            return Err(EmbeddingError::TooLarge(vector.len()));
+        }
+        if vector.iter().any(|v| !v.is_finite()) {
+            return Err(EmbeddingError::NonFinite);
+        }
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm <= f32::EPSILON {
+            return Err(EmbeddingError::ZeroNorm);
+        }
+        Ok(vector.iter().map(|v| v / norm).collect())
+    }
+
+    /// Спроецировать нормированный вектор в координаты (x, y, z).
+    /// PCA-модель используется, если её размерность совпадает с вектором.
+    pub fn project(&self, unit: &[f32]) -> [f32; 3] {
+        let reduced = self.reducer.as_ref().and_then(|r| r.to_position(unit, self.scale));
+        if let Some((x, y, z)) = reduced {
+            return [x as f32, y as f32, z as f32];
+        }
+        let mut pos = [0.0f32; 3];
+        for (i, &v) in unit.iter().enumerate() {
+            let bits = fnv1a_hash(&(i as u32).to_le_bytes());
+            for (axis, p) in pos.iter_mut().enumerate() {
+                if bits >> axis & 1 == 0 {
+                    *p += v;
+                } else {
+                    *p -= v;
+                }
+            }
+        }
+        pos.map(|p| (p * self.scale).clamp(i16::MIN as f32, i16::MAX as f32))
+    }
+
+    /// InjectToken в SUTRA для вектора. Возвращает команду и её sutra_id.
+    pub fn perceive(&self, vector: &[f32]) -> Result<(UclCommand, u32), EmbeddingError> {
+        let unit = Self::normalize(vector)?;
+        let pos = self.project(&unit);
+        let stable_id = embedding_stable_id(&unit);
+
+        let mut cmd =
+            build_inject_token_command(SUTRA_DOMAIN_ID, pos[0], pos[1], pos[2], 100.0, 150.0, 0.5);
+        cmd.payload[2] = TOKEN_FLAG_EMBEDDING as u8;
+        cmd.payload[40..44].copy_from_slice(&stable_id.to_le_bytes());
+        Ok((cmd, stable_id))
+    }
+
+    /// InjectToken + BondTokens к `k` ближайшим токенам из `existing` (SUTRA).
+    ///
+    /// Первая команда — всегда InjectToken.
+    pub fn perceive_and_ground(
+        &self,
+        vector: &[f32],
+        existing: &[Token],
+        k: usize,
+    ) -> Result<Vec<UclCommand>, EmbeddingError> {
+        let (inject, stable_id) = self.perceive(vector)?;
+        let pos = self.project(&Self::normalize(vector)?);
+        let query = (pos[0] as i16, pos[1] as i16, pos[2] as i16);
+
+        let mut batch = PositionBatch::with_capacity(existing.len());
+        for t in existing.iter().filter(|t| t.sutra_id != stable_id) {
+            batch.push(t.sutra_id, (t.position[0], t.position[1], t.position[2]));
+        }
+
+        let mut cmds = vec![inject];
+        for (target_id, _) in batch.top_k(query, k) {
+            let bond = BondTokensPayload {
+                source_id: stable_id,
+                target_id,
+                domain_id: SUTRA_DOMAIN_ID,
+                link_type: link_types::EMBEDDING_GROUNDING_BOND,
+                strength: 0.5,
+                conn_flags: FLAG_ACTIVE,
+                origin_domain: SUTRA_DOMAIN_ID,
+                role_id: 0,
+                reserved: [0; 24],
+            };
+            cmds.push(UclCommand::new(OpCode::BondTokens, 0, 10, 0).with_payload(&bond));
+        }
+        Ok(cmds)
+    }
+}
+
+impl Default for EmbeddingPerceptor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Детерминированный sutra_id для embedding-токена.
+///
+/// Диапазон: 0x3000_0001..=0x3FFF_FFFF (бит 29 + бит 28 — маркер эмбеддинга;
+/// vision_anchor_stable_id бит 28 не ставит). Не пересекается с anchor (бит 31),
+/// text (30), vision (29), temporal (28) и domain_position_hash.
+/// Компоненты квантуются до 1e-3 — шум последних разрядов float не меняет ID.
+fn embedding_stable_id(unit: &[f32]) -> u32 {
+    let bytes: Vec<u8> = unit
+        .iter()
+        .flat_map(|v| ((v * 1000.0).round() as i16).to_le_bytes())
+        .collect();
+    let id = (fnv1a_hash(&bytes) & 0x0FFF_FFFF) as u32;
+    (id | 0x3000_0000).max(0x3000_0001) // бит 29 + бит 28
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sutra_token(sutra_id: u32, pos: [i16; 3]) -> Token {
+        Token::new(sutra_id, SUTRA_DOMAIN_ID, pos, 1)
+    }
+
+    #[test]
+    fn test_rejects_invalid_vectors() {
+        let p = EmbeddingPerceptor::new();
+        assert_eq!(p.perceive(&[]).unwrap_err(), EmbeddingError::Empty);
+        assert_eq!(p.perceive(&[0.0, 0.0]).unwrap_err(), EmbeddingError::ZeroNorm);
+        assert_eq!(p.perceive(&[f32::NAN]).unwrap_err(), EmbeddingError::NonFinite);
+    }
+
+    #[test]
+    fn test_deterministic_and_scale_invariant() {
+        let p = EmbeddingPerceptor::new();
+        let v: Vec<f32> = (0..512).map(|i| ((i * 37 % 11) as f32) - 5.0).collect();
+        let scaled: Vec<f32> = v.iter().map(|x| x * 3.0).collect();
+        let (a, id_a) = p.perceive(&v).unwrap();
+        let (b, id_b) = p.perceive(&scaled).unwrap();
+        assert_eq!(a.payload, b.payload);
+        assert_eq!(id_a, id_b);
+        assert!((0x3000_0001..=0x3FFF_FFFF).contains(&id_a));
+    }
+
+    #[test]
+    fn test_close_vectors_project_close() {
+        let p = EmbeddingPerceptor::new();
+        let v: Vec<f32> = (0..256).map(|i| (i as f32 * 0.1).sin()).collect();
+        let mut near = v.clone();
+        near[0] += 0.01;
+        let far: Vec<f32> = (0..256).map(|i| (i as f32 * 0.7).cos()).collect();
+        let d = |a: [f32; 3], b: [f32; 3]| (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f32>();
+        let pv = p.project(&EmbeddingPerceptor::normalize(&v).unwrap());
+        let pn = p.project(&EmbeddingPerceptor::normalize(&near).unwrap());
+        let pf = p.project(&EmbeddingPerceptor::normalize(&far).unwrap());
+        assert!(d(pv, pn) < d(pv, pf));
+    }
+
+    #[test]
+    fn test_inject_carries_embedding_flag() {
+        let (cmd, _) = EmbeddingPerceptor::new().perceive(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(cmd.opcode, OpCode::InjectToken as u16);
+        assert_eq!(cmd.payload[2] as u16, TOKEN_FLAG_EMBEDDING);
+    }
+
+    #[test]
+    fn test_reducer_replaces_random_projection() {
+        // Корпус вытянут вдоль оси 0, второе направление — ось 1
+        let corpus: Vec<Vec<f32>> = (0..40)
+            .map(|i| {
+                let t = i as f32 / 40.0 - 0.5;
+                EmbeddingPerceptor::normalize(&[1.0, t, 0.1 * (i % 3) as f32, 0.5]).unwrap()
+            })
+            .collect();
+        let reducer = PcaReducer::fit(&corpus).unwrap();
+        let p = EmbeddingPerceptor::new().with_reducer(reducer.clone());
+
+        let unit = &corpus[5];
+        let (x, y, z) = reducer.to_position(unit, DEFAULT_PROJECTION_SCALE).unwrap();
+        assert_eq!(p.project(unit), [x as f32, y as f32, z as f32]);
+        // Другая размерность — прежняя случайная проекция
+        let other = EmbeddingPerceptor::normalize(&[1.0, 2.0]).unwrap();
+        assert_eq!(p.project(&other), EmbeddingPerceptor::new().project(&other));
+    }
+
+    #[test]
+    fn test_ground_bonds_to_nearest() {
+        let p = EmbeddingPerceptor::new();
+        let v = [1.0, 0.5, -0.25, 0.125];
+        let pos = p.project(&EmbeddingPerceptor::normalize(&v).unwrap());
+        let at = |dx: i16| [pos[0] as i16 + dx, pos[1] as i16, pos[2] as i16];
+        let existing = [
+            sutra_token(7, at(10)),
+            sutra_token(8, at(5000)),
+            sutra_token(9, at(-3)),
+        ];
+        let cmds = p.perceive_and_ground(&v, &existing, 2).unwrap();
+        assert_eq!(cmds.len(), 3);
+        let targets: Vec<u32> = cmds[1..]
+            .iter()
+            .map(|c| c.get_payload::<BondTokensPayload>().target_id)
+            .collect();
+        assert_eq!(targets, vec![9, 7]);
+    }
+}
@@ -1,5 +1,9 @@
 /// TextPerceptor — преобразует строку UTF-8 в UclCommand(InjectToken)
 pub mod text;
+/// TextPipeline — предобработка текста до anchor-матчинга (по источнику команды)
+pub mod preprocess;
+/// EmbeddingPerceptor — вектор-эмбеддинг → проекция в SUTRA + kNN grounding
+pub mod embedding;
 /// Символьный/словарный матчинг текста к якорным примитивам (E1 путь А)
 pub mod anchor_match;
 /// Таблица декомпозиции: char/word → якорный ID
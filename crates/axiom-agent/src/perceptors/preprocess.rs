@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// TextPipeline — предобработка текста перед TextPerceptor.
+//
+// Сырой ввод от адаптеров («Привет,   мир!!» / «привет мир») даёт разные
+// stable_id и разные позиции — граф засоряется почти-дубликатами.
+// Pipeline нормализует текст до того, как он попадёт в anchor-матчинг.
+// Пустой pipeline — тождественное преобразование (поведение по умолчанию).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Одна стадия предобработки.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum TextStage {
+    /// Перевести в нижний регистр (Unicode-aware)
+    Lowercase,
+    /// Заменить знаки пунктуации и символы пробелом (буквы, цифры и '!'/'?' сохраняются —
+    /// TextPerceptor использует их для temperature)
+    StripPunctuation,
+    /// Удалить слова из списка (сравнение без учёта регистра)
+    Stopwords { words: Vec<String> },
+    /// Обрезать края и схлопнуть пробельные последовательности в один пробел
+    CollapseWhitespace,
+}
+
+impl TextStage {
+    fn apply(&self, text: &str) -> String {
+        match self {
+            TextStage::Lowercase => text.to_lowercase(),
+            TextStage::StripPunctuation => text
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || c.is_whitespace() || c == '!' || c == '?' {
+                        c
+                    } else {
+                        ' '
+                    }
+                })
+                .collect(),
+            TextStage::Stopwords { words } => text
+                .split_whitespace()
+                .filter(|w| !words.iter().any(|s| s.to_lowercase() == w.to_lowercase()))
+                .collect::<Vec<_>>()
+                .join(" "),
+            TextStage::CollapseWhitespace => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        }
+    }
+}
+
+/// Упорядоченный набор стадий предобработки.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TextPipeline {
+    #[serde(default)]
+    pub stages: Vec<TextStage>,
+}
+
+impl TextPipeline {
+    /// Стандартная нормализация: lowercase → пунктуация → пробелы.
+    pub fn normalize() -> Self {
+        Self {
+            stages: vec![
+                TextStage::Lowercase,
+                TextStage::StripPunctuation,
+                TextStage::CollapseWhitespace,
+            ],
+        }
+    }
+
+    /// True если pipeline не меняет текст.
+    pub fn is_identity(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Прогнать текст через все стадии по порядку.
+    pub fn apply(&self, text: &str) -> String {
+        self.stages
+            .iter()
+            .fold(text.to_string(), |acc, stage| stage.apply(&acc))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_pipeline_is_identity() {
+        let p = TextPipeline::default();
+        assert!(p.is_identity());
+        assert_eq!(p.apply("  Hello,  World "), "  Hello,  World ");
+    }
+
+    #[test]
+    fn test_normalize_merges_near_duplicates() {
+        let p = TextPipeline::normalize();
+        assert_eq!(p.apply("Привет,   МИР"), p.apply("привет мир"));
+        assert_eq!(p.apply("Hello... world"), "hello world");
+    }
+
+    #[test]
+    fn test_strip_keeps_temperature_markers() {
+        let p = TextPipeline::normalize();
+        assert_eq!(p.apply("Why?! (now)"), "why?! now");
+    }
+
+    #[test]
+    fn test_stopwords_case_insensitive() {
+        let p = TextPipeline {
+            stages: vec![TextStage::Stopwords { words: vec!["the".into(), "A".into()] }],
+        };
+        assert_eq!(p.apply("The cat saw a dog"), "cat saw dog");
+    }
+
+    #[test]
+    fn test_pipeline_yaml_roundtrip() {
+        let yaml = "stages:\n  - stage: lowercase\n  - stage: stopwords\n    words: [и, в]\n";
+        let p: TextPipeline = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(p.stages.len(), 2);
+        assert_eq!(p.apply("Кот И пёс"), "кот пёс");
+    }
+}
@@ -128,7 +128,8 @@ fn build_inject_cmd(anchor: &Anchor) -> UclCommand {
 ///   - sequential event_ids (малые значения)
 ///   - domain_position_hash (0x0001..0x0FFF_FFFF, 28 бит)
 ///   - temporal_anchor_stable_id (бит 28: 0x1000_0001..0x1FFF_FFFF)  ← этот диапазон
-///   - vision_anchor_stable_id  (бит 29: 0x2000_0001..0x3FFF_FFFF)
+///   - vision_anchor_stable_id  (бит 29: 0x2000_0001..0x2FFF_FFFF)
+///   - embedding_stable_id      (биты 29 + 28: 0x3000_0001..0x3FFF_FFFF)
 ///   - text_stable_id           (бит 30: 0x4000_0001..0x7FFF_FFFF)
 ///   - anchor_sutra_id          (бит 31: 0x8000_0001..0xFFFF_FFFF)
 pub fn temporal_anchor_stable_id(anchor_id: &str) -> u32 {
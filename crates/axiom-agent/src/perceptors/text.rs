@@ -222,7 +222,7 @@ impl Default for TextPerceptor {
 }
 
 /// Собрать UclCommand(InjectToken) — зеркало parse_inject_token_payload() в engine.rs.
-fn build_inject_token_command(
+pub(super) fn build_inject_token_command(
     target_domain_id: u16,
     x: f32,
     y: f32,
@@ -283,7 +283,7 @@ fn anchor_sutra_id(id: &str) -> u32 {
 }
 
 /// FNV-1a 64-bit hash (детерминированный, без внешних зависимостей).
-fn fnv1a_hash(bytes: &[u8]) -> u64 {
+pub(super) fn fnv1a_hash(bytes: &[u8]) -> u64 {
     let mut h: u64 = 0xcbf29ce484222325;
     for &b in bytes {
         h ^= b as u64;
@@ -277,10 +277,11 @@ fn anchor_to_ucl_command(anchor: &Anchor, semantic_weight: f32) -> UclCommand {
 
 /// Детерминированный sutra_id для L0 визуального якоря.
 ///
-/// Диапазон: `0x2000_0001..=0x3FFF_FFFF` (бит 29 установлен, бит 30 и 31 сброшены).
+/// Диапазон: `0x2000_0001..=0x2FFF_FFFF` (бит 29 установлен, биты 28, 30 и 31 сброшены).
 /// Гарантированно не пересекается с:
 ///   - sequential event_ids (малые значения 1, 2, 3, ...)
 ///   - domain_position_hash (0x0001..0x0FFF_FFFF, 28 бит)
+///   - embedding_stable_id (биты 29 + 28: 0x3000_0001..0x3FFF_FFFF)
 ///   - text_stable_id (бит 30: 0x4000_0001..0x7FFF_FFFF)
 ///   - anchor_sutra_id (бит 31: 0x8000_0001..0xFFFF_FFFF)
 pub fn vision_anchor_stable_id(anchor_id: &str) -> u32 {
@@ -290,7 +291,7 @@ pub fn vision_anchor_stable_id(anchor_id: &str) -> u32 {
         h ^= byte as u64;
         h = h.wrapping_mul(0x100000001b3);
     }
-    let id = (h as u32) & 0x1FFF_FFFF; // 29 бит без старшего
+    let id = (h as u32) & 0x0FFF_FFFF; // 28 бит: бит 28 — маркер эмбеддинга
     (id | 0x2000_0000).max(0x2000_0001) // бит 29 установлен, не ноль
 }
 
@@ -429,7 +430,7 @@ mod tests {
         for anchor in &["visual_edge", "visual_stroke_horizontal", "visual_stroke_vertical", "visual_stroke_diagonal"] {
             let id = vision_anchor_stable_id(anchor);
             assert!(id >= 0x2000_0001, "id={id:#010x} should be >= 0x2000_0001");
-            assert!(id <= 0x3FFF_FFFF, "id={id:#010x} should be <= 0x3FFF_FFFF");
+            assert!(id <= 0x2FFF_FFFF, "id={id:#010x} should be <= 0x2FFF_FFFF");
         }
     }
 
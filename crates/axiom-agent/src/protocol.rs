@@ -4,6 +4,7 @@
 // Протокол обмена между tick_loop и адаптерами (CLI, WebSocket, REST, Telegram).
 // Полный WebSocket protocol (ClientMessage, serde tag и пр.) — Phase 1.
 
+use crate::feedback::{FeedbackBatchStatus, FeedbackSubmitError};
 use axiom_runtime::{DomainDetailSnapshot, over_domain::SensoriumState};
 use serde::Serialize;
 
@@ -63,6 +64,21 @@ pub enum ServerMessage {
     #[serde(rename = "domain_detail")]
     DomainDetail(DomainDetailSnapshot),
 
+    /// Статус пакета обратной связи (FeedbackBatch / FeedbackStatus).
+    #[serde(rename = "feedback_batch")]
+    FeedbackBatch {
+        command_id: String,
+        status: FeedbackBatchStatus,
+    },
+
+    /// Пакет обратной связи не принят (ledger заполнен или конфликт ключа).
+    #[serde(rename = "feedback_rejected")]
+    FeedbackRejected {
+        command_id: String,
+        reason: FeedbackSubmitError,
+        message: String,
+    },
+
     /// Ошибка обработки команды.
     #[serde(rename = "error")]
     Error {
@@ -4,7 +4,7 @@ use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, Json, Response},
     routing::{get, post},
@@ -14,6 +14,8 @@ use serde::Deserialize;
 use tokio::sync::broadcast;
 
 use crate::adapter_command::{AdapterCommand, AdapterPayload, AdapterSource};
+use crate::feedback::{validate_batch, FeedbackSignal, FeedbackSubmitError};
+use crate::perceptors::embedding::EmbeddingPerceptor;
 use crate::protocol::ServerMessage;
 use crate::ws::AppState;
 
@@ -25,7 +27,14 @@ pub fn rest_routes() -> Router<AppState> {
         .route("/api/status", get(get_status))
         .route("/api/domains", get(get_domains))
         .route("/api/domain/{id}", get(get_domain))
+        .route("/api/domain/{id}/centrality", get(get_centrality))
+        .route("/api/domain/{id}/query", get(get_graph_query))
         .route("/api/inject", post(post_inject))
+        .route("/api/embed", post(post_embed))
+        .route("/api/feedback/batch", post(post_feedback_batch))
+        .route("/api/feedback/batch/{key}", get(get_feedback_batch))
+        .route("/api/analytics/advisory", get(get_advisory_analytics))
+        .route("/api/modules", get(get_modules))
         .route("/api/command", post(post_command))
 }
 
@@ -147,6 +156,256 @@ async fn post_inject(
     }
 }
 
+// ── POST /api/embed ───────────────────────────────────────────────────────────
+
+/// Число grounding-связей по умолчанию для /api/embed.
+const DEFAULT_EMBED_K: usize = 4;
+/// Верхняя граница `k`: больший запрос урезается до неё.
+const MAX_EMBED_K: usize = 64;
+
+fn default_embed_k() -> usize {
+    DEFAULT_EMBED_K
+}
+
+#[derive(Deserialize)]
+struct EmbedBody {
+    vector: Vec<f32>,
+    #[serde(default = "default_embed_k")]
+    k: usize,
+}
+
+async fn post_embed(
+    State(state): State<AppState>,
+    body: Result<Json<EmbedBody>, axum::extract::rejection::JsonRejection>,
+) -> Response {
+    let Json(body) = match body {
+        Ok(b) => b,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+    if let Err(e) = EmbeddingPerceptor::normalize(&body.vector) {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
+    let payload = AdapterPayload::Embedding {
+        vector: body.vector,
+        k: body.k.min(MAX_EMBED_K),
+    };
+    match send_and_wait(&state, payload).await {
+        Some(msg @ ServerMessage::Error { .. }) => (StatusCode::BAD_REQUEST, Json(msg)).into_response(),
+        Some(msg) => (StatusCode::OK, Json(msg)).into_response(),
+        None => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+// ── POST /api/feedback/batch, GET /api/feedback/batch/:key ──────────────────
+
+#[derive(Deserialize)]
+struct FeedbackBatchBody {
+    idempotency_key: String,
+    signals: Vec<FeedbackSignal>,
+}
+
+/// Принять пакет вердиктов. 202 — новый пакет поставлен в очередь,
+/// 200 — пакет с этим ключом уже принят ранее (возвращается его статус),
+/// 409 — ключ уже принят с другими вердиктами, 429 — ledger заполнен
+/// неразобранными пакетами, повторить позже.
+async fn post_feedback_batch(
+    State(state): State<AppState>,
+    body: Result<Json<FeedbackBatchBody>, axum::extract::rejection::JsonRejection>,
+) -> Response {
+    let Json(body) = match body {
+        Ok(b) => b,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+    if let Err(e) = validate_batch(&body.idempotency_key, &body.signals) {
+        return (StatusCode::BAD_REQUEST, e.to_string()).into_response();
+    }
+
+    let payload = AdapterPayload::FeedbackBatch {
+        key: body.idempotency_key,
+        signals: body.signals,
+    };
+    match send_and_wait(&state, payload).await {
+        Some(msg @ ServerMessage::FeedbackBatch { .. }) => {
+            let code = match &msg {
+                ServerMessage::FeedbackBatch { status, .. } if status.replayed => StatusCode::OK,
+                _ => StatusCode::ACCEPTED,
+            };
+            (code, Json(msg)).into_response()
+        }
+        Some(msg @ ServerMessage::FeedbackRejected { reason, .. }) => {
+            let code = match reason {
+                FeedbackSubmitError::Full => StatusCode::TOO_MANY_REQUESTS,
+                FeedbackSubmitError::KeyConflict => StatusCode::CONFLICT,
+            };
+            (code, Json(msg)).into_response()
+        }
+        Some(msg) => (StatusCode::INTERNAL_SERVER_ERROR, Json(msg)).into_response(),
+        None => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+async fn get_feedback_batch(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+) -> Response {
+    match send_and_wait(&state, AdapterPayload::FeedbackStatus { key }).await {
+        Some(msg @ ServerMessage::FeedbackBatch { .. }) => (StatusCode::OK, Json(msg)).into_response(),
+        Some(msg) => (StatusCode::NOT_FOUND, Json(msg)).into_response(),
+        None => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+// ── GET /api/analytics/advisory ───────────────────────────────────────────────
+
+/// Длина цикла сводки по умолчанию (event_id).
+const DEFAULT_ANALYTICS_WINDOW: u64 = 1000;
+
+#[derive(Deserialize)]
+struct AnalyticsQuery {
+    window: Option<u64>,
+}
+
+/// Сводки потока advisory (source → решение Arbiter → исход) по циклам,
+/// по одному JSON-объекту на строку.
+///
+/// Строятся по ArbiterLog — последним 500 решениям в памяти. Более старые
+/// циклы и история до перезапуска не сохраняются (ARB-TD-06): для длинных
+/// рядов клиент должен сам накапливать выгрузки.
+async fn get_advisory_analytics(
+    State(state): State<AppState>,
+    Query(q): Query<AnalyticsQuery>,
+) -> Response {
+    let window = q.window.unwrap_or(DEFAULT_ANALYTICS_WINDOW);
+    match send_and_wait(&state, AdapterPayload::AdvisoryAnalytics { window }).await {
+        Some(ServerMessage::CommandResult { output, .. }) => (
+            StatusCode::OK,
+            [(axum::http::header::CONTENT_TYPE, "application/x-ndjson")],
+            output,
+        )
+            .into_response(),
+        _ => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+// ── GET /api/modules ──────────────────────────────────────────────────────────
+
+/// Over-Domain компоненты Engine: имя, ModuleId, интервал тика,
+/// права доступа и протокольные маршруты из GENOME.
+async fn get_modules(State(state): State<AppState>) -> Response {
+    match send_and_wait(&state, AdapterPayload::Modules).await {
+        Some(ServerMessage::CommandResult { output, .. }) => (
+            StatusCode::OK,
+            [(axum::http::header::CONTENT_TYPE, "application/json")],
+            output,
+        )
+            .into_response(),
+        _ => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+// ── GET /api/domain/:id/centrality ────────────────────────────────────────────
+
+/// Число токенов в ответе по умолчанию.
+const DEFAULT_CENTRALITY_TOP: usize = 20;
+
+#[derive(Deserialize)]
+struct CentralityQuery {
+    top: Option<usize>,
+}
+
+/// Токены домена по убыванию PageRank: ранг, betweenness, степени.
+async fn get_centrality(
+    Path(id): Path<u16>,
+    State(state): State<AppState>,
+    Query(q): Query<CentralityQuery>,
+) -> Response {
+    let top = q.top.unwrap_or(DEFAULT_CENTRALITY_TOP);
+    match send_and_wait(&state, AdapterPayload::Centrality { domain_id: id, top }).await {
+        Some(ServerMessage::CommandResult { output, .. }) => (
+            StatusCode::OK,
+            [(axum::http::header::CONTENT_TYPE, "application/json")],
+            output,
+        )
+            .into_response(),
+        Some(msg @ ServerMessage::Error { .. }) => {
+            (StatusCode::NOT_FOUND, Json(msg)).into_response()
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+// ── GET /api/domain/:id/query ─────────────────────────────────────────────────
+
+#[derive(Deserialize)]
+struct GraphQueryParams {
+    q: String,
+}
+
+/// Декларативный запрос к графу домена (`q=near=5 hops=2 ...`) → JSON-массив sutra_id.
+/// 400 — запрос не разобран или домен не найден.
+async fn get_graph_query(
+    Path(id): Path<u16>,
+    State(state): State<AppState>,
+    Query(params): Query<GraphQueryParams>,
+) -> Response {
+    let payload = AdapterPayload::GraphQuery { domain_id: id, query: params.q };
+    match send_and_wait(&state, payload).await {
+        Some(ServerMessage::CommandResult { output, .. }) => (
+            StatusCode::OK,
+            [(axum::http::header::CONTENT_TYPE, "application/json")],
+            output,
+        )
+            .into_response(),
+        Some(msg @ ServerMessage::Error { .. }) => {
+            (StatusCode::BAD_REQUEST, Json(msg)).into_response()
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// Отправить команду в tick_loop и дождаться прямого ответа с тем же id
+/// (Result / FeedbackBatch / CommandResult / Error).
+async fn send_and_wait(state: &AppState, payload: AdapterPayload) -> Option<ServerMessage> {
+    let req_id = format!("rest{}", state.next_conn_id.fetch_add(1, Ordering::Relaxed));
+    let mut rx = state.broadcast_tx.subscribe();
+
+    state
+        .command_tx
+        .send(AdapterCommand {
+            id: req_id.clone(),
+            source: AdapterSource::Rest,
+            payload,
+            priority: axiom_runtime::GatewayPriority::Normal,
+        })
+        .await
+        .ok()?;
+
+    wait_for(Duration::from_secs(5), async move {
+        loop {
+            match rx.recv().await {
+                Ok(msg) => match msg {
+                    ServerMessage::Result { ref command_id, .. }
+                    | ServerMessage::FeedbackBatch { ref command_id, .. }
+                    | ServerMessage::FeedbackRejected { ref command_id, .. }
+                    | ServerMessage::CommandResult { ref command_id, .. }
+                        if *command_id == req_id =>
+                    {
+                        return Some(msg);
+                    }
+                    ServerMessage::Error { command_id: Some(ref c), .. } if *c == req_id => {
+                        return Some(msg);
+                    }
+                    _ => {}
+                },
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+    .await
+}
+
 // ── POST /api/command ─────────────────────────────────────────────────────────
 
 #[derive(Deserialize)]
@@ -7,8 +7,15 @@
 // GET  /api/status          — snapshot (не блокирует Engine)
 // GET  /api/domains         — список доменов из snapshot
 // GET  /api/domain/:id      — детали домена (correlation id через broadcast)
+// GET  /api/domain/:id/centrality — токены по убыванию PageRank (betweenness, степени)
+// GET  /api/domain/:id/query?q=... — декларативный запрос к графу → sutra_id
 // POST /api/inject          — инъекция текста, ждёт ServerMessage::Result
+// POST /api/embed           — вектор-эмбеддинг {vector, k}, ждёт ServerMessage::Result
 // POST /api/command         — мета-команда (:status, :save и т.д.), ждёт CommandResult
+// POST /api/feedback/batch  — пакет вердиктов по advisory (идемпотентно по ключу), 202
+// GET  /api/feedback/batch/:key — статус пакета
+// GET  /api/analytics/advisory?window=N — сводки потока advisory (JSON lines)
+// GET  /api/modules         — Over-Domain компоненты: ModuleId, интервал, права, маршруты
 
 mod handlers;
 
@@ -0,0 +1,332 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// SessionContext — контекст разговора по источнику команды.
+//
+// Без контекста каждая реплика обрабатывается изолированно: «он», «это»,
+// «а что дальше?» не связываются с предыдущей репликой того же собеседника.
+// SessionContext хранит недавние токены источника с затухающим весом и:
+//   - смещает позицию нового InjectToken к взвешенному центру контекста
+//     (близкие позиции → соседние токены при lookup и резонансе);
+//   - связывает новый токен CONTEXT_CONTINUITY_BOND с активными токенами контекста.
+//
+// Реплика без совпавших якорей (местоимение, короткое уточнение) — «неразрешённая
+// ссылка»: смещение для неё удваивается, смысл берётся из контекста.
+//
+// Контекст живёт, пока жив разговор: WebSocket-соединение забывает его при
+// закрытии (`SessionContexts::forget`). REST не держит сессию — каждый запрос
+// обрабатывается без контекста, иначе реплики разных клиентов смешивались бы.
+
+use crate::adapter_command::AdapterSource;
+use axiom_core::FLAG_ACTIVE;
+use axiom_shell::link_types;
+use axiom_ucl::{BondTokensPayload, InjectTokenPayload, OpCode, UclCommand};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// Вес, ниже которого запись выпадает из контекста.
+const MIN_CONTEXT_WEIGHT: f32 = 0.05;
+
+/// Параметры контекста разговора.
+///
+/// Секция `session_context:` в axiom-cli.yaml; отсутствующие поля — значения по умолчанию.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SessionContextConfig {
+    /// false → реплики обрабатываются изолированно (поведение по умолчанию)
+    pub enabled: bool,
+    /// Сколько последних токенов помнит контекст источника
+    pub capacity: usize,
+    /// Множитель веса записи на каждую новую реплику (0..1)
+    pub decay: f32,
+    /// Доля смещения позиции к центру контекста (0..1)
+    pub position_bias: f32,
+    /// Strength связи с записью веса 1.0
+    pub bond_strength: f32,
+}
+
+impl Default for SessionContextConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capacity: 8,
+            decay: 0.7,
+            position_bias: 0.2,
+            bond_strength: 0.3,
+        }
+    }
+}
+
+/// Один токен в контексте.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContextEntry {
+    pub sutra_id: u32,
+    pub position: [f32; 3],
+    pub weight: f32,
+}
+
+/// Контекст одного источника.
+#[derive(Debug, Clone, Default)]
+pub struct SessionContext {
+    entries: VecDeque<ContextEntry>,
+}
+
+impl SessionContext {
+    pub fn entries(&self) -> impl Iterator<Item = &ContextEntry> {
+        self.entries.iter()
+    }
+
+    /// Токены разговора — область для `Experience::sample_session`.
+    pub fn sutra_ids(&self) -> Vec<u32> {
+        self.entries.iter().map(|e| e.sutra_id).collect()
+    }
+
+    /// Взвешенный центр контекста и суммарный вес. None если контекст пуст.
+    pub fn centroid(&self) -> Option<([f32; 3], f32)> {
+        let total: f32 = self.entries.iter().map(|e| e.weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut c = [0.0f32; 3];
+        for e in &self.entries {
+            for (ci, pi) in c.iter_mut().zip(e.position) {
+                *ci += pi * e.weight / total;
+            }
+        }
+        Some((c, total))
+    }
+
+    /// Состарить записи и добавить новый токен с весом 1.0.
+    pub fn observe(&mut self, sutra_id: u32, position: [f32; 3], cfg: &SessionContextConfig) {
+        for e in self.entries.iter_mut() {
+            e.weight *= cfg.decay;
+        }
+        self.entries
+            .retain(|e| e.weight >= MIN_CONTEXT_WEIGHT && e.sutra_id != sutra_id);
+        self.entries.push_back(ContextEntry { sutra_id, position, weight: 1.0 });
+        while self.entries.len() > cfg.capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+/// Ключ контекста: для WebSocket и Telegram — конкретное соединение/чат.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum SourceKey {
+    Cli,
+    WebSocket(u64),
+    Telegram(i64),
+}
+
+impl SourceKey {
+    /// None — источник без сессии (REST): контекст не хранится.
+    fn of(source: &AdapterSource) -> Option<Self> {
+        match source {
+            AdapterSource::Cli => Some(SourceKey::Cli),
+            AdapterSource::WebSocket(id) => Some(SourceKey::WebSocket(*id)),
+            AdapterSource::Rest => None,
+            AdapterSource::Telegram(chat) => Some(SourceKey::Telegram(*chat)),
+        }
+    }
+}
+
+/// Контексты всех источников.
+#[derive(Debug, Default)]
+pub struct SessionContexts {
+    contexts: HashMap<SourceKey, SessionContext>,
+}
+
+impl SessionContexts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Контекст источника (None если источник ещё ничего не присылал).
+    pub fn get(&self, source: &AdapterSource) -> Option<&SessionContext> {
+        self.contexts.get(&SourceKey::of(source)?)
+    }
+
+    /// Забыть контекст источника (разговор окончен). True если он был.
+    pub fn forget(&mut self, source: &AdapterSource) -> bool {
+        SourceKey::of(source).is_some_and(|key| self.contexts.remove(&key).is_some())
+    }
+
+    /// Число источников с контекстом.
+    pub fn len(&self) -> usize {
+        self.contexts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contexts.is_empty()
+    }
+
+    /// Применить контекст к командам `perceive_and_bond`: первая команда —
+    /// InjectToken, остальные — связи с якорями. Смещает позицию InjectToken,
+    /// добавляет CONTEXT_CONTINUITY_BOND к токенам контекста и запоминает
+    /// новый токен. Связи создаются в целевом домене InjectToken. Без sutra_id
+    /// в payload (legacy perceive) и для источника без сессии команды не меняются.
+    pub fn contextualize(
+        &mut self,
+        source: &AdapterSource,
+        cmds: &mut Vec<UclCommand>,
+        cfg: &SessionContextConfig,
+    ) {
+        if !cfg.enabled {
+            return;
+        }
+        let Some(key) = SourceKey::of(source) else {
+            return;
+        };
+        let Some(inject) = cmds.first_mut() else {
+            return;
+        };
+        if inject.opcode != OpCode::InjectToken as u16 {
+            return;
+        }
+        let mut payload = inject.get_payload::<InjectTokenPayload>();
+        // reserved[0..4] — proposed_sutra_id
+        let [a, b, c, d, ..] = payload.reserved;
+        let sutra_id = u32::from_le_bytes([a, b, c, d]);
+        if sutra_id == 0 {
+            return;
+        }
+        let domain_id = payload.target_domain_id;
+
+        let ctx = self.contexts.entry(key).or_default();
+        let unresolved = cmds.len() == 1;
+        if let Some((center, total)) = ctx.centroid() {
+            let k = if unresolved { cfg.position_bias * 2.0 } else { cfg.position_bias };
+            let k = (k * total.min(1.0)).clamp(0.0, 1.0);
+            for (p, c) in payload.position.iter_mut().zip(center) {
+                *p += (c - *p) * k;
+            }
+            cmds[0] = cmds[0].with_payload(&payload);
+        }
+        let pos = payload.position;
+
+        let bonds: Vec<UclCommand> = ctx
+            .entries()
+            .filter(|e| e.sutra_id != sutra_id)
+            .map(|e| {
+                let bond = BondTokensPayload {
+                    source_id: sutra_id,
+                    target_id: e.sutra_id,
+                    domain_id,
+                    link_type: link_types::CONTEXT_CONTINUITY_BOND,
+                    strength: cfg.bond_strength * e.weight,
+                    conn_flags: FLAG_ACTIVE,
+                    origin_domain: domain_id,
+                    role_id: 0,
+                    reserved: [0; 24],
+                };
+                UclCommand::new(OpCode::BondTokens, 0, 10, 0).with_payload(&bond)
+            })
+            .collect();
+        cmds.extend(bonds);
+
+        ctx.observe(sutra_id, pos, cfg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> SessionContextConfig {
+        SessionContextConfig { enabled: true, ..Default::default() }
+    }
+
+    fn inject(sutra_id: u32, pos: [f32; 3]) -> UclCommand {
+        let mut reserved = [0u8; 6];
+        reserved[..4].copy_from_slice(&sutra_id.to_le_bytes());
+        let payload = InjectTokenPayload {
+            target_domain_id: 100,
+            token_type: 0,
+            mass: 1.0,
+            position: pos,
+            velocity: [0.0; 3],
+            semantic_weight: 0.5,
+            temperature: 0.0,
+            reserved,
+        };
+        UclCommand::new(OpCode::InjectToken, 100, 100, 0).with_payload(&payload)
+    }
+
+    fn x_of(cmd: &UclCommand) -> f32 {
+        cmd.get_payload::<InjectTokenPayload>().position[0]
+    }
+
+    #[test]
+    fn test_disabled_leaves_commands_untouched() {
+        let mut ctxs = SessionContexts::new();
+        let mut cmds = vec![inject(1, [100.0, 0.0, 0.0])];
+        ctxs.contextualize(&AdapterSource::Cli, &mut cmds, &SessionContextConfig::default());
+        assert_eq!(cmds.len(), 1);
+        assert!(ctxs.get(&AdapterSource::Cli).is_none());
+    }
+
+    #[test]
+    fn test_second_utterance_is_pulled_and_bonded() {
+        let cfg = enabled();
+        let mut ctxs = SessionContexts::new();
+        let mut first = vec![inject(1, [1000.0, 0.0, 0.0])];
+        ctxs.contextualize(&AdapterSource::Cli, &mut first, &cfg);
+
+        let mut second = vec![inject(2, [0.0, 0.0, 0.0])];
+        ctxs.contextualize(&AdapterSource::Cli, &mut second, &cfg);
+        // Неразрешённая ссылка (нет якорных связей) → двойное смещение
+        assert!((x_of(&second[0]) - 400.0).abs() < 1e-3);
+        assert_eq!(second.len(), 2);
+        let bond = second[1].get_payload::<BondTokensPayload>();
+        assert_eq!((bond.source_id, bond.target_id), (2, 1));
+        assert_eq!(bond.link_type, link_types::CONTEXT_CONTINUITY_BOND);
+        assert_eq!((bond.domain_id, bond.origin_domain), (100, 100));
+        // Прочие поля InjectToken не тронуты
+        let payload = second[0].get_payload::<InjectTokenPayload>();
+        assert_eq!((payload.target_domain_id, payload.mass), (100, 1.0));
+        assert_eq!(payload.reserved[..4], 2u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_contexts_are_per_source() {
+        let cfg = enabled();
+        let mut ctxs = SessionContexts::new();
+        ctxs.contextualize(&AdapterSource::WebSocket(1), &mut vec![inject(1, [500.0; 3])], &cfg);
+        let mut other = vec![inject(2, [0.0; 3])];
+        ctxs.contextualize(&AdapterSource::WebSocket(2), &mut other, &cfg);
+        assert_eq!(other.len(), 1);
+        assert_eq!(x_of(&other[0]), 0.0);
+    }
+
+    #[test]
+    fn test_rest_keeps_no_context_and_forget_drops_session() {
+        let cfg = enabled();
+        let mut ctxs = SessionContexts::new();
+        ctxs.contextualize(&AdapterSource::Rest, &mut vec![inject(1, [500.0; 3])], &cfg);
+        let mut next = vec![inject(2, [0.0; 3])];
+        ctxs.contextualize(&AdapterSource::Rest, &mut next, &cfg);
+        assert_eq!(next.len(), 1);
+        assert!(ctxs.is_empty());
+
+        let ws = AdapterSource::WebSocket(7);
+        ctxs.contextualize(&ws, &mut vec![inject(1, [500.0; 3])], &cfg);
+        assert_eq!(ctxs.len(), 1);
+        assert!(ctxs.forget(&ws));
+        assert!(!ctxs.forget(&ws));
+        assert!(ctxs.get(&ws).is_none());
+    }
+
+    #[test]
+    fn test_observe_decays_and_caps() {
+        let cfg = SessionContextConfig { capacity: 2, decay: 0.5, ..enabled() };
+        let mut ctx = SessionContext::default();
+        for id in 1..=3 {
+            ctx.observe(id, [0.0; 3], &cfg);
+        }
+        let ids: Vec<u32> = ctx.entries().map(|e| e.sutra_id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(ctx.sutra_ids(), ids);
+        assert_eq!(ctx.entries().next().unwrap().weight, 0.5);
+    }
+}
@@ -27,23 +27,29 @@ use axiom_ucl::{OpCode, UclCommand};
 use tokio::sync::{broadcast, mpsc, RwLock};
 use tokio::time::Duration;
 
-use crate::adapter_command::{AdapterCommand, AdapterPayload, CommandResponse};
+use crate::adapter_command::{AdapterCommand, AdapterPayload, AdapterSource, CommandResponse};
 use crate::adapters_config::AdaptersConfig;
 use crate::channels::cli::{CliConfig, PerfTracker};
 use crate::effectors::message::domain_name;
+use crate::feedback::{FeedbackLedger, FeedbackVerdict, FEEDBACK_SIGNALS_PER_TICK};
 use crate::meta_commands::{handle_meta_mutate, handle_meta_read, MetaAction};
+use crate::perceptors::embedding::EmbeddingPerceptor;
 use crate::perceptors::text::TextPerceptor;
 use crate::protocol::ServerMessage;
+use crate::session_context::SessionContexts;
 
 const EVENT_LOG_CAPACITY: usize = 256;
 
-/// CLI-специфичное состояние tick_loop: производительность, лог событий, watch-поля.
+/// CLI-специфичное состояние tick_loop: производительность, лог событий, watch-поля,
+/// очередь пакетной обратной связи, контексты разговоров.
 pub(crate) struct CliState {
     perf: PerfTracker,
     event_log: VecDeque<Event>,
     watch_fields: HashSet<String>,
     multipass_count: u64,
     last_multipass_n: u8,
+    feedback: FeedbackLedger,
+    contexts: SessionContexts,
 }
 
 impl CliState {
@@ -54,6 +60,8 @@ impl CliState {
             watch_fields: HashSet::new(),
             multipass_count: 0,
             last_multipass_n: 0,
+            feedback: FeedbackLedger::new(),
+            contexts: SessionContexts::new(),
         }
     }
 }
@@ -82,8 +90,10 @@ pub async fn tick_loop(
     let mut cli_state = CliState::new();
     let config_watcher = config_watcher;
 
-    // Применяем TickSchedule из конфига
+    // Применяем TickSchedule и политики Experience и графа из конфига
     engine.tick_schedule = config.tick_schedule.clone();
+    engine.ashti.experience_mut().set_overflow_policy(config.overflow_policy);
+    config.graph_policies.apply_to(&mut engine);
 
     loop {
         let sleep_ms = if config.adaptive_tick_rate {
@@ -118,9 +128,11 @@ pub async fn tick_loop(
             match command_rx.try_recv() {
                 Ok(cmd) => {
                     had_commands = true;
+                    let payload = config.preprocessing.preprocess(&cmd.source, cmd.payload);
                     match process_adapter_command(
-                        cmd.payload,
+                        payload,
                         cmd.id,
+                        &cmd.source,
                         &mut engine,
                         &mut auto_saver,
                         &mut perceptor,
@@ -159,6 +171,11 @@ pub async fn tick_loop(
             }
         }
 
+        // 1c. Пакетная обратная связь — не больше FEEDBACK_SIGNALS_PER_TICK за тик
+        if cli_state.feedback.queued() > 0 {
+            drain_feedback(&mut engine, &mut cli_state.feedback);
+        }
+
         // 2. Tick ядра
         let tick_start = Instant::now();
         engine.process_command(&tick_cmd);
@@ -228,6 +245,7 @@ pub async fn tick_loop(
 pub(crate) fn process_adapter_command(
     payload: AdapterPayload,
     id: String,
+    source: &AdapterSource,
     engine: &mut AxiomEngine,
     auto_saver: &mut AutoSaver,
     perceptor: &mut TextPerceptor,
@@ -238,6 +256,7 @@ pub(crate) fn process_adapter_command(
     match payload {
         AdapterPayload::Inject { text } => {
             let mut cmds = perceptor.perceive_and_bond(&text);
+            cli_state.contexts.contextualize(source, &mut cmds, &config.session_context);
             let r = engine.process_and_observe(&cmds.remove(0));
             for cmd in &cmds { engine.process_command(&cmd); }
 
@@ -263,6 +282,38 @@ pub(crate) fn process_adapter_command(
             })
         }
 
+        AdapterPayload::Embedding { vector, k } => {
+            let existing = engine
+                .ashti
+                .index_of(100)
+                .and_then(|i| engine.ashti.state(i))
+                .map_or(&[][..], |s| s.tokens.as_slice());
+            let mut cmds = match EmbeddingPerceptor::new().perceive_and_ground(&vector, existing, k) {
+                Ok(cmds) => cmds,
+                Err(e) => {
+                    return CommandResponse::Message(ServerMessage::Error {
+                        command_id: Some(id),
+                        message: e.to_string(),
+                    })
+                }
+            };
+            let r = engine.process_and_observe(&cmds.remove(0));
+            for cmd in &cmds { engine.process_command(cmd); }
+
+            CommandResponse::Message(ServerMessage::Result {
+                command_id: id,
+                path: format!("{:?}", r.path),
+                domain_id: r.dominant_domain_id,
+                domain_name: domain_name(r.dominant_domain_id).to_string(),
+                coherence: r.coherence_score.unwrap_or(0.0),
+                reflex_hit: r.reflex_hit,
+                traces_matched: r.traces_matched,
+                position: r.output_position,
+                shell: r.output_shell,
+                event_id: r.event_id,
+            })
+        }
+
         AdapterPayload::MetaRead { cmd } => {
             let cli_cfg = make_cli_config(config);
             let output = handle_meta_read(
@@ -333,14 +384,130 @@ pub(crate) fn process_adapter_command(
             }
         }
 
+        AdapterPayload::FeedbackBatch { key, signals } => {
+            match cli_state.feedback.submit(key, signals) {
+                Ok(status) => CommandResponse::Message(ServerMessage::FeedbackBatch {
+                    command_id: id,
+                    status,
+                }),
+                Err(reason) => CommandResponse::Message(ServerMessage::FeedbackRejected {
+                    command_id: id,
+                    reason,
+                    message: reason.to_string(),
+                }),
+            }
+        }
+
+        AdapterPayload::FeedbackStatus { key } => match cli_state.feedback.status(&key) {
+            Some(status) => CommandResponse::Message(ServerMessage::FeedbackBatch {
+                command_id: id,
+                status: status.clone(),
+            }),
+            None => CommandResponse::Message(ServerMessage::Error {
+                command_id: Some(id),
+                message: format!("feedback batch '{}' not found", key),
+            }),
+        },
+
+        AdapterPayload::AdvisoryAnalytics { window } => {
+            let output = engine
+                .over_domain_arbiter
+                .log()
+                .summarize(window)
+                .iter()
+                .filter_map(|s| serde_json::to_string(s).ok())
+                .collect::<Vec<_>>()
+                .join("\n");
+            CommandResponse::Message(ServerMessage::CommandResult { command_id: id, output })
+        }
+
+        AdapterPayload::Modules => {
+            let output =
+                serde_json::to_string(&engine.over_domain_modules()).unwrap_or_default();
+            CommandResponse::Message(ServerMessage::CommandResult { command_id: id, output })
+        }
+
+        AdapterPayload::Centrality { domain_id, top } => {
+            match engine.domain_centrality(domain_id, &Default::default()) {
+                Some(nodes) => {
+                    let rows: Vec<serde_json::Value> = nodes
+                        .iter()
+                        .take(top)
+                        .map(|n| {
+                            serde_json::json!({
+                                "sutra_id": n.sutra_id,
+                                "pagerank": n.pagerank,
+                                "betweenness": n.betweenness,
+                                "in_degree": n.degree.incoming,
+                                "out_degree": n.degree.outgoing,
+                            })
+                        })
+                        .collect();
+                    let output = serde_json::to_string(&rows).unwrap_or_default();
+                    CommandResponse::Message(ServerMessage::CommandResult {
+                        command_id: id,
+                        output,
+                    })
+                }
+                None => CommandResponse::Message(ServerMessage::Error {
+                    command_id: Some(id),
+                    message: format!("domain {} not found", domain_id),
+                }),
+            }
+        }
+
+        AdapterPayload::GraphQuery { domain_id, query } => {
+            match engine.query_domain(domain_id, &query) {
+                Some(Ok(ids)) => CommandResponse::Message(ServerMessage::CommandResult {
+                    command_id: id,
+                    output: serde_json::to_string(&ids).unwrap_or_default(),
+                }),
+                Some(Err(e)) => CommandResponse::Message(ServerMessage::Error {
+                    command_id: Some(id),
+                    message: e.to_string(),
+                }),
+                None => CommandResponse::Message(ServerMessage::Error {
+                    command_id: Some(id),
+                    message: format!("domain {} not found", domain_id),
+                }),
+            }
+        }
+
         AdapterPayload::Subscribe { .. } | AdapterPayload::Unsubscribe { .. } => {
             CommandResponse::None // обрабатывается per-connection в WebSocket handler
         }
+
+        AdapterPayload::SessionClosed => {
+            cli_state.contexts.forget(source);
+            CommandResponse::None
+        }
     }
 }
 
 // ── helpers ───────────────────────────────────────────────────────────────────
 
+/// Применить очередной срез вердиктов к очереди Arbiter.
+fn drain_feedback(engine: &mut AxiomEngine, ledger: &mut FeedbackLedger) {
+    let mut pending: HashSet<u64> = engine
+        .over_domain_arbiter
+        .pending_snapshot()
+        .iter()
+        .map(|p| p.advisory.id)
+        .collect();
+    let mut verdicts = Vec::new();
+    ledger.process(
+        FEEDBACK_SIGNALS_PER_TICK,
+        |advisory_id| pending.remove(&advisory_id),
+        |signal| verdicts.push(*signal),
+    );
+    for signal in verdicts {
+        match signal.verdict {
+            FeedbackVerdict::Confirm => engine.confirm_pending_advisory(signal.advisory_id),
+            FeedbackVerdict::Reject => engine.reject_pending_advisory(signal.advisory_id),
+        }
+    }
+}
+
 fn handle_wstation_command(
     cmd_id: u64,
     cmd: EngineCommand,
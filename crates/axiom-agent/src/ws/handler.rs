@@ -10,6 +10,7 @@ use tokio::sync::broadcast;
 
 use super::server::AppState;
 use crate::adapter_command::{AdapterCommand, AdapterPayload, AdapterSource};
+use crate::feedback::{validate_batch, FeedbackSignal};
 use crate::protocol::ServerMessage;
 
 /// Входящее сообщение от WebSocket-клиента.
@@ -22,20 +23,63 @@ pub enum ClientMessage {
     Subscribe { channels: Vec<String> },
     Unsubscribe { channels: Vec<String> },
     DomainSnapshot { domain_id: u16 },
+    /// Вердикты по advisory. `key` — ключ идемпотентности в пределах сессии
+    /// (по умолчанию — порядковый номер кадра).
+    Feedback {
+        #[serde(default)]
+        key: Option<String>,
+        signals: Vec<FeedbackSignal>,
+    },
+    /// Статус ранее отправленного в этой сессии пакета обратной связи
+    FeedbackStatus { key: String },
+}
+
+/// Состояние одного WebSocket-соединения.
+///
+/// Команды сессии получают id `ws{conn_id}_{seq}`; прямые ответы (Result,
+/// CommandResult, FeedbackBatch, ...) на чужие команды в сокет не попадают.
+struct WsSession {
+    conn_id: u64,
+    seq: u64,
+    /// None  = никогда не подписывался → получать всё (default)
+    /// Some  = явно управляет подписками; пустой set = ничего, кроме прямых ответов
+    subscriptions: Option<HashSet<String>>,
+}
+
+impl WsSession {
+    fn new(conn_id: u64) -> Self {
+        Self { conn_id, seq: 0, subscriptions: None }
+    }
+
+    fn next_command_id(&mut self) -> String {
+        self.seq += 1;
+        format!("ws{}_{}", self.conn_id, self.seq)
+    }
+
+    /// Ключ пакета обратной связи, изолированный от других сессий и REST.
+    fn feedback_key(&self, key: &str) -> String {
+        format!("ws{}:{}", self.conn_id, key)
+    }
+
+    /// Команда с этим id отправлена этой сессией.
+    fn owns(&self, command_id: &str) -> bool {
+        command_id
+            .strip_prefix("ws")
+            .and_then(|rest| rest.split_once('_'))
+            .is_some_and(|(conn, _)| conn.parse() == Ok(self.conn_id))
+    }
 }
 
 /// Обработать одно WebSocket-соединение.
 ///
 /// Читает ClientMessage → AdapterCommand → command_tx.
 /// Читает broadcast_rx → фильтрует по подпискам → отправляет клиенту.
+/// При закрытии отправляет SessionClosed — tick_loop забывает контекст разговора.
 pub async fn handle_socket(socket: WebSocket, state: AppState) {
     let conn_id = state.next_conn_id.fetch_add(1, Ordering::Relaxed);
     let (mut ws_tx, mut ws_rx) = socket.split();
     let mut broadcast_rx = state.broadcast_tx.subscribe();
-    // None  = никогда не подписывался → получать всё (default)
-    // Some  = явно управляет подписками; пустой set = ничего, кроме прямых ответов
-    let mut subscriptions: Option<HashSet<String>> = None;
-    let mut seq: u64 = 0;
+    let mut session = WsSession::new(conn_id);
 
     loop {
         tokio::select! {
@@ -44,10 +88,7 @@ pub async fn handle_socket(socket: WebSocket, state: AppState) {
                     Some(Ok(Message::Text(text))) => {
                         match serde_json::from_str::<ClientMessage>(&text) {
                             Ok(msg) => {
-                                seq += 1;
-                                if !dispatch(msg, conn_id, seq, &mut subscriptions,
-                                             &state, &mut ws_tx).await
-                                {
+                                if !dispatch(msg, &mut session, &state, &mut ws_tx).await {
                                     break;
                                 }
                             }
@@ -66,7 +107,7 @@ pub async fn handle_socket(socket: WebSocket, state: AppState) {
             outgoing = broadcast_rx.recv() => {
                 match outgoing {
                     Ok(msg) => {
-                        if !should_send(&msg, &subscriptions) { continue; }
+                        if !should_send(&msg, &session) { continue; }
                         let json = serde_json::to_string(&msg).unwrap_or_default();
                         if ws_tx.send(Message::Text(json.into())).await.is_err() { break; }
                     }
@@ -79,6 +120,16 @@ pub async fn handle_socket(socket: WebSocket, state: AppState) {
             }
         }
     }
+
+    let _ = state
+        .command_tx
+        .send(AdapterCommand {
+            id: String::new(),
+            source: AdapterSource::WebSocket(conn_id),
+            payload: AdapterPayload::SessionClosed,
+            priority: axiom_runtime::GatewayPriority::Normal,
+        })
+        .await;
 }
 
 // ── helpers ───────────────────────────────────────────────────────────────────
@@ -87,31 +138,35 @@ pub async fn handle_socket(socket: WebSocket, state: AppState) {
 /// Возвращает false если нужно закрыть соединение.
 async fn dispatch(
     msg: ClientMessage,
-    conn_id: u64,
-    seq: u64,
-    subscriptions: &mut Option<HashSet<String>>,
+    session: &mut WsSession,
     state: &AppState,
     ws_tx: &mut (impl SinkExt<Message, Error = axum::Error> + Unpin),
 ) -> bool {
+    let id = session.next_command_id();
     match msg {
         ClientMessage::Subscribe { channels } => {
-            subscriptions
+            session
+                .subscriptions
                 .get_or_insert_with(HashSet::new)
                 .extend(channels);
         }
         ClientMessage::Unsubscribe { channels } => {
-            if let Some(set) = subscriptions.as_mut() {
+            if let Some(set) = session.subscriptions.as_mut() {
                 for ch in channels {
                     set.remove(&ch);
                 }
             }
         }
         other => {
-            let id = format!("ws{conn_id}_{seq}");
-            let payload = to_payload(other);
+            let payload = match to_payload(other, session) {
+                Ok(p) => p,
+                Err(message) => {
+                    return ws_tx.send(error_msg(Some(id), message)).await.is_ok();
+                }
+            };
             let cmd = AdapterCommand {
                 id,
-                source: AdapterSource::WebSocket(conn_id),
+                source: AdapterSource::WebSocket(session.conn_id),
                 payload,
                 priority: axiom_runtime::GatewayPriority::Normal,
             };
@@ -125,33 +180,51 @@ async fn dispatch(
     true
 }
 
-fn to_payload(msg: ClientMessage) -> AdapterPayload {
-    match msg {
+fn to_payload(msg: ClientMessage, session: &WsSession) -> Result<AdapterPayload, String> {
+    Ok(match msg {
         ClientMessage::Inject { text } => AdapterPayload::Inject { text },
         ClientMessage::ReadCommand { cmd } => AdapterPayload::MetaRead { cmd },
         ClientMessage::MutateCommand { cmd } => AdapterPayload::MetaMutate { cmd },
         ClientMessage::DomainSnapshot { domain_id } => AdapterPayload::DomainSnapshot { domain_id },
         ClientMessage::Subscribe { channels } => AdapterPayload::Subscribe { channels },
         ClientMessage::Unsubscribe { channels } => AdapterPayload::Unsubscribe { channels },
-    }
+        ClientMessage::Feedback { key, signals } => {
+            let key = key.unwrap_or_else(|| session.seq.to_string());
+            validate_batch(&key, &signals).map_err(|e| e.to_string())?;
+            AdapterPayload::FeedbackBatch { key: session.feedback_key(&key), signals }
+        }
+        ClientMessage::FeedbackStatus { key } => {
+            AdapterPayload::FeedbackStatus { key: session.feedback_key(&key) }
+        }
+    })
 }
 
-/// Нужно ли отправить сообщение этому клиенту с учётом его подписок?
+/// Нужно ли отправить сообщение этому клиенту с учётом сессии и подписок?
 ///
-/// `None`  = никогда не подписывался → получать всё (default).
-/// `Some`  = явное управление: включён только то, на что подписан.
-///           Пустой Some → только прямые ответы.
-fn should_send(msg: &ServerMessage, subs: &Option<HashSet<String>>) -> bool {
-    match subs {
+/// Прямые ответы доставляются только сессии, отправившей команду.
+/// Для остального: `None` = никогда не подписывался → получать всё (default);
+/// `Some` = явное управление: включён только то, на что подписан.
+fn should_send(msg: &ServerMessage, session: &WsSession) -> bool {
+    match msg {
+        ServerMessage::Result { command_id, .. }
+        | ServerMessage::CommandResult { command_id, .. }
+        | ServerMessage::FeedbackBatch { command_id, .. }
+        | ServerMessage::FeedbackRejected { command_id, .. } => return session.owns(command_id),
+        ServerMessage::Error { command_id: Some(id), .. } => return session.owns(id),
+        _ => {}
+    }
+    match &session.subscriptions {
         None => true,
         Some(set) => match msg {
             ServerMessage::Tick { .. } => set.contains("ticks"),
             ServerMessage::State { .. } => set.contains("state"),
-            // Прямые ответы доставляются независимо от подписок
-            ServerMessage::Result { .. }
+            // DomainDetail не несёт command_id; глобальные ошибки — всем
+            ServerMessage::DomainDetail(_)
+            | ServerMessage::Error { .. }
+            | ServerMessage::Result { .. }
             | ServerMessage::CommandResult { .. }
-            | ServerMessage::DomainDetail(_)
-            | ServerMessage::Error { .. } => true,
+            | ServerMessage::FeedbackBatch { .. }
+            | ServerMessage::FeedbackRejected { .. } => true,
         },
     }
 }
@@ -5,6 +5,8 @@
 // Клиенты подключаются к /ws, обмениваются JSON-сообщениями.
 // Все команды проходят через общий tick_loop (command_tx).
 // Ответы доставляются через broadcast + фильтрацию по подпискам.
+// Соединение — сессия: прямые ответы получает только отправитель команды,
+// кадры feedback/feedback_status работают с пакетами этой сессии.
 
 mod handler;
 mod server;
@@ -45,6 +45,104 @@ fn test_handle_meta_read_status_nonempty() {
     );
 }
 
+#[test]
+fn test_handle_meta_read_status_shows_overflow_policy() {
+    let out = read(":status");
+    assert!(out.contains("overflow:      drop_lowest_weight"), "{out}");
+    assert!(out.contains("spilled: 0 (0 queued, 0 lost)"), "{out}");
+}
+
+#[test]
+fn test_experience_config_yaml_sets_overflow_policy() {
+    use axiom_agent::channels::cli::CliConfigFile;
+    use axiom_arbiter::OverflowPolicy;
+
+    let file: CliConfigFile =
+        serde_yaml::from_str("experience:\n  overflow_policy: spill\n").unwrap();
+    let mut policy = OverflowPolicy::default();
+    file.experience.unwrap().apply_to(&mut policy);
+    assert_eq!(policy, OverflowPolicy::Spill);
+}
+
+#[test]
+fn test_tick_schedule_yaml_sets_graph_intervals() {
+    use axiom_agent::channels::cli::CliConfigFile;
+    use axiom_runtime::TickSchedule;
+
+    let yaml = "tick_schedule:\n  orphan_gc_interval: 50\n  connection_prune_interval: 20\n  \
+                connection_learning_interval: 5\n  strength_norm_interval: 7\n  \
+                connection_decay_interval: 8\n  connection_ttl_interval: 9\n  \
+                connection_compact_interval: 3\n";
+    let file: CliConfigFile = serde_yaml::from_str(yaml).unwrap();
+    let mut s = TickSchedule::default();
+    file.tick_schedule.unwrap().apply_to(&mut s);
+    assert_eq!(s.orphan_gc_interval, 50);
+    assert_eq!(s.connection_prune_interval, 20);
+    assert_eq!(s.connection_learning_interval, 5);
+    assert_eq!(s.strength_norm_interval, 7);
+    assert_eq!(s.connection_decay_interval, 8);
+    assert_eq!(s.connection_ttl_interval, 9);
+    assert_eq!(s.connection_compact_interval, 3);
+}
+
+#[test]
+fn test_graph_policies_yaml_configures_engine() {
+    use axiom_agent::channels::cli::{CliConfigFile, GraphPolicies};
+    use axiom_domain::{ConnectionLearningRule, NormalizationMode, SymmetryPolicy};
+
+    let yaml = "\
+graph_policies:
+  orphan_criteria: { max_mass: 3, idle_events: 500 }
+  connection_pruner: { min_strength: 0.2, prune_flagged: false, per_link_type_cap: { 7: 4 } }
+  connection_decay: { factor: 0.9, idle_after: 10 }
+  connection_learning: { rule: stdp, a_plus: 0.05, tau: 20.0 }
+  strength_normalization:
+    default: { mode: sum_to_cap, cap: 1.0 }
+    per_link_type: { 2: { mode: softmax, cap: 2.0, temperature: 0.5 } }
+  bidirectional_symmetry: max
+";
+    let file: CliConfigFile = serde_yaml::from_str(yaml).unwrap();
+    let mut policies = GraphPolicies::default();
+    file.graph_policies.unwrap().apply_to(&mut policies);
+
+    let mut engine = make_engine();
+    policies.apply_to(&mut engine);
+    assert_eq!(engine.orphan_criteria.max_mass, 3);
+    assert_eq!(engine.orphan_criteria.idle_events, 500);
+    assert_eq!(engine.connection_pruner.min_strength, 0.2);
+    assert!(!engine.connection_pruner.prune_flagged);
+    assert_eq!(engine.connection_pruner.per_link_type_cap.get(&7), Some(&4));
+    assert_eq!(engine.connection_decay.factor, 0.9);
+    assert_eq!(engine.connection_decay.idle_after, 10);
+    assert_eq!(
+        engine.connection_learning,
+        ConnectionLearningRule::Stdp { a_plus: 0.05, a_minus: 0.05, tau: 20.0 }
+    );
+    assert_eq!(
+        engine.strength_normalization.default,
+        Some(NormalizationMode::SumToCap { cap: 1.0 })
+    );
+    assert_eq!(
+        engine.strength_normalization.per_link_type.get(&2),
+        Some(&NormalizationMode::Softmax { cap: 2.0, temperature: 0.5 })
+    );
+    assert_eq!(engine.bidirectional_symmetry, SymmetryPolicy::Max);
+}
+
+#[test]
+fn test_graph_policies_yaml_unknown_rule_keeps_default() {
+    use axiom_agent::channels::cli::{CliConfigFile, GraphPolicies};
+
+    let yaml = "graph_policies:\n  connection_learning: { rule: magic, rate: 0.1 }\n  \
+                bidirectional_symmetry: sideways\n";
+    let file: CliConfigFile = serde_yaml::from_str(yaml).unwrap();
+    let mut policies = GraphPolicies::default();
+    file.graph_policies.unwrap().apply_to(&mut policies);
+    let default = GraphPolicies::default();
+    assert_eq!(policies.connection_learning, default.connection_learning);
+    assert_eq!(policies.bidirectional_symmetry, default.bidirectional_symmetry);
+}
+
 #[test]
 fn test_handle_meta_read_domains_lists_11() {
     let out = read(":domains");
@@ -26,6 +26,11 @@ fn make_saver() -> AutoSaver {
 
 /// Запустить полный сервер (WS + REST + tick_loop). Возвращает базовый URL.
 async fn spawn_server() -> String {
+    spawn_server_with(make_engine()).await
+}
+
+/// То же, что spawn_server, но с заранее подготовленным Engine.
+async fn spawn_server_with(engine: AxiomEngine) -> String {
     let (command_tx, command_rx) = mpsc::channel::<AdapterCommand>(64);
     let (broadcast_tx, _) = broadcast::channel::<ServerMessage>(256);
     let snapshot = Arc::new(RwLock::new(Option::<SensoriumState>::None));
@@ -46,7 +51,7 @@ async fn spawn_server() -> String {
     cfg.websocket.state_broadcast_interval = 1; // обновлять snapshot каждый тик
 
     tokio::spawn(tick_loop(
-        make_engine(),
+        engine,
         command_rx,
         broadcast_tx,
         snapshot,
@@ -177,7 +182,290 @@ async fn test_rest_post_inject_missing_text_returns_400() {
     assert_eq!(resp.status(), 400);
 }
 
-// ── POST /api/command ─────────────────────────────────────────────────────────
+// ── POST /api/embed ───────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_rest_post_embed_returns_result() {
+    let base = spawn_server().await;
+
+    let resp = http()
+        .post(format!("{base}/api/embed"))
+        .json(&serde_json::json!({"vector": [0.1, -0.4, 0.8, 0.2], "k": 2}))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(json["type"], "result", "expected ServerMessage::Result");
+}
+
+#[tokio::test]
+async fn test_rest_post_embed_huge_k_is_clamped() {
+    let base = spawn_server().await;
+
+    let resp = http()
+        .post(format!("{base}/api/embed"))
+        .json(&serde_json::json!({"vector": [0.3, 0.1, -0.2], "k": u64::MAX}))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(json["type"], "result");
+}
+
+#[tokio::test]
+async fn test_rest_post_embed_zero_vector_returns_400() {
+    let base = spawn_server().await;
+
+    let resp = http()
+        .post(format!("{base}/api/embed"))
+        .json(&serde_json::json!({"vector": [0.0, 0.0, 0.0]}))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 400);
+}
+
+// ── POST /api/feedback/batch ──────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_rest_feedback_batch_is_idempotent_and_reports_status() {
+    let base = spawn_server().await;
+    let body = serde_json::json!({
+        "idempotency_key": "ab-run-1",
+        "signals": [
+            {"advisory_id": 9001, "verdict": "confirm"},
+            {"advisory_id": 9002, "verdict": "reject"}
+        ]
+    });
+
+    let resp = http()
+        .post(format!("{base}/api/feedback/batch"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(json["type"], "feedback_batch");
+    assert_eq!(json["status"]["total"], 2);
+
+    let replay = http()
+        .post(format!("{base}/api/feedback/batch"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(replay.status(), 200);
+    let json: serde_json::Value = replay.json().await.unwrap();
+    assert_eq!(json["status"]["replayed"], true);
+
+    // Очередь разбирается tick_loop — advisory не в очереди Arbiter → unknown
+    let mut status = serde_json::Value::Null;
+    for _ in 0..50 {
+        let resp = http()
+            .get(format!("{base}/api/feedback/batch/ab-run-1"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        status = resp.json::<serde_json::Value>().await.unwrap()["status"].clone();
+        if status["state"] == "done" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(status["state"], "done");
+    assert_eq!(status["applied"], 0);
+    assert_eq!(status["unknown"], serde_json::json!([9001, 9002]));
+}
+
+#[tokio::test]
+async fn test_rest_feedback_batch_key_reuse_with_other_signals_is_409() {
+    let base = spawn_server().await;
+    let batch = |verdict: &str| {
+        serde_json::json!({
+            "idempotency_key": "ab-run-2",
+            "signals": [{"advisory_id": 7, "verdict": verdict}]
+        })
+    };
+
+    let resp = http()
+        .post(format!("{base}/api/feedback/batch"))
+        .json(&batch("confirm"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 202);
+
+    let resp = http()
+        .post(format!("{base}/api/feedback/batch"))
+        .json(&batch("reject"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 409);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(json["type"], "feedback_rejected");
+    assert_eq!(json["reason"], "key_conflict");
+}
+
+#[tokio::test]
+async fn test_rest_feedback_batch_validation_and_missing_status() {
+    let base = spawn_server().await;
+
+    let resp = http()
+        .post(format!("{base}/api/feedback/batch"))
+        .json(&serde_json::json!({
+            "idempotency_key": "dup",
+            "signals": [
+                {"advisory_id": 1, "verdict": "confirm"},
+                {"advisory_id": 1, "verdict": "reject"}
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+
+    let resp = http()
+        .get(format!("{base}/api/feedback/batch/never-sent"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+// ── GET /api/analytics/advisory ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_rest_advisory_analytics_is_ndjson() {
+    use axiom_runtime::over_domain::{Advisory, AdvisoryAction, AdvisoryType};
+
+    let advisory = |id, confidence| Advisory {
+        id,
+        source: 0,
+        advisory_type: AdvisoryType::OctantCorrection,
+        subject_id: 1,
+        confidence,
+        action: AdvisoryAction::NotifyWorkstation { label: "test".into() },
+        created_at_event: 10,
+        octant_hint: None,
+    };
+    // Цикл 0..500: два в очередь (одно подтверждено, одно отклонено), одно ниже порога.
+    // Цикл 500..1000: одно в очереди.
+    let mut engine = make_engine();
+    let ctx = &mut engine.context_recognizer;
+    let arbiter = &mut engine.over_domain_arbiter;
+    let advs = [advisory(1, 0.8), advisory(2, 1.0), advisory(3, 0.1)];
+    arbiter.tick_with_stores(10, &advs, ctx.depth_store_mut());
+    arbiter.tick_with_stores(600, &[advisory(4, 0.9)], ctx.depth_store_mut());
+    engine.confirm_pending_advisory(1);
+    engine.reject_pending_advisory(2);
+
+    let base = spawn_server_with(engine).await;
+    let resp = http()
+        .get(format!("{base}/api/analytics/advisory?window=500"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers()["content-type"].to_str().unwrap(),
+        "application/x-ndjson"
+    );
+    let body = resp.text().await.unwrap();
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .filter(|v: &serde_json::Value| {
+            v["source"] == 0 && v["advisory_type"] == "OctantCorrection"
+        })
+        .collect();
+    assert_eq!(lines.len(), 2, "one summary per cycle: {body}");
+
+    let first = &lines[0];
+    assert_eq!(first["cycle_start"], 0);
+    assert_eq!(first["cycle_end"], 499);
+    assert_eq!(first["queued"], 2);
+    assert_eq!(first["skipped"], 1);
+    assert_eq!(first["confirmed"], 1);
+    assert_eq!(first["rejected"], 1);
+    assert_eq!(first["applied"], 0);
+    assert_eq!(first["acceptance"], 0.5);
+    let mean = first["mean_confidence"].as_f64().unwrap();
+    assert!((mean - 0.74).abs() < 1e-4, "mean_confidence = {mean}");
+
+    let second = &lines[1];
+    assert_eq!(second["cycle_start"], 500);
+    assert_eq!(second["queued"], 1);
+    assert!(second["acceptance"].is_null());
+}
+
+// ── GET /api/modules ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_rest_modules_lists_builtin_components() {
+    let base = spawn_server().await;
+
+    let resp = http().get(format!("{base}/api/modules")).send().await.unwrap();
+    assert_eq!(resp.status(), 200);
+    let modules: Vec<serde_json::Value> = resp.json().await.unwrap();
+    let names: Vec<&str> = modules.iter().filter_map(|m| m["name"].as_str()).collect();
+    assert!(names.contains(&"FrameWeaver"));
+    assert!(names.contains(&"OverDomainArbiter"));
+    assert!(modules.iter().all(|m| m["tick_interval"].as_u64().unwrap() >= 1));
+}
+
+// ── GET /api/domain/:id/centrality ────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_rest_centrality() {
+    let base = spawn_server().await;
+
+    let resp = http()
+        .get(format!("{base}/api/domain/106/centrality?top=5"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let nodes: Vec<serde_json::Value> = resp.json().await.unwrap();
+    assert!(nodes.len() <= 5);
+    assert!(nodes.iter().all(|n| n["pagerank"].as_f64().is_some()));
+
+    let resp = http().get(format!("{base}/api/domain/999/centrality")).send().await.unwrap();
+    assert_eq!(resp.status(), 404);
+}
+
+// ── GET /api/domain/:id/query ─────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_rest_graph_query() {
+    let base = spawn_server().await;
+
+    let resp = http()
+        .get(format!("{base}/api/domain/106/query"))
+        .query(&[("q", "near=1 hops=2 min_strength=0.5")])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+    let ids: Vec<u32> = resp.json().await.unwrap();
+    assert!(!ids.contains(&1));
+
+    let resp = http()
+        .get(format!("{base}/api/domain/106/query"))
+        .query(&[("q", "hops=2")])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 400);
+}
+
 
 #[tokio::test]
 async fn test_rest_post_read_command_status() {
@@ -61,6 +61,82 @@ fn test_process_inject_builds_server_message() {
     assert!(matches!(payload, AdapterPayload::Inject { .. }));
 }
 
+// ── PreprocessingConfig ───────────────────────────────────────────────────────
+
+#[test]
+fn test_preprocessing_default_passes_text_through() {
+    let config = make_config();
+    let payload = config.preprocessing.preprocess(
+        &AdapterSource::Rest,
+        AdapterPayload::Inject { text: "Hello,  World".to_string() },
+    );
+    assert!(matches!(payload, AdapterPayload::Inject { text } if text == "Hello,  World"));
+}
+
+#[test]
+fn test_preprocessing_applies_per_source_pipeline() {
+    use axiom_agent::perceptors::preprocess::TextPipeline;
+    let mut config = make_config();
+    config.preprocessing.telegram = TextPipeline::normalize();
+
+    let tg = config.preprocessing.preprocess(
+        &AdapterSource::Telegram(1),
+        AdapterPayload::Inject { text: "Hello,  World".to_string() },
+    );
+    assert!(matches!(tg, AdapterPayload::Inject { text } if text == "hello world"));
+
+    let cli = config.preprocessing.preprocess(
+        &AdapterSource::Cli,
+        AdapterPayload::Inject { text: "Hello,  World".to_string() },
+    );
+    assert!(matches!(cli, AdapterPayload::Inject { text } if text == "Hello,  World"));
+}
+
+#[test]
+fn test_preprocessing_loaded_from_config_file() {
+    use axiom_agent::channels::cli::CliConfigFile;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("axiom-cli.yaml");
+    let yaml = "preprocessing:\n  rest:\n    stages:\n      - stage: lowercase\n      \
+                - stage: collapse_whitespace\n";
+    std::fs::write(&path, yaml).unwrap();
+
+    let file = CliConfigFile::load(&path).unwrap();
+    let cli = CliConfig { preprocessing: file.preprocessing.unwrap(), ..CliConfig::default() };
+    let config = AdaptersConfig::from_cli_config(&cli);
+
+    let rest = config.preprocessing.preprocess(
+        &AdapterSource::Rest,
+        AdapterPayload::Inject { text: "Hello,  World".to_string() },
+    );
+    assert!(matches!(rest, AdapterPayload::Inject { text } if text == "hello, world"));
+    assert!(config.preprocessing.telegram.is_identity());
+}
+
+#[test]
+fn test_session_context_loaded_from_config_file() {
+    use axiom_agent::channels::cli::CliConfigFile;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("axiom-cli.yaml");
+    std::fs::write(&path, "session_context:\n  enabled: true\n  capacity: 4\n").unwrap();
+
+    let file = CliConfigFile::load(&path).unwrap();
+    let session_context = file.session_context.unwrap();
+    let cli = CliConfig { session_context, ..CliConfig::default() };
+    let config = AdaptersConfig::from_cli_config(&cli);
+
+    assert!(config.session_context.enabled);
+    assert_eq!(config.session_context.capacity, 4);
+    // Незаданные поля — значения по умолчанию
+    let defaults = axiom_agent::session_context::SessionContextConfig::default();
+    assert_eq!(config.session_context.decay, defaults.decay);
+    assert_eq!(config.session_context.bond_strength, defaults.bond_strength);
+    // Без секции контекст выключен
+    assert!(!AdaptersConfig::from_cli_config(&CliConfig::default()).session_context.enabled);
+}
+
 // ── tick_loop (async) ─────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -331,6 +331,63 @@ async fn test_ws_read_command_status_returns_output() {
     );
 }
 
+#[tokio::test]
+async fn test_ws_direct_responses_stay_in_session() {
+    let port = spawn_full(0, 0).await;
+    let url = ws_url(port).await;
+    let (ws1, _) = connect_async(url.clone()).await.unwrap();
+    let (ws2, _) = connect_async(url).await.unwrap();
+    let (mut write1, mut read1) = ws1.split();
+    let (_write2, mut read2) = ws2.split();
+
+    write1
+        .send(Message::Text(r#"{"type":"inject","text":"private"}"#.into()))
+        .await
+        .unwrap();
+
+    let own = collect_msgs(&mut read1, 1, Duration::from_secs(2)).await;
+    assert!(own.iter().any(|m| m["type"] == "result"), "got: {:?}", own);
+
+    let other = collect_msgs(&mut read2, 1, Duration::from_millis(300)).await;
+    assert!(
+        other.iter().all(|m| m["type"] != "result"),
+        "result leaked to another session: {:?}",
+        other
+    );
+}
+
+#[tokio::test]
+async fn test_ws_feedback_frame_returns_session_status() {
+    let port = spawn_full(0, 0).await;
+    let (ws, _) = connect_async(ws_url(port).await).await.unwrap();
+    let (mut write, mut read) = ws.split();
+
+    write
+        .send(Message::Text(
+            r#"{"type":"feedback","key":"f1","signals":[{"advisory_id":42,"verdict":"confirm"}]}"#
+                .into(),
+        ))
+        .await
+        .unwrap();
+    let msgs = collect_msgs(&mut read, 1, Duration::from_secs(2)).await;
+    let ack = msgs.iter().find(|m| m["type"] == "feedback_batch").expect("no ack");
+    assert_eq!(ack["status"]["total"], 1);
+
+    write
+        .send(Message::Text(r#"{"type":"feedback","signals":[]}"#.into()))
+        .await
+        .unwrap();
+    let msgs = collect_msgs(&mut read, 1, Duration::from_secs(2)).await;
+    assert!(msgs.iter().any(|m| m["type"] == "error"), "got: {:?}", msgs);
+
+    write
+        .send(Message::Text(r#"{"type":"feedback_status","key":"f1"}"#.into()))
+        .await
+        .unwrap();
+    let msgs = collect_msgs(&mut read, 1, Duration::from_secs(2)).await;
+    assert!(msgs.iter().any(|m| m["type"] == "feedback_batch"), "got: {:?}", msgs);
+}
+
 #[tokio::test]
 async fn test_ws_tick_broadcast_arrives() {
     // tick_broadcast_interval=1 — Tick отправляется каждый тик
@@ -27,9 +27,63 @@ pub enum ResonanceLevel {
     Reflex,
 }
 
+/// Что делать с новым следом, когда достигнут `max_traces`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Вытеснить след с наименьшим весом
+    #[default]
+    DropLowestWeight,
+    /// Вытеснить самый старый след (по created_at)
+    DropOldest,
+    /// Не добавлять новый след
+    RejectNew,
+    /// Вытеснить след с наименьшим весом в очередь `drain_spilled` —
+    /// вызывающий сохраняет его (например, экспортом traces). Очередь хранит
+    /// не больше MAX_SPILLED_TRACES следов: незабранные старейшие теряются
+    Spill,
+}
+
+impl OverflowPolicy {
+    /// Имя политики в конфиге и :status.
+    pub fn name(self) -> &'static str {
+        match self {
+            OverflowPolicy::DropLowestWeight => "drop_lowest_weight",
+            OverflowPolicy::DropOldest => "drop_oldest",
+            OverflowPolicy::RejectNew => "reject_new",
+            OverflowPolicy::Spill => "spill",
+        }
+    }
+
+    /// Политика по имени (см. `name`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::DropLowestWeight, Self::DropOldest, Self::RejectNew, Self::Spill]
+            .into_iter()
+            .find(|p| p.name() == name)
+    }
+}
+
+/// Максимум незабранных следов в очереди Spill.
+pub const MAX_SPILLED_TRACES: usize = 1024;
+
+/// Счётчики переполнения по причинам.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OverflowStats {
+    /// Вытеснено с наименьшим весом
+    pub evicted_lowest_weight: u64,
+    /// Вытеснено самых старых
+    pub evicted_oldest: u64,
+    /// Отклонено новых
+    pub rejected: u64,
+    /// Отправлено в очередь spill
+    pub spilled: u64,
+    /// Потеряно из переполненной очереди spill (не забраны вовремя)
+    pub spill_dropped: u64,
+}
+
 /// След опыта (паттерн + вес + метаданные)
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct ExperienceTrace {
     /// Паттерн токена
     pub pattern: Token,
@@ -75,6 +129,12 @@ pub struct Experience {
     /// Shell-профили vocab-seed токенов (sutra_id → shell [L1..L8]).
     /// Заполняется через set_shell_registry() из AxiomEngine при boot.
     shell_registry: HashMap<u32, [u8; 8]>,
+    /// Поведение при достижении max_traces
+    overflow_policy: OverflowPolicy,
+    /// Счётчики переполнения
+    overflow_stats: OverflowStats,
+    /// Следы, вытесненные политикой Spill и ещё не забранные
+    spilled: Vec<ExperienceTrace>,
 }
 
 impl Experience {
@@ -90,6 +150,9 @@ impl Experience {
             last_traces_matched: Cell::new(0),
             traces_seen_total: 0,
             shell_registry: HashMap::new(),
+            overflow_policy: OverflowPolicy::default(),
+            overflow_stats: OverflowStats::default(),
+            spilled: Vec::new(),
         }
     }
 
@@ -325,22 +388,79 @@ impl Experience {
             + self.tension_traces.len() * std::mem::size_of::<TensionTrace>()
     }
 
-    /// Добавить след опыта. Если лимит достигнут, вытесняет след с наименьшим весом.
+    /// Установить поведение при достижении лимита следов.
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+        self.overflow_policy = policy;
+    }
+
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Счётчики переполнения по причинам.
+    pub fn overflow_stats(&self) -> OverflowStats {
+        self.overflow_stats
+    }
+
+    /// Забрать следы, вытесненные политикой Spill.
+    pub fn drain_spilled(&mut self) -> Vec<ExperienceTrace> {
+        std::mem::take(&mut self.spilled)
+    }
+
+    /// Следов в очереди Spill, ещё не забранных.
+    pub fn spilled_len(&self) -> usize {
+        self.spilled.len()
+    }
+
+    /// Освободить место под новый след по `overflow_policy`.
+    /// false — лимит достигнут и новый след отклоняется (RejectNew).
+    fn make_room(&mut self) -> bool {
+        if self.traces.len() < self.max_traces {
+            return true;
+        }
+        let victim = match self.overflow_policy {
+            OverflowPolicy::RejectNew => {
+                self.overflow_stats.rejected += 1;
+                return false;
+            }
+            OverflowPolicy::DropOldest => {
+                self.overflow_stats.evicted_oldest += 1;
+                self.traces.iter().enumerate().min_by_key(|(_, t)| t.created_at)
+            }
+            policy @ (OverflowPolicy::DropLowestWeight | OverflowPolicy::Spill) => {
+                if policy == OverflowPolicy::Spill {
+                    self.overflow_stats.spilled += 1;
+                } else {
+                    self.overflow_stats.evicted_lowest_weight += 1;
+                }
+                self.traces
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.weight.total_cmp(&b.weight))
+            }
+        };
+        // Удаляем из индекса ДО удаления из Vec
+        if let Some(idx) = victim.map(|(i, _)| i) {
+            let evicted_id = self.traces[idx].created_at;
+            self.index.remove_by_trace_id(evicted_id);
+            let evicted = self.traces.remove(idx);
+            if self.overflow_policy == OverflowPolicy::Spill {
+                if self.spilled.len() >= MAX_SPILLED_TRACES {
+                    self.spilled.remove(0);
+                    self.overflow_stats.spill_dropped += 1;
+                }
+                self.spilled.push(evicted);
+            }
+        }
+        true
+    }
+
+    /// Добавить след опыта. Если лимит достигнут, место освобождается по
+    /// `overflow_policy` (по умолчанию вытесняется след с наименьшим весом).
     pub fn add_trace(&mut self, pattern: Token, weight: f32, created_at: u64) {
         self.traces_seen_total += 1;
-        if self.traces.len() >= self.max_traces {
-            // Evict lowest weight trace — удаляем из индекса ДО удаления из Vec
-            if let Some(min_idx) = self
-                .traces
-                .iter()
-                .enumerate()
-                .min_by(|(_, a), (_, b)| a.weight.total_cmp(&b.weight))
-                .map(|(i, _)| i)
-            {
-                let evicted_id = self.traces[min_idx].created_at;
-                self.index.remove_by_trace_id(evicted_id);
-                self.traces.remove(min_idx);
-            }
+        if !self.make_room() {
+            return;
         }
 
         let ph = pattern_hash(&pattern);
@@ -582,6 +702,7 @@ impl Default for Experience {
 /// Хранит горячий паттерн, который Heartbeat будет подталкивать обратно в pipeline.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct TensionTrace {
     /// Паттерн, который не был обработан до конца
     pub pattern: Token,
@@ -648,20 +769,10 @@ impl Experience {
     /// Импортировать след с уже применённым weight factor (для загрузки из персистентного хранилища).
     ///
     /// В отличие от `add_trace()`, не ограничивает weight и не пересчитывает hash —
-    /// принимает след как есть. Вытесняет слабейший след при достижении лимита.
+    /// принимает след как есть. При достижении лимита — по `overflow_policy`.
     pub fn import_trace(&mut self, trace: ExperienceTrace) {
-        if self.traces.len() >= self.max_traces {
-            if let Some(min_idx) = self
-                .traces
-                .iter()
-                .enumerate()
-                .min_by(|(_, a), (_, b)| a.weight.total_cmp(&b.weight))
-                .map(|(i, _)| i)
-            {
-                let evicted_id = self.traces[min_idx].created_at;
-                self.index.remove_by_trace_id(evicted_id);
-                self.traces.remove(min_idx);
-            }
+        if !self.make_room() {
+            return;
         }
         let key = grid_hash(&trace.pattern, self.index.shift);
         let trace_id = trace.created_at;
@@ -5,8 +5,11 @@
 // 2. AssociativeIndex — HashMap<u64, Vec<u64>>, ключ = grid_hash, значение = created_at следов
 // 3. Двухфазный поиск: Phase 1 (O(1) GridHash lookup) → Phase 2 (O(N) физика) при промахе
 // 4. Обучение: insert при add_trace, remove при затухании
+// 5. KeyBloom — counting Bloom-фильтр перед HashMap: определённый промах
+//    отсекается тремя чтениями байт, без хэширования ключа в таблице
 
 use axiom_core::Token;
+use std::cell::Cell;
 use std::collections::HashMap;
 
 /// Вычислить GridHash токена
@@ -69,6 +72,95 @@ pub fn grid_hash_with_shell(token: &Token, shell: &[u8; 8], shift: u32) -> u64 {
     h
 }
 
+/// log2 числа счётчиков KeyBloom по умолчанию (65536 байт).
+///
+/// При max_traces=1000 и k=3 ложноположительная доля ≈ 1e-4.
+pub const BLOOM_DEFAULT_LOG2_SLOTS: u32 = 16;
+
+/// Counting Bloom-фильтр над grid-ключами (k=3, счётчики u8).
+///
+/// Счётчики вместо битов позволяют удалять ключи при затухании следов.
+/// Насыщенный счётчик (255) больше не уменьшается — фильтр остаётся
+/// консервативным: ложноотрицательных ответов не бывает.
+#[derive(Debug, Clone)]
+pub struct KeyBloom {
+    counters: Vec<u8>,
+    mask: u64,
+}
+
+impl KeyBloom {
+    /// Создать фильтр на `1 << log2_slots` счётчиков.
+    pub fn new(log2_slots: u32) -> Self {
+        let slots = 1usize << log2_slots;
+        Self {
+            counters: vec![0; slots],
+            mask: (slots - 1) as u64,
+        }
+    }
+
+    /// Три позиции для ключа (double hashing; grid_hash уже перемешан FNV).
+    #[inline]
+    fn slots(&self, key: u64) -> [usize; 3] {
+        let h2 = key.rotate_left(32) | 1;
+        [
+            (key & self.mask) as usize,
+            (key.wrapping_add(h2) & self.mask) as usize,
+            (key.wrapping_add(h2.wrapping_mul(2)) & self.mask) as usize,
+        ]
+    }
+
+    /// Учесть одно вхождение ключа.
+    pub fn insert(&mut self, key: u64) {
+        for i in self.slots(key) {
+            self.counters[i] = self.counters[i].saturating_add(1);
+        }
+    }
+
+    /// Снять одно вхождение ключа (насыщенные счётчики не трогаются).
+    pub fn remove(&mut self, key: u64) {
+        for i in self.slots(key) {
+            let c = self.counters[i];
+            if c != 0 && c != u8::MAX {
+                self.counters[i] = c - 1;
+            }
+        }
+    }
+
+    /// `false` — ключа точно нет; `true` — возможно есть.
+    #[inline]
+    pub fn may_contain(&self, key: u64) -> bool {
+        self.slots(key).iter().all(|&i| self.counters[i] != 0)
+    }
+
+    /// Обнулить все счётчики.
+    pub fn clear(&mut self) {
+        self.counters.fill(0);
+    }
+}
+
+/// Статистика Bloom pre-check в Phase 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BloomStats {
+    /// Определённые промахи, отсечённые фильтром
+    pub rejects: u64,
+    /// Фильтр пропустил, ключ найден в таблице
+    pub true_positives: u64,
+    /// Фильтр пропустил, ключа в таблице нет
+    pub false_positives: u64,
+}
+
+impl BloomStats {
+    /// Доля ложноположительных среди всех отсутствующих ключей.
+    pub fn false_positive_rate(&self) -> f32 {
+        let negatives = self.rejects + self.false_positives;
+        if negatives == 0 {
+            0.0
+        } else {
+            self.false_positives as f32 / negatives as f32
+        }
+    }
+}
+
 /// Ассоциативный индекс: grid_hash → список trace_id (created_at)
 ///
 /// Обеспечивает O(1) Phase 1 поиск в Experience.
@@ -81,6 +173,12 @@ pub struct AssociativeIndex {
     reverse: HashMap<u64, u64>,
     /// Shift-фактор для coarsening позиции
     pub shift: u32,
+    /// Pre-check перед table: отсекает определённые промахи
+    bloom: KeyBloom,
+    /// Счётчики Bloom pre-check. Cell — lookup вызывается через &self.
+    bloom_rejects: Cell<u64>,
+    bloom_true_positives: Cell<u64>,
+    bloom_false_positives: Cell<u64>,
 }
 
 impl AssociativeIndex {
@@ -88,10 +186,19 @@ impl AssociativeIndex {
     ///
     /// `shift = 4` — хороший старт (ячейки 16 квантов).
     pub fn new(shift: u32) -> Self {
+        Self::with_bloom_slots(shift, BLOOM_DEFAULT_LOG2_SLOTS)
+    }
+
+    /// Создать индекс с KeyBloom на `1 << log2_slots` счётчиков.
+    pub fn with_bloom_slots(shift: u32, log2_slots: u32) -> Self {
         Self {
             table: HashMap::new(),
             reverse: HashMap::new(),
             shift,
+            bloom: KeyBloom::new(log2_slots),
+            bloom_rejects: Cell::new(0),
+            bloom_true_positives: Cell::new(0),
+            bloom_false_positives: Cell::new(0),
         }
     }
 
@@ -99,6 +206,7 @@ impl AssociativeIndex {
     pub fn insert(&mut self, key: u64, trace_id: u64) {
         self.table.entry(key).or_default().push(trace_id);
         self.reverse.insert(trace_id, key);
+        self.bloom.insert(key);
     }
 
     /// Удалить след из индекса по trace_id
@@ -106,6 +214,7 @@ impl AssociativeIndex {
     /// Возвращает true если след был найден и удалён.
     pub fn remove_by_trace_id(&mut self, trace_id: u64) -> bool {
         if let Some(key) = self.reverse.remove(&trace_id) {
+            self.bloom.remove(key);
             if let Some(ids) = self.table.get_mut(&key) {
                 ids.retain(|&id| id != trace_id);
                 if ids.is_empty() {
@@ -120,8 +229,29 @@ impl AssociativeIndex {
     /// Найти trace_id по ключу
     ///
     /// Возвращает срез `created_at` значений для этого grid-ключа.
+    /// Сначала KeyBloom: определённый промах не доходит до HashMap.
     pub fn lookup(&self, key: u64) -> Option<&[u64]> {
-        self.table.get(&key).map(|v| v.as_slice())
+        if !self.bloom.may_contain(key) {
+            self.bloom_rejects.set(self.bloom_rejects.get() + 1);
+            return None;
+        }
+        let found = self.table.get(&key).map(|v| v.as_slice());
+        let counter = if found.is_some() {
+            &self.bloom_true_positives
+        } else {
+            &self.bloom_false_positives
+        };
+        counter.set(counter.get() + 1);
+        found
+    }
+
+    /// Статистика Bloom pre-check (накопительная, не сбрасывается `clear()`).
+    pub fn bloom_stats(&self) -> BloomStats {
+        BloomStats {
+            rejects: self.bloom_rejects.get(),
+            true_positives: self.bloom_true_positives.get(),
+            false_positives: self.bloom_false_positives.get(),
+        }
     }
 
     /// Количество уникальных ячеек (занятых grid-ключей)
@@ -138,6 +268,7 @@ impl AssociativeIndex {
     pub fn clear(&mut self) {
         self.table.clear();
         self.reverse.clear();
+        self.bloom.clear();
     }
 }
 
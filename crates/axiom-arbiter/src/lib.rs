@@ -18,7 +18,9 @@ mod com;
 pub mod experience;
 mod gridhash;
 mod maya_processor;
+pub mod query;
 mod reflector;
+pub mod replay;
 mod skillset;
 
 use ashti_processor::{membrane_transform, AshtiProcessor};
@@ -32,11 +34,13 @@ use std::collections::HashMap;
 pub use axiom_genome::MembraneProfile;
 pub use com::COM;
 pub use experience::{
-    Experience as ExperienceModule, ExperienceTrace, ResonanceLevel as ResonanceLevelEnum,
-    TensionTrace,
+    Experience as ExperienceModule, ExperienceTrace, OverflowPolicy, OverflowStats,
+    ResonanceLevel as ResonanceLevelEnum, TensionTrace, MAX_SPILLED_TRACES,
 };
-pub use gridhash::{grid_hash, grid_hash_with_shell, AssociativeIndex};
+pub use gridhash::{grid_hash, grid_hash_with_shell, AssociativeIndex, BloomStats, KeyBloom};
+pub use query::TraceFilter;
 pub use reflector::{DomainProfile, Reflector, ReflexStats};
+pub use replay::{ExperienceBatch, SamplingStrategy, PRIORITY_EPSILON};
 pub use skillset::{Skill, SkillSet};
 
 // ── Cognitive Depth V1.0 — 13D: Goal & Curiosity ─────────────────────────────
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// TraceFilter — отбор следов опыта по предикатам.
+//
+// Потребители, которым нужны не все следы (цели, один домен, сильные
+// рефлексы), обходят `traces()` и фильтруют вручную. `Experience::query`
+// делает это ленивым итератором без копирования буфера. Пустой фильтр
+// (Default) пропускает всё; условия объединяются по И.
+
+use crate::experience::{Experience, ExperienceTrace};
+
+/// Предикаты отбора следов.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TraceFilter {
+    /// Вес следа в [min, max]
+    pub weight: Option<(f32, f32)>,
+    /// `pattern.sutra_id` из списка (пусто — любой)
+    pub sutra_ids: Vec<u32>,
+    /// `pattern.domain_id`
+    pub domain_id: Option<u16>,
+    /// Все эти биты `pattern.type_flags` установлены
+    pub flags_all: u16,
+    /// Ни один из этих битов `pattern.type_flags` не установлен
+    pub flags_none: u16,
+    /// `created_at` в [from, to)
+    pub created: Option<(u64, u64)>,
+    /// Не меньше успешных рефлексов
+    pub min_success: u32,
+}
+
+impl TraceFilter {
+    /// Проходит ли след фильтр.
+    pub fn accepts(&self, t: &ExperienceTrace) -> bool {
+        let flags = t.pattern.type_flags;
+        self.weight.is_none_or(|(min, max)| (min..=max).contains(&t.weight))
+            && (self.sutra_ids.is_empty() || self.sutra_ids.contains(&t.pattern.sutra_id))
+            && self.domain_id.is_none_or(|d| t.pattern.domain_id == d)
+            && flags & self.flags_all == self.flags_all
+            && flags & self.flags_none == 0
+            && self.created.is_none_or(|(from, to)| (from..to).contains(&t.created_at))
+            && t.success_count >= self.min_success
+    }
+}
+
+impl Experience {
+    /// Следы, проходящие фильтр, с индексами в `traces()`; лениво, по порядку.
+    pub fn query<'a, 'f>(
+        &'a self,
+        filter: &'f TraceFilter,
+    ) -> impl Iterator<Item = (usize, &'a ExperienceTrace)> + use<'a, 'f> {
+        self.traces().iter().enumerate().filter(move |(_, t)| filter.accepts(t))
+    }
+}
@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Replay — выборка следов опыта для переигрывания.
+//
+// Потребители (DREAMING, обучение советников) переигрывают опыт батчами.
+// Равномерная выборка тратит циклы на слабые следы, поэтому есть
+// приоритетная (Prioritized Experience Replay): P(i) = p_i^α / Σ p^α, где
+// приоритет p_i — вес следа (накопленное подкрепление). Смещение выборки
+// компенсируют importance-sampling веса w_i = (N·P(i))^-β, нормированные
+// на максимум по буферу. Выборка детерминирована по `seed`.
+//
+// Выборку можно сузить: TimeWindow — следы, созданные в интервале event_id
+// (причинное время COM), `sample_session` — следы токенов одного разговора
+// (sutra_id из SessionContext источника).
+
+use crate::experience::{Experience, ExperienceTrace};
+
+/// Добавка к приоритету: следы с нулевым весом остаются достижимыми.
+pub const PRIORITY_EPSILON: f32 = 0.01;
+
+/// Стратегия выборки батча.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SamplingStrategy {
+    /// Равновероятно, с возвращением
+    Uniform,
+    /// Последние использованные следы, без повторов
+    Recent,
+    /// Пропорционально весу следа в степени `alpha`, с возвращением;
+    /// `beta` — сила коррекции смещения (0 — нет, 1 — полная)
+    Prioritized { alpha: f32, beta: f32 },
+    /// Равновероятно среди следов с `created_at` в [from, to), с возвращением
+    TimeWindow { from: u64, to: u64 },
+}
+
+/// Батч следов для переигрывания.
+#[derive(Debug, Clone, Default)]
+pub struct ExperienceBatch {
+    /// Индексы следов в `Experience::traces()`
+    pub indices: Vec<usize>,
+    /// Следы батча
+    pub traces: Vec<ExperienceTrace>,
+    /// Importance-sampling веса (1.0 для всех стратегий, кроме Prioritized)
+    pub weights: Vec<f32>,
+}
+
+impl ExperienceBatch {
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+impl Experience {
+    /// Выбрать `n` следов по стратегии. Пустой Experience — пустой батч;
+    /// Recent возвращает не больше `trace_count()` следов.
+    pub fn sample_batch(&self, n: usize, strategy: SamplingStrategy, seed: u64) -> ExperienceBatch {
+        self.sample_among((0..self.trace_count()).collect(), n, strategy, seed)
+    }
+
+    /// Как `sample_batch`, но только среди следов, чей паттерн — токен
+    /// разговора (`pattern.sutra_id` из `session_sutra_ids`).
+    pub fn sample_session(
+        &self,
+        n: usize,
+        strategy: SamplingStrategy,
+        session_sutra_ids: &[u32],
+        seed: u64,
+    ) -> ExperienceBatch {
+        let candidates = (0..self.trace_count())
+            .filter(|&i| session_sutra_ids.contains(&self.traces()[i].pattern.sutra_id))
+            .collect();
+        self.sample_among(candidates, n, strategy, seed)
+    }
+
+    fn sample_among(
+        &self,
+        mut candidates: Vec<usize>,
+        n: usize,
+        strategy: SamplingStrategy,
+        seed: u64,
+    ) -> ExperienceBatch {
+        let traces = self.traces();
+        if let SamplingStrategy::TimeWindow { from, to } = strategy {
+            candidates.retain(|&i| (from..to).contains(&traces[i].created_at));
+        }
+        if candidates.is_empty() || n == 0 {
+            return ExperienceBatch::default();
+        }
+        let mut rng = XorShift::new(seed);
+        let (indices, weights) = match strategy {
+            SamplingStrategy::Uniform | SamplingStrategy::TimeWindow { .. } => {
+                let indices: Vec<usize> = (0..n)
+                    .map(|_| candidates[(rng.next_u64() % candidates.len() as u64) as usize])
+                    .collect();
+                (indices, vec![1.0; n])
+            }
+            SamplingStrategy::Recent => {
+                candidates.sort_by(|&a, &b| {
+                    let key = |i: usize| (traces[i].last_used, traces[i].created_at);
+                    key(b).cmp(&key(a))
+                });
+                candidates.truncate(n);
+                let len = candidates.len();
+                (candidates, vec![1.0; len])
+            }
+            SamplingStrategy::Prioritized { alpha, beta } => {
+                prioritized(traces, &candidates, n, alpha, beta, &mut rng)
+            }
+        };
+        let batch_traces = indices.iter().map(|&i| traces[i].clone()).collect();
+        ExperienceBatch { indices, traces: batch_traces, weights }
+    }
+}
+
+fn prioritized(
+    traces: &[ExperienceTrace],
+    candidates: &[usize],
+    n: usize,
+    alpha: f32,
+    beta: f32,
+    rng: &mut XorShift,
+) -> (Vec<usize>, Vec<f32>) {
+    let priorities: Vec<f64> = candidates
+        .iter()
+        .map(|&i| ((traces[i].weight.max(0.0) + PRIORITY_EPSILON) as f64).powf(alpha as f64))
+        .collect();
+    let mut cumulative = Vec::with_capacity(priorities.len());
+    let mut total = 0.0f64;
+    for p in &priorities {
+        total += p;
+        cumulative.push(total);
+    }
+    let count = candidates.len() as f64;
+    // w_i = (N·P(i))^-β / max_j w_j; максимум — у следа с наименьшим P
+    let min_p = priorities.iter().copied().fold(f64::INFINITY, f64::min);
+    let max_w = (count * min_p / total).powf(-beta as f64);
+
+    let mut indices = Vec::with_capacity(n);
+    let mut weights = Vec::with_capacity(n);
+    for _ in 0..n {
+        let r = rng.next_f64() * total;
+        let k = cumulative.partition_point(|&c| c <= r).min(candidates.len() - 1);
+        indices.push(candidates[k]);
+        weights.push(((count * priorities[k] / total).powf(-beta as f64) / max_w) as f32);
+    }
+    (indices, weights)
+}
+
+/// Xorshift64 — детерминированный генератор без внешних зависимостей.
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        Self((seed ^ 0x6c62_272e_07bb_0142).max(1))
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Равномерно в [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
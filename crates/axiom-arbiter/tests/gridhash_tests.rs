@@ -1,4 +1,4 @@
-use axiom_arbiter::{grid_hash, grid_hash_with_shell, AssociativeIndex, ExperienceModule};
+use axiom_arbiter::{grid_hash, grid_hash_with_shell, AssociativeIndex, ExperienceModule, KeyBloom};
 use axiom_core::Token;
 
 fn token_at(x: i16, y: i16, z: i16, temp: u8, mass: u8) -> Token {
@@ -254,3 +254,61 @@ fn test_grid_hash_collision_rate() {
         keys.len()
     );
 }
+
+// ─── KeyBloom: pre-check Phase 1 ─────────────────────────────────────────────
+
+#[test]
+fn test_bloom_no_false_negatives() {
+    let mut bloom = KeyBloom::new(10);
+    let keys: Vec<u64> = (0..200u64).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15)).collect();
+    for &k in &keys {
+        bloom.insert(k);
+    }
+    assert!(keys.iter().all(|&k| bloom.may_contain(k)));
+}
+
+#[test]
+fn test_bloom_remove_forgets_key() {
+    let mut bloom = KeyBloom::new(16);
+    bloom.insert(0xABCD_1234_5678_9EF0);
+    bloom.remove(0xABCD_1234_5678_9EF0);
+    assert!(!bloom.may_contain(0xABCD_1234_5678_9EF0));
+}
+
+#[test]
+fn test_index_bloom_rejects_miss() {
+    let mut idx = AssociativeIndex::new(4);
+    idx.insert(42, 100);
+    assert!(idx.lookup(0xDEAD_BEEF_0000_0001).is_none());
+    assert!(idx.lookup(42).is_some());
+    let stats = idx.bloom_stats();
+    assert_eq!(stats.rejects, 1);
+    assert_eq!(stats.true_positives, 1);
+}
+
+#[test]
+fn test_index_bloom_tracks_false_positive_rate() {
+    // 4 счётчика — фильтр почти сразу насыщается, промахи проходят как FP
+    let mut idx = AssociativeIndex::with_bloom_slots(4, 2);
+    for k in 0..16u64 {
+        idx.insert(k.wrapping_mul(0x9E3779B97F4A7C15), k);
+    }
+    for k in 100..200u64 {
+        assert!(idx.lookup(k.wrapping_mul(0x9E3779B97F4A7C15)).is_none());
+    }
+    let stats = idx.bloom_stats();
+    assert_eq!(stats.rejects + stats.false_positives, 100);
+    assert!(stats.false_positive_rate() > 0.5);
+}
+
+#[test]
+fn test_index_remove_keeps_shared_key_in_bloom() {
+    let mut idx = AssociativeIndex::new(4);
+    idx.insert(7, 1);
+    idx.insert(7, 2);
+    idx.remove_by_trace_id(1);
+    assert!(idx.lookup(7).is_some(), "второй след в той же ячейке ещё жив");
+    idx.remove_by_trace_id(2);
+    assert!(idx.lookup(7).is_none());
+    assert_eq!(idx.bloom_stats().rejects, 1);
+}
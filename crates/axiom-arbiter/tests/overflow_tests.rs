@@ -0,0 +1,108 @@
+// Тесты OverflowPolicy — поведение Experience при достижении max_traces
+
+use axiom_arbiter::{
+    ExperienceModule as Experience, OverflowPolicy, OverflowStats, MAX_SPILLED_TRACES,
+};
+use axiom_core::Token;
+
+fn token(i: u32) -> Token {
+    let mut t = Token::new(i, 109, [i as i16 * 100, 0, 0], 1);
+    t.temperature = (i * 40) as u8;
+    t
+}
+
+/// Лимит 3; следы created_at 1..=3 с весами 0.5, 0.1, 0.9.
+fn full(policy: OverflowPolicy) -> Experience {
+    let mut exp = Experience::new();
+    exp.set_max_traces(3);
+    exp.set_overflow_policy(policy);
+    for (i, w) in [(1, 0.5), (2, 0.1), (3, 0.9)] {
+        exp.add_trace(token(i), w, i as u64);
+    }
+    exp
+}
+
+fn created(exp: &Experience) -> Vec<u64> {
+    let mut ids: Vec<u64> = exp.traces().iter().map(|t| t.created_at).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_default_policy_drops_lowest_weight() {
+    let mut exp = full(OverflowPolicy::default());
+    assert_eq!(exp.overflow_policy(), OverflowPolicy::DropLowestWeight);
+    exp.add_trace(token(4), 0.3, 4);
+    assert_eq!(created(&exp), vec![1, 3, 4]);
+    assert_eq!(exp.overflow_stats().evicted_lowest_weight, 1);
+    assert!(exp.drain_spilled().is_empty());
+}
+
+#[test]
+fn test_drop_oldest_policy() {
+    let mut exp = full(OverflowPolicy::DropOldest);
+    exp.add_trace(token(4), 0.3, 4);
+    exp.add_trace(token(5), 0.3, 5);
+    assert_eq!(created(&exp), vec![3, 4, 5]);
+    assert_eq!(exp.overflow_stats(), OverflowStats { evicted_oldest: 2, ..Default::default() });
+}
+
+#[test]
+fn test_reject_new_policy_keeps_existing() {
+    let mut exp = full(OverflowPolicy::RejectNew);
+    exp.add_trace(token(4), 1.0, 4);
+    assert_eq!(created(&exp), vec![1, 2, 3]);
+    assert_eq!(exp.overflow_stats().rejected, 1);
+    // Индекс не получил ключ отклонённого следа
+    assert_eq!(exp.index.trace_count(), 3);
+}
+
+#[test]
+fn test_spill_policy_hands_out_evicted_traces() {
+    let mut exp = full(OverflowPolicy::Spill);
+    exp.add_trace(token(4), 0.3, 4);
+    exp.add_trace(token(5), 0.8, 5);
+    assert_eq!(created(&exp), vec![1, 3, 5]);
+    assert_eq!(exp.overflow_stats().spilled, 2);
+    let spilled: Vec<u64> = exp.drain_spilled().iter().map(|t| t.created_at).collect();
+    assert_eq!(spilled, vec![2, 4]);
+    assert!(exp.drain_spilled().is_empty());
+}
+
+#[test]
+fn test_spill_queue_is_bounded() {
+    let mut exp = Experience::new();
+    exp.set_max_traces(1);
+    exp.set_overflow_policy(OverflowPolicy::Spill);
+    let total = MAX_SPILLED_TRACES as u64 + 5;
+    for i in 0..=total {
+        exp.add_trace(token(1), 0.5, i);
+    }
+    assert_eq!(exp.spilled_len(), MAX_SPILLED_TRACES);
+    assert_eq!(exp.overflow_stats().spilled, total);
+    assert_eq!(exp.overflow_stats().spill_dropped, 5);
+    // Теряются старейшие незабранные
+    assert_eq!(exp.drain_spilled()[0].created_at, 5);
+}
+
+#[test]
+fn test_policy_names_round_trip() {
+    for policy in [
+        OverflowPolicy::DropLowestWeight,
+        OverflowPolicy::DropOldest,
+        OverflowPolicy::RejectNew,
+        OverflowPolicy::Spill,
+    ] {
+        assert_eq!(OverflowPolicy::from_name(policy.name()), Some(policy));
+    }
+    assert_eq!(OverflowPolicy::from_name("lru"), None);
+}
+
+#[test]
+fn test_import_trace_follows_policy() {
+    let mut exp = full(OverflowPolicy::RejectNew);
+    let extra = exp.traces()[2].clone();
+    exp.import_trace(extra);
+    assert_eq!(exp.trace_count(), 3);
+    assert_eq!(exp.overflow_stats().rejected, 1);
+}
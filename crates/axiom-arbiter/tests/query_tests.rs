@@ -0,0 +1,60 @@
+// Тесты TraceFilter и Experience::query
+
+use axiom_arbiter::{ExperienceModule as Experience, TraceFilter, TOKEN_FLAG_GOAL};
+use axiom_core::Token;
+
+/// Следы: sutra_id 1..=6, вес i/10, чётные — цели, домены 100/101 поочерёдно.
+fn experience() -> Experience {
+    let mut exp = Experience::new();
+    for i in 1..=6u32 {
+        let mut t = Token::new(i, 100 + (i % 2) as u16, [i as i16 * 50, 0, 0], 1);
+        t.temperature = (i * 30) as u8;
+        if i % 2 == 0 {
+            t.type_flags |= TOKEN_FLAG_GOAL;
+        }
+        exp.add_trace(t, i as f32 / 10.0, i as u64 * 10);
+    }
+    exp
+}
+
+fn sutra_ids(exp: &Experience, filter: &TraceFilter) -> Vec<u32> {
+    exp.query(filter).map(|(_, t)| t.pattern.sutra_id).collect()
+}
+
+#[test]
+fn test_query_default_filter_returns_all_in_order() {
+    let exp = experience();
+    assert_eq!(sutra_ids(&exp, &TraceFilter::default()), vec![1, 2, 3, 4, 5, 6]);
+    let (idx, trace) = exp.query(&TraceFilter::default()).nth(3).unwrap();
+    assert_eq!(exp.traces()[idx].created_at, trace.created_at);
+}
+
+#[test]
+fn test_query_single_predicates() {
+    let exp = experience();
+    let by = |f: TraceFilter| sutra_ids(&exp, &f);
+    assert_eq!(by(TraceFilter { weight: Some((0.25, 0.45)), ..Default::default() }), vec![3, 4]);
+    assert_eq!(by(TraceFilter { sutra_ids: vec![6, 1, 9], ..Default::default() }), vec![1, 6]);
+    assert_eq!(by(TraceFilter { domain_id: Some(101), ..Default::default() }), vec![1, 3, 5]);
+    let goal = TOKEN_FLAG_GOAL;
+    assert_eq!(by(TraceFilter { flags_all: goal, ..Default::default() }), vec![2, 4, 6]);
+    assert_eq!(by(TraceFilter { flags_none: goal, ..Default::default() }), vec![1, 3, 5]);
+    assert_eq!(by(TraceFilter { created: Some((20, 40)), ..Default::default() }), vec![2, 3]);
+}
+
+#[test]
+fn test_query_combines_predicates_and_success() {
+    let mut exp = experience();
+    exp.strengthen_trace(3, 0.0);
+    exp.strengthen_trace(3, 0.0);
+    let filter = TraceFilter {
+        flags_all: TOKEN_FLAG_GOAL,
+        weight: Some((0.3, 1.0)),
+        ..Default::default()
+    };
+    assert_eq!(sutra_ids(&exp, &filter), vec![4, 6]);
+    let filter = TraceFilter { min_success: 2, ..filter };
+    assert_eq!(sutra_ids(&exp, &filter), vec![4]);
+    let filter = TraceFilter { domain_id: Some(101), ..filter };
+    assert_eq!(exp.query(&filter).count(), 0);
+}
@@ -0,0 +1,107 @@
+// Тесты выборки следов опыта для переигрывания (SamplingStrategy)
+
+use axiom_arbiter::{ExperienceModule as Experience, ExperienceTrace, SamplingStrategy};
+use axiom_core::Token;
+
+fn experience(weights: &[f32]) -> Experience {
+    let mut exp = Experience::new();
+    for (i, &w) in weights.iter().enumerate() {
+        let mut t = Token::new(i as u32 + 1, 109, [i as i16 * 100, 0, 0], 1);
+        t.temperature = (i * 20) as u8;
+        exp.add_trace(t, w, i as u64 + 1);
+    }
+    exp
+}
+
+#[test]
+fn test_sample_batch_empty_experience() {
+    let exp = Experience::new();
+    assert!(exp.sample_batch(8, SamplingStrategy::Uniform, 1).is_empty());
+    assert!(experience(&[0.5]).sample_batch(0, SamplingStrategy::Recent, 1).is_empty());
+}
+
+#[test]
+fn test_sample_batch_uniform_is_deterministic() {
+    let exp = experience(&[0.1, 0.2, 0.3, 0.4]);
+    let a = exp.sample_batch(32, SamplingStrategy::Uniform, 7);
+    let b = exp.sample_batch(32, SamplingStrategy::Uniform, 7);
+    assert_eq!(a.len(), 32);
+    assert_eq!(a.indices, b.indices);
+    assert!(a.weights.iter().all(|&w| w == 1.0));
+    assert!(a.indices.iter().all(|&i| i < 4));
+    let same_trace =
+        |(&i, t): (&usize, &ExperienceTrace)| exp.traces()[i].created_at == t.created_at;
+    assert!(a.indices.iter().zip(&a.traces).all(same_trace));
+}
+
+#[test]
+fn test_sample_batch_recent_orders_by_last_used() {
+    let mut exp = experience(&[0.5, 0.5, 0.5]);
+    // Повторный паттерн первого следа обновляет его last_used
+    let first = exp.traces()[0].pattern;
+    exp.strengthen_or_add(first, 0.5, 100);
+    let batch = exp.sample_batch(10, SamplingStrategy::Recent, 0);
+    let created: Vec<u64> = batch.traces.iter().map(|t| t.created_at).collect();
+    assert_eq!(created, vec![1, 3, 2]);
+}
+
+#[test]
+fn test_sample_batch_prioritized_prefers_heavy_traces() {
+    let exp = experience(&[0.0, 0.05, 1.0, 0.02]);
+    let strategy = SamplingStrategy::Prioritized { alpha: 1.0, beta: 1.0 };
+    let batch = exp.sample_batch(400, strategy, 3);
+    let heavy = batch.indices.iter().filter(|&&i| i == 2).count();
+    assert!(heavy > 300, "heavy trace sampled {heavy} times");
+
+    // Редкие следы получают больший IS-вес, максимум нормирован к 1
+    let weight_of = |idx: usize| {
+        batch.indices.iter().position(|&i| i == idx).map(|p| batch.weights[p])
+    };
+    let heavy_w = weight_of(2).unwrap();
+    assert!(batch.weights.iter().all(|&w| w > 0.0 && w <= 1.0));
+    assert!(batch.weights.iter().all(|&w| w >= heavy_w));
+    if let Some(light_w) = weight_of(0) {
+        assert!((light_w - 1.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_sample_batch_prioritized_alpha_zero_is_uniform() {
+    let exp = experience(&[0.0, 1.0]);
+    let strategy = SamplingStrategy::Prioritized { alpha: 0.0, beta: 1.0 };
+    let batch = exp.sample_batch(1000, strategy, 11);
+    let zeros = batch.indices.iter().filter(|&&i| i == 0).count();
+    assert!((400..600).contains(&zeros), "{zeros}");
+    assert!(batch.weights.iter().all(|&w| (w - 1.0).abs() < 1e-6));
+}
+
+#[test]
+fn test_sample_batch_time_window() {
+    // created_at = 1..=6
+    let exp = experience(&[0.5; 6]);
+    let batch = exp.sample_batch(50, SamplingStrategy::TimeWindow { from: 2, to: 4 }, 5);
+    assert_eq!(batch.len(), 50);
+    assert!(batch.traces.iter().all(|t| (2..4).contains(&t.created_at)));
+    assert!(batch.traces.iter().any(|t| t.created_at == 2));
+    assert!(batch.traces.iter().any(|t| t.created_at == 3));
+    assert!(exp.sample_batch(5, SamplingStrategy::TimeWindow { from: 7, to: 9 }, 5).is_empty());
+}
+
+#[test]
+fn test_sample_session_scopes_to_sutra_ids() {
+    // sutra_id = 1..=5
+    let exp = experience(&[0.1, 0.9, 0.2, 0.8, 0.3]);
+    let session = [2, 5];
+    let batch = exp.sample_session(40, SamplingStrategy::Uniform, &session, 1);
+    assert!(batch.traces.iter().all(|t| session.contains(&t.pattern.sutra_id)));
+
+    let recent = exp.sample_session(10, SamplingStrategy::Recent, &session, 1);
+    assert_eq!(recent.traces.iter().map(|t| t.pattern.sutra_id).collect::<Vec<_>>(), vec![5, 2]);
+
+    // Prioritized нормирует веса внутри области
+    let strategy = SamplingStrategy::Prioritized { alpha: 1.0, beta: 1.0 };
+    let scoped = exp.sample_session(100, strategy, &session, 2);
+    assert!(scoped.indices.iter().all(|&i| i == 1 || i == 4));
+    assert!(scoped.weights.iter().all(|&w| w > 0.0 && w <= 1.0));
+    assert!(exp.sample_session(10, SamplingStrategy::Uniform, &[42], 1).is_empty());
+}
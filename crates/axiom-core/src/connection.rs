@@ -14,7 +14,8 @@
 //! - `last_event_id >= created_at` — события монотонно возрастают
 //! - Размер структуры строго 64 байта
 
-use std::fmt;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 /// Флаги состояния связи
 pub const FLAG_ACTIVE: u32 = 1;
@@ -24,6 +25,13 @@ pub const FLAG_INHIBITED: u32 = 2;
 pub const FLAG_TEMPORARY: u32 = 4;
 /// Связь находится в критическом состоянии
 pub const FLAG_CRITICAL: u32 = 8;
+/// Связь давно не активировалась и ослабла ниже порога — кандидат на удаление
+pub const FLAG_PRUNE_CANDIDATE: u32 = 16;
+/// Половина управляемой пары связей: обратная связь (target → source, тот же
+/// link_type) существует и синхронизируется с этой
+pub const FLAG_BIDIRECTIONAL: u32 = 32;
+/// Связь удалена (tombstone): неактивна, место в буфере освобождает компакция
+pub const FLAG_TOMBSTONE: u32 = 64;
 
 /// Connection — связь между двумя токенами
 ///
@@ -32,6 +40,7 @@ pub const FLAG_CRITICAL: u32 = 8;
 #[repr(C, align(64))]
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Connection {
     // --- ТОПОЛОГИЯ (16 Байт) ---
     /// ID токена-источника связи
@@ -69,7 +78,8 @@ pub struct Connection {
     /// Термальный шлюз (максимальная температура для прохождения)
     pub thermal_gate: u8,
 
-    /// Резерв для будущих шлюзов
+    /// Резерв шлюзов: [0..4] origin_domain/role_id (BondTokens),
+    /// [4..8] TTL (`set_ttl`), [8..14] конец действительности (`set_valid_to`)
     pub reserved_gate: [u8; 14],
 
     // --- МЕТАДАННЫЕ (16 Байт) ---
@@ -81,7 +91,7 @@ pub struct Connection {
 }
 
 // Проверка размера на этапе компиляции
-const _: () = assert!(std::mem::size_of::<Connection>() == 64);
+const _: () = assert!(core::mem::size_of::<Connection>() == 64);
 
 impl Default for Connection {
     fn default() -> Self {
@@ -139,6 +149,12 @@ impl Connection {
         (self.flags & FLAG_INHIBITED) != 0
     }
 
+    /// Проверяет, удалена ли связь (ждёт компакции)
+    #[inline]
+    pub fn is_tombstoned(&self) -> bool {
+        (self.flags & FLAG_TOMBSTONE) != 0
+    }
+
     /// Проверяет, временная ли связь
     #[inline]
     pub fn is_temporary(&self) -> bool {
@@ -151,6 +167,72 @@ impl Connection {
         (self.flags & FLAG_CRITICAL) != 0
     }
 
+    /// Задать время жизни: связь истекает через `ttl` COM-событий после created_at.
+    ///
+    /// TTL хранится в reserved_gate[4..8] (u32 LE; [0..4] занят BondTokens
+    /// под origin_domain и role_id) и взводит FLAG_TEMPORARY. `ttl = 0` снимает TTL.
+    pub fn set_ttl(&mut self, ttl: u32) {
+        self.reserved_gate[4..8].copy_from_slice(&ttl.to_le_bytes());
+        if ttl == 0 {
+            self.flags &= !FLAG_TEMPORARY;
+        } else {
+            self.flags |= FLAG_TEMPORARY;
+        }
+    }
+
+    /// Время жизни в COM-событиях; None — связь бессрочная
+    pub fn ttl(&self) -> Option<u32> {
+        let ttl = u32::from_le_bytes([
+            self.reserved_gate[4],
+            self.reserved_gate[5],
+            self.reserved_gate[6],
+            self.reserved_gate[7],
+        ]);
+        (self.is_temporary() && ttl != 0).then_some(ttl)
+    }
+
+    /// Истекла ли связь к моменту `now` (COM event_id)
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.ttl().is_some_and(|ttl| now >= self.created_at.saturating_add(ttl as u64))
+    }
+
+    /// Наибольший момент закрытия, который помещается в reserved_gate[8..14]
+    pub const MAX_VALID_TO: u64 = (1 << 48) - 1;
+
+    /// Закрыть связь: с момента `valid_to` (COM event_id) она считается
+    /// недействительной, но остаётся в домене как история.
+    ///
+    /// Хранится в reserved_gate[8..14] (u48 LE), значения выше MAX_VALID_TO
+    /// обрезаются. `valid_to = 0` снова открывает связь.
+    pub fn set_valid_to(&mut self, valid_to: u64) {
+        let bytes = valid_to.min(Self::MAX_VALID_TO).to_le_bytes();
+        self.reserved_gate[8..14].copy_from_slice(&bytes[..6]);
+    }
+
+    /// Начало интервала действительности — момент создания
+    #[inline]
+    pub fn valid_from(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Конец интервала действительности (не включительно): ранний из явного
+    /// закрытия и истечения TTL. None — связь открыта.
+    pub fn valid_to(&self) -> Option<u64> {
+        let mut bytes = [0u8; 8];
+        bytes[..6].copy_from_slice(&self.reserved_gate[8..14]);
+        let closed = Some(u64::from_le_bytes(bytes)).filter(|&v| v != 0);
+        let expires = self.ttl().map(|ttl| self.created_at.saturating_add(ttl as u64));
+        match (closed, expires) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Действительна ли связь в момент `at` (COM event_id)
+    pub fn is_valid_at(&self, at: u64) -> bool {
+        at >= self.valid_from() && self.valid_to().is_none_or(|end| at < end)
+    }
+
     /// Валидирует инварианты связи
     ///
     /// # Returns
@@ -233,7 +315,8 @@ impl Connection {
     /// * `target_pos` - Позиция токена-цели
     ///
     /// # Returns
-    /// Евклидово расстояние между позициями
+    /// Евклидово расстояние между позициями (требует `std`: f32::sqrt)
+    #[cfg(feature = "std")]
     pub fn compute_distance(&self, source_pos: [i32; 3], target_pos: [i32; 3]) -> f32 {
         let dx = (target_pos[0] - source_pos[0]) as f32;
         let dy = (target_pos[1] - source_pos[1]) as f32;
@@ -0,0 +1,99 @@
+//! Пакетная (де)сериализация связей в 64-байтовые записи
+//!
+//! Формат тот же, что у пакета токенов (`token_io`): заголовок
+//! `BATCH_HEADER_LEN` байт с сигнатурой `CONNECTION_BATCH_MAGIC`, затем `count`
+//! записей по 64 байта в нативной раскладке Connection (little-endian, без
+//! padding). Пакет не зависит от токенов: связи переносятся между runtime
+//! без пространства и самих токенов.
+
+use std::io::{self, Read, Write};
+
+use crate::connection::Connection;
+use crate::token_io::{
+    check_checksum, fnv1a, read_header, write_header, FNV_OFFSET, MAX_PREALLOC,
+};
+
+/// Сигнатура пакета связей
+pub const CONNECTION_BATCH_MAGIC: [u8; 4] = *b"AXCB";
+
+/// Длина одной записи — размер Connection
+pub const CONNECTION_RECORD_LEN: usize = 64;
+
+impl Connection {
+    /// Нативная раскладка связи (little-endian) — совпадает с памятью
+    /// на little-endian платформах
+    pub fn to_le_bytes(&self) -> [u8; CONNECTION_RECORD_LEN] {
+        let mut b = [0u8; CONNECTION_RECORD_LEN];
+        let mut off = 0;
+        let mut put = |bytes: &[u8]| {
+            b[off..off + bytes.len()].copy_from_slice(bytes);
+            off += bytes.len();
+        };
+        put(&self.source_id.to_le_bytes());
+        put(&self.target_id.to_le_bytes());
+        put(&self.domain_id.to_le_bytes());
+        put(&self.link_type.to_le_bytes());
+        put(&self.flags.to_le_bytes());
+        for v in [self.strength, self.current_stress, self.ideal_dist, self.elasticity] {
+            put(&v.to_le_bytes());
+        }
+        put(&[self.density_gate, self.thermal_gate]);
+        put(&self.reserved_gate);
+        put(&self.created_at.to_le_bytes());
+        put(&self.last_event_id.to_le_bytes());
+        b
+    }
+
+    /// Обратное к `to_le_bytes`
+    pub fn from_le_bytes(b: &[u8; CONNECTION_RECORD_LEN]) -> Connection {
+        let u16_at = |o: usize| u16::from_le_bytes([b[o], b[o + 1]]);
+        let u32_at = |o: usize| u32::from_le_bytes(b[o..o + 4].try_into().unwrap());
+        let f32_at = |o: usize| f32::from_le_bytes(b[o..o + 4].try_into().unwrap());
+        let u64_at = |o: usize| u64::from_le_bytes(b[o..o + 8].try_into().unwrap());
+        Connection {
+            source_id: u32_at(0),
+            target_id: u32_at(4),
+            domain_id: u16_at(8),
+            link_type: u16_at(10),
+            flags: u32_at(12),
+            strength: f32_at(16),
+            current_stress: f32_at(20),
+            ideal_dist: f32_at(24),
+            elasticity: f32_at(28),
+            density_gate: b[32],
+            thermal_gate: b[33],
+            reserved_gate: b[34..48].try_into().unwrap(),
+            created_at: u64_at(48),
+            last_event_id: u64_at(56),
+        }
+    }
+
+    /// Записать пакет связей: заголовок + `connections.len()` записей по 64 байта
+    pub fn write_batch(connections: &[Connection], w: &mut impl Write) -> io::Result<()> {
+        let checksum = connections
+            .iter()
+            .fold(FNV_OFFSET, |h, c| fnv1a(h, &c.to_le_bytes()));
+        write_header(w, CONNECTION_BATCH_MAGIC, connections.len(), checksum)?;
+        for c in connections {
+            w.write_all(&c.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Прочитать пакет, записанный `write_batch`
+    ///
+    /// Ошибки — как у `Token::read_batch`.
+    pub fn read_batch(r: &mut impl Read) -> io::Result<Vec<Connection>> {
+        let (count, expected) = read_header(r, CONNECTION_BATCH_MAGIC, "connection")?;
+        let mut connections = Vec::with_capacity((count as usize).min(MAX_PREALLOC));
+        let mut record = [0u8; CONNECTION_RECORD_LEN];
+        let mut checksum = FNV_OFFSET;
+        for _ in 0..count {
+            r.read_exact(&mut record)?;
+            checksum = fnv1a(checksum, &record);
+            connections.push(Connection::from_le_bytes(&record));
+        }
+        check_checksum("connection", checksum, expected)?;
+        Ok(connections)
+    }
+}
@@ -14,13 +14,16 @@
 //! - `domain_id` определяет контекст события
 //! - Размер структуры строго 64 байта
 
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
 
 /// Тип события (причинный порядок)
 ///
 /// Семантические события физики и эволюции системы.
 /// Разбиты по категориям с зарезервированными диапазонами.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum EventType {
     // Token события (0x0000-0x0FFF)
@@ -157,6 +160,7 @@ impl From<u16> for EventType {
 
 /// Приоритеты событий
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum EventPriority {
     /// Низкий приоритет
@@ -184,6 +188,8 @@ pub const EVENT_INTERNAL: u8 = 8;
 /// Содержит информацию о причинности, содержании, идентификации и привязке к Heartbeat.
 #[repr(C, align(64))]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Event {
     // --- ПРИЧИННОСТЬ [16 байт] ---
     /// Монотонный причинный индекс (COM)
@@ -246,7 +252,7 @@ pub struct Event {
 }
 
 // Проверка размера на этапе компиляции
-const _: () = assert!(std::mem::size_of::<Event>() == 64);
+const _: () = assert!(core::mem::size_of::<Event>() == 64);
 
 // === Event subtypes ===
 /// Подтип не указан (обратная совместимость — все существующие события).
@@ -466,6 +472,7 @@ impl fmt::Display for Event {
 /// Содержит только причинный порядок, НЕ wall-clock время.
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Snapshot {
     /// Причинный порядок (event_id последнего события)
     pub snapshot_id: u64,
@@ -2,12 +2,15 @@
 //!
 //! Базовые структуры данных: Token, Connection, Event.
 //! Не имеет внешних зависимостей (zero dependencies).
+//! Собирается без std (`default-features = false`, нужен только `alloc`) —
+//! например, для wasm32-unknown-unknown.
 //!
 //! # Архитектура
 //!
 //! - `token` — Token структура (64 байта, repr(C, align(64)))
 //! - `connection` — Connection структура (64 байта, repr(C, align(64)))
 //! - `event` — Event структура и типы событий (64 байта, repr(C, align(64)))
+//! - `token_store` — TokenStore, колоночное (SoA) представление токенов для массовых операций
 //!
 //! Все структуры используют:
 //! - Фиксированный размер 64 байта для cache-line оптимизации
@@ -20,20 +23,39 @@
 //! Каждая структура имеет метод `validate()` для проверки инвариантов.
 //! Все ID-поля должны быть > 0 (0 зарезервирован для "отсутствует").
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unsafe_code)]
 #![warn(missing_docs)]
 
+extern crate alloc;
+
 pub mod connection;
+#[cfg(feature = "std")]
+pub mod connection_io;
 pub mod event;
 pub mod token;
+pub mod token_delta;
+#[cfg(feature = "std")]
+pub mod token_io;
+pub mod token_store;
 
 // Реэкспорт основных типов
-pub use connection::{Connection, FLAG_ACTIVE, FLAG_CRITICAL, FLAG_INHIBITED, FLAG_TEMPORARY};
+pub use connection::{
+    Connection, FLAG_ACTIVE, FLAG_BIDIRECTIONAL, FLAG_CRITICAL, FLAG_INHIBITED,
+    FLAG_PRUNE_CANDIDATE, FLAG_TEMPORARY, FLAG_TOMBSTONE,
+};
+#[cfg(feature = "std")]
+pub use connection_io::{CONNECTION_BATCH_MAGIC, CONNECTION_RECORD_LEN};
 pub use event::{
     Event, EventPriority, EventType, Snapshot, EVENT_BATCHED, EVENT_CRITICAL, EVENT_REVERSIBLE,
 };
 pub use token::{
-    Token, FRAME_CATEGORY_MASK, FRAME_CATEGORY_SYNTAX, STATE_ACTIVE, STATE_LOCKED, STATE_SLEEPING,
-    TOKEN_FLAG_DILEMMA, TOKEN_FLAG_DREAM_REPORT, TOKEN_FLAG_FRAME_ANCHOR, TOKEN_FLAG_GOAL,
+    validate_all, MergeStrategy, Token, TokenBuilder, TokenValidationError, FRAME_CATEGORY_MASK,
+    FRAME_CATEGORY_SYNTAX, STATE_ACTIVE, STATE_LOCKED, STATE_SLEEPING, TOKEN_FLAG_DILEMMA,
+    TOKEN_FLAG_DREAM_REPORT, TOKEN_FLAG_EMBEDDING, TOKEN_FLAG_FRAME_ANCHOR, TOKEN_FLAG_GOAL,
     TOKEN_FLAG_IMPULSE, TOKEN_FLAG_PROMOTED_FROM_EXPERIENCE,
 };
+pub use token_delta::TokenDelta;
+#[cfg(feature = "std")]
+pub use token_io::{BATCH_HEADER_LEN, BATCH_MAGIC, BATCH_VERSION, TOKEN_RECORD_LEN};
+pub use token_store::TokenStore;
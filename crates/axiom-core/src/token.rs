@@ -10,7 +10,10 @@
 //! - `last_event_id > 0` — каждый токен имеет событие создания
 //! - Размер структуры строго 64 байта
 
-use std::fmt;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
 
 /// Флаги типа токена в `type_flags` поле
 ///
@@ -23,6 +26,10 @@ pub const TOKEN_FLAG_GOAL: u16 = 0x0001;
 /// Предотвращает петлю: impulse → tension → impulse → ...
 pub const TOKEN_FLAG_IMPULSE: u16 = 0x0002;
 
+/// Токен получен из внешнего вектора-эмбеддинга (EmbeddingPerceptor), а не из текста.
+/// Provenance-метка: позиция — проекция вектора, а не результат anchor-матчинга.
+pub const TOKEN_FLAG_EMBEDDING: u16 = 0x0004;
+
 /// Токен является анкером Frame в EXPERIENCE (domain_id=109, state=STATE_ACTIVE).
 /// Устанавливается FrameWeaver при кристаллизации узора.
 pub const TOKEN_FLAG_FRAME_ANCHOR: u16 = 0x0010;
@@ -65,6 +72,7 @@ pub const STATE_LOCKED: u8 = 3;
 #[repr(C, align(64))]
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct Token {
     // --- ИДЕНТИФИКАЦИЯ (8 Байт) ---
     /// ID потока (Sutra), которому принадлежит токен
@@ -119,7 +127,7 @@ pub struct Token {
 }
 
 // Проверка размера на этапе компиляции
-const _: () = assert!(std::mem::size_of::<Token>() == 64);
+const _: () = assert!(core::mem::size_of::<Token>() == 64);
 
 // === Token origin ===
 /// Токен рождён в текущем уровне (создан в SUTRA)
@@ -185,6 +193,35 @@ impl Token {
         Ok(())
     }
 
+    /// Все нарушения инвариантов токена (пусто — токен корректен)
+    ///
+    /// Строже `validate()`: помимо обязательных полей проверяет state и
+    /// согласованность type_flags.
+    pub fn validation_errors(&self) -> Vec<TokenValidationError> {
+        let mut errors = Vec::new();
+        if self.sutra_id == 0 {
+            errors.push(TokenValidationError::ZeroSutraId);
+        }
+        if self.domain_id == 0 {
+            errors.push(TokenValidationError::ZeroDomainId);
+        }
+        if self.mass == 0 {
+            errors.push(TokenValidationError::ZeroMass);
+        }
+        if self.last_event_id == 0 {
+            errors.push(TokenValidationError::ZeroEventId);
+        }
+        if !matches!(self.state, STATE_ACTIVE | STATE_SLEEPING | STATE_LOCKED) {
+            errors.push(TokenValidationError::UnknownState(self.state));
+        }
+        if self.type_flags & TOKEN_FLAG_PROMOTED_FROM_EXPERIENCE != 0
+            && self.type_flags & TOKEN_FLAG_FRAME_ANCHOR == 0
+        {
+            errors.push(TokenValidationError::PromotedWithoutAnchor);
+        }
+        errors
+    }
+
     /// Проверяет, активен ли токен
     #[inline]
     pub fn is_active(&self) -> bool {
@@ -254,6 +291,85 @@ impl Token {
     }
 }
 
+/// Нарушение инварианта токена (`Token::validation_errors`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenValidationError {
+    /// sutra_id == 0
+    ZeroSutraId,
+    /// domain_id == 0
+    ZeroDomainId,
+    /// mass == 0
+    ZeroMass,
+    /// last_event_id == 0
+    ZeroEventId,
+    /// state не из STATE_ACTIVE / STATE_SLEEPING / STATE_LOCKED
+    UnknownState(u8),
+    /// TOKEN_FLAG_PROMOTED_FROM_EXPERIENCE без TOKEN_FLAG_FRAME_ANCHOR
+    PromotedWithoutAnchor,
+}
+
+impl fmt::Display for TokenValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenValidationError::ZeroSutraId => write!(f, "Token.sutra_id must be > 0"),
+            TokenValidationError::ZeroDomainId => write!(f, "Token.domain_id must be > 0"),
+            TokenValidationError::ZeroMass => write!(f, "Token.mass must be > 0"),
+            TokenValidationError::ZeroEventId => write!(f, "Token.last_event_id must be > 0"),
+            TokenValidationError::UnknownState(s) => {
+                write!(f, "Token.state {s} is not a known state")
+            }
+            TokenValidationError::PromotedWithoutAnchor => {
+                write!(f, "Token promoted from EXPERIENCE must be a frame anchor")
+            }
+        }
+    }
+}
+
+/// Проверить срез токенов: (индекс, нарушение) для каждого найденного нарушения
+pub fn validate_all(tokens: &[Token]) -> Vec<(usize, TokenValidationError)> {
+    tokens
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t)| t.validation_errors().into_iter().map(move |e| (i, e)))
+        .collect()
+}
+
+/// Стратегия слияния двух токенов (`Token::merge`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Физика и позиция — от основного токена (self)
+    KeepPrimary,
+    /// Позиция, температура и валентность — среднее, взвешенное по массе
+    #[default]
+    MassWeighted,
+}
+
+impl Token {
+    /// Слить `other` в `self` — результат наследует sutra_id и domain_id `self`
+    ///
+    /// Независимо от стратегии: масса суммируется (с насыщением), type_flags
+    /// объединяются, last_event_id — более поздний из двух.
+    pub fn merge(&self, other: &Token, strategy: MergeStrategy) -> Token {
+        let mut merged = *self;
+        if strategy == MergeStrategy::MassWeighted {
+            let (wa, wb) = (self.mass as i32, other.mass as i32);
+            let total = (wa + wb).max(1);
+            let mix = |a: i32, b: i32| (a * wa + b * wb) / total;
+            for axis in 0..3 {
+                merged.position[axis] =
+                    mix(self.position[axis] as i32, other.position[axis] as i32) as i16;
+            }
+            merged.target = merged.position;
+            merged.temperature = mix(self.temperature as i32, other.temperature as i32) as u8;
+            merged.valence = mix(self.valence as i32, other.valence as i32) as i8;
+        }
+        merged.mass = self.mass.saturating_add(other.mass);
+        merged.type_flags |= other.type_flags;
+        merged.last_event_id = self.last_event_id.max(other.last_event_id);
+        merged
+    }
+}
+
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -270,6 +386,118 @@ impl fmt::Display for Token {
     }
 }
 
+/// Пошаговое построение токена с проверкой инвариантов в `build()`
+///
+/// ```
+/// use axiom_core::{Token, TOKEN_FLAG_GOAL};
+///
+/// let token = Token::builder(7, 100)
+///     .position([10, 0, -5])
+///     .type_flags(TOKEN_FLAG_GOAL)
+///     .mass(40)
+///     .event_id(1)
+///     .build()
+///     .unwrap();
+/// assert_eq!(token.target, [10, 0, -5]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TokenBuilder {
+    token: Token,
+    target_set: bool,
+}
+
+impl Token {
+    /// Начать построение токена (параметры по умолчанию — как у `Token::new`,
+    /// event_id = 0 и должен быть задан до `build()`)
+    pub fn builder(sutra_id: u32, domain_id: u16) -> TokenBuilder {
+        TokenBuilder {
+            token: Token::new(sutra_id, domain_id, [0, 0, 0], 0),
+            target_set: false,
+        }
+    }
+}
+
+impl TokenBuilder {
+    /// Позиция; target следует за ней, если не задан явно
+    pub fn position(mut self, position: [i16; 3]) -> Self {
+        self.token.position = position;
+        if !self.target_set {
+            self.token.target = position;
+        }
+        self
+    }
+
+    /// Целевая позиция (по умолчанию = position)
+    pub fn target(mut self, target: [i16; 3]) -> Self {
+        self.token.target = target;
+        self.target_set = true;
+        self
+    }
+
+    /// Скорость
+    pub fn velocity(mut self, velocity: [i16; 3]) -> Self {
+        self.token.velocity = velocity;
+        self
+    }
+
+    /// Флаги типа (TOKEN_FLAG_*)
+    pub fn type_flags(mut self, type_flags: u16) -> Self {
+        self.token.type_flags = type_flags;
+        self
+    }
+
+    /// Происхождение (TOKEN_ORIGIN_*)
+    pub fn origin(mut self, origin: u16) -> Self {
+        self.token.origin = origin;
+        self
+    }
+
+    /// Валентность
+    pub fn valence(mut self, valence: i8) -> Self {
+        self.token.valence = valence;
+        self
+    }
+
+    /// Масса (> 0)
+    pub fn mass(mut self, mass: u8) -> Self {
+        self.token.mass = mass;
+        self
+    }
+
+    /// Температура
+    pub fn temperature(mut self, temperature: u8) -> Self {
+        self.token.temperature = temperature;
+        self
+    }
+
+    /// Состояние (STATE_ACTIVE / STATE_SLEEPING / STATE_LOCKED)
+    pub fn state(mut self, state: u8) -> Self {
+        self.token.state = state;
+        self
+    }
+
+    /// Хеш происхождения
+    pub fn lineage_hash(mut self, lineage_hash: u64) -> Self {
+        self.token.lineage_hash = lineage_hash;
+        self
+    }
+
+    /// ID события создания (> 0)
+    pub fn event_id(mut self, event_id: u64) -> Self {
+        self.token.last_event_id = event_id;
+        self
+    }
+
+    /// Собрать токен; ошибка — как у `Token::validate`, плюс неизвестный state
+    pub fn build(self) -> Result<Token, String> {
+        if !matches!(self.token.state, STATE_ACTIVE | STATE_SLEEPING | STATE_LOCKED) {
+            return Err(format!("Token.state {} is not a known state", self.token.state));
+        }
+        self.token.validate()?;
+        Ok(self.token)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
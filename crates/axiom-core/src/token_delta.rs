@@ -0,0 +1,265 @@
+//! TokenDelta — разностное представление изменения токена
+//!
+//! Большинство обновлений трогают одно-два поля (позиция, масса, температура),
+//! но пересылка и журналирование токена целиком — это всегда 64 байта.
+//! TokenDelta несёт только изменившиеся поля: `Token::diff` строит дельту,
+//! `Token::apply_delta` накатывает её на предыдущую версию токена.
+//!
+//! Бинарная форма (`encode` / `decode`): sutra_id (u32 LE), маска полей (u16 LE),
+//! затем значения присутствующих полей в порядке битов маски, little-endian.
+//! Идентификация (sutra_id, domain_id) не меняется и в маску не входит.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::token::Token;
+
+const F_TYPE_FLAGS: u16 = 1 << 0;
+const F_POSITION: u16 = 1 << 1;
+const F_VELOCITY: u16 = 1 << 2;
+const F_TARGET: u16 = 1 << 3;
+const F_ORIGIN: u16 = 1 << 4;
+const F_VALENCE: u16 = 1 << 5;
+const F_MASS: u16 = 1 << 6;
+const F_TEMPERATURE: u16 = 1 << 7;
+const F_STATE: u16 = 1 << 8;
+const F_LINEAGE_HASH: u16 = 1 << 9;
+const F_MOMENTUM: u16 = 1 << 10;
+const F_RESONANCE: u16 = 1 << 11;
+const F_LAST_EVENT_ID: u16 = 1 << 12;
+
+/// Изменённые поля токена; None — поле не менялось
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TokenDelta {
+    /// Токен, к которому относится дельта
+    pub sutra_id: u32,
+    /// Новые type_flags
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub type_flags: Option<u16>,
+    /// Новая позиция
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub position: Option<[i16; 3]>,
+    /// Новая скорость
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub velocity: Option<[i16; 3]>,
+    /// Новая целевая позиция
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub target: Option<[i16; 3]>,
+    /// Новый origin
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub origin: Option<u16>,
+    /// Новая валентность
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub valence: Option<i8>,
+    /// Новая масса
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub mass: Option<u8>,
+    /// Новая температура
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub temperature: Option<u8>,
+    /// Новое состояние
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub state: Option<u8>,
+    /// Новый lineage_hash
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub lineage_hash: Option<u64>,
+    /// Новый импульс
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub momentum: Option<[i32; 3]>,
+    /// Новый резонанс
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub resonance: Option<u32>,
+    /// Новый last_event_id
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub last_event_id: Option<u64>,
+}
+
+/// Some(new) если значение изменилось
+fn changed<T: PartialEq + Copy>(old: T, new: T) -> Option<T> {
+    (old != new).then_some(new)
+}
+
+impl Token {
+    /// Дельта от `self` к `newer` (только отличающиеся поля)
+    pub fn diff(&self, newer: &Token) -> TokenDelta {
+        TokenDelta {
+            sutra_id: self.sutra_id,
+            type_flags: changed(self.type_flags, newer.type_flags),
+            position: changed(self.position, newer.position),
+            velocity: changed(self.velocity, newer.velocity),
+            target: changed(self.target, newer.target),
+            origin: changed(self.origin, newer.origin),
+            valence: changed(self.valence, newer.valence),
+            mass: changed(self.mass, newer.mass),
+            temperature: changed(self.temperature, newer.temperature),
+            state: changed(self.state, newer.state),
+            lineage_hash: changed(self.lineage_hash, newer.lineage_hash),
+            momentum: changed(self.momentum, newer.momentum),
+            resonance: changed(self.resonance, newer.resonance),
+            last_event_id: changed(self.last_event_id, newer.last_event_id),
+        }
+    }
+
+    /// Применить дельту. Ошибка (токен не меняется) — дельта другого токена
+    pub fn apply_delta(&mut self, delta: &TokenDelta) -> Result<(), String> {
+        if delta.sutra_id != self.sutra_id {
+            return Err(format!(
+                "delta for sutra_id {} applied to token {}",
+                delta.sutra_id, self.sutra_id
+            ));
+        }
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                $(if let Some(v) = delta.$field { self.$field = v; })*
+            };
+        }
+        apply!(
+            type_flags, position, velocity, target, origin, valence, mass, temperature, state,
+            lineage_hash, momentum, resonance, last_event_id
+        );
+        Ok(())
+    }
+}
+
+impl TokenDelta {
+    /// True если ни одно поле не изменилось
+    pub fn is_empty(&self) -> bool {
+        self.mask() == 0
+    }
+
+    /// Битовая маска присутствующих полей
+    pub fn mask(&self) -> u16 {
+        let bits = [
+            (self.type_flags.is_some(), F_TYPE_FLAGS),
+            (self.position.is_some(), F_POSITION),
+            (self.velocity.is_some(), F_VELOCITY),
+            (self.target.is_some(), F_TARGET),
+            (self.origin.is_some(), F_ORIGIN),
+            (self.valence.is_some(), F_VALENCE),
+            (self.mass.is_some(), F_MASS),
+            (self.temperature.is_some(), F_TEMPERATURE),
+            (self.state.is_some(), F_STATE),
+            (self.lineage_hash.is_some(), F_LINEAGE_HASH),
+            (self.momentum.is_some(), F_MOMENTUM),
+            (self.resonance.is_some(), F_RESONANCE),
+            (self.last_event_id.is_some(), F_LAST_EVENT_ID),
+        ];
+        bits.iter().filter(|(set, _)| *set).fold(0, |m, (_, bit)| m | bit)
+    }
+
+    /// Дописать бинарную форму дельты в `out`
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.sutra_id.to_le_bytes());
+        out.extend_from_slice(&self.mask().to_le_bytes());
+        if let Some(v) = self.type_flags {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in [self.position, self.velocity, self.target].into_iter().flatten() {
+            v.iter().for_each(|c| out.extend_from_slice(&c.to_le_bytes()));
+        }
+        if let Some(v) = self.origin {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        if let Some(v) = self.valence {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in [self.mass, self.temperature, self.state].into_iter().flatten() {
+            out.push(v);
+        }
+        if let Some(v) = self.lineage_hash {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        if let Some(v) = self.momentum {
+            v.iter().for_each(|c| out.extend_from_slice(&c.to_le_bytes()));
+        }
+        if let Some(v) = self.resonance {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        if let Some(v) = self.last_event_id {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+
+    /// Прочитать дельту из начала `bytes`. Возвращает дельту и число
+    /// прочитанных байт; None — буфер обрезан или маска содержит неизвестные биты
+    pub fn decode(bytes: &[u8]) -> Option<(TokenDelta, usize)> {
+        let mut r = Reader { bytes, pos: 0 };
+        let mut d = TokenDelta {
+            sutra_id: u32::from_le_bytes(r.take()?),
+            ..Default::default()
+        };
+        let mask = u16::from_le_bytes(r.take()?);
+        if mask >> 13 != 0 {
+            return None;
+        }
+        let has = |bit: u16| mask & bit != 0;
+        if has(F_TYPE_FLAGS) {
+            d.type_flags = Some(u16::from_le_bytes(r.take()?));
+        }
+        if has(F_POSITION) {
+            d.position = Some(r.i16x3()?);
+        }
+        if has(F_VELOCITY) {
+            d.velocity = Some(r.i16x3()?);
+        }
+        if has(F_TARGET) {
+            d.target = Some(r.i16x3()?);
+        }
+        if has(F_ORIGIN) {
+            d.origin = Some(u16::from_le_bytes(r.take()?));
+        }
+        if has(F_VALENCE) {
+            d.valence = Some(i8::from_le_bytes(r.take()?));
+        }
+        if has(F_MASS) {
+            d.mass = Some(r.take::<1>()?[0]);
+        }
+        if has(F_TEMPERATURE) {
+            d.temperature = Some(r.take::<1>()?[0]);
+        }
+        if has(F_STATE) {
+            d.state = Some(r.take::<1>()?[0]);
+        }
+        if has(F_LINEAGE_HASH) {
+            d.lineage_hash = Some(u64::from_le_bytes(r.take()?));
+        }
+        if has(F_MOMENTUM) {
+            d.momentum = Some([
+                i32::from_le_bytes(r.take()?),
+                i32::from_le_bytes(r.take()?),
+                i32::from_le_bytes(r.take()?),
+            ]);
+        }
+        if has(F_RESONANCE) {
+            d.resonance = Some(u32::from_le_bytes(r.take()?));
+        }
+        if has(F_LAST_EVENT_ID) {
+            d.last_event_id = Some(u64::from_le_bytes(r.take()?));
+        }
+        Some((d, r.pos))
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let chunk = self.bytes.get(self.pos..self.pos + N)?;
+        self.pos += N;
+        chunk.try_into().ok()
+    }
+
+    fn i16x3(&mut self) -> Option<[i16; 3]> {
+        Some([
+            i16::from_le_bytes(self.take()?),
+            i16::from_le_bytes(self.take()?),
+            i16::from_le_bytes(self.take()?),
+        ])
+    }
+}
@@ -0,0 +1,206 @@
+//! Пакетная (де)сериализация токенов в 64-байтовые записи
+//!
+//! Формат потока: заголовок `BATCH_HEADER_LEN` байт, затем `count` записей
+//! по 64 байта в нативной раскладке Token (little-endian, без padding).
+//!
+//! Заголовок:
+//! - `[0..4]`   BATCH_MAGIC
+//! - `[4..6]`   BATCH_VERSION (u16 LE)
+//! - `[6..8]`   резерв (0)
+//! - `[8..16]`  count (u64 LE)
+//! - `[16..24]` checksum — FNV-1a 64 по всем записям (u64 LE)
+//!
+//! Запись и чтение идут через буфер на стеке — без аллокации на токен.
+
+use std::io::{self, Read, Write};
+
+use crate::token::Token;
+
+/// Сигнатура пакета токенов
+pub const BATCH_MAGIC: [u8; 4] = *b"AXTB";
+
+/// Версия формата пакета
+pub const BATCH_VERSION: u16 = 1;
+
+/// Длина заголовка пакета в байтах
+pub const BATCH_HEADER_LEN: usize = 24;
+
+/// Длина одной записи — размер Token
+pub const TOKEN_RECORD_LEN: usize = 64;
+
+pub(crate) const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Верхняя граница предвыделения при чтении: count из заголовка не доверенный
+pub(crate) const MAX_PREALLOC: usize = 1 << 16;
+
+pub(crate) fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+pub(crate) fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Записать заголовок пакета с сигнатурой `magic`
+pub(crate) fn write_header(
+    w: &mut impl Write,
+    magic: [u8; 4],
+    count: usize,
+    checksum: u64,
+) -> io::Result<()> {
+    let mut header = [0u8; BATCH_HEADER_LEN];
+    header[0..4].copy_from_slice(&magic);
+    header[4..6].copy_from_slice(&BATCH_VERSION.to_le_bytes());
+    header[8..16].copy_from_slice(&(count as u64).to_le_bytes());
+    header[16..24].copy_from_slice(&checksum.to_le_bytes());
+    w.write_all(&header)
+}
+
+/// Прочитать и проверить заголовок пакета `what` с сигнатурой `magic`.
+/// Возвращает (count, checksum)
+pub(crate) fn read_header(
+    r: &mut impl Read,
+    magic: [u8; 4],
+    what: &str,
+) -> io::Result<(u64, u64)> {
+    let mut header = [0u8; BATCH_HEADER_LEN];
+    r.read_exact(&mut header)?;
+    if header[0..4] != magic {
+        return Err(invalid(format!("not a {what} batch")));
+    }
+    let version = u16::from_le_bytes([header[4], header[5]]);
+    if version != BATCH_VERSION {
+        return Err(invalid(format!("unsupported {what} batch version {version}")));
+    }
+    let count = u64::from_le_bytes(header[8..16].try_into().unwrap());
+    let checksum = u64::from_le_bytes(header[16..24].try_into().unwrap());
+    Ok((count, checksum))
+}
+
+/// Сверить контрольную сумму прочитанных записей с заголовком
+pub(crate) fn check_checksum(what: &str, checksum: u64, expected: u64) -> io::Result<()> {
+    if checksum != expected {
+        return Err(invalid(format!(
+            "{what} batch checksum mismatch: {checksum:#018x} != {expected:#018x}"
+        )));
+    }
+    Ok(())
+}
+
+impl Token {
+    /// Нативная раскладка токена (little-endian) — совпадает с памятью
+    /// на little-endian платформах
+    pub fn to_le_bytes(&self) -> [u8; TOKEN_RECORD_LEN] {
+        let mut b = [0u8; TOKEN_RECORD_LEN];
+        let mut off = 0;
+        let mut put = |bytes: &[u8]| {
+            b[off..off + bytes.len()].copy_from_slice(bytes);
+            off += bytes.len();
+        };
+        put(&self.sutra_id.to_le_bytes());
+        put(&self.domain_id.to_le_bytes());
+        put(&self.type_flags.to_le_bytes());
+        for v in self.position.iter().chain(&self.velocity).chain(&self.target) {
+            put(&v.to_le_bytes());
+        }
+        put(&self.origin.to_le_bytes());
+        put(&[self.valence as u8, self.mass, self.temperature, self.state]);
+        put(&self.lineage_hash.to_le_bytes());
+        for v in &self.momentum {
+            put(&v.to_le_bytes());
+        }
+        put(&self.resonance.to_le_bytes());
+        put(&self.last_event_id.to_le_bytes());
+        b
+    }
+
+    /// Обратное к `to_le_bytes`
+    pub fn from_le_bytes(b: &[u8; TOKEN_RECORD_LEN]) -> Token {
+        let mut off = 0;
+        let mut take = |n: usize| {
+            let s = &b[off..off + n];
+            off += n;
+            s
+        };
+        let u16_at = |s: &[u8]| u16::from_le_bytes([s[0], s[1]]);
+        let i16x3 = |s: &[u8]| {
+            [
+                i16::from_le_bytes([s[0], s[1]]),
+                i16::from_le_bytes([s[2], s[3]]),
+                i16::from_le_bytes([s[4], s[5]]),
+            ]
+        };
+        let sutra_id = u32::from_le_bytes(take(4).try_into().unwrap());
+        let domain_id = u16_at(take(2));
+        let type_flags = u16_at(take(2));
+        let position = i16x3(take(6));
+        let velocity = i16x3(take(6));
+        let target = i16x3(take(6));
+        let origin = u16_at(take(2));
+        let thermo = take(4);
+        let lineage_hash = u64::from_le_bytes(take(8).try_into().unwrap());
+        let m = take(12);
+        let momentum = [
+            i32::from_le_bytes(m[0..4].try_into().unwrap()),
+            i32::from_le_bytes(m[4..8].try_into().unwrap()),
+            i32::from_le_bytes(m[8..12].try_into().unwrap()),
+        ];
+        let resonance = u32::from_le_bytes(take(4).try_into().unwrap());
+        let last_event_id = u64::from_le_bytes(take(8).try_into().unwrap());
+        Token {
+            sutra_id,
+            domain_id,
+            type_flags,
+            position,
+            velocity,
+            target,
+            origin,
+            valence: thermo[0] as i8,
+            mass: thermo[1],
+            temperature: thermo[2],
+            state: thermo[3],
+            lineage_hash,
+            momentum,
+            resonance,
+            last_event_id,
+        }
+    }
+
+    /// Записать пакет токенов: заголовок + `tokens.len()` записей по 64 байта
+    ///
+    /// Контрольная сумма считается отдельным проходом до записи, поэтому поток
+    /// может быть не-seekable (сокет, pipe). Буферизацию задаёт вызывающий.
+    pub fn write_batch(tokens: &[Token], w: &mut impl Write) -> io::Result<()> {
+        let checksum = tokens
+            .iter()
+            .fold(FNV_OFFSET, |h, t| fnv1a(h, &t.to_le_bytes()));
+        write_header(w, BATCH_MAGIC, tokens.len(), checksum)?;
+        for t in tokens {
+            w.write_all(&t.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Прочитать пакет, записанный `write_batch`
+    ///
+    /// Ошибка `InvalidData` — чужая сигнатура, неизвестная версия или
+    /// несовпадение контрольной суммы; `UnexpectedEof` — пакет обрезан.
+    pub fn read_batch(r: &mut impl Read) -> io::Result<Vec<Token>> {
+        let (count, expected) = read_header(r, BATCH_MAGIC, "token")?;
+        let mut tokens = Vec::with_capacity((count as usize).min(MAX_PREALLOC));
+        let mut record = [0u8; TOKEN_RECORD_LEN];
+        let mut checksum = FNV_OFFSET;
+        for _ in 0..count {
+            r.read_exact(&mut record)?;
+            checksum = fnv1a(checksum, &record);
+            tokens.push(Token::from_le_bytes(&record));
+        }
+        check_checksum("token", checksum, expected)?;
+        Ok(tokens)
+    }
+}
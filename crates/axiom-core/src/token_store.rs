@@ -0,0 +1,138 @@
+//! TokenStore — колоночное (SoA) представление набора токенов
+//!
+//! `Vec<Token>` хранит токены по 64 байта: проход по одной позиции или массе
+//! тянет в кеш всю строку. Для массовых операций (пространственный скан,
+//! пересчёт масс/температур) TokenStore раскладывает горячие поля по отдельным
+//! колонкам — проходы становятся последовательными и векторизуемыми.
+//!
+//! Канонический формат остаётся `Token`: store строится из среза токенов,
+//! а изменённые колонки записываются обратно через `write_back`.
+
+use alloc::vec::Vec;
+
+use crate::token::Token;
+
+/// Колоночное хранилище горячих полей токенов.
+///
+/// Индекс строки совпадает с индексом токена в исходном срезе.
+#[derive(Debug, Clone, Default)]
+pub struct TokenStore {
+    /// sutra_id
+    pub sutra_ids: Vec<u32>,
+    /// position[0]
+    pub xs: Vec<i16>,
+    /// position[1]
+    pub ys: Vec<i16>,
+    /// position[2]
+    pub zs: Vec<i16>,
+    /// mass
+    pub masses: Vec<u8>,
+    /// temperature
+    pub temperatures: Vec<u8>,
+    /// valence
+    pub valences: Vec<i8>,
+    /// state (STATE_ACTIVE / STATE_SLEEPING / STATE_LOCKED)
+    pub states: Vec<u8>,
+}
+
+impl TokenStore {
+    /// Создать пустое хранилище с предвыделённой ёмкостью
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            sutra_ids: Vec::with_capacity(capacity),
+            xs: Vec::with_capacity(capacity),
+            ys: Vec::with_capacity(capacity),
+            zs: Vec::with_capacity(capacity),
+            masses: Vec::with_capacity(capacity),
+            temperatures: Vec::with_capacity(capacity),
+            valences: Vec::with_capacity(capacity),
+            states: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Разложить срез токенов по колонкам
+    pub fn from_tokens(tokens: &[Token]) -> Self {
+        let mut store = Self::with_capacity(tokens.len());
+        for token in tokens {
+            store.push(token);
+        }
+        store
+    }
+
+    /// Добавить строку из токена
+    pub fn push(&mut self, token: &Token) {
+        self.sutra_ids.push(token.sutra_id);
+        self.xs.push(token.position[0]);
+        self.ys.push(token.position[1]);
+        self.zs.push(token.position[2]);
+        self.masses.push(token.mass);
+        self.temperatures.push(token.temperature);
+        self.valences.push(token.valence);
+        self.states.push(token.state);
+    }
+
+    /// Число строк
+    pub fn len(&self) -> usize {
+        self.sutra_ids.len()
+    }
+
+    /// True если строк нет
+    pub fn is_empty(&self) -> bool {
+        self.sutra_ids.is_empty()
+    }
+
+    /// Индексы строк внутри осевого бокса `[min, max]` (границы включены)
+    pub fn query_box(&self, min: [i16; 3], max: [i16; 3]) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&i| {
+                (min[0]..=max[0]).contains(&self.xs[i])
+                    && (min[1]..=max[1]).contains(&self.ys[i])
+                    && (min[2]..=max[2]).contains(&self.zs[i])
+            })
+            .collect()
+    }
+
+    /// Индексы строк с заданным state
+    pub fn indices_with_state(&self, state: u8) -> Vec<usize> {
+        self.states
+            .iter()
+            .enumerate()
+            .filter(|&(_, &s)| s == state)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Пересчитать массу всех строк одним проходом
+    pub fn map_masses(&mut self, f: impl Fn(u8) -> u8) {
+        for m in self.masses.iter_mut() {
+            *m = f(*m);
+        }
+    }
+
+    /// Пересчитать температуру всех строк одним проходом
+    pub fn map_temperatures(&mut self, f: impl Fn(u8) -> u8) {
+        for t in self.temperatures.iter_mut() {
+            *t = f(*t);
+        }
+    }
+
+    /// Записать колонки обратно в токены
+    ///
+    /// `tokens` должен быть тем же срезом (тот же порядок), из которого построен
+    /// store; строки сверяются по sutra_id. Возвращает число обновлённых токенов.
+    pub fn write_back(&self, tokens: &mut [Token]) -> usize {
+        let mut updated = 0;
+        for (i, token) in tokens.iter_mut().enumerate().take(self.len()) {
+            if token.sutra_id != self.sutra_ids[i] {
+                continue;
+            }
+            token.position = [self.xs[i], self.ys[i], self.zs[i]];
+            token.mass = self.masses[i];
+            token.temperature = self.temperatures[i];
+            token.valence = self.valences[i];
+            token.state = self.states[i];
+            updated += 1;
+        }
+        updated
+    }
+}
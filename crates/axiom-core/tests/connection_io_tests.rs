@@ -0,0 +1,58 @@
+use axiom_core::{Connection, Token, BATCH_HEADER_LEN, CONNECTION_RECORD_LEN};
+use std::io::ErrorKind;
+
+fn sample(n: u32) -> Vec<Connection> {
+    (1..=n)
+        .map(|i| {
+            let mut c = Connection::new(i, i + 1, 100 + (i % 11) as u16, i as u64);
+            c.link_type = 0x0800 | i as u16;
+            c.strength = 0.25 * i as f32;
+            c.current_stress = -1.5;
+            c.density_gate = 7;
+            c.reserved_gate[13] = 0xAB;
+            c.last_event_id = 1000 + i as u64;
+            c
+        })
+        .collect()
+}
+
+#[test]
+fn test_le_bytes_roundtrip() {
+    let c = sample(3)[2];
+    let back = Connection::from_le_bytes(&c.to_le_bytes());
+    assert_eq!(back.to_le_bytes(), c.to_le_bytes());
+    assert_eq!((back.source_id, back.target_id, back.link_type), (3, 4, 0x0803));
+    assert_eq!(back.strength, 0.75);
+    assert_eq!(back.reserved_gate[13], 0xAB);
+    assert_eq!(back.last_event_id, 1003);
+}
+
+#[test]
+fn test_batch_roundtrip() {
+    let conns = sample(50);
+    let mut buf = Vec::new();
+    Connection::write_batch(&conns, &mut buf).unwrap();
+    assert_eq!(buf.len(), BATCH_HEADER_LEN + conns.len() * CONNECTION_RECORD_LEN);
+
+    let back = Connection::read_batch(&mut buf.as_slice()).unwrap();
+    assert_eq!(back.len(), conns.len());
+    assert!(back.iter().zip(&conns).all(|(a, b)| a.to_le_bytes() == b.to_le_bytes()));
+}
+
+#[test]
+fn test_batch_rejects_token_batch_and_corruption() {
+    let mut tokens = Vec::new();
+    Token::write_batch(&[Token::new(1, 100, [0, 0, 0], 1)], &mut tokens).unwrap();
+    let err = Connection::read_batch(&mut tokens.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let mut buf = Vec::new();
+    Connection::write_batch(&sample(4), &mut buf).unwrap();
+    let mut corrupted = buf.clone();
+    *corrupted.last_mut().unwrap() ^= 1;
+    let err = Connection::read_batch(&mut corrupted.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let err = Connection::read_batch(&mut &buf[..buf.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
@@ -77,6 +77,25 @@ fn test_connection_flags() {
     assert!(conn.is_critical());
 }
 
+#[test]
+fn test_ttl() {
+    let mut conn = Connection::new(1, 2, 1, 100);
+    assert_eq!(conn.ttl(), None);
+    assert!(!conn.is_expired(u64::MAX));
+
+    conn.reserved_gate[0] = 0xAA;
+    conn.set_ttl(50);
+    assert!(conn.is_temporary());
+    assert_eq!(conn.ttl(), Some(50));
+    assert_eq!(conn.reserved_gate[0], 0xAA, "origin_domain в [0..4] не затронут");
+    assert!(!conn.is_expired(149));
+    assert!(conn.is_expired(150));
+
+    conn.set_ttl(0);
+    assert!(!conn.is_temporary());
+    assert!(!conn.is_expired(u64::MAX));
+}
+
 #[test]
 fn test_update_stress() {
     let mut conn = Connection::new(1, 2, 1, 100);
@@ -150,3 +169,27 @@ fn test_spring_force() {
     let force = conn.compute_spring_force(10.0);
     assert_eq!(force, 0.0);
 }
+
+#[test]
+fn test_validity_interval() {
+    let mut conn = Connection::new(1, 2, 1, 100);
+    assert_eq!((conn.valid_from(), conn.valid_to()), (100, None));
+    assert!(!conn.is_valid_at(99));
+    assert!(conn.is_valid_at(u64::MAX));
+
+    conn.reserved_gate[4] = 0xBB;
+    conn.set_valid_to(300);
+    assert_eq!(conn.valid_to(), Some(300));
+    assert!(conn.is_valid_at(299));
+    assert!(!conn.is_valid_at(300));
+    assert_eq!(conn.reserved_gate[4], 0xBB, "TTL в [4..8] не затронут");
+
+    conn.reserved_gate[4] = 0;
+    conn.set_ttl(50);
+    assert_eq!(conn.valid_to(), Some(150), "истечение TTL раньше закрытия");
+    conn.set_ttl(0);
+    conn.set_valid_to(u64::MAX);
+    assert_eq!(conn.valid_to(), Some(Connection::MAX_VALID_TO));
+    conn.set_valid_to(0);
+    assert_eq!(conn.valid_to(), None);
+}
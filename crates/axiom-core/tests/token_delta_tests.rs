@@ -0,0 +1,55 @@
+use axiom_core::{Token, TokenDelta, STATE_SLEEPING};
+
+fn base() -> Token {
+    Token::new(7, 100, [10, 20, 30], 1)
+}
+
+#[test]
+fn test_diff_of_identical_tokens_is_empty() {
+    let t = base();
+    let delta = t.diff(&t);
+    assert!(delta.is_empty());
+    assert_eq!(delta.sutra_id, 7);
+}
+
+#[test]
+fn test_diff_apply_roundtrip() {
+    let old = base();
+    let mut new = old;
+    new.position[1] = -5;
+    new.mass = 42;
+    new.state = STATE_SLEEPING;
+    new.last_event_id = 9;
+
+    let delta = old.diff(&new);
+    assert_eq!(delta.mass, Some(42));
+    assert!(delta.velocity.is_none());
+
+    let mut patched = old;
+    patched.apply_delta(&delta).unwrap();
+    assert!(patched.diff(&new).is_empty());
+}
+
+#[test]
+fn test_apply_delta_rejects_other_token() {
+    let mut t = base();
+    let delta = TokenDelta { sutra_id: 8, mass: Some(1), ..Default::default() };
+    assert!(t.apply_delta(&delta).is_err());
+    assert_eq!(t.mass, base().mass);
+}
+
+#[test]
+fn test_binary_encoding_roundtrip_is_compact() {
+    let old = base();
+    let mut new = old;
+    new.position = [1, 2, 3];
+    new.temperature = 200;
+    new.momentum = [-1, 0, 1];
+    let delta = old.diff(&new);
+
+    let mut buf = Vec::new();
+    delta.encode(&mut buf);
+    assert_eq!(buf.len(), 4 + 2 + 6 + 1 + 12);
+    assert_eq!(TokenDelta::decode(&buf), Some((delta, buf.len())));
+    assert!(TokenDelta::decode(&buf[..buf.len() - 1]).is_none());
+}
@@ -0,0 +1,53 @@
+use axiom_core::{Token, BATCH_HEADER_LEN, TOKEN_RECORD_LEN};
+use std::io::ErrorKind;
+
+fn sample(n: u32) -> Vec<Token> {
+    (1..=n)
+        .map(|i| {
+            let mut t = Token::new(i, 100 + (i % 11) as u16, [i as i16, -(i as i16), 7], i as u64);
+            t.valence = -3;
+            t.momentum = [i as i32, -1, 1 << 20];
+            t.lineage_hash = 0xDEAD_BEEF_0000_0000 | i as u64;
+            t
+        })
+        .collect()
+}
+
+fn same(a: &Token, b: &Token) -> bool {
+    a.diff(b).is_empty() && a.sutra_id == b.sutra_id && a.domain_id == b.domain_id
+}
+
+#[test]
+fn test_le_bytes_roundtrip() {
+    let t = sample(1)[0];
+    assert!(same(&Token::from_le_bytes(&t.to_le_bytes()), &t));
+}
+
+#[test]
+fn test_batch_roundtrip() {
+    let tokens = sample(100);
+    let mut buf = Vec::new();
+    Token::write_batch(&tokens, &mut buf).unwrap();
+    assert_eq!(buf.len(), BATCH_HEADER_LEN + tokens.len() * TOKEN_RECORD_LEN);
+
+    let back = Token::read_batch(&mut buf.as_slice()).unwrap();
+    assert_eq!(back.len(), tokens.len());
+    assert!(back.iter().zip(&tokens).all(|(a, b)| same(a, b)));
+}
+
+#[test]
+fn test_batch_detects_corruption_and_truncation() {
+    let mut buf = Vec::new();
+    Token::write_batch(&sample(3), &mut buf).unwrap();
+
+    let mut corrupt = buf.clone();
+    corrupt[BATCH_HEADER_LEN + 10] ^= 0xFF;
+    let err = Token::read_batch(&mut corrupt.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let err = Token::read_batch(&mut &buf[..buf.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    let err = Token::read_batch(&mut &b"nope, not a batch at all"[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,50 @@
+use axiom_core::{Token, TokenStore, STATE_ACTIVE, STATE_SLEEPING};
+
+fn tokens() -> Vec<Token> {
+    (1..=4)
+        .map(|i| Token::new(i, 100, [i as i16 * 10, 0, -(i as i16)], i as u64))
+        .collect()
+}
+
+#[test]
+fn test_from_tokens_splits_columns() {
+    let store = TokenStore::from_tokens(&tokens());
+    assert_eq!(store.len(), 4);
+    assert_eq!(store.sutra_ids, vec![1, 2, 3, 4]);
+    assert_eq!(store.xs, vec![10, 20, 30, 40]);
+    assert_eq!(store.zs, vec![-1, -2, -3, -4]);
+    assert!(TokenStore::default().is_empty());
+}
+
+#[test]
+fn test_query_box_and_state() {
+    let mut ts = tokens();
+    ts[2].state = STATE_SLEEPING;
+    let store = TokenStore::from_tokens(&ts);
+    assert_eq!(store.query_box([15, -10, -10], [35, 10, 10]), vec![1, 2]);
+    assert_eq!(store.indices_with_state(STATE_SLEEPING), vec![2]);
+    assert_eq!(store.indices_with_state(STATE_ACTIVE).len(), 3);
+}
+
+#[test]
+fn test_bulk_update_writes_back() {
+    let mut ts = tokens();
+    let mut store = TokenStore::from_tokens(&ts);
+    store.map_masses(|m| m / 2);
+    store.map_temperatures(|t| t.saturating_add(200));
+    store.xs[0] = 999;
+
+    assert_eq!(store.write_back(&mut ts), 4);
+    assert_eq!(ts[0].position, [999, 0, -1]);
+    assert!(ts.iter().all(|t| t.mass == 50 && t.temperature == 255));
+}
+
+#[test]
+fn test_write_back_skips_reordered_tokens() {
+    let mut ts = tokens();
+    let store = TokenStore::from_tokens(&ts);
+    ts.swap(0, 1);
+    ts[0].mass = 7;
+    assert_eq!(store.write_back(&mut ts), 2);
+    assert_eq!(ts[0].mass, 7);
+}
@@ -1,4 +1,7 @@
-use axiom_core::{Token, STATE_ACTIVE, STATE_LOCKED, STATE_SLEEPING};
+use axiom_core::{
+    validate_all, MergeStrategy, Token, TokenValidationError, STATE_ACTIVE, STATE_LOCKED,
+    STATE_SLEEPING, TOKEN_FLAG_PROMOTED_FROM_EXPERIENCE,
+};
 
 #[test]
 fn test_token_size() {
@@ -97,3 +100,69 @@ fn test_compute_resonance() {
     let resonance = token1.compute_resonance(&token2);
     assert!(resonance < 60, "resonance = {}", resonance);
 }
+
+#[test]
+fn test_token_builder_defaults_match_new() {
+    let built = Token::builder(5, 100).position([1, 2, 3]).event_id(9).build().unwrap();
+    let plain = Token::new(5, 100, [1, 2, 3], 9);
+    assert_eq!(format!("{built:?}"), format!("{plain:?}"));
+}
+
+#[test]
+fn test_token_builder_explicit_target_kept() {
+    let t = Token::builder(1, 1)
+        .target([9, 9, 9])
+        .position([1, 1, 1])
+        .state(STATE_LOCKED)
+        .event_id(1)
+        .build()
+        .unwrap();
+    assert_eq!(t.position, [1, 1, 1]);
+    assert_eq!(t.target, [9, 9, 9]);
+}
+
+#[test]
+fn test_token_builder_rejects_invalid() {
+    assert!(Token::builder(1, 1).build().is_err()); // event_id не задан
+    assert!(Token::builder(1, 1).mass(0).event_id(1).build().is_err());
+    assert!(Token::builder(1, 1).state(STATE_SLEEPING + 10).event_id(1).build().is_err());
+}
+
+#[test]
+fn test_token_merge_strategies() {
+    let mut a = Token::new(1, 100, [0, 0, 0], 5);
+    a.mass = 30;
+    let mut b = Token::new(2, 100, [40, 0, 0], 9);
+    b.mass = 10;
+    b.type_flags = 0x0002;
+
+    let kept = a.merge(&b, MergeStrategy::KeepPrimary);
+    assert_eq!((kept.sutra_id, kept.position, kept.mass), (1, [0, 0, 0], 40));
+    assert_eq!(kept.type_flags & 0x0002, 0x0002);
+    assert_eq!(kept.last_event_id, 9);
+
+    let mixed = a.merge(&b, MergeStrategy::MassWeighted);
+    assert_eq!(mixed.position, [10, 0, 0]);
+    assert_eq!(mixed.target, mixed.position);
+}
+
+#[test]
+fn test_validate_all_reports_structured_errors() {
+    let good = Token::new(1, 100, [0, 0, 0], 1);
+    let mut bad = Token::new(2, 100, [0, 0, 0], 1);
+    bad.mass = 0;
+    bad.state = 9;
+    bad.type_flags = TOKEN_FLAG_PROMOTED_FROM_EXPERIENCE;
+
+    assert!(good.validation_errors().is_empty());
+    let errors = validate_all(&[good, bad]);
+    assert_eq!(
+        errors,
+        vec![
+            (1, TokenValidationError::ZeroMass),
+            (1, TokenValidationError::UnknownState(9)),
+            (1, TokenValidationError::PromotedWithoutAnchor),
+        ]
+    );
+    assert_eq!(errors[0].1.to_string(), bad.validate().unwrap_err());
+}
@@ -10,13 +10,63 @@
 //   - docs/spec/Ashti_Core_v2_0.md (каноническая)
 //   - docs/spec/Arbiter_V1_0.md
 
-use crate::{CausalHorizon, Domain, DomainState};
+use crate::{
+    CausalHorizon, ConnectionPruner, Domain, DomainState, EdgeConflict, NodeMapping,
+    OrphanCriteria, PruneReason, StrengthNormalization, Subgraph, SubgraphMergeReport,
+};
 use axiom_arbiter::{Arbiter, MembraneProfile, RoutingResult, COM};
 use axiom_config::DomainConfig;
-use axiom_core::{Event, Token};
-use axiom_space::SpatialHashGrid;
+use axiom_core::{Connection, Event, Token};
+use axiom_space::{Affine3, OccupancyStats, PositionBatch, SpatialHashGrid};
 use std::collections::HashMap;
 
+/// Итог GC-прохода по осиротевшим токенам (`AshtiCore::collect_orphans`).
+#[derive(Debug, Clone, Default)]
+pub struct OrphanGcReport {
+    /// Кандидаты, найденные по критериям во всех доменах
+    pub candidates: usize,
+    /// Кандидаты, отклонённые при review
+    pub vetoed: usize,
+    /// Tombstone-записи удалённых токенов: (domain_id, токен на момент удаления)
+    pub tombstones: Vec<(u16, Token)>,
+    /// Связи, удалённые вместе с токенами
+    pub connections_removed: usize,
+}
+
+/// Исход точечного удаления (`AshtiCore::remove_connection`, `remove_token`).
+#[derive(Debug, Clone)]
+pub enum Removal<T> {
+    /// Удалено; запись на момент удаления
+    Removed(T),
+    /// Review отклонил удаление
+    Vetoed,
+    /// Домена или объекта нет
+    NotFound,
+}
+
+/// Итог прохода удаления связей (`AshtiCore::prune_connections`).
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionPruneReport {
+    /// Удалённые связи: (domain_id, связь на момент удаления, причина)
+    pub removed: Vec<(u16, Connection, PruneReason)>,
+}
+
+impl ConnectionPruneReport {
+    /// Число удалённых связей по данной причине.
+    pub fn count(&self, reason: PruneReason) -> usize {
+        self.removed.iter().filter(|(_, _, r)| *r == reason).count()
+    }
+}
+
+/// Итог GC-прохода по связям с истёкшим TTL (`AshtiCore::expire_connections`).
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionExpiryReport {
+    /// Удалённые связи: (domain_id, связь на момент удаления)
+    pub expired: Vec<(u16, Connection)>,
+    /// Истёкшие связи, удаление которых отклонено при review
+    pub vetoed: usize,
+}
+
 /// Один фрактальный уровень Ashti_Core: 11 доменов + маршрутизатор.
 ///
 /// Порядок доменов по structural_role:
@@ -315,6 +365,27 @@ impl AshtiCore {
         Ok(result)
     }
 
+    /// Впрыснуть пакет токенов атомарно: пакет проверяется целиком
+    /// (TokenBatchBuilder::validate), при ошибке домен не меняется.
+    ///
+    /// Спящие токены, нужные под пакет, вытесняются до вставки — возвращённые
+    /// индексы остаются действительными.
+    pub fn inject_batch(
+        &mut self,
+        batch: &crate::TokenBatchBuilder,
+    ) -> Result<Vec<usize>, crate::TokenBatchError> {
+        let evict = batch.validate(self)?;
+        let idx = self
+            .index_of(batch.domain_id())
+            .ok_or(crate::TokenBatchError::UnknownDomain(batch.domain_id()))?;
+        let state = &mut self.states[idx];
+        state.evict_sleeping(evict);
+        let start = state.token_count();
+        state.tokens.extend_from_slice(batch.tokens());
+        self.domains[idx].active_tokens = state.token_count();
+        Ok((start..start + batch.len()).collect())
+    }
+
     /// Добавить токен по индексу во frontier домена (CR-TD-01).
     ///
     /// Вызывается после `inject_token` для E1-fix токенов чтобы они попадали
@@ -363,6 +434,103 @@ impl AshtiCore {
         Ok(result)
     }
 
+    /// Добавить управляемую пару связей: `forward` и обратную (target → source).
+    ///
+    /// Обе половины получают FLAG_BIDIRECTIONAL; sync_bidirectional держит их
+    /// strength согласованной. Ёмкость проверяется на обе половины сразу.
+    /// Возвращает индексы прямой и обратной половины.
+    pub fn inject_bidirectional_connection(
+        &mut self,
+        domain_id: u16,
+        forward: Connection,
+    ) -> Result<(usize, usize), crate::CapacityExceeded> {
+        let idx = self.index_of(domain_id).ok_or(crate::CapacityExceeded)?;
+        let state = &mut self.states[idx];
+        if state.connection_count() + 2 > state.connection_capacity() {
+            return Err(crate::CapacityExceeded);
+        }
+        let (forward, backward) = crate::connection_pair::make_pair(forward);
+        let f = state.add_connection(forward)?;
+        let b = state.add_connection(backward)?;
+        self.domains[idx].active_connections = state.connection_count();
+        Ok((f, b))
+    }
+
+    /// Свести strength половин всех управляемых пар во всех доменах.
+    /// Возвращает число выровненных пар.
+    pub fn sync_bidirectional(&mut self, policy: crate::SymmetryPolicy) -> usize {
+        self.states
+            .iter_mut()
+            .map(|s| crate::connection_pair::sync_pairs(&mut s.connections, policy))
+            .sum()
+    }
+
+    /// Выгрузить действующие связи всех доменов пакетом 64-байтовых записей
+    /// (`Connection::write_batch`). Токены и удалённые связи не пишутся.
+    pub fn export_connections(&self, w: &mut impl std::io::Write) -> std::io::Result<usize> {
+        let all: Vec<Connection> =
+            self.states.iter().flat_map(|s| s.live_connections().copied()).collect();
+        Connection::write_batch(&all, w)?;
+        Ok(all.len())
+    }
+
+    /// k-hop окрестность токенов `seeds` домена (см. `Subgraph::extract`).
+    pub fn extract_subgraph(&self, domain_id: u16, seeds: &[u32], hops: usize) -> Option<Subgraph> {
+        let state = self.state(self.index_of(domain_id)?)?;
+        Some(Subgraph::extract(state, seeds, hops))
+    }
+
+    /// Влить фрагмент в домен `domain_id` (см. `Subgraph::merge_into`).
+    pub fn merge_subgraph(
+        &mut self,
+        domain_id: u16,
+        subgraph: &Subgraph,
+        mapping: NodeMapping,
+        conflict: EdgeConflict,
+    ) -> Result<SubgraphMergeReport, crate::CapacityExceeded> {
+        let idx = self.index_of(domain_id).ok_or(crate::CapacityExceeded)?;
+        let report = subgraph.merge_into(&mut self.states[idx], domain_id, mapping, conflict)?;
+        self.domains[idx].active_tokens = self.states[idx].token_count();
+        self.domains[idx].active_connections = self.states[idx].connection_count();
+        Ok(report)
+    }
+
+    /// Загрузить пакет связей, записанный `export_connections`, добавив каждую
+    /// в домен из её domain_id. Возвращает число добавленных связей.
+    ///
+    /// Пакет применяется целиком или никак: неизвестный домен или нехватка
+    /// ёмкости — ошибка `InvalidData` до любых изменений. Наличие концов связей
+    /// не проверяется — токены переносятся отдельно (или не переносятся).
+    pub fn import_connections(&mut self, r: &mut impl std::io::Read) -> std::io::Result<usize> {
+        use std::io::{Error, ErrorKind};
+        let batch = Connection::read_batch(r)?;
+        let mut incoming: HashMap<usize, usize> = HashMap::new();
+        for c in &batch {
+            let idx = self.index_of(c.domain_id).ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, format!("unknown domain {}", c.domain_id))
+            })?;
+            *incoming.entry(idx).or_default() += 1;
+        }
+        for (&idx, &n) in &incoming {
+            let state = &self.states[idx];
+            if state.connection_count() + n > state.connection_capacity() {
+                let domain_id = self.domains[idx].config.domain_id;
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("domain {domain_id} connection capacity exceeded"),
+                ));
+            }
+        }
+        for c in &batch {
+            let idx = self.index_of(c.domain_id).unwrap();
+            self.states[idx].connections.push(*c);
+        }
+        for &idx in incoming.keys() {
+            self.domains[idx].active_connections = self.states[idx].connection_count();
+        }
+        Ok(batch.len())
+    }
+
     /// Найти токен по sutra_id в указанном домене. Возвращает копию токена если найден.
     pub fn find_token_by_sutra_id(&self, domain_id: u16, sutra_id: u32) -> Option<Token> {
         let idx = self.index_of(domain_id)?;
@@ -397,6 +565,23 @@ impl AshtiCore {
         false
     }
 
+    /// Заменить токен с тем же sutra_id в домене `token.domain_id` целиком.
+    ///
+    /// Перестраивает spatial grid домена. Возвращает прежнюю версию;
+    /// None — домен или токен не найден (домен не меняется).
+    pub fn replace_token(&mut self, token: Token) -> Option<Token> {
+        let i = self.index_of(token.domain_id)?;
+        let slot = self.states[i]
+            .tokens
+            .iter_mut()
+            .find(|t| t.sutra_id == token.sutra_id)?;
+        let old = std::mem::replace(slot, token);
+        let tokens = self.states[i].tokens.clone();
+        self.domains[i].rebuild_spatial_grid(&tokens);
+        self.speculative_grids[i] = None;
+        Some(old)
+    }
+
     /// Доступ к REFLECTOR — статистика рефлексов для адаптации порогов.
     pub fn reflector(&self) -> &axiom_arbiter::Reflector {
         &self.arbiter.reflector
@@ -483,6 +668,368 @@ impl AshtiCore {
             .archive_behind_horizon(horizon)
     }
 
+    /// GC осиротевших токенов: масса ≤ порога, нет сильных связей, давно не активны.
+    ///
+    /// `review` вызывается для каждого кандидата и может отклонить удаление
+    /// (Guardian). Одобренные токены удаляются из DomainState вместе со всеми
+    /// их связями, spatial grid домена перестраивается сразу — между вызовами
+    /// нет состояния, в котором грид или связи ссылаются на удалённый токен.
+    pub fn collect_orphans(
+        &mut self,
+        criteria: &OrphanCriteria,
+        current_event_id: u64,
+        mut review: impl FnMut(&Token) -> bool,
+    ) -> OrphanGcReport {
+        let mut report = OrphanGcReport::default();
+
+        for i in 0..self.states.len() {
+            let candidates = self.states[i].find_orphans(criteria, current_event_id);
+            if candidates.is_empty() {
+                continue;
+            }
+            report.candidates += candidates.len();
+
+            let domain_id = self.domains[i].config.domain_id;
+            let mut approved = Vec::with_capacity(candidates.len());
+            for sutra_id in candidates {
+                let Some(token) = self.states[i]
+                    .tokens
+                    .iter()
+                    .find(|t| t.sutra_id == sutra_id)
+                    .copied()
+                else {
+                    continue;
+                };
+                if review(&token) {
+                    approved.push(sutra_id);
+                    report.tombstones.push((domain_id, token));
+                } else {
+                    report.vetoed += 1;
+                }
+            }
+            if approved.is_empty() {
+                continue;
+            }
+
+            let (_, conns) = self.states[i].remove_tokens(&approved);
+            report.connections_removed += conns;
+            self.domains[i].active_tokens = self.states[i].token_count();
+            self.domains[i].active_connections = self.states[i].connection_count();
+            let tokens = self.states[i].tokens.clone();
+            self.domains[i].rebuild_spatial_grid(&tokens);
+            // Предвычисленный грид мог содержать удалённые токены
+            self.speculative_grids[i] = None;
+        }
+
+        report
+    }
+
+    /// Удалить связи по политике во всех доменах к моменту `now` (COM event_id).
+    pub fn prune_connections(
+        &mut self,
+        pruner: &ConnectionPruner,
+        now: u64,
+    ) -> ConnectionPruneReport {
+        let mut report = ConnectionPruneReport::default();
+        if pruner.is_noop() {
+            return report;
+        }
+        for i in 0..self.states.len() {
+            // Удалённые связи уже отчитаны в remove_connection — убрать до отбора.
+            self.compact_domain(i);
+            let selected = pruner.select(&self.states[i].connections, now);
+            if selected.is_empty() {
+                continue;
+            }
+            let domain_id = self.domains[i].config.domain_id;
+            let mut selected = selected.into_iter().peekable();
+            let all = std::mem::take(&mut self.states[i].connections);
+            let mut kept = Vec::with_capacity(all.len());
+            for (idx, c) in all.into_iter().enumerate() {
+                match selected.next_if(|&(j, _)| j == idx) {
+                    Some((_, reason)) => report.removed.push((domain_id, c, reason)),
+                    None => kept.push(c),
+                }
+            }
+            self.states[i].connections = kept;
+            self.domains[i].active_connections = self.states[i].connection_count();
+        }
+        report
+    }
+
+    /// Удалить связь домена (tombstone; см. `DomainState::tombstone_connection`),
+    /// если `review` одобрил.
+    pub fn remove_connection(
+        &mut self,
+        domain_id: u16,
+        edge: (u32, u32, u16),
+        review: impl FnOnce(&Connection) -> bool,
+    ) -> Removal<Connection> {
+        let Some(state) = self.index_of(domain_id).map(|i| &mut self.states[i]) else {
+            return Removal::NotFound;
+        };
+        let (source_id, target_id, link_type) = edge;
+        let Some(conn) = state.connection(source_id, target_id, link_type).copied() else {
+            return Removal::NotFound;
+        };
+        if !review(&conn) {
+            return Removal::Vetoed;
+        }
+        state.tombstone_connection(source_id, target_id, link_type);
+        Removal::Removed(conn)
+    }
+
+    /// Удалить токен домена вместе с его связями, если `review` одобрил.
+    /// Возвращает токен и связи на момент удаления; spatial grid перестраивается.
+    pub fn remove_token(
+        &mut self,
+        domain_id: u16,
+        sutra_id: u32,
+        review: impl FnOnce(&Token) -> bool,
+    ) -> Removal<(Token, Vec<Connection>)> {
+        let Some(i) = self.index_of(domain_id) else {
+            return Removal::NotFound;
+        };
+        let Some(token) = self.states[i].tokens.iter().find(|t| t.sutra_id == sutra_id).copied()
+        else {
+            return Removal::NotFound;
+        };
+        if !review(&token) {
+            return Removal::Vetoed;
+        }
+        let touching: Vec<Connection> = self.states[i]
+            .connections
+            .iter()
+            .filter(|c| !c.is_tombstoned())
+            .filter(|c| c.source_id == sutra_id || c.target_id == sutra_id)
+            .copied()
+            .collect();
+        self.states[i].remove_tokens(&[sutra_id]);
+        self.domains[i].active_tokens = self.states[i].token_count();
+        self.domains[i].active_connections = self.states[i].connection_count();
+        let tokens = self.states[i].tokens.clone();
+        self.domains[i].rebuild_spatial_grid(&tokens);
+        self.speculative_grids[i] = None;
+        Removal::Removed((token, touching))
+    }
+
+    /// Убрать удалённые (tombstone) связи во всех доменах. Возвращает их число.
+    pub fn compact_connections(&mut self) -> usize {
+        (0..self.states.len()).map(|i| self.compact_domain(i)).sum()
+    }
+
+    fn compact_domain(&mut self, i: usize) -> usize {
+        let removed = self.states[i].compact_connections();
+        if removed > 0 {
+            self.domains[i].active_connections = self.states[i].connection_count();
+        }
+        removed
+    }
+
+    /// GC-проход по связям с истёкшим TTL (`Connection::is_expired`) к моменту `now`.
+    ///
+    /// Каждая истёкшая связь удаляется только если `review` её одобрил;
+    /// отклонённые остаются на месте и будут предложены снова на следующем проходе.
+    pub fn expire_connections(
+        &mut self,
+        now: u64,
+        mut review: impl FnMut(&Connection) -> bool,
+    ) -> ConnectionExpiryReport {
+        let mut report = ConnectionExpiryReport::default();
+        for i in 0..self.states.len() {
+            self.compact_domain(i);
+            if !self.states[i].connections.iter().any(|c| c.is_expired(now)) {
+                continue;
+            }
+            let domain_id = self.domains[i].config.domain_id;
+            self.states[i].connections.retain(|c| {
+                if !c.is_expired(now) {
+                    return true;
+                }
+                if review(c) {
+                    report.expired.push((domain_id, *c));
+                    false
+                } else {
+                    report.vetoed += 1;
+                    true
+                }
+            });
+            self.domains[i].active_connections = self.states[i].connection_count();
+        }
+        report
+    }
+
+    /// Дедупликация почти совпадающих токенов домена (`DomainState::dedup_tokens`).
+    ///
+    /// Перестраивает spatial grid домена. None — неизвестный domain_id.
+    pub fn dedup_tokens(
+        &mut self,
+        domain_id: u16,
+        radius: i16,
+        strategy: axiom_core::MergeStrategy,
+    ) -> Option<crate::DedupReport> {
+        let i = self.index_of(domain_id)?;
+        let report = self.states[i].dedup_tokens(radius, strategy);
+        if !report.merged.is_empty() {
+            self.domains[i].active_tokens = self.states[i].token_count();
+            self.domains[i].active_connections = self.states[i].connection_count();
+            let tokens = self.states[i].tokens.clone();
+            self.domains[i].rebuild_spatial_grid(&tokens);
+            self.speculative_grids[i] = None;
+        }
+        Some(report)
+    }
+
+    /// Взвешенный kNN по нескольким доменам-пространствам одним проходом.
+    ///
+    /// `queries[i] = (domain_id, weight, точка запроса в этом домене)`.
+    /// Кандидаты — токены в радиусе `radius` хотя бы в одном пространстве
+    /// (через spatial grid домена); токены сопоставляются по sutra_id.
+    /// Оценка = Σ wᵢ·dᵢ² / Σ wᵢ, где для пространства без токена (или вне радиуса)
+    /// dᵢ² = radius² — отсутствие в слое штрафуется как максимальная дистанция.
+    /// Возвращает `(sutra_id, score)` по возрастанию оценки, при равенстве — по sutra_id.
+    pub fn knn_multi(
+        &self,
+        queries: &[(u16, f32, (i16, i16, i16))],
+        radius: i16,
+        k: usize,
+    ) -> Vec<(u32, f32)> {
+        let radius2 = (radius as i64 * radius as i64) as f32;
+        let spaces: Vec<(usize, f32, (i16, i16, i16))> = queries
+            .iter()
+            .filter(|&&(_, w, _)| w > 0.0)
+            .filter_map(|&(domain_id, w, p)| Some((self.index_of(domain_id)?, w, p)))
+            .collect();
+        let total_weight: f32 = spaces.iter().map(|s| s.1).sum();
+        if spaces.is_empty() || k == 0 {
+            return Vec::new();
+        }
+
+        // sutra_id → Σ wᵢ·(dᵢ² − radius²); штраф radius² добавляется в конце для всех
+        let mut acc: HashMap<u32, f32> = HashMap::new();
+        let mut batch = PositionBatch::default();
+        for &(i, weight, center) in &spaces {
+            let tokens = &self.states[i].tokens;
+            let found = self.domains[i].spatial_grid.find_k_nearest(
+                center,
+                radius,
+                usize::MAX,
+                |idx| {
+                    tokens
+                        .get(idx as usize)
+                        .map_or((i16::MAX, i16::MAX, i16::MAX), |t| {
+                            (t.position[0], t.position[1], t.position[2])
+                        })
+                },
+                &mut batch,
+            );
+            for (idx, d2) in found {
+                let Some(token) = tokens.get(idx as usize) else { continue };
+                *acc.entry(token.sutra_id).or_insert(0.0) += weight * (d2 as f32 - radius2);
+            }
+        }
+
+        let mut scored: Vec<(u32, f32)> = acc
+            .into_iter()
+            .map(|(id, delta)| (id, radius2 + delta / total_weight))
+            .collect();
+        scored.sort_unstable_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+        scored.truncate(k);
+        scored
+    }
+
+    /// Точный kNN в пространстве домена без ограничения радиусом (kd-дерево
+    /// домена, см. `Domain::kd_tree`). Возвращает `(sutra_id, dist²)` по
+    /// возрастанию расстояния. None — неизвестный domain_id.
+    pub fn knn(
+        &mut self,
+        domain_id: u16,
+        center: (i16, i16, i16),
+        k: usize,
+    ) -> Option<Vec<(u32, i64)>> {
+        let i = self.index_of(domain_id)?;
+        let tokens = &self.states[i].tokens;
+        let nearest = self.domains[i].kd_tree(tokens).nearest(center, k);
+        Some(
+            nearest
+                .into_iter()
+                .filter_map(|(idx, d2)| Some((tokens.get(idx as usize)?.sutra_id, d2)))
+                .collect(),
+        )
+    }
+
+    /// Токены домена в радиусе `radius` от `center`: `(sutra_id, dist²)` по
+    /// возрастанию расстояния. None — неизвестный domain_id.
+    pub fn range_query(
+        &mut self,
+        domain_id: u16,
+        center: (i16, i16, i16),
+        radius: i16,
+    ) -> Option<Vec<(u32, i64)>> {
+        let i = self.index_of(domain_id)?;
+        let tokens = &self.states[i].tokens;
+        let found = self.domains[i].kd_tree(tokens).within_radius(center, radius);
+        Some(
+            found
+                .into_iter()
+                .filter_map(|(idx, d2)| Some((tokens.get(idx as usize)?.sutra_id, d2)))
+                .collect(),
+        )
+    }
+
+    /// Токены домена в параллелепипеде [min, max] (границы включительно):
+    /// sutra_id по возрастанию. None — неизвестный domain_id.
+    pub fn box_query(
+        &mut self,
+        domain_id: u16,
+        min: (i16, i16, i16),
+        max: (i16, i16, i16),
+    ) -> Option<Vec<u32>> {
+        let i = self.index_of(domain_id)?;
+        let tokens = &self.states[i].tokens;
+        let found = self.domains[i].kd_tree(tokens).within_box(min, max);
+        let mut ids: Vec<u32> = found
+            .into_iter()
+            .filter_map(|(idx, _)| Some(tokens.get(idx as usize)?.sutra_id))
+            .collect();
+        ids.sort_unstable();
+        Some(ids)
+    }
+
+    /// Заполненность ячеек spatial grid домена — для heatmap и поиска
+    /// перегруженных областей. Позиции — текущие, состав — на момент
+    /// последней перестройки grid. None — неизвестный domain_id.
+    pub fn grid_occupancy(&self, domain_id: u16) -> Option<OccupancyStats> {
+        let i = self.index_of(domain_id)?;
+        let tokens = &self.states[i].tokens;
+        Some(self.domains[i].spatial_grid.occupancy(|idx| {
+            tokens
+                .get(idx)
+                .map(|t| (t.position[0], t.position[1], t.position[2]))
+                .unwrap_or((0, 0, 0))
+        }))
+    }
+
+    /// Подобрать отображение пространства домена `from` в пространство `to`
+    /// по токенам, которые есть в обоих доменах (общий sutra_id).
+    /// None — неизвестный домен или общих токенов не хватает (см. `Affine3::fit`).
+    pub fn fit_domain_transform(&self, from: u16, to: u16) -> Option<Affine3> {
+        let source = &self.states[self.index_of(from)?].tokens;
+        let target: HashMap<u32, [i16; 3]> = self.states[self.index_of(to)?]
+            .tokens
+            .iter()
+            .map(|t| (t.sutra_id, t.position))
+            .collect();
+        let pairs: Vec<_> = source
+            .iter()
+            .filter_map(|t| {
+                let p = target.get(&t.sutra_id)?;
+                Some(((t.position[0], t.position[1], t.position[2]), (p[0], p[1], p[2])))
+            })
+            .collect();
+        Affine3::fit(&pairs)
+    }
+
     /// Конфигурации всех доменов (domain_id, DomainConfig) — для snapshot.
     /// Получить конфиг домена по domain_id.
     pub fn config_of(&self, domain_id: u16) -> Option<axiom_config::DomainConfig> {
@@ -619,6 +1166,50 @@ impl AshtiCore {
         pruned_total
     }
 
+    /// Гомеостаз связей: нормализовать исходящие strength во всех доменах.
+    ///
+    /// Возвращает суммарное число перемасштабированных групп (source_id, link_type).
+    pub fn normalize_strengths(&mut self, policy: &StrengthNormalization) -> usize {
+        if policy.is_noop() {
+            return 0;
+        }
+        self.states
+            .iter_mut()
+            .map(|state| policy.apply(&mut state.connections))
+            .sum()
+    }
+
+    /// Затухание простаивающих связей во всех доменах к моменту `now` (COM event_id).
+    pub fn decay_connections(
+        &mut self,
+        policy: &crate::ConnectionDecay,
+        now: u64,
+    ) -> crate::ConnectionDecayReport {
+        let mut report = crate::ConnectionDecayReport::default();
+        if policy.is_noop() {
+            return report;
+        }
+        for state in &mut self.states {
+            report.merge(policy.apply(&mut state.connections, now));
+        }
+        report
+    }
+
+    /// Шаг пластичности связей во всех доменах по времени активации их концов.
+    pub fn learn_connections(
+        &mut self,
+        rule: &crate::ConnectionLearningRule,
+    ) -> crate::ConnectionLearningReport {
+        let mut report = crate::ConnectionLearningReport::default();
+        if rule.is_noop() {
+            return report;
+        }
+        for state in &mut self.states {
+            report.merge(rule.apply(&mut state.connections, &state.tokens));
+        }
+        report
+    }
+
     /// Перевести токен с данным sutra_id в STATE_SLEEPING, valence=0.
     /// Вызывается при обработке TokenDecayed события.
     /// Ищет токен во всех 11 доменах. Возвращает копию токена если нашёл
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Структурная важность токенов в графе связей домена
+//
+// PageRank — взвешенный по strength (ингибированные связи не голосуют);
+// токены без исходящих связей раздают свой ранг равномерно. Повторный расчёт
+// после небольших изменений графа стартует с прошлых оценок (`previous`)
+// и сходится за несколько итераций. Betweenness — алгоритм Brandes по
+// направленным связям без весов. Узлы — sutra_id, встречающиеся в связях;
+// удалённые (tombstone) связи не учитываются.
+
+use axiom_core::Connection;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+/// Параметры PageRank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageRankConfig {
+    /// Вероятность перейти по связи (1 − вероятность телепортации)
+    pub damping: f32,
+    /// Предел числа итераций
+    pub max_iterations: usize,
+    /// Остановка, когда суммарное изменение рангов меньше порога
+    pub tolerance: f32,
+}
+
+impl Default for PageRankConfig {
+    fn default() -> Self {
+        Self { damping: 0.85, max_iterations: 100, tolerance: 1e-6 }
+    }
+}
+
+/// Результат PageRank.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PageRank {
+    /// sutra_id → ранг (сумма рангов = 1)
+    pub scores: HashMap<u32, f32>,
+    /// Число выполненных итераций
+    pub iterations: usize,
+}
+
+/// Степени токена.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Degree {
+    /// Входящих связей
+    pub incoming: usize,
+    /// Исходящих связей
+    pub outgoing: usize,
+}
+
+/// Все метрики одного токена.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeCentrality {
+    /// Токен
+    pub sutra_id: u32,
+    /// Ранг PageRank
+    pub pagerank: f32,
+    /// Betweenness (Brandes)
+    pub betweenness: f32,
+    /// Степени
+    pub degree: Degree,
+}
+
+/// PageRank по связям домена. `previous` — оценки прошлого расчёта
+/// (тёплый старт; новые токены начинают с равной доли).
+pub fn pagerank(
+    connections: &[Connection],
+    config: &PageRankConfig,
+    previous: Option<&HashMap<u32, f32>>,
+) -> PageRank {
+    let nodes = nodes_of(connections);
+    if nodes.is_empty() {
+        return PageRank::default();
+    }
+    let n = nodes.len() as f32;
+    let index: HashMap<u32, usize> = nodes.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+    let mut out_weight = vec![0.0f32; nodes.len()];
+    let mut edges = Vec::with_capacity(connections.len());
+    for c in live(connections) {
+        let w = if c.is_inhibited() { 0.0 } else { c.strength.max(0.0) };
+        if w > 0.0 {
+            let (s, t) = (index[&c.source_id], index[&c.target_id]);
+            out_weight[s] += w;
+            edges.push((s, t, w));
+        }
+    }
+
+    let mut rank: Vec<f32> = nodes
+        .iter()
+        .map(|id| previous.and_then(|p| p.get(id).copied()).unwrap_or(1.0 / n))
+        .collect();
+    let total: f32 = rank.iter().sum();
+    if total > 0.0 {
+        rank.iter_mut().for_each(|r| *r /= total);
+    }
+
+    let mut iterations = 0;
+    while iterations < config.max_iterations {
+        iterations += 1;
+        let dangling: f32 =
+            (0..nodes.len()).filter(|&i| out_weight[i] == 0.0).map(|i| rank[i]).sum();
+        let base = (1.0 - config.damping) / n + config.damping * dangling / n;
+        let mut next = vec![base; nodes.len()];
+        for &(s, t, w) in &edges {
+            next[t] += config.damping * rank[s] * w / out_weight[s];
+        }
+        let change: f32 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < config.tolerance {
+            break;
+        }
+    }
+
+    PageRank { scores: nodes.into_iter().zip(rank).collect(), iterations }
+}
+
+/// Входящие и исходящие степени токенов.
+pub fn degree_centrality(connections: &[Connection]) -> HashMap<u32, Degree> {
+    let mut degrees: HashMap<u32, Degree> = HashMap::new();
+    for c in live(connections) {
+        degrees.entry(c.source_id).or_default().outgoing += 1;
+        degrees.entry(c.target_id).or_default().incoming += 1;
+    }
+    degrees
+}
+
+/// Betweenness (Brandes): сколько кратчайших путей между другими токенами
+/// проходит через токен. Без нормализации.
+pub fn betweenness_centrality(connections: &[Connection]) -> HashMap<u32, f32> {
+    let nodes = nodes_of(connections);
+    let index: HashMap<u32, usize> = nodes.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+    let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for c in live(connections) {
+        let (s, t) = (index[&c.source_id], index[&c.target_id]);
+        if s != t && !outgoing[s].contains(&t) {
+            outgoing[s].push(t);
+        }
+    }
+
+    let mut score = vec![0.0f32; nodes.len()];
+    for source in 0..nodes.len() {
+        let mut order = Vec::with_capacity(nodes.len());
+        let mut preds: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut paths = vec![0.0f32; nodes.len()];
+        let mut dist = vec![usize::MAX; nodes.len()];
+        paths[source] = 1.0;
+        dist[source] = 0;
+        let mut queue = VecDeque::from([source]);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            for &w in &outgoing[v] {
+                if dist[w] == usize::MAX {
+                    dist[w] = dist[v] + 1;
+                    queue.push_back(w);
+                }
+                if dist[w] == dist[v] + 1 {
+                    paths[w] += paths[v];
+                    preds[w].push(v);
+                }
+            }
+        }
+        let mut dependency = vec![0.0f32; nodes.len()];
+        for &w in order.iter().rev() {
+            for &v in &preds[w] {
+                dependency[v] += paths[v] / paths[w] * (1.0 + dependency[w]);
+            }
+            if w != source {
+                score[w] += dependency[w];
+            }
+        }
+    }
+    nodes.into_iter().zip(score).collect()
+}
+
+/// PageRank, betweenness и степени всех токенов, по убыванию PageRank.
+pub fn centrality(connections: &[Connection], config: &PageRankConfig) -> Vec<NodeCentrality> {
+    let ranks = pagerank(connections, config, None).scores;
+    let betweenness = betweenness_centrality(connections);
+    let degrees = degree_centrality(connections);
+    let mut result: Vec<NodeCentrality> = ranks
+        .iter()
+        .map(|(&sutra_id, &pagerank)| NodeCentrality {
+            sutra_id,
+            pagerank,
+            betweenness: betweenness[&sutra_id],
+            degree: degrees[&sutra_id],
+        })
+        .collect();
+    result.sort_by(|a, b| b.pagerank.total_cmp(&a.pagerank).then(a.sutra_id.cmp(&b.sutra_id)));
+    result
+}
+
+/// Узлы графа по возрастанию sutra_id.
+fn nodes_of(connections: &[Connection]) -> Vec<u32> {
+    let ids: BTreeSet<u32> = live(connections).flat_map(|c| [c.source_id, c.target_id]).collect();
+    ids.into_iter().collect()
+}
+
+/// Связи без удалённых (tombstone).
+fn live(connections: &[Connection]) -> impl Iterator<Item = &Connection> {
+    connections.iter().filter(|c| !c.is_tombstoned())
+}
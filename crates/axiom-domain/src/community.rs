@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Поиск сообществ в графе связей домена (label propagation)
+//
+// Связи рассматриваются как неориентированные, вес — strength. Каждый токен
+// начинает со своей метки (sutra_id) и на каждом проходе принимает метку,
+// суммарный вес которой среди соседей максимален. Токены обходятся по
+// возрастанию sutra_id, ничьи решаются в пользу меньшей метки — результат
+// детерминирован. Id сообщества — наименьший sutra_id среди его членов.
+
+use axiom_core::Connection;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::TokenLabels;
+
+/// Разбиение токенов на сообщества.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Communities {
+    assignment: BTreeMap<u32, u32>,
+    /// Число выполненных проходов
+    pub iterations: usize,
+}
+
+impl Communities {
+    /// Сообщество токена (None — токен не участвует ни в одной связи).
+    pub fn community_of(&self, sutra_id: u32) -> Option<u32> {
+        self.assignment.get(&sutra_id).copied()
+    }
+
+    /// Члены сообщества по возрастанию sutra_id.
+    pub fn members(&self, community: u32) -> Vec<u32> {
+        self.assignment
+            .iter()
+            .filter(|(_, &c)| c == community)
+            .map(|(&id, _)| id)
+            .collect()
+    }
+
+    /// Число сообществ.
+    pub fn count(&self) -> usize {
+        let mut ids: Vec<u32> = self.assignment.values().copied().collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.len()
+    }
+
+    /// Пары (sutra_id, сообщество) по возрастанию sutra_id.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.assignment.iter().map(|(&id, &c)| (id, c))
+    }
+
+    /// Записать сообщество каждого токена меткой `{prefix}{id}`.
+    /// Прежние метки с тем же префиксом снимаются. Возвращает число токенов.
+    pub fn write_labels(&self, labels: &mut TokenLabels, prefix: &str) -> usize {
+        for (sutra_id, community) in self.iter() {
+            let stale: Vec<String> = labels
+                .labels(sutra_id)
+                .iter()
+                .filter(|l| l.starts_with(prefix))
+                .cloned()
+                .collect();
+            for label in stale {
+                labels.remove_label(sutra_id, &label);
+            }
+            labels.add(sutra_id, &format!("{prefix}{community}"));
+        }
+        self.assignment.len()
+    }
+}
+
+/// Label propagation по связям домена, не больше `max_iterations` проходов.
+pub fn label_propagation(connections: &[Connection], max_iterations: usize) -> Communities {
+    let mut neighbours: BTreeMap<u32, Vec<(u32, f32)>> = BTreeMap::new();
+    for c in connections {
+        if c.source_id == c.target_id || c.is_tombstoned() {
+            continue;
+        }
+        let w = c.strength.max(0.0);
+        neighbours.entry(c.source_id).or_default().push((c.target_id, w));
+        neighbours.entry(c.target_id).or_default().push((c.source_id, w));
+    }
+
+    let mut label: HashMap<u32, u32> = neighbours.keys().map(|&id| (id, id)).collect();
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        iterations += 1;
+        let mut changed = false;
+        for (&node, edges) in &neighbours {
+            let mut weight: BTreeMap<u32, f32> = BTreeMap::new();
+            for &(n, w) in edges {
+                *weight.entry(label[&n]).or_default() += w;
+            }
+            // BTreeMap: при равном весе остаётся меньшая метка
+            let best = weight
+                .iter()
+                .fold(None, |acc: Option<(u32, f32)>, (&l, &w)| match acc {
+                    Some((_, bw)) if bw >= w => acc,
+                    _ => Some((l, w)),
+                })
+                .map(|(l, _)| l);
+            if let Some(best) = best {
+                if best != label[&node] {
+                    label.insert(node, best);
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // Нормализовать id: наименьший sutra_id среди членов
+    let mut canonical: HashMap<u32, u32> = HashMap::new();
+    for &node in neighbours.keys() {
+        canonical.entry(label[&node]).or_insert(node);
+    }
+    let assignment = neighbours.keys().map(|&id| (id, canonical[&label[&id]])).collect();
+    Communities { assignment, iterations }
+}
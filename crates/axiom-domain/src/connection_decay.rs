@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ConnectionDecay — затухание давно не активированных связей
+//
+// Связь усиливается, когда её касаются (BondTokens, подкрепление), но без
+// обращений strength не меняется: устаревшее ребро навсегда остаётся таким же
+// сильным, как в момент последнего использования. Периодический проход ослабляет
+// связи, чей last_event_id отстал от текущего COM-времени больше чем на idle_after,
+// и помечает слишком слабые FLAG_PRUNE_CANDIDATE. Сами связи не удаляются —
+// решение об удалении остаётся за вызывающей стороной.
+
+use axiom_core::{Connection, FLAG_CRITICAL, FLAG_PRUNE_CANDIDATE};
+
+use crate::strength_norm::MIN_NORMALIZED_STRENGTH;
+
+/// Политика затухания связей.
+///
+/// Политика по умолчанию (`factor = 1.0`) — no-op.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionDecay {
+    /// Множитель strength за один проход (0..1); 1.0 — затухание выключено
+    pub factor: f32,
+    /// Сколько COM-событий связь должна простоять без активации, чтобы затухать
+    pub idle_after: u64,
+    /// Ниже этой strength связь помечается FLAG_PRUNE_CANDIDATE
+    pub prune_below: f32,
+}
+
+impl Default for ConnectionDecay {
+    fn default() -> Self {
+        Self { factor: 1.0, idle_after: 1000, prune_below: 0.05 }
+    }
+}
+
+/// Итог прохода затухания.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectionDecayReport {
+    /// Связей ослаблено
+    pub decayed: usize,
+    /// Связей, помеченных кандидатами на удаление в этом проходе
+    pub flagged: usize,
+}
+
+impl ConnectionDecayReport {
+    pub(crate) fn merge(&mut self, other: ConnectionDecayReport) {
+        self.decayed += other.decayed;
+        self.flagged += other.flagged;
+    }
+}
+
+impl ConnectionDecay {
+    /// True если политика ничего не делает.
+    pub fn is_noop(&self) -> bool {
+        self.factor >= 1.0
+    }
+
+    /// Ослабить простаивающие связи к моменту `now` (COM event_id).
+    ///
+    /// FLAG_CRITICAL- и удалённые (tombstone) связи не затухают. Связь,
+    /// активированная после пометки (больше не простаивает), теряет FLAG_PRUNE_CANDIDATE.
+    pub fn apply(&self, connections: &mut [Connection], now: u64) -> ConnectionDecayReport {
+        let mut report = ConnectionDecayReport::default();
+        if self.is_noop() {
+            return report;
+        }
+        let factor = self.factor.max(0.0);
+        for c in connections.iter_mut().filter(|c| !c.is_tombstoned()) {
+            if now.saturating_sub(c.last_event_id) < self.idle_after {
+                c.flags &= !FLAG_PRUNE_CANDIDATE;
+                continue;
+            }
+            if c.flags & FLAG_CRITICAL != 0 {
+                continue;
+            }
+            c.strength = (c.strength * factor).max(MIN_NORMALIZED_STRENGTH);
+            report.decayed += 1;
+            if c.strength < self.prune_below && c.flags & FLAG_PRUNE_CANDIDATE == 0 {
+                c.flags |= FLAG_PRUNE_CANDIDATE;
+                report.flagged += 1;
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ConnectionTypeIndex — вторичный индекс связей домена по link_type
+//
+// Запросы «все связи типа T от токена X» и «сколько связей типа T» без индекса
+// сканируют весь DomainState::connections, а распознавание узоров (FrameWeaver)
+// делает это для каждой Frame-головы. Индекс строится одним проходом и хранит
+// позиции связей в срезе; внутри каждого списка — по возрастанию позиции,
+// так что порядок совпадает с порядком прямого скана.
+//
+// Индекс — снимок: connections мутируется напрямую (retain, swap, dedup),
+// поэтому после изменения среза его нужно построить заново.
+
+use axiom_core::Connection;
+use std::collections::HashMap;
+
+/// Позиции связей по link_type и по (source_id, link_type).
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionTypeIndex {
+    by_type: HashMap<u16, Vec<usize>>,
+    by_source: HashMap<(u32, u16), Vec<usize>>,
+}
+
+impl ConnectionTypeIndex {
+    /// Построить индекс по срезу связей; удалённые (tombstone) не индексируются.
+    pub fn build(connections: &[Connection]) -> Self {
+        let mut index = Self::default();
+        for (i, c) in connections.iter().enumerate().filter(|(_, c)| !c.is_tombstoned()) {
+            index.by_type.entry(c.link_type).or_default().push(i);
+            index.by_source.entry((c.source_id, c.link_type)).or_default().push(i);
+        }
+        index
+    }
+
+    /// Позиции связей типа `link_type`.
+    pub fn of_type(&self, link_type: u16) -> &[usize] {
+        self.by_type.get(&link_type).map_or(&[], Vec::as_slice)
+    }
+
+    /// Число связей типа `link_type`.
+    pub fn count(&self, link_type: u16) -> usize {
+        self.of_type(link_type).len()
+    }
+
+    /// Позиции связей типа `link_type`, исходящих из `source_id`.
+    pub fn from_source(&self, source_id: u32, link_type: u16) -> &[usize] {
+        self.by_source.get(&(source_id, link_type)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Позиции связей категории `category` (старший байт link_type), по возрастанию.
+    pub fn of_category(&self, category: u8) -> Vec<usize> {
+        collect_sorted(self.types_in(category).map(|t| self.of_type(t)))
+    }
+
+    /// Позиции связей категории `category` из `source_id`, по возрастанию.
+    pub fn from_source_in_category(&self, source_id: u32, category: u8) -> Vec<usize> {
+        collect_sorted(self.types_in(category).map(|t| self.from_source(source_id, t)))
+    }
+
+    /// Встречающиеся link_type.
+    pub fn types(&self) -> impl Iterator<Item = u16> + '_ {
+        self.by_type.keys().copied()
+    }
+
+    fn types_in(&self, category: u8) -> impl Iterator<Item = u16> + '_ {
+        self.types().filter(move |t| (t >> 8) as u8 == category)
+    }
+}
+
+fn collect_sorted<'a>(lists: impl Iterator<Item = &'a [usize]>) -> Vec<usize> {
+    let mut out: Vec<usize> = lists.flatten().copied().collect();
+    out.sort_unstable();
+    out
+}
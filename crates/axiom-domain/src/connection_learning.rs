@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ConnectionLearningRule — пластичность связей по времени активации концов
+//
+// Связь знает только собственное время (created_at, last_event_id); время
+// активации концов — last_event_id токенов домена. Правило превращает разность
+// dt = t(target) − t(source) в изменение strength:
+//   Simple  — фиксированный шаг: +rate если концы активированы в пределах окна, иначе −rate;
+//   Hebbian — усиление, линейно убывающее с |dt| до нуля на границе окна;
+//   Stdp    — источник раньше цели усиливает, позже — ослабляет, экспоненциально по |dt|.
+// Связь учится только на новых активациях: после шага её last_event_id
+// сдвигается к последней активации концов, и повторный проход её не трогает.
+
+use axiom_core::{Connection, Token, FLAG_CRITICAL};
+use std::collections::HashMap;
+
+use crate::strength_norm::MIN_NORMALIZED_STRENGTH;
+
+/// Верхняя граница strength при усилении (выше неё связь может быть только задана извне).
+pub const MAX_LEARNED_STRENGTH: f32 = 1.0;
+
+/// Правило пластичности связей.
+///
+/// Правило по умолчанию (`Simple` с `rate = 0.0`) — no-op.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnectionLearningRule {
+    /// ±rate в зависимости от того, попали ли активации концов в окно
+    Simple {
+        /// Шаг изменения strength
+        rate: f32,
+        /// Окно совместной активации (COM-события)
+        window: u64,
+    },
+    /// rate · (1 − |dt| / window), только усиление
+    Hebbian {
+        /// Максимальное усиление (при одновременной активации)
+        rate: f32,
+        /// Окно совместной активации (COM-события)
+        window: u64,
+    },
+    /// +a_plus · e^(−dt/tau) при dt > 0, −a_minus · e^(dt/tau) при dt < 0
+    Stdp {
+        /// Амплитуда усиления (источник раньше цели)
+        a_plus: f32,
+        /// Амплитуда ослабления (цель раньше источника)
+        a_minus: f32,
+        /// Постоянная времени (COM-события)
+        tau: f32,
+    },
+}
+
+impl Default for ConnectionLearningRule {
+    fn default() -> Self {
+        ConnectionLearningRule::Simple { rate: 0.0, window: 100 }
+    }
+}
+
+/// Итог прохода обучения.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectionLearningReport {
+    /// Связей усилено
+    pub strengthened: usize,
+    /// Связей ослаблено
+    pub weakened: usize,
+}
+
+impl ConnectionLearningReport {
+    pub(crate) fn merge(&mut self, other: ConnectionLearningReport) {
+        self.strengthened += other.strengthened;
+        self.weakened += other.weakened;
+    }
+}
+
+impl ConnectionLearningRule {
+    /// True если правило ничего не меняет.
+    pub fn is_noop(&self) -> bool {
+        match *self {
+            ConnectionLearningRule::Simple { rate, .. } => rate == 0.0,
+            ConnectionLearningRule::Hebbian { rate, window } => rate == 0.0 || window == 0,
+            ConnectionLearningRule::Stdp { a_plus, a_minus, tau } => {
+                (a_plus == 0.0 && a_minus == 0.0) || tau <= 0.0
+            }
+        }
+    }
+
+    /// Изменение strength по времени активации источника и цели (COM event_id).
+    pub fn delta(&self, source_at: u64, target_at: u64) -> f32 {
+        let dt = target_at as i128 - source_at as i128;
+        let abs = dt.unsigned_abs() as f32;
+        match *self {
+            ConnectionLearningRule::Simple { rate, window } => {
+                if abs <= window as f32 { rate } else { -rate }
+            }
+            ConnectionLearningRule::Hebbian { rate, window } => {
+                if window == 0 {
+                    return 0.0;
+                }
+                rate * (1.0 - abs / window as f32).max(0.0)
+            }
+            ConnectionLearningRule::Stdp { a_plus, a_minus, tau } => {
+                if tau <= 0.0 || dt == 0 {
+                    0.0
+                } else if dt > 0 {
+                    a_plus * (-abs / tau).exp()
+                } else {
+                    -a_minus * (-abs / tau).exp()
+                }
+            }
+        }
+    }
+
+    /// Применить правило к связям домена; время активации концов берётся из `tokens`.
+    ///
+    /// Пропускаются FLAG_CRITICAL- и удалённые (tombstone) связи, связи с неизвестным
+    /// или ни разу не активированным концом и связи без новых активаций с прошлого шага.
+    pub fn apply(
+        &self,
+        connections: &mut [Connection],
+        tokens: &[Token],
+    ) -> ConnectionLearningReport {
+        let mut report = ConnectionLearningReport::default();
+        if self.is_noop() {
+            return report;
+        }
+        let activated: HashMap<u32, u64> =
+            tokens.iter().map(|t| (t.sutra_id, t.last_event_id)).collect();
+        for c in connections.iter_mut().filter(|c| !c.is_tombstoned()) {
+            if c.flags & FLAG_CRITICAL != 0 {
+                continue;
+            }
+            let (Some(&source_at), Some(&target_at)) =
+                (activated.get(&c.source_id), activated.get(&c.target_id))
+            else {
+                continue;
+            };
+            let latest = source_at.max(target_at);
+            if source_at == 0 || target_at == 0 || latest <= c.last_event_id {
+                continue;
+            }
+            c.last_event_id = latest;
+            let d = self.delta(source_at, target_at);
+            if d > 0.0 {
+                c.strength = (c.strength + d).min(MAX_LEARNED_STRENGTH).max(c.strength);
+                report.strengthened += 1;
+            } else if d < 0.0 {
+                c.strength = (c.strength + d).max(MIN_NORMALIZED_STRENGTH);
+                report.weakened += 1;
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Управляемые пары связей (прямая + обратная)
+//
+// Connection направленная; симметричное отношение хранится двумя связями.
+// Обучение, затухание и нормализация меняют каждую половину независимо,
+// и без синхронизации пара расходится. Обе половины несут FLAG_BIDIRECTIONAL;
+// sync_pairs сводит их strength по SymmetryPolicy. Половина, чью пару уже
+// удалили, синхронизации не подлежит и живёт как обычная связь.
+
+use axiom_core::{Connection, FLAG_BIDIRECTIONAL};
+use std::collections::HashMap;
+
+/// Как свести strength двух половин пары.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymmetryPolicy {
+    /// Взять strength половины, изменённой последней (больший last_event_id)
+    #[default]
+    LatestWins,
+    /// Большая из двух
+    Max,
+    /// Меньшая из двух
+    Min,
+    /// Среднее арифметическое
+    Mean,
+}
+
+impl SymmetryPolicy {
+    /// Общая strength пары.
+    pub fn resolve(&self, a: &Connection, b: &Connection) -> f32 {
+        match self {
+            SymmetryPolicy::LatestWins => {
+                if b.last_event_id > a.last_event_id { b.strength } else { a.strength }
+            }
+            SymmetryPolicy::Max => a.strength.max(b.strength),
+            SymmetryPolicy::Min => a.strength.min(b.strength),
+            SymmetryPolicy::Mean => (a.strength + b.strength) / 2.0,
+        }
+    }
+}
+
+/// Пара половин для связи `forward`: сама связь и обратная, обе с FLAG_BIDIRECTIONAL.
+pub fn make_pair(forward: Connection) -> (Connection, Connection) {
+    let mut forward = forward;
+    forward.flags |= FLAG_BIDIRECTIONAL;
+    let mut backward = forward;
+    backward.source_id = forward.target_id;
+    backward.target_id = forward.source_id;
+    (forward, backward)
+}
+
+/// Синхронизировать strength и last_event_id половин всех пар в срезе;
+/// удалённые (tombstone) половины не участвуют. Возвращает число пар, которые пришлось выровнять.
+pub fn sync_pairs(connections: &mut [Connection], policy: SymmetryPolicy) -> usize {
+    let halves: HashMap<(u32, u32, u16), usize> = connections
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.flags & FLAG_BIDIRECTIONAL != 0 && !c.is_tombstoned())
+        .map(|(i, c)| ((c.source_id, c.target_id, c.link_type), i))
+        .collect();
+    let mut synced = 0;
+    for (&(source, target, link_type), &i) in &halves {
+        // Каждую пару обрабатывает половина с меньшим индексом
+        let Some(&j) = halves.get(&(target, source, link_type)) else {
+            continue;
+        };
+        if j <= i {
+            continue;
+        }
+        let (a, b) = (connections[i], connections[j]);
+        let strength = policy.resolve(&a, &b);
+        let last_event_id = a.last_event_id.max(b.last_event_id);
+        if a.strength == strength && b.strength == strength && a.last_event_id == b.last_event_id {
+            continue;
+        }
+        for k in [i, j] {
+            connections[k].strength = strength;
+            connections[k].last_event_id = last_event_id;
+        }
+        synced += 1;
+    }
+    synced
+}
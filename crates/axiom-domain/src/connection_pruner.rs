@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ConnectionPruner — забывание связей по политике
+//
+// Связи удаляются только вместе с токенами (reconcile, orphan GC), поэтому
+// граф между живыми токенами растёт без ограничений. ConnectionPruner удаляет
+// рёбра, которые по политике больше не несут смысла: слабые, давно не
+// активированные, помеченные ConnectionDecay (FLAG_PRUNE_CANDIDATE) или
+// превышающие лимит исходящих связей одного типа. FLAG_CRITICAL — неприкосновенны.
+
+use axiom_core::{Connection, FLAG_CRITICAL, FLAG_PRUNE_CANDIDATE};
+use std::collections::HashMap;
+
+/// Почему связь удалена.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PruneReason {
+    /// strength ниже min_strength
+    BelowStrength,
+    /// Не активировалась дольше max_idle COM-событий
+    Idle,
+    /// Помечена FLAG_PRUNE_CANDIDATE
+    Flagged,
+    /// Сверх лимита исходящих связей (source_id, link_type) — удалены самые слабые
+    OverCap,
+}
+
+/// Политика удаления связей. Каждое правило выключено нулём / false / пустой картой;
+/// политика по умолчанию — no-op.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectionPruner {
+    /// Удалять связи со strength ниже порога (0.0 — правило выключено)
+    pub min_strength: f32,
+    /// Удалять связи, простоявшие дольше N COM-событий (0 — выключено)
+    pub max_idle: u64,
+    /// Удалять связи с FLAG_PRUNE_CANDIDATE
+    pub prune_flagged: bool,
+    /// Лимит исходящих связей на (source_id, link_type) для данного link_type
+    pub per_link_type_cap: HashMap<u16, usize>,
+}
+
+impl ConnectionPruner {
+    /// True если политика ничего не делает.
+    pub fn is_noop(&self) -> bool {
+        self.min_strength <= 0.0
+            && self.max_idle == 0
+            && !self.prune_flagged
+            && self.per_link_type_cap.is_empty()
+    }
+
+    /// Причина удаления связи по правилам, не зависящим от соседей (без лимитов).
+    pub fn reason(&self, c: &Connection, now: u64) -> Option<PruneReason> {
+        if c.flags & FLAG_CRITICAL != 0 {
+            return None;
+        }
+        if self.prune_flagged && c.flags & FLAG_PRUNE_CANDIDATE != 0 {
+            Some(PruneReason::Flagged)
+        } else if c.strength < self.min_strength {
+            Some(PruneReason::BelowStrength)
+        } else if self.max_idle > 0 && now.saturating_sub(c.last_event_id) > self.max_idle {
+            Some(PruneReason::Idle)
+        } else {
+            None
+        }
+    }
+
+    /// Выбрать связи на удаление: (индекс, причина) по возрастанию индекса.
+    /// Удалённые (tombstone) связи не выбираются и не занимают мест в cap.
+    pub fn select(&self, connections: &[Connection], now: u64) -> Vec<(usize, PruneReason)> {
+        if self.is_noop() {
+            return Vec::new();
+        }
+        let mut reasons: Vec<Option<PruneReason>> = connections
+            .iter()
+            .map(|c| self.reason(c, now).filter(|_| !c.is_tombstoned()))
+            .collect();
+
+        if !self.per_link_type_cap.is_empty() {
+            let mut groups: HashMap<(u32, u16), Vec<usize>> = HashMap::new();
+            for (i, c) in connections.iter().enumerate() {
+                if reasons[i].is_none()
+                    && !c.is_tombstoned()
+                    && self.per_link_type_cap.contains_key(&c.link_type)
+                {
+                    groups.entry((c.source_id, c.link_type)).or_default().push(i);
+                }
+            }
+            for ((_, link_type), mut indices) in groups {
+                let cap = self.per_link_type_cap[&link_type];
+                if indices.len() <= cap {
+                    continue;
+                }
+                // Критические связи занимают места первыми, затем — по убыванию strength
+                indices.sort_by(|&a, &b| {
+                    let crit = |i: usize| connections[i].flags & FLAG_CRITICAL != 0;
+                    crit(b)
+                        .cmp(&crit(a))
+                        .then(connections[b].strength.total_cmp(&connections[a].strength))
+                });
+                for &i in &indices[cap..] {
+                    if connections[i].flags & FLAG_CRITICAL == 0 {
+                        reasons[i] = Some(PruneReason::OverCap);
+                    }
+                }
+            }
+        }
+
+        reasons
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.map(|r| (i, r)))
+            .collect()
+    }
+}
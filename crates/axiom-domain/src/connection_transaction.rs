@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ConnectionTransaction — атомарный пакет изменений связей домена.
+//
+// Действие, меняющее несколько связей подряд (добавить, снять, перевзвесить),
+// может упасть на середине — граф остаётся наполовину изменённым. Транзакция
+// копит операции и применяет их к рабочей копии буфера связей; каждая
+// операция проходит review (GUARDIAN на стороне engine). Любая ошибка или
+// вето откатывает всё: домен меняется только если прошли все операции.
+
+use axiom_core::Connection;
+
+use crate::{AshtiCore, ConnectionKey};
+
+/// Операция транзакции.
+#[derive(Debug, Clone, Copy)]
+pub enum TransactionOp {
+    /// Добавить связь
+    Add(Connection),
+    /// Снять связь
+    Remove(ConnectionKey),
+    /// Задать strength связи
+    SetStrength(ConnectionKey, f32),
+}
+
+/// Ошибка транзакции. Домен при ошибке не меняется.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionError {
+    /// domain_id не принадлежит этому AshtiCore
+    UnknownDomain(u16),
+    /// Добавляемая связь адресована другому домену
+    DomainMismatch { index: usize, domain_id: u16 },
+    /// Связь с таким ключом уже есть
+    Duplicate { index: usize, key: ConnectionKey },
+    /// Связи с таким ключом нет
+    Missing { index: usize, key: ConnectionKey },
+    /// strength не конечна или отрицательна
+    InvalidStrength { index: usize },
+    /// Review отклонил операцию
+    Vetoed { index: usize },
+    /// Не хватает места под связи
+    Capacity { needed: usize, available: usize },
+}
+
+impl std::fmt::Display for TransactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransactionError::UnknownDomain(id) => write!(f, "unknown domain {id}"),
+            TransactionError::DomainMismatch { index, domain_id } => {
+                write!(f, "op #{index}: connection belongs to domain {domain_id}")
+            }
+            TransactionError::Duplicate { index, key } => {
+                write!(f, "op #{index}: connection {key:?} already exists")
+            }
+            TransactionError::Missing { index, key } => {
+                write!(f, "op #{index}: connection {key:?} not found")
+            }
+            TransactionError::InvalidStrength { index } => {
+                write!(f, "op #{index}: strength must be finite and non-negative")
+            }
+            TransactionError::Vetoed { index } => write!(f, "op #{index}: vetoed"),
+            TransactionError::Capacity { needed, available } => {
+                write!(f, "transaction needs {needed} connection slots, {available} available")
+            }
+        }
+    }
+}
+
+/// Итог применённой транзакции.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionReport {
+    /// Добавлено связей
+    pub added: usize,
+    /// Снятые связи (на момент снятия)
+    pub removed: Vec<Connection>,
+    /// Перевзвешено связей
+    pub updated: usize,
+}
+
+/// Пакет операций над связями одного домена.
+///
+/// ```ignore
+/// let mut tx = ConnectionTransaction::new(106);
+/// tx.add(conn).set_strength((1, 2, 0), 0.8).remove((2, 3, 0));
+/// ashti.apply_transaction(&tx, |_, _| true)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConnectionTransaction {
+    domain_id: u16,
+    ops: Vec<TransactionOp>,
+}
+
+impl ConnectionTransaction {
+    pub fn new(domain_id: u16) -> Self {
+        Self { domain_id, ops: Vec::new() }
+    }
+
+    pub fn add(&mut self, conn: Connection) -> &mut Self {
+        self.ops.push(TransactionOp::Add(conn));
+        self
+    }
+
+    pub fn remove(&mut self, key: ConnectionKey) -> &mut Self {
+        self.ops.push(TransactionOp::Remove(key));
+        self
+    }
+
+    pub fn set_strength(&mut self, key: ConnectionKey, strength: f32) -> &mut Self {
+        self.ops.push(TransactionOp::SetStrength(key, strength));
+        self
+    }
+
+    pub fn domain_id(&self) -> u16 {
+        self.domain_id
+    }
+
+    pub fn ops(&self) -> &[TransactionOp] {
+        &self.ops
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+}
+
+impl AshtiCore {
+    /// Применить транзакцию атомарно.
+    ///
+    /// `review` получает операцию и связь, которую она затрагивает (None для Add);
+    /// false — вето, транзакция откатывается. Снятие связи здесь сразу убирает
+    /// её из буфера, tombstone не оставляется; накопленные tombstone домена
+    /// вычищаются при фиксации.
+    pub fn apply_transaction(
+        &mut self,
+        tx: &ConnectionTransaction,
+        mut review: impl FnMut(&TransactionOp, Option<&Connection>) -> bool,
+    ) -> Result<TransactionReport, TransactionError> {
+        let unknown = TransactionError::UnknownDomain(tx.domain_id);
+        let i = self.index_of(tx.domain_id).ok_or(unknown.clone())?;
+        let state = self.state(i).ok_or(unknown.clone())?;
+        let mut working: Vec<Connection> =
+            state.connections.iter().filter(|c| !c.is_tombstoned()).copied().collect();
+        let position = |working: &[Connection], key: ConnectionKey| {
+            working.iter().position(|c| (c.source_id, c.target_id, c.link_type) == key)
+        };
+
+        let mut report = TransactionReport::default();
+        for (index, op) in tx.ops.iter().enumerate() {
+            match *op {
+                TransactionOp::Add(conn) => {
+                    let key = (conn.source_id, conn.target_id, conn.link_type);
+                    if conn.domain_id != tx.domain_id {
+                        let domain_id = conn.domain_id;
+                        return Err(TransactionError::DomainMismatch { index, domain_id });
+                    }
+                    if position(&working, key).is_some() {
+                        return Err(TransactionError::Duplicate { index, key });
+                    }
+                    if !review(op, None) {
+                        return Err(TransactionError::Vetoed { index });
+                    }
+                    working.push(conn);
+                    report.added += 1;
+                }
+                TransactionOp::Remove(key) => {
+                    let at = position(&working, key)
+                        .ok_or(TransactionError::Missing { index, key })?;
+                    if !review(op, Some(&working[at])) {
+                        return Err(TransactionError::Vetoed { index });
+                    }
+                    report.removed.push(working.remove(at));
+                }
+                TransactionOp::SetStrength(key, strength) => {
+                    if !strength.is_finite() || strength < 0.0 {
+                        return Err(TransactionError::InvalidStrength { index });
+                    }
+                    let at = position(&working, key)
+                        .ok_or(TransactionError::Missing { index, key })?;
+                    if !review(op, Some(&working[at])) {
+                        return Err(TransactionError::Vetoed { index });
+                    }
+                    working[at].strength = strength;
+                    report.updated += 1;
+                }
+            }
+        }
+
+        let capacity = state.connection_capacity();
+        if working.len() > capacity {
+            return Err(TransactionError::Capacity { needed: working.len(), available: capacity });
+        }
+        let state = self.state_mut(i).ok_or(unknown.clone())?;
+        state.connections = working;
+        let count = state.connection_count();
+        self.domain_mut(i).ok_or(unknown)?.active_connections = count;
+        Ok(report)
+    }
+}
@@ -11,7 +11,7 @@ use axiom_config::DomainConfig;
 use axiom_core::{Connection, Event, Token};
 use axiom_frontier::{CausalFrontier, FrontierConfig, FrontierEntity};
 use axiom_heartbeat::{HeartbeatConfig, HeartbeatGenerator};
-use axiom_space::SpatialHashGrid;
+use axiom_space::{KdRefresh, KdTree, SpatialHashGrid};
 
 use crate::physics::{
     EventGenerator, DEFAULT_COLLISION_RADIUS, DEFAULT_DECAY_RATE, DEFAULT_STRESS_THRESHOLD,
@@ -37,6 +37,12 @@ pub struct Domain {
     /// SPACE V6.0: быстрый поиск соседей через хеш-сетку
     pub spatial_grid: SpatialHashGrid,
 
+    /// kd-дерево для точного kNN (None — устарело, см. `kd_refresh`)
+    pub kd_tree: Option<KdTree>,
+
+    /// Когда перестраивать kd_tree
+    pub kd_refresh: KdRefresh,
+
     /// Текущее количество активных токенов
     pub active_tokens: usize,
 
@@ -81,6 +87,8 @@ impl Domain {
             config,
             frontier: CausalFrontier::new(frontier_config),
             spatial_grid: SpatialHashGrid::new(),
+            kd_tree: None,
+            kd_refresh: KdRefresh::default(),
             active_tokens: 0,
             active_connections: 0,
             events_since_rebuild: 0,
@@ -239,6 +247,21 @@ impl Domain {
                     .unwrap_or((0, 0, 0))
             });
         self.events_since_rebuild = 0;
+        self.kd_tree = match self.kd_refresh {
+            KdRefresh::OnDemand => None,
+            KdRefresh::WithGrid => Some(build_kd_tree(tokens, self.active_tokens)),
+        };
+    }
+
+    /// kd-дерево позиций токенов (id — индекс токена, как в spatial grid).
+    /// Дерево строится заново, если сброшено или число токенов изменилось;
+    /// сдвиги токенов оно, как и grid, видит только после перестройки.
+    pub fn kd_tree(&mut self, tokens: &[Token]) -> &KdTree {
+        let active = self.active_tokens.min(tokens.len());
+        if self.kd_tree.as_ref().is_some_and(|tree| tree.len() != active) {
+            self.kd_tree = None;
+        }
+        self.kd_tree.get_or_insert_with(|| build_kd_tree(tokens, active))
     }
 
     /// Нужна ли перестройка spatial grid?
@@ -274,3 +297,14 @@ impl Domain {
         )
     }
 }
+
+fn build_kd_tree(tokens: &[Token], active_tokens: usize) -> KdTree {
+    KdTree::build(
+        tokens
+            .iter()
+            .take(active_tokens)
+            .enumerate()
+            .map(|(i, t)| (i as u32, (t.position[0], t.position[1], t.position[2])))
+            .collect(),
+    )
+}
@@ -4,12 +4,63 @@
 // Domain V1.4: DomainState — предвыделённые буферы токенов и связей
 
 use axiom_config::DomainConfig;
-use axiom_core::{Connection, Token, STATE_LOCKED, STATE_SLEEPING};
+use axiom_core::{
+    Connection, MergeStrategy, Token, FLAG_ACTIVE, FLAG_TOMBSTONE, STATE_LOCKED, STATE_SLEEPING,
+    TOKEN_FLAG_FRAME_ANCHOR, TOKEN_FLAG_GOAL,
+};
+use axiom_space::SpatialHashGrid;
+use std::collections::{HashMap, HashSet};
 
 /// Ошибка превышения ёмкости домена.
 #[derive(Debug, PartialEq)]
 pub struct CapacityExceeded;
 
+/// Критерии «осиротевшего» токена для GC-прохода.
+///
+/// Токен — сирота, если выполнены все три условия: масса не выше `max_mass`,
+/// ни одна связь с ним не сильнее `min_bond_strength`, и он не активировался
+/// последние `idle_events` событий (causal time, не wall-clock).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrphanCriteria {
+    pub max_mass: u8,
+    pub min_bond_strength: f32,
+    pub idle_events: u64,
+}
+
+impl Default for OrphanCriteria {
+    fn default() -> Self {
+        Self { max_mass: 1, min_bond_strength: 0.1, idle_events: 10_000 }
+    }
+}
+
+/// Итог прохода дедупликации (`DomainState::dedup_tokens`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DedupReport {
+    /// Пары (оставленный sutra_id, поглощённый sutra_id)
+    pub merged: Vec<(u32, u32)>,
+    /// Связи, перенаправленные на оставленный токен
+    pub connections_rewritten: usize,
+    /// Связи, ставшие петлями или дубликатами, — удалены
+    pub connections_dropped: usize,
+}
+
+/// Как свести параллельные связи одной пары (source, target) разных link_type
+/// в одно число (`DomainState::pair_strength`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PairAggregate {
+    /// Сильнейшая из связей
+    Max,
+    /// Сумма strength
+    Sum,
+    /// Σ weight(link_type) · strength
+    WeightedSum {
+        /// Вес по link_type
+        weights: HashMap<u16, f32>,
+        /// Вес link_type, которого нет в `weights`
+        default: f32,
+    },
+}
+
 /// Рантаймовое состояние домена: предвыделённые буферы токенов и связей.
 pub struct DomainState {
     pub tokens: Vec<Token>,
@@ -18,6 +69,9 @@ pub struct DomainState {
     pub neighbor_buffer: Vec<u32>,
     token_capacity: usize,
     connection_capacity: usize,
+    /// Связей, помеченных tombstone с прошлой компакции: при нуле
+    /// `compact_connections` не сканирует буфер.
+    pending_tombstones: usize,
 }
 
 impl DomainState {
@@ -30,6 +84,7 @@ impl DomainState {
             neighbor_buffer: Vec::with_capacity(64),
             token_capacity: token_cap,
             connection_capacity: conn_cap,
+            pending_tombstones: 0,
         }
     }
 
@@ -55,12 +110,129 @@ impl DomainState {
         }
         let idx = self.connections.len();
         self.connections.push(conn);
+        self.pending_tombstones += conn.is_tombstoned() as usize;
         Ok(idx)
     }
 
     pub fn token_count(&self) -> usize { self.tokens.len() }
     pub fn token_capacity(&self) -> usize { self.token_capacity }
     pub fn connection_count(&self) -> usize { self.connections.len() }
+    pub fn connection_capacity(&self) -> usize { self.connection_capacity }
+
+    /// Связь source → target данного link_type (связи других типов между той же
+    /// парой — отдельные рёбра со своей strength).
+    pub fn connection(
+        &self,
+        source_id: u32,
+        target_id: u32,
+        link_type: u16,
+    ) -> Option<&Connection> {
+        self.connections_between(source_id, target_id).find(|c| c.link_type == link_type)
+    }
+
+    /// Изменяемая связь source → target данного link_type.
+    pub fn connection_mut(
+        &mut self,
+        source_id: u32,
+        target_id: u32,
+        link_type: u16,
+    ) -> Option<&mut Connection> {
+        self.connections.iter_mut().find(|c| {
+            c.source_id == source_id
+                && c.target_id == target_id
+                && c.link_type == link_type
+                && !c.is_tombstoned()
+        })
+    }
+
+    /// Все связи source → target, любого link_type (удалённые не видны).
+    pub fn connections_between(
+        &self,
+        source_id: u32,
+        target_id: u32,
+    ) -> impl Iterator<Item = &Connection> {
+        self.connections
+            .iter()
+            .filter(move |c| {
+                c.source_id == source_id && c.target_id == target_id && !c.is_tombstoned()
+            })
+    }
+
+    /// Сводная strength параллельных связей source → target. None — связей нет.
+    pub fn pair_strength(
+        &self,
+        source_id: u32,
+        target_id: u32,
+        aggregate: &PairAggregate,
+    ) -> Option<f32> {
+        let mut edges = self.connections_between(source_id, target_id).peekable();
+        edges.peek()?;
+        Some(match aggregate {
+            PairAggregate::Max => edges.map(|c| c.strength).fold(f32::MIN, f32::max),
+            PairAggregate::Sum => edges.map(|c| c.strength).sum(),
+            PairAggregate::WeightedSum { weights, default } => edges
+                .map(|c| weights.get(&c.link_type).unwrap_or(default) * c.strength)
+                .sum(),
+        })
+    }
+
+    /// Пометить связь удалённой: FLAG_TOMBSTONE, FLAG_ACTIVE снят. Буфер не
+    /// сдвигается — место освобождает `compact_connections`. Возвращает связь
+    /// на момент удаления; None — связи нет или она уже удалена.
+    pub fn tombstone_connection(
+        &mut self,
+        source_id: u32,
+        target_id: u32,
+        link_type: u16,
+    ) -> Option<Connection> {
+        let conn = self.connection_mut(source_id, target_id, link_type)?;
+        let removed = *conn;
+        conn.flags = (conn.flags | FLAG_TOMBSTONE) & !FLAG_ACTIVE;
+        self.pending_tombstones += 1;
+        Some(removed)
+    }
+
+    /// Число удалённых, но ещё не убранных связей.
+    pub fn tombstone_count(&self) -> usize {
+        self.connections.iter().filter(|c| c.is_tombstoned()).count()
+    }
+
+    /// Убрать удалённые связи из буфера. Возвращает их число.
+    ///
+    /// Без tombstone с прошлой компакции — O(1): буфер не сканируется. Связи,
+    /// помеченные FLAG_TOMBSTONE в обход `tombstone_connection`/`add_connection`,
+    /// убираются при следующей компакции после любого учтённого удаления.
+    pub fn compact_connections(&mut self) -> usize {
+        if self.pending_tombstones == 0 {
+            return 0;
+        }
+        self.pending_tombstones = 0;
+        let before = self.connections.len();
+        self.connections.retain(|c| !c.is_tombstoned());
+        before - self.connections.len()
+    }
+
+    /// Действующие связи: без удалённых (tombstone), ещё не убранных компакцией.
+    /// Читатели графа обходят связи через этот итератор.
+    pub fn live_connections(&self) -> impl Iterator<Item = &Connection> + '_ {
+        self.connections.iter().filter(|c| !c.is_tombstoned())
+    }
+
+    /// Связи, действительные в момент `at` (COM event_id; см. `Connection::is_valid_at`).
+    pub fn connections_as_of(&self, at: u64) -> impl Iterator<Item = &Connection> + '_ {
+        self.live_connections().filter(move |c| c.is_valid_at(at))
+    }
+
+    /// Снимок индекса связей по link_type (устаревает при изменении connections).
+    pub fn connection_index(&self) -> crate::ConnectionTypeIndex {
+        crate::ConnectionTypeIndex::build(&self.connections)
+    }
+
+    /// Копия связей в раскладке CSR для проходов чтения (устаревает при
+    /// изменении connections; новые связи можно дописывать в её overlay).
+    pub fn freeze(&self) -> crate::FrozenGraph {
+        crate::FrozenGraph::from_connections(&self.connections)
+    }
 
     /// Перевести токен в STATE_SLEEPING и обнулить valence.
     /// Токен остаётся физически — просто становится инертным.
@@ -93,8 +265,132 @@ impl DomainState {
 
     /// True если sutra_id упоминается в любой связи домена.
     pub fn is_connection_referenced(&self, sutra_id: u32) -> bool {
-        self.connections
+        self.live_connections().any(|c| c.source_id == sutra_id || c.target_id == sutra_id)
+    }
+
+    /// Найти осиротевшие токены по критериям `criteria` на момент `current_event_id`.
+    ///
+    /// STATE_LOCKED, FRAME_ANCHOR и GOAL токены никогда не попадают в кандидаты.
+    pub fn find_orphans(&self, criteria: &OrphanCriteria, current_event_id: u64) -> Vec<u32> {
+        // Концы сильных связей — одним проходом по буферу, а не на каждый токен
+        let bonded: HashSet<u32> = self
+            .live_connections()
+            .filter(|c| c.strength >= criteria.min_bond_strength)
+            .flat_map(|c| [c.source_id, c.target_id])
+            .collect();
+        self.tokens
             .iter()
-            .any(|c| c.source_id == sutra_id || c.target_id == sutra_id)
+            .filter(|t| {
+                t.state != STATE_LOCKED
+                    && t.type_flags & (TOKEN_FLAG_FRAME_ANCHOR | TOKEN_FLAG_GOAL) == 0
+                    && t.mass <= criteria.max_mass
+                    && current_event_id.saturating_sub(t.last_event_id) >= criteria.idle_events
+                    && !bonded.contains(&t.sutra_id)
+            })
+            .map(|t| t.sutra_id)
+            .collect()
+    }
+
+    /// Слить почти совпадающие токены: одинаковые type_flags, расстояние ≤ `radius`.
+    ///
+    /// Основным остаётся более ранний токен (меньший индекс), остальные
+    /// поглощаются через `Token::merge`. Связи поглощённых перенаправляются на
+    /// основной; петли удаляются, дубликаты (source, target, link_type) схлопываются
+    /// с сохранением максимальной strength. Удалённые (tombstone) связи при этом
+    /// убираются из буфера и в схлопывании не участвуют. STATE_LOCKED и
+    /// FRAME_ANCHOR не сливаются. Метки, история и происхождение связей живут вне
+    /// DomainState — их переносит вызывающая сторона (`AxiomEngine::dedup_tokens`).
+    /// Spatial grid домена после вызова устарел — его перестраивает вызывающая сторона.
+    pub fn dedup_tokens(&mut self, radius: i16, strategy: MergeStrategy) -> DedupReport {
+        let mut report = DedupReport::default();
+        let n = self.tokens.len();
+        let mergeable =
+            |t: &Token| t.state != STATE_LOCKED && t.type_flags & TOKEN_FLAG_FRAME_ANCHOR == 0;
+
+        let positions: Vec<(i16, i16, i16)> =
+            self.tokens.iter().map(|t| (t.position[0], t.position[1], t.position[2])).collect();
+        let mut grid = SpatialHashGrid::new();
+        grid.rebuild(n, |i| positions[i]);
+
+        let mut absorbed = vec![false; n];
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+        for i in 0..n {
+            if absorbed[i] || !mergeable(&self.tokens[i]) {
+                continue;
+            }
+            let flags = self.tokens[i].type_flags;
+            let (x, y, z) = positions[i];
+            let mut near = grid.find_neighbors(x, y, z, radius, |j| positions[j as usize]);
+            near.sort_unstable();
+            for j in near.into_iter().map(|j| j as usize).filter(|&j| j > i) {
+                let other = self.tokens[j];
+                if absorbed[j] || !mergeable(&other) || other.type_flags != flags {
+                    continue;
+                }
+                self.tokens[i] = self.tokens[i].merge(&other, strategy);
+                absorbed[j] = true;
+                remap.insert(other.sutra_id, self.tokens[i].sutra_id);
+                report.merged.push((self.tokens[i].sutra_id, other.sutra_id));
+            }
+        }
+        if report.merged.is_empty() {
+            return report;
+        }
+
+        let mut idx = 0;
+        self.tokens.retain(|_| {
+            idx += 1;
+            !absorbed[idx - 1]
+        });
+
+        let mut seen: HashMap<(u32, u32, u16), usize> = HashMap::new();
+        let mut kept: Vec<Connection> = Vec::with_capacity(self.connections.len());
+        for mut c in self.connections.drain(..) {
+            if c.is_tombstoned() {
+                continue;
+            }
+            let (src, dst) = (
+                *remap.get(&c.source_id).unwrap_or(&c.source_id),
+                *remap.get(&c.target_id).unwrap_or(&c.target_id),
+            );
+            if (src, dst) != (c.source_id, c.target_id) {
+                report.connections_rewritten += 1;
+            }
+            (c.source_id, c.target_id) = (src, dst);
+            if src == dst {
+                report.connections_dropped += 1;
+                continue;
+            }
+            match seen.get(&(src, dst, c.link_type)) {
+                Some(&k) => {
+                    kept[k].strength = kept[k].strength.max(c.strength);
+                    report.connections_dropped += 1;
+                }
+                None => {
+                    seen.insert((src, dst, c.link_type), kept.len());
+                    kept.push(c);
+                }
+            }
+        }
+        self.connections = kept;
+        self.pending_tombstones = 0;
+        report
+    }
+
+    /// Удалить токены и все связи, которые их касаются, за один проход.
+    ///
+    /// Возвращает `(удалено токенов, удалено связей)`. Spatial grid домена
+    /// после вызова устарел — его перестраивает вызывающая сторона.
+    pub fn remove_tokens(&mut self, sutra_ids: &[u32]) -> (usize, usize) {
+        if sutra_ids.is_empty() {
+            return (0, 0);
+        }
+        let doomed: HashSet<u32> = sutra_ids.iter().copied().collect();
+        let tokens_before = self.tokens.len();
+        self.tokens.retain(|t| !doomed.contains(&t.sutra_id));
+        let conns_before = self.connections.len();
+        self.connections
+            .retain(|c| !doomed.contains(&c.source_id) && !doomed.contains(&c.target_id));
+        (tokens_before - self.tokens.len(), conns_before - self.connections.len())
     }
 }
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// FrozenGraph — связи домена в раскладке CSR для тяжёлых проходов чтения
+//
+// DomainState хранит связи в порядке добавления, и выборка исходящих связей
+// токена — линейный проход по всему буферу. FrozenGraph копирует связи,
+// отсортированные по source_id, и держит смещения: исходящие связи токена —
+// непрерывный срез. Связи, добавленные после заморозки, копятся в overlay и
+// видны в выборках сразу; compact вливает overlay в CSR-часть.
+
+use axiom_core::Connection;
+
+/// Связи в раскладке CSR (compressed sparse row) с overlay для новых связей.
+#[derive(Debug, Clone, Default)]
+pub struct FrozenGraph {
+    /// sutra_id источников по возрастанию
+    sources: Vec<u32>,
+    /// Исходящие связи sources[i] — edges[offsets[i]..offsets[i + 1]]
+    offsets: Vec<usize>,
+    /// Связи, упорядоченные по source_id (внутри источника — порядок добавления)
+    edges: Vec<Connection>,
+    /// Связи, добавленные после заморозки
+    overlay: Vec<Connection>,
+}
+
+impl FrozenGraph {
+    /// Заморозить срез связей; удалённые (tombstone) не копируются.
+    pub fn from_connections(connections: &[Connection]) -> Self {
+        let mut edges: Vec<Connection> =
+            connections.iter().filter(|c| !c.is_tombstoned()).copied().collect();
+        edges.sort_by_key(|c| c.source_id);
+        let mut sources = Vec::new();
+        let mut offsets = Vec::new();
+        for (i, c) in edges.iter().enumerate() {
+            if sources.last() != Some(&c.source_id) {
+                sources.push(c.source_id);
+                offsets.push(i);
+            }
+        }
+        offsets.push(edges.len());
+        Self { sources, offsets, edges, overlay: Vec::new() }
+    }
+
+    /// Исходящие связи токена: CSR-срез, затем связи из overlay.
+    pub fn outgoing(&self, source_id: u32) -> impl Iterator<Item = &Connection> + '_ {
+        self.frozen_outgoing(source_id)
+            .iter()
+            .chain(self.overlay.iter().filter(move |c| c.source_id == source_id))
+    }
+
+    /// Число исходящих связей токена.
+    pub fn out_degree(&self, source_id: u32) -> usize {
+        self.outgoing(source_id).count()
+    }
+
+    /// Все связи: CSR-часть по source_id, затем overlay.
+    pub fn iter(&self) -> impl Iterator<Item = &Connection> + '_ {
+        self.edges.iter().chain(self.overlay.iter())
+    }
+
+    /// Добавить связь после заморозки. Удалённая (tombstone) не добавляется.
+    pub fn insert(&mut self, conn: Connection) {
+        if !conn.is_tombstoned() {
+            self.overlay.push(conn);
+        }
+    }
+
+    /// Влить overlay в CSR-часть.
+    pub fn compact(&mut self) {
+        if self.overlay.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.edges);
+        all.append(&mut self.overlay);
+        *self = Self::from_connections(&all);
+    }
+
+    /// Всего связей (включая overlay).
+    pub fn edge_count(&self) -> usize {
+        self.edges.len() + self.overlay.len()
+    }
+
+    /// Связей в overlay.
+    pub fn overlay_len(&self) -> usize {
+        self.overlay.len()
+    }
+
+    /// Число токенов с исходящими связями в CSR-части.
+    pub fn source_count(&self) -> usize {
+        self.sources.len()
+    }
+
+    fn frozen_outgoing(&self, source_id: u32) -> &[Connection] {
+        match self.sources.binary_search(&source_id) {
+            Ok(i) => &self.edges[self.offsets[i]..self.offsets[i + 1]],
+            Err(_) => &[],
+        }
+    }
+}
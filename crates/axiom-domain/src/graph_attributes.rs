@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// GraphAttributes — типизированные атрибуты токенов и связей вне 64-байтовых
+// структур.
+//
+// Приложению нужно пометить концепт данными своей предметной области (id
+// документа, оценка, источник), не занимая координаты токена. Хранение
+// колоночное: ключ атрибута → (токен или связь → значение), так что выборка
+// «все узлы с score > 0.7» проходит по одной колонке. Токен адресуется
+// sutra_id (как в TokenLabels), связь — EdgeId с доменом.
+
+use axiom_core::Connection;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Адрес связи: (domain_id, source_id, target_id, link_type).
+pub type EdgeId = (u16, u32, u32, u16);
+
+/// EdgeId связи.
+pub fn edge_id(c: &Connection) -> EdgeId {
+    (c.domain_id, c.source_id, c.target_id, c.link_type)
+}
+
+/// Значение атрибута.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttrValue {
+    /// Флаг
+    Bool(bool),
+    /// Целое
+    Int(i64),
+    /// Вещественное
+    Float(f64),
+    /// Строка
+    Text(String),
+}
+
+impl AttrValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            AttrValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            AttrValue::Int(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Числовое значение (Int приводится к f64).
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            AttrValue::Int(v) => Some(*v as f64),
+            AttrValue::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AttrValue::Text(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for AttrValue {
+    fn from(v: bool) -> Self {
+        AttrValue::Bool(v)
+    }
+}
+
+impl From<i64> for AttrValue {
+    fn from(v: i64) -> Self {
+        AttrValue::Int(v)
+    }
+}
+
+impl From<f64> for AttrValue {
+    fn from(v: f64) -> Self {
+        AttrValue::Float(v)
+    }
+}
+
+impl From<&str> for AttrValue {
+    fn from(v: &str) -> Self {
+        AttrValue::Text(v.to_string())
+    }
+}
+
+impl From<String> for AttrValue {
+    fn from(v: String) -> Self {
+        AttrValue::Text(v)
+    }
+}
+
+/// Колонки атрибутов токенов и связей.
+#[derive(Debug, Clone, Default)]
+pub struct GraphAttributes {
+    nodes: Columns<u32>,
+    edges: Columns<EdgeId>,
+}
+
+impl GraphAttributes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Задать атрибут токена. Возвращает прежнее значение.
+    pub fn set_node_attr(
+        &mut self,
+        sutra_id: u32,
+        key: &str,
+        value: impl Into<AttrValue>,
+    ) -> Option<AttrValue> {
+        self.nodes.set(sutra_id, key, value.into())
+    }
+
+    /// Атрибут токена.
+    pub fn node_attr(&self, sutra_id: u32, key: &str) -> Option<&AttrValue> {
+        self.nodes.get(sutra_id, key)
+    }
+
+    /// Все атрибуты токена, по ключу.
+    pub fn node_attrs(&self, sutra_id: u32) -> Vec<(&str, &AttrValue)> {
+        self.nodes.all_of(sutra_id)
+    }
+
+    /// Снять атрибут токена.
+    pub fn remove_node_attr(&mut self, sutra_id: u32, key: &str) -> Option<AttrValue> {
+        self.nodes.remove(sutra_id, key)
+    }
+
+    /// Снять все атрибуты токена. Возвращает число снятых.
+    pub fn remove_node(&mut self, sutra_id: u32) -> usize {
+        self.nodes.remove_all(sutra_id)
+    }
+
+    /// Перенести атрибуты токена `from` на `into` (слияние токенов): значения
+    /// `into` сохраняются, недостающие ключи берутся у `from`. Связи домена с
+    /// концом `from` переадресуются на `into`; ставшие петлями снимаются.
+    /// Возвращает число перенесённых атрибутов токена.
+    pub fn merge_node(&mut self, domain_id: u16, into: u32, from: u32) -> usize {
+        if into == from {
+            return 0;
+        }
+        let moved = self.nodes.rekey(|id| (id == from).then_some(Some(into)));
+        let rename = |id: u32| if id == from { into } else { id };
+        self.edges.rekey(|(d, s, t, link_type)| {
+            if d != domain_id || (s != from && t != from) {
+                return None;
+            }
+            let (s, t) = (rename(s), rename(t));
+            Some((s != t).then_some((d, s, t, link_type)))
+        });
+        moved
+    }
+
+    /// Токены, у которых атрибут `key` удовлетворяет `pred`, по возрастанию sutra_id.
+    pub fn nodes_where(&self, key: &str, pred: impl Fn(&AttrValue) -> bool) -> Vec<u32> {
+        self.nodes.filter(key, pred)
+    }
+
+    /// Задать атрибут связи. Возвращает прежнее значение.
+    pub fn set_edge_attr(
+        &mut self,
+        edge: EdgeId,
+        key: &str,
+        value: impl Into<AttrValue>,
+    ) -> Option<AttrValue> {
+        self.edges.set(edge, key, value.into())
+    }
+
+    /// Атрибут связи.
+    pub fn edge_attr(&self, edge: EdgeId, key: &str) -> Option<&AttrValue> {
+        self.edges.get(edge, key)
+    }
+
+    /// Все атрибуты связи, по ключу.
+    pub fn edge_attrs(&self, edge: EdgeId) -> Vec<(&str, &AttrValue)> {
+        self.edges.all_of(edge)
+    }
+
+    /// Снять атрибут связи.
+    pub fn remove_edge_attr(&mut self, edge: EdgeId, key: &str) -> Option<AttrValue> {
+        self.edges.remove(edge, key)
+    }
+
+    /// Снять все атрибуты связи. Возвращает число снятых.
+    pub fn remove_edge(&mut self, edge: EdgeId) -> usize {
+        self.edges.remove_all(edge)
+    }
+
+    /// Связи, у которых атрибут `key` удовлетворяет `pred`, по возрастанию EdgeId.
+    pub fn edges_where(&self, key: &str, pred: impl Fn(&AttrValue) -> bool) -> Vec<EdgeId> {
+        self.edges.filter(key, pred)
+    }
+
+    /// True если не задано ни одного атрибута.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.0.is_empty() && self.edges.0.is_empty()
+    }
+}
+
+/// Ключ атрибута → (объект → значение).
+#[derive(Debug, Clone)]
+struct Columns<K>(HashMap<String, HashMap<K, AttrValue>>);
+
+impl<K> Default for Columns<K> {
+    fn default() -> Self {
+        Columns(HashMap::new())
+    }
+}
+
+impl<K: Copy + Eq + Ord + std::hash::Hash> Columns<K> {
+    fn set(&mut self, id: K, key: &str, value: AttrValue) -> Option<AttrValue> {
+        self.0.entry(key.to_string()).or_default().insert(id, value)
+    }
+
+    fn get(&self, id: K, key: &str) -> Option<&AttrValue> {
+        self.0.get(key)?.get(&id)
+    }
+
+    fn all_of(&self, id: K) -> Vec<(&str, &AttrValue)> {
+        let mut attrs: Vec<(&str, &AttrValue)> = self
+            .0
+            .iter()
+            .filter_map(|(key, column)| column.get(&id).map(|v| (key.as_str(), v)))
+            .collect();
+        attrs.sort_by_key(|&(key, _)| key);
+        attrs
+    }
+
+    fn remove(&mut self, id: K, key: &str) -> Option<AttrValue> {
+        let column = self.0.get_mut(key)?;
+        let value = column.remove(&id);
+        if column.is_empty() {
+            self.0.remove(key);
+        }
+        value
+    }
+
+    fn remove_all(&mut self, id: K) -> usize {
+        let mut removed = 0;
+        self.0.retain(|_, column| {
+            removed += usize::from(column.remove(&id).is_some());
+            !column.is_empty()
+        });
+        removed
+    }
+
+    /// Переадресовать значения: `f(id)` — None (не трогать), Some(None) (снять)
+    /// или Some(Some(new)) (перенести, если у `new` ключа ещё нет).
+    /// Возвращает число перенесённых значений.
+    fn rekey(&mut self, f: impl Fn(K) -> Option<Option<K>>) -> usize {
+        let mut moved = 0;
+        self.0.retain(|_, column| {
+            let affected: Vec<(K, Option<K>)> =
+                column.keys().filter_map(|&id| f(id).map(|to| (id, to))).collect();
+            for (id, to) in affected {
+                let value = column.remove(&id).expect("key collected above");
+                if let Some(Entry::Vacant(slot)) =
+                    to.map(|to| column.entry(to))
+                {
+                    slot.insert(value);
+                    moved += 1;
+                }
+            }
+            !column.is_empty()
+        });
+        moved
+    }
+
+    fn filter(&self, key: &str, pred: impl Fn(&AttrValue) -> bool) -> Vec<K> {
+        let mut ids: Vec<K> = self
+            .0
+            .get(key)
+            .map(|column| column.iter().filter(|(_, v)| pred(v)).map(|(&id, _)| id).collect())
+            .unwrap_or_default();
+        ids.sort_unstable();
+        ids
+    }
+}
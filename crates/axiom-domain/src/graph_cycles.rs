@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Циклы и сильно связные компоненты графа связей домена
+//
+// Обучение может замкнуть связи одного типа в кольцо (A → B → C → A), и для
+// причинных по смыслу связей такое кольцо — противоречие. Компоненты ищутся
+// алгоритмом Тарьяна (итеративно, без рекурсии), элементарные циклы —
+// ограниченным по длине обходом от наименьшего sutra_id цикла, так что каждый
+// цикл находится ровно один раз. Удалённые (tombstone) связи не учитываются;
+// отбор по link_type — на стороне вызывающего.
+
+use axiom_core::Connection;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Сильно связные компоненты. Каждая упорядочена по sutra_id, список — по
+/// наименьшему члену. Одиночный токен без петли — тоже компонента.
+pub fn strongly_connected_components(connections: &[Connection]) -> Vec<Vec<u32>> {
+    let (nodes, adjacent) = adjacency(connections);
+    let mut tarjan = Tarjan::default();
+    for &root in &nodes {
+        if !tarjan.index.contains_key(&root) {
+            tarjan.run(root, &adjacent);
+        }
+    }
+    let mut components = tarjan.components;
+    components.sort_unstable_by_key(|c| c[0]);
+    components
+}
+
+/// Состояние алгоритма Тарьяна.
+#[derive(Default)]
+struct Tarjan {
+    index: HashMap<u32, usize>,
+    low: HashMap<u32, usize>,
+    stack: Vec<u32>,
+    on_stack: HashSet<u32>,
+    /// Стек обхода: (узел, номер следующего соседа)
+    work: Vec<(u32, usize)>,
+    components: Vec<Vec<u32>>,
+}
+
+impl Tarjan {
+    fn visit(&mut self, v: u32) {
+        let n = self.index.len();
+        self.index.insert(v, n);
+        self.low.insert(v, n);
+        self.stack.push(v);
+        self.on_stack.insert(v);
+        self.work.push((v, 0));
+    }
+
+    fn lower(&mut self, v: u32, to: usize) {
+        if let Some(l) = self.low.get_mut(&v) {
+            *l = (*l).min(to);
+        }
+    }
+
+    fn run(&mut self, root: u32, adjacent: &HashMap<u32, Vec<u32>>) {
+        self.visit(root);
+        while let Some(&(v, i)) = self.work.last() {
+            let successors = adjacent.get(&v).map_or(&[][..], Vec::as_slice);
+            if let Some(&w) = successors.get(i) {
+                if let Some(frame) = self.work.last_mut() {
+                    frame.1 += 1;
+                }
+                match self.index.get(&w) {
+                    None => self.visit(w),
+                    Some(&iw) if self.on_stack.contains(&w) => self.lower(v, iw),
+                    Some(_) => {}
+                }
+                continue;
+            }
+            self.work.pop();
+            let lv = self.low[&v];
+            if let Some(&(parent, _)) = self.work.last() {
+                self.lower(parent, lv);
+            }
+            if lv == self.index[&v] {
+                let mut component = Vec::new();
+                while let Some(w) = self.stack.pop() {
+                    self.on_stack.remove(&w);
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                self.components.push(component);
+            }
+        }
+    }
+}
+
+/// Элементарные циклы длиной не больше `max_len` связей, включая петли.
+/// Цикл начинается с наименьшего sutra_id; список упорядочен.
+pub fn find_cycles(connections: &[Connection], max_len: usize) -> Vec<Vec<u32>> {
+    let (nodes, adjacent) = adjacency(connections);
+    let mut cycles = Vec::new();
+    if max_len == 0 {
+        return cycles;
+    }
+    for &start in &nodes {
+        let mut path = vec![start];
+        extend_cycles(&adjacent, start, &mut path, max_len, &mut cycles);
+    }
+    cycles.sort_unstable();
+    cycles
+}
+
+fn extend_cycles(
+    adjacent: &HashMap<u32, Vec<u32>>,
+    start: u32,
+    path: &mut Vec<u32>,
+    max_len: usize,
+    cycles: &mut Vec<Vec<u32>>,
+) {
+    let v = *path.last().expect("path starts with start");
+    for &w in adjacent.get(&v).map_or(&[][..], Vec::as_slice) {
+        if w == start {
+            cycles.push(path.clone());
+        } else if w > start && path.len() < max_len && !path.contains(&w) {
+            path.push(w);
+            extend_cycles(adjacent, start, path, max_len, cycles);
+            path.pop();
+        }
+    }
+}
+
+/// Узлы по возрастанию и исходящие соседи (без повторов, по возрастанию).
+fn adjacency(connections: &[Connection]) -> (BTreeSet<u32>, HashMap<u32, Vec<u32>>) {
+    let mut nodes = BTreeSet::new();
+    let mut adjacent: HashMap<u32, Vec<u32>> = HashMap::new();
+    for c in connections.iter().filter(|c| !c.is_tombstoned()) {
+        nodes.insert(c.source_id);
+        nodes.insert(c.target_id);
+        adjacent.entry(c.source_id).or_default().push(c.target_id);
+    }
+    for targets in adjacent.values_mut() {
+        targets.sort_unstable();
+        targets.dedup();
+    }
+    (nodes, adjacent)
+}
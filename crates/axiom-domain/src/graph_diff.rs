@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// GraphDiff — что изменилось в графе домена между двумя снимками
+//
+// Снимок — Subgraph (`Subgraph::whole` для всего домена). Токены сравниваются
+// по sutra_id, связи — по (source_id, target_id, link_type). Для изменённых
+// токенов перечисляются различающиеся поля, для связей — strength и flags до
+// и после. Diff сериализуем (serde) для просмотра вне runtime.
+
+use axiom_core::{Connection, Token};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::Subgraph;
+
+/// Ключ связи: (source_id, target_id, link_type).
+pub type ConnectionKey = (u32, u32, u16);
+
+/// Изменённый токен.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TokenChange {
+    /// Токен
+    pub sutra_id: u32,
+    /// Имена различающихся полей
+    pub fields: Vec<&'static str>,
+}
+
+/// Изменённая связь.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConnectionChange {
+    /// (source_id, target_id, link_type)
+    pub key: ConnectionKey,
+    /// strength до
+    pub strength_before: f32,
+    /// strength после
+    pub strength_after: f32,
+    /// flags до
+    pub flags_before: u32,
+    /// flags после
+    pub flags_after: u32,
+}
+
+impl ConnectionChange {
+    /// Изменение strength (после − до).
+    pub fn strength_delta(&self) -> f32 {
+        self.strength_after - self.strength_before
+    }
+}
+
+/// Разница двух снимков. Все списки упорядочены по ключу.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GraphDiff {
+    /// Токены, появившиеся во втором снимке
+    pub tokens_added: Vec<u32>,
+    /// Токены, исчезнувшие из второго снимка
+    pub tokens_removed: Vec<u32>,
+    /// Токены с изменёнными полями
+    pub tokens_changed: Vec<TokenChange>,
+    /// Связи, появившиеся во втором снимке
+    pub connections_added: Vec<ConnectionKey>,
+    /// Связи, исчезнувшие из второго снимка
+    pub connections_removed: Vec<ConnectionKey>,
+    /// Связи с изменённой strength или flags
+    pub connections_changed: Vec<ConnectionChange>,
+}
+
+impl GraphDiff {
+    /// Разница `before` → `after`.
+    pub fn between(before: &Subgraph, after: &Subgraph) -> GraphDiff {
+        let mut diff = GraphDiff::default();
+
+        let old: BTreeMap<u32, &Token> = before.tokens.iter().map(|t| (t.sutra_id, t)).collect();
+        let new: BTreeMap<u32, &Token> = after.tokens.iter().map(|t| (t.sutra_id, t)).collect();
+        for (&id, a) in &old {
+            match new.get(&id) {
+                None => diff.tokens_removed.push(id),
+                Some(b) => {
+                    let fields = changed_fields(a, b);
+                    if !fields.is_empty() {
+                        diff.tokens_changed.push(TokenChange { sutra_id: id, fields });
+                    }
+                }
+            }
+        }
+        diff.tokens_added = new.keys().filter(|id| !old.contains_key(id)).copied().collect();
+
+        let old: BTreeMap<ConnectionKey, &Connection> =
+            live(&before.connections).map(|c| (key(c), c)).collect();
+        let new: BTreeMap<ConnectionKey, &Connection> =
+            live(&after.connections).map(|c| (key(c), c)).collect();
+        for (&k, a) in &old {
+            match new.get(&k) {
+                None => diff.connections_removed.push(k),
+                Some(b) if a.strength != b.strength || a.flags != b.flags => {
+                    diff.connections_changed.push(ConnectionChange {
+                        key: k,
+                        strength_before: a.strength,
+                        strength_after: b.strength,
+                        flags_before: a.flags,
+                        flags_after: b.flags,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        diff.connections_added = new.keys().filter(|k| !old.contains_key(k)).copied().collect();
+        diff
+    }
+
+    /// True если снимки совпадают.
+    pub fn is_empty(&self) -> bool {
+        self.tokens_added.is_empty()
+            && self.tokens_removed.is_empty()
+            && self.tokens_changed.is_empty()
+            && self.connections_added.is_empty()
+            && self.connections_removed.is_empty()
+            && self.connections_changed.is_empty()
+    }
+}
+
+/// Удалённая (tombstone) связь в снимке считается отсутствующей.
+fn live(connections: &[Connection]) -> impl Iterator<Item = &Connection> {
+    connections.iter().filter(|c| !c.is_tombstoned())
+}
+
+fn key(c: &Connection) -> ConnectionKey {
+    (c.source_id, c.target_id, c.link_type)
+}
+
+fn changed_fields(a: &Token, b: &Token) -> Vec<&'static str> {
+    let checks: [(&'static str, bool); 9] = [
+        ("position", a.position != b.position),
+        ("velocity", a.velocity != b.velocity),
+        ("mass", a.mass != b.mass),
+        ("temperature", a.temperature != b.temperature),
+        ("valence", a.valence != b.valence),
+        ("state", a.state != b.state),
+        ("type_flags", a.type_flags != b.type_flags),
+        ("resonance", a.resonance != b.resonance),
+        ("last_event_id", a.last_event_id != b.last_event_id),
+    ];
+    checks.into_iter().filter(|&(_, differs)| differs).map(|(name, _)| name).collect()
+}
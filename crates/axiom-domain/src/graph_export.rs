@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Экспорт фрагмента графа в DOT (Graphviz) и GraphML (Gephi, yEd)
+//
+// Узлы — токены фрагмента и концы его связей; подпись узла — основная метка
+// из TokenLabels (если есть), иначе sutra_id. У рёбер атрибуты link_type
+// (hex) и strength. ExportFilter отсекает слабые и ингибированные связи;
+// узлы без оставшихся связей при этом не выбрасываются.
+
+use axiom_core::Connection;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+use crate::{Subgraph, TokenLabels};
+
+/// Какие связи попадают в экспорт.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ExportFilter {
+    /// Связи слабее порога пропускаются
+    pub min_strength: f32,
+    /// Пропускать ингибированные связи
+    pub skip_inhibited: bool,
+}
+
+impl ExportFilter {
+    /// Попадает ли связь в экспорт. Удалённые (tombstone) не попадают никогда.
+    pub fn accepts(&self, c: &Connection) -> bool {
+        !c.is_tombstoned()
+            && c.strength >= self.min_strength
+            && !(self.skip_inhibited && c.is_inhibited())
+    }
+}
+
+impl Subgraph {
+    /// Записать фрагмент как ориентированный граф DOT.
+    pub fn to_dot(
+        &self,
+        labels: Option<&TokenLabels>,
+        filter: &ExportFilter,
+        w: &mut impl Write,
+    ) -> io::Result<()> {
+        writeln!(w, "digraph axiom {{")?;
+        for id in self.node_ids() {
+            writeln!(w, "  {id} [label=\"{}\"];", escape_dot(&node_label(labels, id)))?;
+        }
+        for c in self.connections.iter().filter(|c| filter.accepts(c)) {
+            writeln!(
+                w,
+                "  {} -> {} [link_type=\"0x{:04X}\", weight={}];",
+                c.source_id, c.target_id, c.link_type, c.strength
+            )?;
+        }
+        writeln!(w, "}}")
+    }
+
+    /// Записать фрагмент как GraphML с ключами label, link_type и strength.
+    pub fn to_graphml(
+        &self,
+        labels: Option<&TokenLabels>,
+        filter: &ExportFilter,
+        w: &mut impl Write,
+    ) -> io::Result<()> {
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(w, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+        writeln!(w, r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#)?;
+        writeln!(
+            w,
+            r#"  <key id="link_type" for="edge" attr.name="link_type" attr.type="int"/>"#
+        )?;
+        writeln!(
+            w,
+            r#"  <key id="strength" for="edge" attr.name="strength" attr.type="float"/>"#
+        )?;
+        writeln!(w, r#"  <graph id="axiom" edgedefault="directed">"#)?;
+        for id in self.node_ids() {
+            writeln!(
+                w,
+                r#"    <node id="n{id}"><data key="label">{}</data></node>"#,
+                escape_xml(&node_label(labels, id))
+            )?;
+        }
+        for c in self.connections.iter().filter(|c| filter.accepts(c)) {
+            write!(w, r#"    <edge source="n{}" target="n{}">"#, c.source_id, c.target_id)?;
+            writeln!(
+                w,
+                r#"<data key="link_type">{}</data><data key="strength">{}</data></edge>"#,
+                c.link_type, c.strength
+            )?;
+        }
+        writeln!(w, "  </graph>")?;
+        writeln!(w, "</graphml>")
+    }
+
+    /// sutra_id токенов и концов связей по возрастанию.
+    fn node_ids(&self) -> BTreeSet<u32> {
+        self.tokens
+            .iter()
+            .map(|t| t.sutra_id)
+            .chain(self.connections.iter().flat_map(|c| [c.source_id, c.target_id]))
+            .collect()
+    }
+}
+
+fn node_label(labels: Option<&TokenLabels>, id: u32) -> String {
+    labels.and_then(|l| l.primary(id)).map_or_else(|| id.to_string(), str::to_string)
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
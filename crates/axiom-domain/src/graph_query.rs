@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// GraphQuery — декларативный запрос к графу связей домена
+//
+// «Токены с флагом X, связанные связями типа T не дальше 2 шагов от N,
+// strength > 0.7» записывается одной строкой:
+//   near=N hops=2 link_type=0x0801 min_strength=0.7 type_flags=0x10
+// и исполняется без ручного обхода. План: если задан link_type или category,
+// рёбра берутся из ConnectionTypeIndex, иначе — все связи; по отобранным
+// рёбрам идёт BFS от near в пределах hops; найденные токены (кроме самих
+// near) фильтруются по type_flags. Результат — sutra_id по возрастанию.
+// as_of=T ограничивает обход связями, действительными в момент T.
+
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+
+use crate::DomainState;
+
+/// Направление обхода связей.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QueryDirection {
+    /// По направлению связи (source → target)
+    Outgoing,
+    /// Против направления (target → source)
+    Incoming,
+    /// В обе стороны
+    #[default]
+    Any,
+}
+
+/// Ошибка разбора строки запроса.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphQueryError {
+    /// Неизвестный ключ
+    UnknownKey(String),
+    /// Значение не разобрано
+    InvalidValue { key: String, value: String },
+    /// Элемент без `=`
+    Malformed(String),
+    /// Не задан near
+    MissingNear,
+}
+
+impl std::fmt::Display for GraphQueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphQueryError::UnknownKey(key) => write!(f, "unknown query key '{key}'"),
+            GraphQueryError::InvalidValue { key, value } => {
+                write!(f, "invalid value '{value}' for '{key}'")
+            }
+            GraphQueryError::Malformed(item) => write!(f, "expected key=value, got '{item}'"),
+            GraphQueryError::MissingNear => write!(f, "query needs near=<sutra_id>"),
+        }
+    }
+}
+
+/// Запрос: окрестность токенов `near` по отобранным связям.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphQuery {
+    /// Стартовые токены
+    pub near: Vec<u32>,
+    /// Радиус в связях
+    pub hops: usize,
+    /// Только связи этого link_type
+    pub link_type: Option<u16>,
+    /// Только связи этой категории (старший байт link_type)
+    pub category: Option<u8>,
+    /// Только связи не слабее порога
+    pub min_strength: f32,
+    /// Только токены, у которых взведён хотя бы один из этих type_flags
+    pub type_flags: Option<u16>,
+    /// Направление обхода
+    pub direction: QueryDirection,
+    /// Только связи, действительные в этот момент (COM event_id)
+    pub as_of: Option<u64>,
+}
+
+impl GraphQuery {
+    /// Запрос окрестности радиусом 1 без фильтров.
+    pub fn near(ids: &[u32]) -> Self {
+        Self {
+            near: ids.to_vec(),
+            hops: 1,
+            link_type: None,
+            category: None,
+            min_strength: 0.0,
+            type_flags: None,
+            direction: QueryDirection::default(),
+            as_of: None,
+        }
+    }
+
+    pub fn with_hops(mut self, hops: usize) -> Self {
+        self.hops = hops;
+        self
+    }
+
+    pub fn with_link_type(mut self, link_type: u16) -> Self {
+        self.link_type = Some(link_type);
+        self
+    }
+
+    pub fn with_category(mut self, category: u8) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn with_min_strength(mut self, min_strength: f32) -> Self {
+        self.min_strength = min_strength;
+        self
+    }
+
+    pub fn with_type_flags(mut self, type_flags: u16) -> Self {
+        self.type_flags = Some(type_flags);
+        self
+    }
+
+    pub fn with_direction(mut self, direction: QueryDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn with_as_of(mut self, at: u64) -> Self {
+        self.as_of = Some(at);
+        self
+    }
+
+    /// Исполнить запрос над состоянием домена.
+    pub fn run(&self, state: &DomainState) -> Vec<u32> {
+        let candidates: Vec<usize> = match (self.link_type, self.category) {
+            (None, None) => (0..state.connections.len()).collect(),
+            _ => {
+                let index = state.connection_index();
+                match self.link_type {
+                    Some(t) => index.of_type(t).to_vec(),
+                    None => index.of_category(self.category.unwrap_or_default()),
+                }
+            }
+        };
+
+        let mut adjacent: HashMap<u32, Vec<u32>> = HashMap::new();
+        for i in candidates {
+            let c = &state.connections[i];
+            if c.is_tombstoned()
+                || c.strength < self.min_strength
+                || self.category.is_some_and(|cat| (c.link_type >> 8) as u8 != cat)
+                || self.as_of.is_some_and(|at| !c.is_valid_at(at))
+            {
+                continue;
+            }
+            if self.direction != QueryDirection::Incoming {
+                adjacent.entry(c.source_id).or_default().push(c.target_id);
+            }
+            if self.direction != QueryDirection::Outgoing {
+                adjacent.entry(c.target_id).or_default().push(c.source_id);
+            }
+        }
+
+        let mut reached: BTreeSet<u32> = self.near.iter().copied().collect();
+        let mut frontier = self.near.clone();
+        for _ in 0..self.hops {
+            let mut next = Vec::new();
+            for id in frontier {
+                for &n in adjacent.get(&id).map_or(&[][..], Vec::as_slice) {
+                    if reached.insert(n) {
+                        next.push(n);
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        let flags: HashMap<u32, u16> =
+            state.tokens.iter().map(|t| (t.sutra_id, t.type_flags)).collect();
+        reached
+            .into_iter()
+            .filter(|id| !self.near.contains(id))
+            .filter(|id| match self.type_flags {
+                None => true,
+                Some(mask) => flags.get(id).is_some_and(|f| f & mask != 0),
+            })
+            .collect()
+    }
+}
+
+impl FromStr for GraphQuery {
+    type Err = GraphQueryError;
+
+    /// `key=value` через пробел. Ключи: near (id через запятую), hops,
+    /// link_type, category, min_strength, type_flags, direction (out|in|any), as_of.
+    /// Числа — десятичные или `0x`-шестнадцатеричные.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut query = GraphQuery::near(&[]);
+        for item in s.split_whitespace() {
+            let (key, value) =
+                item.split_once('=').ok_or_else(|| GraphQueryError::Malformed(item.into()))?;
+            let invalid =
+                || GraphQueryError::InvalidValue { key: key.into(), value: value.into() };
+            match key {
+                "near" => {
+                    query.near =
+                        value.split(',').map(parse_as).collect::<Option<_>>().ok_or_else(invalid)?;
+                }
+                "hops" => query.hops = value.parse().map_err(|_| invalid())?,
+                "link_type" => query.link_type = Some(parse_as(value).ok_or_else(invalid)?),
+                "category" => query.category = Some(parse_as(value).ok_or_else(invalid)?),
+                "min_strength" => query.min_strength = value.parse().map_err(|_| invalid())?,
+                "type_flags" => query.type_flags = Some(parse_as(value).ok_or_else(invalid)?),
+                "as_of" => query.as_of = Some(parse_as(value).ok_or_else(invalid)?),
+                "direction" => {
+                    query.direction = match value {
+                        "out" => QueryDirection::Outgoing,
+                        "in" => QueryDirection::Incoming,
+                        "any" => QueryDirection::Any,
+                        _ => return Err(invalid()),
+                    };
+                }
+                _ => return Err(GraphQueryError::UnknownKey(key.into())),
+            }
+        }
+        if query.near.is_empty() {
+            return Err(GraphQueryError::MissingNear);
+        }
+        Ok(query)
+    }
+}
+
+fn parse_as<T: TryFrom<u64>>(s: &str) -> Option<T> {
+    let n = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+        None => s.parse().ok()?,
+    };
+    T::try_from(n).ok()
+}
@@ -7,19 +7,74 @@
 
 pub mod ashti_core;
 pub mod causal_horizon;
+pub mod centrality;
+pub mod community;
+pub mod connection_decay;
+pub mod connection_index;
+pub mod connection_learning;
+pub mod connection_pair;
+pub mod connection_pruner;
+pub mod connection_transaction;
 pub mod domain;
 pub mod domain_state;
 pub mod fractal_chain;
+pub mod frozen_graph;
+pub mod graph_attributes;
+pub mod graph_cycles;
+pub mod graph_diff;
+pub mod graph_export;
+pub mod graph_query;
 pub mod membrane;
+pub mod neighborhood;
+pub mod path_search;
 pub mod physics;
+pub mod strength_norm;
+pub mod subgraph;
+pub mod token_batch;
+pub mod token_history;
+pub mod token_labels;
 
-pub use ashti_core::AshtiCore;
+pub use ashti_core::{
+    AshtiCore, ConnectionExpiryReport, ConnectionPruneReport, OrphanGcReport, Removal,
+};
 pub use causal_horizon::CausalHorizon;
+pub use centrality::{
+    betweenness_centrality, centrality, degree_centrality, pagerank, Degree, NodeCentrality,
+    PageRank, PageRankConfig,
+};
+pub use community::{label_propagation, Communities};
+pub use connection_decay::{ConnectionDecay, ConnectionDecayReport};
+pub use connection_index::ConnectionTypeIndex;
+pub use connection_learning::{
+    ConnectionLearningReport, ConnectionLearningRule, MAX_LEARNED_STRENGTH,
+};
+pub use connection_pair::SymmetryPolicy;
+pub use connection_pruner::{ConnectionPruner, PruneReason};
+pub use connection_transaction::{
+    ConnectionTransaction, TransactionError, TransactionOp, TransactionReport,
+};
 pub use domain::Domain;
-pub use domain_state::{CapacityExceeded, DomainState};
+pub use domain_state::{
+    CapacityExceeded, DedupReport, DomainState, OrphanCriteria, PairAggregate,
+};
 pub use fractal_chain::FractalChain;
+pub use frozen_graph::FrozenGraph;
+pub use graph_attributes::{edge_id, AttrValue, EdgeId, GraphAttributes};
+pub use graph_cycles::{find_cycles, strongly_connected_components};
+pub use graph_diff::{ConnectionChange, ConnectionKey, GraphDiff, TokenChange};
+pub use graph_export::ExportFilter;
+pub use graph_query::{GraphQuery, GraphQueryError, QueryDirection};
 pub use membrane::{can_enter_domain, can_exit_domain};
+pub use neighborhood::{Neighborhood, NeighborhoodConfig};
+pub use path_search::{
+    a_star, position_heuristic, shortest_path, strength_cost, ConnectionPath,
+};
 pub use physics::EventGenerator;
+pub use strength_norm::{NormalizationMode, StrengthNormalization};
+pub use subgraph::{EdgeConflict, NodeMapping, Subgraph, SubgraphMergeReport};
+pub use token_batch::{TokenBatchBuilder, TokenBatchError};
+pub use token_history::TokenHistory;
+pub use token_labels::TokenLabels;
 
 // Re-export из axiom-config для удобства пользователей axiom-domain
 pub use axiom_config::{DomainConfig, DomainType, StructuralRole};
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Neighborhood — ленивый обход окрестности токена в пределах k связей
+//
+// Обход в ширину раскрывает очередной токен только когда потребителю нужен
+// следующий сосед, так что `take(n)` не трогает остальной граф. Бюджеты
+// ограничивают число выданных токенов и пройденных связей: у токена-хаба
+// тысячи связей, и без бюджета одна окрестность обходит весь домен.
+// Раскрытие токена — проход по буферу связей домена.
+
+use axiom_core::Connection;
+use std::collections::{HashSet, VecDeque};
+
+use crate::{DomainState, QueryDirection};
+
+/// Фильтры и бюджеты обхода окрестности.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NeighborhoodConfig {
+    /// Предел числа выданных токенов
+    pub max_nodes: usize,
+    /// Предел числа пройденных связей (после фильтров)
+    pub max_edges: usize,
+    /// Направление обхода
+    pub direction: QueryDirection,
+    /// Только связи этого link_type
+    pub link_type: Option<u16>,
+    /// Только связи не слабее порога
+    pub min_strength: f32,
+}
+
+impl Default for NeighborhoodConfig {
+    fn default() -> Self {
+        Self {
+            max_nodes: usize::MAX,
+            max_edges: usize::MAX,
+            direction: QueryDirection::default(),
+            link_type: None,
+            min_strength: 0.0,
+        }
+    }
+}
+
+impl NeighborhoodConfig {
+    /// Проходит ли связь фильтры.
+    fn accepts(&self, c: &Connection) -> bool {
+        !c.is_tombstoned()
+            && c.strength >= self.min_strength
+            && self.link_type.is_none_or(|t| c.link_type == t)
+    }
+
+    /// Другой конец связи, если её можно пройти от `from` в заданном направлении.
+    fn step(&self, c: &Connection, from: u32) -> Option<u32> {
+        let forward = self.direction != QueryDirection::Incoming && c.source_id == from;
+        let backward = self.direction != QueryDirection::Outgoing && c.target_id == from;
+        match (forward, backward) {
+            (true, _) => Some(c.target_id),
+            (_, true) => Some(c.source_id),
+            _ => None,
+        }
+    }
+}
+
+/// Ленивый итератор окрестности: (sutra_id, число связей от старта).
+/// Сам стартовый токен не выдаётся; порядок — по удалённости.
+pub struct Neighborhood<'a> {
+    connections: &'a [Connection],
+    config: NeighborhoodConfig,
+    hops: usize,
+    seen: HashSet<u32>,
+    /// Токены, ждущие раскрытия
+    frontier: VecDeque<(u32, usize)>,
+    /// Найденные, но ещё не выданные
+    found: VecDeque<(u32, usize)>,
+    nodes: usize,
+    edges: usize,
+    truncated: bool,
+}
+
+impl<'a> Neighborhood<'a> {
+    /// Окрестность `start` радиусом `hops` по срезу связей.
+    pub fn new(
+        connections: &'a [Connection],
+        start: u32,
+        hops: usize,
+        config: NeighborhoodConfig,
+    ) -> Self {
+        Self {
+            connections,
+            config,
+            hops,
+            seen: HashSet::from([start]),
+            frontier: VecDeque::from([(start, 0)]),
+            found: VecDeque::new(),
+            nodes: 0,
+            edges: 0,
+            truncated: false,
+        }
+    }
+
+    /// True если обход остановил бюджет, а не исчерпание окрестности.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Пройдено связей.
+    pub fn edges_visited(&self) -> usize {
+        self.edges
+    }
+
+    /// Раскрыть следующий токен фронта. False — фронт пуст или бюджет связей исчерпан.
+    fn expand(&mut self) -> bool {
+        let Some((id, depth)) = self.frontier.pop_front() else {
+            return false;
+        };
+        if depth >= self.hops {
+            return true;
+        }
+        for c in self.connections.iter().filter(|c| self.config.accepts(c)) {
+            let Some(next) = self.config.step(c, id) else {
+                continue;
+            };
+            if self.edges >= self.config.max_edges {
+                self.truncated = true;
+                self.frontier.clear();
+                return false;
+            }
+            self.edges += 1;
+            if self.seen.insert(next) {
+                self.found.push_back((next, depth + 1));
+                self.frontier.push_back((next, depth + 1));
+            }
+        }
+        true
+    }
+}
+
+impl Iterator for Neighborhood<'_> {
+    type Item = (u32, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() && self.expand() {}
+        let item = self.found.pop_front()?;
+        if self.nodes >= self.config.max_nodes {
+            self.truncated = true;
+            self.found.clear();
+            self.frontier.clear();
+            return None;
+        }
+        self.nodes += 1;
+        Some(item)
+    }
+}
+
+impl DomainState {
+    /// Ленивая окрестность токена радиусом `hops` (см. `Neighborhood`).
+    pub fn neighborhood(
+        &self,
+        sutra_id: u32,
+        hops: usize,
+        config: NeighborhoodConfig,
+    ) -> Neighborhood<'_> {
+        Neighborhood::new(&self.connections, sutra_id, hops, config)
+    }
+}
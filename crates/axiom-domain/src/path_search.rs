@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Взвешенный поиск пути по связям домена (Dijkstra / A*)
+//
+// Цепочка связей между двумя концептами — объяснение, почему они связаны.
+// Стоимость ребра задаёт вызывающий (например `strength_cost`: 1 − strength —
+// сильные связи дешевле); None — ребро непроходимо. Эвристика A* — оценка
+// оставшейся стоимости по токену; `position_heuristic` берёт её из позиций
+// токенов в пространстве домена. Без эвристики поиск — обычный Dijkstra.
+
+use axiom_core::{Connection, Token};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Найденный путь.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionPath {
+    /// sutra_id токенов от начала до конца (включительно)
+    pub nodes: Vec<u32>,
+    /// Индексы пройденных связей в срезе, по порядку
+    pub connections: Vec<usize>,
+    /// Суммарная стоимость
+    pub cost: f32,
+}
+
+/// Стоимость ребра 1 − strength (не ниже 0); ингибированные и удалённые связи непроходимы.
+pub fn strength_cost(c: &Connection) -> Option<f32> {
+    (!c.is_inhibited() && !c.is_tombstoned()).then(|| (1.0 - c.strength).max(0.0))
+}
+
+/// Эвристика A*: евклидово расстояние от токена до `to` в пространстве домена,
+/// умноженное на `per_unit`. Токен без позиции оценивается нулём.
+///
+/// Допустима (путь остаётся кратчайшим), если `per_unit` не больше минимальной
+/// стоимости ребра на единицу расстояния между его концами.
+pub fn position_heuristic(tokens: &[Token], to: u32, per_unit: f32) -> impl Fn(u32) -> f32 {
+    let positions: HashMap<u32, [i16; 3]> =
+        tokens.iter().map(|t| (t.sutra_id, t.position)).collect();
+    let goal = positions.get(&to).copied();
+    move |node| match (goal, positions.get(&node)) {
+        (Some(g), Some(p)) => {
+            let d2: f32 = (0..3).map(|i| (g[i] as f32 - p[i] as f32).powi(2)).sum();
+            d2.sqrt() * per_unit
+        }
+        _ => 0.0,
+    }
+}
+
+/// Кратчайший путь `from` → `to` по направленным связям (Dijkstra).
+pub fn shortest_path(
+    connections: &[Connection],
+    from: u32,
+    to: u32,
+    cost: impl Fn(&Connection) -> Option<f32>,
+) -> Option<ConnectionPath> {
+    a_star(connections, from, to, cost, |_| 0.0)
+}
+
+/// Кратчайший путь `from` → `to` по направленным связям (A* с эвристикой).
+///
+/// Отрицательные стоимости считаются нулевыми. None — пути нет.
+pub fn a_star(
+    connections: &[Connection],
+    from: u32,
+    to: u32,
+    cost: impl Fn(&Connection) -> Option<f32>,
+    heuristic: impl Fn(u32) -> f32,
+) -> Option<ConnectionPath> {
+    let mut outgoing: HashMap<u32, Vec<usize>> = HashMap::new();
+    for (i, c) in connections.iter().enumerate() {
+        outgoing.entry(c.source_id).or_default().push(i);
+    }
+
+    let mut best: HashMap<u32, f32> = HashMap::from([(from, 0.0)]);
+    let mut came_by: HashMap<u32, usize> = HashMap::new();
+    let mut closed: HashSet<u32> = HashSet::new();
+    let mut open = BinaryHeap::from([Reverse(Frontier { estimate: heuristic(from), node: from })]);
+
+    while let Some(Reverse(Frontier { node, .. })) = open.pop() {
+        if node == to {
+            return Some(reconstruct(connections, &came_by, from, to, best[&to]));
+        }
+        if !closed.insert(node) {
+            continue;
+        }
+        let g = best[&node];
+        for &i in outgoing.get(&node).map_or(&[][..], Vec::as_slice) {
+            let c = &connections[i];
+            let Some(w) = cost(c) else {
+                continue;
+            };
+            let next = g + w.max(0.0);
+            if best.get(&c.target_id).is_none_or(|&known| next < known) {
+                best.insert(c.target_id, next);
+                came_by.insert(c.target_id, i);
+                open.push(Reverse(Frontier {
+                    estimate: next + heuristic(c.target_id),
+                    node: c.target_id,
+                }));
+            }
+        }
+    }
+    None
+}
+
+fn reconstruct(
+    connections: &[Connection],
+    came_by: &HashMap<u32, usize>,
+    from: u32,
+    to: u32,
+    cost: f32,
+) -> ConnectionPath {
+    let mut nodes = vec![to];
+    let mut used = Vec::new();
+    let mut node = to;
+    while node != from {
+        let i = came_by[&node];
+        used.push(i);
+        node = connections[i].source_id;
+        nodes.push(node);
+    }
+    nodes.reverse();
+    used.reverse();
+    ConnectionPath { nodes, connections: used, cost }
+}
+
+/// Элемент открытого списка: оценка полной стоимости через `node`.
+#[derive(Debug, Clone, Copy)]
+struct Frontier {
+    estimate: f32,
+    node: u32,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.estimate.total_cmp(&other.estimate).then(self.node.cmp(&other.node))
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// StrengthNormalization — гомеостаз исходящих связей
+//
+// Многократно подкреплённый токен-хаб набирает исходящие связи со strength
+// далеко за 1.0, и распространение активации вокруг него взрывается.
+// Периодический проход перемасштабирует исходящие связи каждого источника
+// (группа = source_id + link_type), чтобы их суммарная сила не превышала cap.
+
+use axiom_core::Connection;
+use std::collections::HashMap;
+
+/// Нижняя граница strength после нормализации (инвариант Connection: strength > 0).
+pub const MIN_NORMALIZED_STRENGTH: f32 = 1e-4;
+
+/// Способ перемасштабирования исходящих связей одной группы.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalizationMode {
+    /// Пропорционально сжать группу так, чтобы сумма strength = cap.
+    /// Относительные веса сохраняются.
+    SumToCap { cap: f32 },
+    /// Перераспределить cap по softmax(strength / temperature).
+    /// Низкая temperature усиливает доминирующую связь, высокая — выравнивает.
+    Softmax { cap: f32, temperature: f32 },
+}
+
+impl NormalizationMode {
+    fn cap(&self) -> f32 {
+        match *self {
+            NormalizationMode::SumToCap { cap } | NormalizationMode::Softmax { cap, .. } => cap,
+        }
+    }
+}
+
+/// Политика нормализации: режим по умолчанию + переопределения по link_type.
+///
+/// Пустая политика (`default = None`, нет переопределений) — no-op.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StrengthNormalization {
+    /// Режим для link_type без переопределения; None — такие связи не трогаются
+    pub default: Option<NormalizationMode>,
+    /// Режимы для конкретных link_type
+    pub per_link_type: HashMap<u16, NormalizationMode>,
+}
+
+impl StrengthNormalization {
+    /// Политика с одним режимом для всех типов связей.
+    pub fn uniform(mode: NormalizationMode) -> Self {
+        Self {
+            default: Some(mode),
+            per_link_type: HashMap::new(),
+        }
+    }
+
+    /// Режим для данного link_type (переопределение или default).
+    pub fn mode_for(&self, link_type: u16) -> Option<NormalizationMode> {
+        self.per_link_type.get(&link_type).copied().or(self.default)
+    }
+
+    /// True если политика ничего не делает.
+    pub fn is_noop(&self) -> bool {
+        self.default.is_none() && self.per_link_type.is_empty()
+    }
+
+    /// Нормализовать исходящие связи (удалённые не учитываются). Группы, чья сумма
+    /// strength не превышает cap, не изменяются — проход только гасит насыщение,
+    /// но не раздувает слабые узлы.
+    ///
+    /// Возвращает число перемасштабированных групп.
+    pub fn apply(&self, connections: &mut [Connection]) -> usize {
+        if self.is_noop() {
+            return 0;
+        }
+
+        let mut groups: HashMap<(u32, u16), Vec<usize>> = HashMap::new();
+        for (i, c) in connections.iter().enumerate() {
+            if !c.is_tombstoned() && self.mode_for(c.link_type).is_some() {
+                groups
+                    .entry((c.source_id, c.link_type))
+                    .or_default()
+                    .push(i);
+            }
+        }
+
+        let mut rescaled = 0;
+        for ((_, link_type), indices) in groups {
+            let Some(mode) = self.mode_for(link_type) else {
+                continue;
+            };
+            let cap = mode.cap();
+            let sum: f32 = indices.iter().map(|&i| connections[i].strength).sum();
+            if sum <= cap {
+                continue;
+            }
+
+            match mode {
+                NormalizationMode::SumToCap { .. } => {
+                    let scale = cap / sum;
+                    for &i in &indices {
+                        connections[i].strength =
+                            (connections[i].strength * scale).max(MIN_NORMALIZED_STRENGTH);
+                    }
+                }
+                NormalizationMode::Softmax { temperature, .. } => {
+                    let t = temperature.max(f32::EPSILON);
+                    let max = indices
+                        .iter()
+                        .map(|&i| connections[i].strength)
+                        .fold(f32::NEG_INFINITY, f32::max);
+                    let exp_sum: f32 = indices
+                        .iter()
+                        .map(|&i| ((connections[i].strength - max) / t).exp())
+                        .sum();
+                    for &i in &indices {
+                        let w = ((connections[i].strength - max) / t).exp() / exp_sum;
+                        connections[i].strength = (cap * w).max(MIN_NORMALIZED_STRENGTH);
+                    }
+                }
+            }
+            rescaled += 1;
+        }
+
+        rescaled
+    }
+}
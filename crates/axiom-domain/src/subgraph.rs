@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Subgraph — окрестность токенов домена как самостоятельный фрагмент
+//
+// extract берёт k-hop окрестность набора токенов (связи считаются
+// неориентированными) вместе со всеми связями внутри неё — «что система знает
+// о теме X». merge_into вливает фрагмент в домен (того же или другого
+// runtime): sutra_id переназначаются по NodeMapping, совпавшие связи
+// сводятся по EdgeConflict. Слияние атомарно: при нехватке ёмкости домен не
+// меняется. write/read переносят фрагмент как два пакета подряд (токены, связи).
+
+use axiom_core::{Connection, Token};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{CapacityExceeded, DomainState};
+
+/// Как сопоставить токены фрагмента с токенами домена.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NodeMapping {
+    /// Одинаковый sutra_id — один и тот же токен; отсутствующие добавляются
+    /// со своим sutra_id
+    #[default]
+    Identity,
+    /// Все токены фрагмента — новые, со свежими sutra_id
+    Fresh,
+}
+
+/// Что делать, если связь (source, target, link_type) уже есть в домене.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EdgeConflict {
+    /// Оставить связь домена
+    #[default]
+    KeepExisting,
+    /// Заменить связью фрагмента
+    Replace,
+    /// Оставить ту, что сильнее
+    Strongest,
+}
+
+/// Итог слияния.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SubgraphMergeReport {
+    /// sutra_id во фрагменте → sutra_id в домене
+    pub id_map: HashMap<u32, u32>,
+    /// Токенов добавлено
+    pub tokens_added: usize,
+    /// Связей добавлено
+    pub connections_added: usize,
+    /// Существующих связей изменено по EdgeConflict
+    pub connections_updated: usize,
+}
+
+/// Фрагмент графа: токены и связи между ними.
+#[derive(Debug, Clone, Default)]
+pub struct Subgraph {
+    /// Токены фрагмента
+    pub tokens: Vec<Token>,
+    /// Связи, оба конца которых во фрагменте
+    pub connections: Vec<Connection>,
+}
+
+impl Subgraph {
+    /// Снимок всего домена: все токены и действующие связи.
+    pub fn whole(state: &DomainState) -> Subgraph {
+        let connections = state.live_connections().copied().collect();
+        Subgraph { tokens: state.tokens.clone(), connections }
+    }
+
+    /// Срез фрагмента на момент `at`: только действительные тогда связи.
+    /// Токены не фильтруются — время действительности есть только у связей.
+    pub fn as_of(&self, at: u64) -> Subgraph {
+        Subgraph {
+            tokens: self.tokens.clone(),
+            connections: self.connections.iter().filter(|c| c.is_valid_at(at)).copied().collect(),
+        }
+    }
+
+    /// Окрестность `seeds` радиусом `hops` связей. Семена, которых нет среди
+    /// токенов домена, попадают во фрагмент только если у них есть связи.
+    pub fn extract(state: &DomainState, seeds: &[u32], hops: usize) -> Subgraph {
+        let mut adjacent: HashMap<u32, Vec<u32>> = HashMap::new();
+        for c in state.live_connections() {
+            adjacent.entry(c.source_id).or_default().push(c.target_id);
+            adjacent.entry(c.target_id).or_default().push(c.source_id);
+        }
+        let mut reached: BTreeSet<u32> = seeds.iter().copied().collect();
+        let mut frontier: Vec<u32> = reached.iter().copied().collect();
+        for _ in 0..hops {
+            let mut next = Vec::new();
+            for id in frontier {
+                for &n in adjacent.get(&id).map_or(&[][..], Vec::as_slice) {
+                    if reached.insert(n) {
+                        next.push(n);
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        let tokens: Vec<Token> =
+            state.tokens.iter().filter(|t| reached.contains(&t.sutra_id)).copied().collect();
+        let connections: Vec<Connection> = state
+            .live_connections()
+            .filter(|c| reached.contains(&c.source_id) && reached.contains(&c.target_id))
+            .copied()
+            .collect();
+        Subgraph { tokens, connections }
+    }
+
+    /// Влить фрагмент в домен `domain_id` (состояние `state`).
+    ///
+    /// Связи, чей конец не найден ни среди токенов фрагмента, ни в домене,
+    /// переносятся с исходным sutra_id конца.
+    pub fn merge_into(
+        &self,
+        state: &mut DomainState,
+        domain_id: u16,
+        mapping: NodeMapping,
+        conflict: EdgeConflict,
+    ) -> Result<SubgraphMergeReport, CapacityExceeded> {
+        let existing: HashSet<u32> = state.tokens.iter().map(|t| t.sutra_id).collect();
+        let mut next_id = state
+            .tokens
+            .iter()
+            .map(|t| t.sutra_id)
+            .chain(state.connections.iter().flat_map(|c| [c.source_id, c.target_id]))
+            .max()
+            .map_or(1, |m| m + 1);
+
+        let mut report = SubgraphMergeReport::default();
+        let mut new_tokens = Vec::new();
+        for t in &self.tokens {
+            if report.id_map.contains_key(&t.sutra_id) {
+                continue;
+            }
+            let id = match mapping {
+                NodeMapping::Identity if existing.contains(&t.sutra_id) => {
+                    report.id_map.insert(t.sutra_id, t.sutra_id);
+                    continue;
+                }
+                NodeMapping::Identity => t.sutra_id,
+                NodeMapping::Fresh => {
+                    next_id += 1;
+                    next_id - 1
+                }
+            };
+            report.id_map.insert(t.sutra_id, id);
+            let mut token = *t;
+            token.sutra_id = id;
+            token.domain_id = domain_id;
+            new_tokens.push(token);
+        }
+
+        let remap = |id: u32| report.id_map.get(&id).copied().unwrap_or(id);
+        let mut new_connections: Vec<Connection> = Vec::new();
+        let mut updates: Vec<(usize, Connection)> = Vec::new();
+        let mut seen: HashSet<(u32, u32, u16)> = HashSet::new();
+        for c in self.connections.iter().filter(|c| !c.is_tombstoned()) {
+            let mut conn = *c;
+            conn.source_id = remap(c.source_id);
+            conn.target_id = remap(c.target_id);
+            conn.domain_id = domain_id;
+            if !seen.insert((conn.source_id, conn.target_id, conn.link_type)) {
+                continue;
+            }
+            let found = state.connections.iter().position(|e| {
+                !e.is_tombstoned()
+                    && (e.source_id, e.target_id, e.link_type)
+                        == (conn.source_id, conn.target_id, conn.link_type)
+            });
+            match (found, conflict) {
+                (None, _) => new_connections.push(conn),
+                (Some(_), EdgeConflict::KeepExisting) => {}
+                (Some(i), EdgeConflict::Replace) => updates.push((i, conn)),
+                (Some(i), EdgeConflict::Strongest) => {
+                    if conn.strength > state.connections[i].strength {
+                        updates.push((i, conn));
+                    }
+                }
+            }
+        }
+
+        if state.token_count() + new_tokens.len() > state.token_capacity()
+            || state.connection_count() + new_connections.len() > state.connection_capacity()
+        {
+            return Err(CapacityExceeded);
+        }
+        report.tokens_added = new_tokens.len();
+        report.connections_added = new_connections.len();
+        report.connections_updated = updates.len();
+        for token in new_tokens {
+            state.add_token(token)?;
+        }
+        for (i, conn) in updates {
+            state.connections[i] = conn;
+        }
+        state.connections.extend(new_connections);
+        Ok(report)
+    }
+
+    /// Записать фрагмент: пакет токенов, затем пакет связей.
+    pub fn write(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        Token::write_batch(&self.tokens, w)?;
+        Connection::write_batch(&self.connections, w)
+    }
+
+    /// Прочитать фрагмент, записанный `write`.
+    pub fn read(r: &mut impl std::io::Read) -> std::io::Result<Subgraph> {
+        let tokens = Token::read_batch(r)?;
+        let connections = Connection::read_batch(r)?;
+        Ok(Subgraph { tokens, connections })
+    }
+}
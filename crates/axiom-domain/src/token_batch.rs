@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// TokenBatchBuilder — пакетное создание токенов с общими параметрами.
+//
+// Цикл inject_token по одному токену может упасть посередине (CapacityExceeded,
+// невалидный токен) — часть пакета уже в домене, часть нет. Пакет проверяется
+// целиком до вставки: либо все токены попадают в домен, либо ни один.
+
+use axiom_core::{Token, STATE_ACTIVE, STATE_SLEEPING};
+use std::collections::HashSet;
+
+use crate::AshtiCore;
+
+/// Ошибка пакетной вставки. Домен при ошибке не меняется.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenBatchError {
+    /// domain_id не принадлежит этому AshtiCore
+    UnknownDomain(u16),
+    /// Токен пакета нарушает инварианты Token::validate
+    InvalidToken { index: usize, reason: String },
+    /// Токен пакета адресован другому домену
+    DomainMismatch { index: usize, domain_id: u16 },
+    /// sutra_id встречается в пакете дважды
+    DuplicateSutraId(u32),
+    /// Не хватает места даже после вытеснения спящих токенов
+    Capacity { needed: usize, available: usize },
+}
+
+impl std::fmt::Display for TokenBatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenBatchError::UnknownDomain(id) => write!(f, "unknown domain {id}"),
+            TokenBatchError::InvalidToken { index, reason } => {
+                write!(f, "token #{index}: {reason}")
+            }
+            TokenBatchError::DomainMismatch { index, domain_id } => {
+                write!(f, "token #{index} belongs to domain {domain_id}")
+            }
+            TokenBatchError::DuplicateSutraId(id) => {
+                write!(f, "sutra_id {id} appears more than once")
+            }
+            TokenBatchError::Capacity { needed, available } => {
+                write!(f, "batch needs {needed} slots, {available} available")
+            }
+        }
+    }
+}
+
+/// Пакет токенов одного домена с общими значениями по умолчанию.
+///
+/// ```ignore
+/// let mut batch = TokenBatchBuilder::new(100, event_id).with_mass(50);
+/// batch.push(1, [0, 0, 0]).push(2, [10, 0, 0]);
+/// ashti.inject_batch(&batch)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct TokenBatchBuilder {
+    domain_id: u16,
+    event_id: u64,
+    type_flags: u16,
+    mass: u8,
+    temperature: u8,
+    valence: i8,
+    state: u8,
+    tokens: Vec<Token>,
+}
+
+impl TokenBatchBuilder {
+    pub fn new(domain_id: u16, event_id: u64) -> Self {
+        Self {
+            domain_id,
+            event_id,
+            type_flags: 0,
+            mass: 100,
+            temperature: 100,
+            valence: 0,
+            state: STATE_ACTIVE,
+            tokens: Vec::new(),
+        }
+    }
+
+    pub fn with_type_flags(mut self, type_flags: u16) -> Self {
+        self.type_flags = type_flags;
+        self
+    }
+
+    pub fn with_mass(mut self, mass: u8) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: u8) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    pub fn with_valence(mut self, valence: i8) -> Self {
+        self.valence = valence;
+        self
+    }
+
+    pub fn with_state(mut self, state: u8) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Добавить токен с общими параметрами пакета.
+    pub fn push(&mut self, sutra_id: u32, position: [i16; 3]) -> &mut Self {
+        let mut token = Token::new(sutra_id, self.domain_id, position, self.event_id);
+        token.type_flags = self.type_flags;
+        token.mass = self.mass;
+        token.temperature = self.temperature;
+        token.valence = self.valence;
+        token.state = self.state;
+        self.tokens.push(token);
+        self
+    }
+
+    /// Добавить готовый токен как есть (общие параметры не применяются).
+    pub fn push_token(&mut self, token: Token) -> &mut Self {
+        self.tokens.push(token);
+        self
+    }
+
+    pub fn domain_id(&self) -> u16 {
+        self.domain_id
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Проверить пакет против текущего состояния домена, ничего не меняя.
+    ///
+    /// Возвращает число спящих токенов, которые придётся вытеснить.
+    pub fn validate(&self, ashti: &AshtiCore) -> Result<usize, TokenBatchError> {
+        let state = ashti
+            .index_of(self.domain_id)
+            .and_then(|i| ashti.state(i))
+            .ok_or(TokenBatchError::UnknownDomain(self.domain_id))?;
+
+        let mut seen = HashSet::with_capacity(self.tokens.len());
+        for (index, token) in self.tokens.iter().enumerate() {
+            token
+                .validate()
+                .map_err(|reason| TokenBatchError::InvalidToken { index, reason })?;
+            if token.domain_id != self.domain_id {
+                return Err(TokenBatchError::DomainMismatch { index, domain_id: token.domain_id });
+            }
+            if !seen.insert(token.sutra_id) {
+                return Err(TokenBatchError::DuplicateSutraId(token.sutra_id));
+            }
+        }
+
+        let free = state.token_capacity().saturating_sub(state.token_count());
+        let sleeping = state.tokens.iter().filter(|t| t.state == STATE_SLEEPING).count();
+        if self.tokens.len() > free + sleeping {
+            return Err(TokenBatchError::Capacity {
+                needed: self.tokens.len(),
+                available: free + sleeping,
+            });
+        }
+        Ok(self.tokens.len().saturating_sub(free))
+    }
+}
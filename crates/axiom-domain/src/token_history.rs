@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// TokenHistory — ограниченная история версий токенов.
+//
+// Обучающие циклы (subsystem gravity, подкрепление Frame) медленно сдвигают
+// токены, и по текущему состоянию уже не понять, откуда токен пришёл.
+// TokenHistory — опциональный sidecar: перед мутацией в кольцо токена
+// кладётся копия предыдущего состояния (не больше `depth` поколений).
+// Сам DomainState историю не хранит — горячий путь не платит за неё.
+
+use axiom_core::Token;
+use std::collections::{HashMap, VecDeque};
+
+/// Кольца предыдущих версий токенов, ключ — (domain_id, sutra_id).
+#[derive(Debug, Clone)]
+pub struct TokenHistory {
+    depth: usize,
+    rings: HashMap<(u16, u32), VecDeque<Token>>,
+}
+
+impl TokenHistory {
+    /// История глубиной `depth` поколений на токен (минимум 1).
+    pub fn new(depth: usize) -> Self {
+        Self { depth: depth.max(1), rings: HashMap::new() }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Запомнить текущее состояние токена.
+    ///
+    /// Повтор без изменений (пустой `Token::diff` с последним поколением)
+    /// не занимает слот. Возвращает true если поколение добавлено.
+    pub fn record(&mut self, token: &Token) -> bool {
+        let ring = self.rings.entry((token.domain_id, token.sutra_id)).or_default();
+        if ring.back().is_some_and(|last| last.diff(token).is_empty()) {
+            return false;
+        }
+        if ring.len() == self.depth {
+            ring.pop_front();
+        }
+        ring.push_back(*token);
+        true
+    }
+
+    /// Запомнить все токены среза. Возвращает число добавленных поколений.
+    pub fn record_all(&mut self, tokens: &[Token]) -> usize {
+        tokens.iter().filter(|t| self.record(t)).count()
+    }
+
+    /// Поколения токена от старого к новому.
+    pub fn generations(&self, domain_id: u16, sutra_id: u32) -> impl Iterator<Item = &Token> {
+        self.rings.get(&(domain_id, sutra_id)).into_iter().flatten()
+    }
+
+    /// Версия `back` поколений назад (0 — последняя записанная).
+    pub fn previous(&self, domain_id: u16, sutra_id: u32, back: usize) -> Option<&Token> {
+        let ring = self.rings.get(&(domain_id, sutra_id))?;
+        ring.len().checked_sub(back + 1).map(|i| &ring[i])
+    }
+
+    /// Забыть историю токена (например, после удаления из домена).
+    pub fn forget(&mut self, domain_id: u16, sutra_id: u32) {
+        self.rings.remove(&(domain_id, sutra_id));
+    }
+
+    /// Перенести историю поглощённого токена `from` на `into` (слияние токенов):
+    /// поколения `from` с sutra_id = `into` становятся самыми старыми
+    /// поколениями `into`; при переполнении отбрасываются старейшие.
+    pub fn absorb(&mut self, domain_id: u16, from: u32, into: u32) {
+        if from == into {
+            return;
+        }
+        let Some(absorbed) = self.rings.remove(&(domain_id, from)) else {
+            return;
+        };
+        let ring = self.rings.entry((domain_id, into)).or_default();
+        let own = std::mem::take(ring);
+        ring.extend(absorbed.into_iter().map(|mut t| {
+            t.sutra_id = into;
+            t
+        }));
+        ring.extend(own);
+        while ring.len() > self.depth {
+            ring.pop_front();
+        }
+    }
+
+    /// Число токенов с историей.
+    pub fn tracked(&self) -> usize {
+        self.rings.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.rings.clear();
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// TokenLabels — человекочитаемые метки токенов вне 64-байтового Token.
+//
+// Перцепторы переводят слова в позиции и sutra_id, но обратного пути нет:
+// адаптер вывода видит только числа. TokenLabels хранит метки по sutra_id
+// и обратный индекс метка → sutra_id. Ключ — только sutra_id: один концепт
+// может жить в нескольких доменах (якорь в MAYA и SUTRA) под одним id.
+
+use std::collections::HashMap;
+
+/// Метки токенов с обратным индексом.
+#[derive(Debug, Clone, Default)]
+pub struct TokenLabels {
+    by_token: HashMap<u32, Vec<String>>,
+    by_label: HashMap<String, Vec<u32>>,
+}
+
+impl TokenLabels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Добавить метку токену. Возвращает false если такая метка уже есть.
+    pub fn add(&mut self, sutra_id: u32, label: &str) -> bool {
+        let labels = self.by_token.entry(sutra_id).or_default();
+        if labels.iter().any(|l| l == label) {
+            return false;
+        }
+        labels.push(label.to_string());
+        self.by_label.entry(label.to_string()).or_default().push(sutra_id);
+        true
+    }
+
+    /// Метки токена в порядке добавления (пусто если меток нет).
+    pub fn labels(&self, sutra_id: u32) -> &[String] {
+        self.by_token.get(&sutra_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Первая (основная) метка токена.
+    pub fn primary(&self, sutra_id: u32) -> Option<&str> {
+        self.labels(sutra_id).first().map(String::as_str)
+    }
+
+    /// Токены с меткой `label` (точное совпадение).
+    pub fn find_by_label(&self, label: &str) -> &[u32] {
+        self.by_label.get(label).map_or(&[], Vec::as_slice)
+    }
+
+    /// Снять одну метку с токена. Возвращает false если такой метки не было.
+    pub fn remove_label(&mut self, sutra_id: u32, label: &str) -> bool {
+        let Some(labels) = self.by_token.get_mut(&sutra_id) else {
+            return false;
+        };
+        let Some(pos) = labels.iter().position(|l| l == label) else {
+            return false;
+        };
+        labels.remove(pos);
+        if labels.is_empty() {
+            self.by_token.remove(&sutra_id);
+        }
+        if let Some(ids) = self.by_label.get_mut(label) {
+            ids.retain(|&id| id != sutra_id);
+            if ids.is_empty() {
+                self.by_label.remove(label);
+            }
+        }
+        true
+    }
+
+    /// Удалить все метки токена. Возвращает число удалённых меток.
+    pub fn remove_token(&mut self, sutra_id: u32) -> usize {
+        let Some(labels) = self.by_token.remove(&sutra_id) else {
+            return 0;
+        };
+        for label in &labels {
+            if let Some(ids) = self.by_label.get_mut(label) {
+                ids.retain(|&id| id != sutra_id);
+                if ids.is_empty() {
+                    self.by_label.remove(label);
+                }
+            }
+        }
+        labels.len()
+    }
+
+    /// Перенести метки токена `from` на `into` (слияние токенов); у `from`
+    /// меток не остаётся. Возвращает число перенесённых новых для `into` меток.
+    pub fn merge_token(&mut self, into: u32, from: u32) -> usize {
+        if into == from {
+            return 0;
+        }
+        let labels = self.labels(from).to_vec();
+        self.remove_token(from);
+        labels.iter().filter(|label| self.add(into, label)).count()
+    }
+
+    /// Число токенов с метками.
+    pub fn len(&self) -> usize {
+        self.by_token.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_token.is_empty()
+    }
+}
@@ -1,7 +1,10 @@
 // Тесты AshtiCore — 11-доменный фрактальный уровень Ashti_Core v2.0
 
-use axiom_core::{Connection, Token};
-use axiom_domain::AshtiCore;
+use axiom_core::{Connection, MergeStrategy, Token};
+use axiom_domain::{
+    degree_centrality, label_propagation, pagerank, AshtiCore, ConnectionDecay, GraphDiff,
+    OrphanCriteria, PageRankConfig, Removal, Subgraph, TokenBatchBuilder, TokenBatchError,
+};
 
 fn make_token(sutra_id: u32, mass: u8, temp: u8) -> Token {
     let mut t = Token::new(sutra_id, 1, [0, 0, 0], 1);
@@ -239,3 +242,451 @@ fn test_reconcile_does_not_remove_tokens() {
         "reconcile не должен удалять живые токены"
     );
 }
+
+// --- collect_orphans ---
+
+fn inject_light(core: &mut AshtiCore, domain_id: u16, sutra_id: u32, event_id: u64) {
+    let mut token = Token::new(sutra_id, domain_id, [0, 0, 0], event_id);
+    token.mass = 1;
+    let _ = core.inject_token(domain_id, token);
+}
+
+#[test]
+fn test_collect_orphans_removes_light_idle_token() {
+    let mut core = AshtiCore::new(1);
+    inject_light(&mut core, LOGIC_DOMAIN, 1, 1);
+    inject(&mut core, LOGIC_DOMAIN, 2); // mass 100 — не сирота
+
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    let report = core.collect_orphans(&criteria, 100, |_| true);
+
+    assert_eq!(report.candidates, 1);
+    assert_eq!(report.tombstones.len(), 1);
+    assert_eq!(report.tombstones[0].0, LOGIC_DOMAIN);
+    assert_eq!(report.tombstones[0].1.sutra_id, 1);
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 1);
+    assert!(core.find_token_by_sutra_id(LOGIC_DOMAIN, 1).is_none());
+}
+
+#[test]
+fn test_collect_orphans_keeps_recent_and_strongly_bonded() {
+    let mut core = AshtiCore::new(1);
+    inject_light(&mut core, LOGIC_DOMAIN, 1, 95); // недавно активен
+    inject_light(&mut core, LOGIC_DOMAIN, 2, 1);
+    inject(&mut core, LOGIC_DOMAIN, 3);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    let mut bond = Connection::new(2, 3, LOGIC_DOMAIN, 1);
+    bond.strength = 0.9;
+    let _ = core.state_mut(idx).unwrap().add_connection(bond);
+
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.5, idle_events: 10 };
+    let report = core.collect_orphans(&criteria, 100, |_| true);
+
+    assert_eq!(report.candidates, 0);
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 3);
+}
+
+#[test]
+fn test_collect_orphans_drops_weak_connections_and_grid_entries() {
+    let mut core = AshtiCore::new(1);
+    inject_light(&mut core, LOGIC_DOMAIN, 1, 1);
+    inject(&mut core, LOGIC_DOMAIN, 2);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    let mut weak = Connection::new(1, 2, LOGIC_DOMAIN, 1);
+    weak.strength = 0.01;
+    core.inject_connection(LOGIC_DOMAIN, weak).unwrap();
+    assert_eq!(core.domain(idx).unwrap().active_connections, 1);
+
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    let report = core.collect_orphans(&criteria, 100, |_| true);
+
+    assert_eq!(report.connections_removed, 1);
+    assert!(core.state(idx).unwrap().connections.is_empty());
+    assert_eq!(core.domain(idx).unwrap().active_tokens, 1);
+    assert_eq!(core.domain(idx).unwrap().active_connections, 0);
+    // Грид перестроен: единственный оставшийся токен имеет индекс 0
+    let tokens = core.state(idx).unwrap().tokens.clone();
+    let found = core.domain(idx).unwrap().spatial_grid.find_neighbors(0, 0, 0, 16, |i| {
+        let p = tokens[i as usize].position;
+        (p[0], p[1], p[2])
+    });
+    assert_eq!(found, vec![0]);
+}
+
+#[test]
+fn test_collect_orphans_respects_review_veto() {
+    let mut core = AshtiCore::new(1);
+    inject_light(&mut core, LOGIC_DOMAIN, 1, 1);
+
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    let report = core.collect_orphans(&criteria, 100, |_| false);
+
+    assert_eq!(report.candidates, 1);
+    assert_eq!(report.vetoed, 1);
+    assert!(report.tombstones.is_empty());
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 1);
+}
+
+// --- remove_connection / remove_token / compact_connections ---
+
+#[test]
+fn test_remove_connection_tombstones_until_compaction() {
+    let mut core = AshtiCore::new(1);
+    inject(&mut core, LOGIC_DOMAIN, 1);
+    inject(&mut core, LOGIC_DOMAIN, 2);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    let _ = core.state_mut(idx).unwrap().add_connection(Connection::new(1, 2, LOGIC_DOMAIN, 1));
+
+    let removal = core.remove_connection(LOGIC_DOMAIN, (1, 2, 0), |_| true);
+    assert!(matches!(removal, Removal::Removed(c) if c.target_id == 2));
+    let state = core.state(idx).unwrap();
+    assert!(state.connection(1, 2, 0).is_none());
+    assert_eq!(state.tombstone_count(), 1);
+    assert!(!state.connections[0].is_active());
+    let again = core.remove_connection(LOGIC_DOMAIN, (1, 2, 0), |_| true);
+    assert!(matches!(again, Removal::NotFound));
+
+    assert_eq!(core.compact_connections(), 1);
+    assert!(core.state(idx).unwrap().connections.is_empty());
+    assert_eq!(core.domain(idx).unwrap().active_connections, 0);
+    assert_eq!(core.compact_connections(), 0);
+}
+
+#[test]
+fn test_remove_connection_respects_review_veto() {
+    let mut core = AshtiCore::new(1);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    let _ = core.state_mut(idx).unwrap().add_connection(Connection::new(1, 2, LOGIC_DOMAIN, 1));
+
+    let vetoed = core.remove_connection(LOGIC_DOMAIN, (1, 2, 0), |_| false);
+    assert!(matches!(vetoed, Removal::Vetoed));
+    assert_eq!(core.state(idx).unwrap().tombstone_count(), 0);
+    assert!(matches!(core.remove_connection(999, (1, 2, 0), |_| true), Removal::NotFound));
+}
+
+#[test]
+fn test_remove_token_drops_its_connections() {
+    let mut core = AshtiCore::new(1);
+    inject(&mut core, LOGIC_DOMAIN, 1);
+    inject(&mut core, LOGIC_DOMAIN, 2);
+    inject(&mut core, LOGIC_DOMAIN, 3);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    let state = core.state_mut(idx).unwrap();
+    let _ = state.add_connection(Connection::new(1, 2, LOGIC_DOMAIN, 1));
+    let _ = state.add_connection(Connection::new(3, 1, LOGIC_DOMAIN, 1));
+    let _ = state.add_connection(Connection::new(2, 3, LOGIC_DOMAIN, 1));
+
+    let Removal::Removed((token, connections)) = core.remove_token(LOGIC_DOMAIN, 1, |_| true)
+    else {
+        panic!("token 1 should be removed");
+    };
+    assert_eq!(token.sutra_id, 1);
+    assert_eq!(connections.len(), 2);
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 2);
+    assert_eq!(core.state(idx).unwrap().connections.len(), 1);
+    assert_eq!(core.domain(idx).unwrap().active_connections, 1);
+    assert!(matches!(core.remove_token(LOGIC_DOMAIN, 2, |_| false), Removal::Vetoed));
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 2);
+}
+
+#[test]
+fn test_prune_and_expire_skip_tombstones() {
+    let mut core = AshtiCore::new(1);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    let mut temporary = Connection::new(1, 2, LOGIC_DOMAIN, 1);
+    temporary.set_ttl(5);
+    let _ = core.state_mut(idx).unwrap().add_connection(temporary);
+    let _ = core.remove_connection(LOGIC_DOMAIN, (1, 2, 0), |_| true);
+
+    let report = core.expire_connections(100, |_| true);
+    assert!(report.expired.is_empty());
+    assert!(core.state(idx).unwrap().connections.is_empty());
+}
+
+#[test]
+fn test_graph_readers_ignore_tombstones() {
+    let mut core = AshtiCore::new(1);
+    for id in 1..=3 {
+        inject_light(&mut core, LOGIC_DOMAIN, id, 1);
+    }
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    for target in [2, 3] {
+        let mut c = Connection::new(1, target, LOGIC_DOMAIN, 1);
+        c.strength = 0.8;
+        let _ = core.state_mut(idx).unwrap().add_connection(c);
+    }
+    let before = Subgraph::whole(core.state(idx).unwrap());
+    let _ = core.remove_connection(LOGIC_DOMAIN, (1, 3, 0), |_| true);
+    let state = core.state(idx).unwrap();
+
+    assert_eq!(state.live_connections().count(), 1);
+    assert!(!degree_centrality(&state.connections).contains_key(&3));
+    assert!(!pagerank(&state.connections, &PageRankConfig::default(), None)
+        .scores
+        .contains_key(&3));
+    assert_eq!(label_propagation(&state.connections, 10).community_of(3), None);
+    assert_eq!(Subgraph::whole(state).connections.len(), 1);
+    assert!(Subgraph::extract(state, &[1], 1).tokens.iter().all(|t| t.sutra_id != 3));
+    assert_eq!(state.freeze().edge_count(), 1);
+    assert_eq!(state.connection_index().count(0), 1);
+
+    let raw = Subgraph { tokens: state.tokens.clone(), connections: state.connections.clone() };
+    assert_eq!(GraphDiff::between(&before, &raw).connections_removed, vec![(1, 3, 0)]);
+
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 0 };
+    let orphans = state.find_orphans(&criteria, 10);
+    assert!(orphans.contains(&3) && !orphans.contains(&2));
+
+    let mut connections = state.connections.clone();
+    let decay = ConnectionDecay { factor: 0.5, idle_after: 0, prune_below: 0.0 };
+    assert_eq!(decay.apply(&mut connections, 100).decayed, 1);
+    assert_eq!(connections.iter().find(|c| c.is_tombstoned()).unwrap().strength, 0.8);
+}
+
+// --- knn_multi ---
+
+const MAP_DOMAIN: u16 = 105;
+
+fn place(core: &mut AshtiCore, domain_id: u16, sutra_id: u32, pos: [i16; 3]) {
+    let _ = core.inject_token(domain_id, Token::new(sutra_id, domain_id, pos, 1));
+    let idx = core.index_of(domain_id).unwrap();
+    let tokens = core.state(idx).unwrap().tokens.clone();
+    core.domain_mut(idx).unwrap().rebuild_spatial_grid(&tokens);
+}
+
+#[test]
+fn test_knn_multi_combines_weighted_spaces() {
+    let mut core = AshtiCore::new(1);
+    // sutra 1: близко в LOGIC, далеко в MAP; sutra 2: средне в обоих
+    place(&mut core, LOGIC_DOMAIN, 1, [10, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 1, [90, 0, 0]);
+    place(&mut core, LOGIC_DOMAIN, 2, [40, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 2, [40, 0, 0]);
+
+    let o = (0, 0, 0);
+    let logic_heavy = core.knn_multi(&[(LOGIC_DOMAIN, 1.0, o), (MAP_DOMAIN, 0.1, o)], 100, 2);
+    assert_eq!(logic_heavy[0].0, 1);
+
+    let balanced = core.knn_multi(&[(LOGIC_DOMAIN, 1.0, o), (MAP_DOMAIN, 1.0, o)], 100, 2);
+    assert_eq!(balanced.iter().map(|r| r.0).collect::<Vec<_>>(), vec![2, 1]);
+    assert!((balanced[0].1 - 1600.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_knn_multi_penalizes_missing_space() {
+    let mut core = AshtiCore::new(1);
+    place(&mut core, LOGIC_DOMAIN, 1, [0, 0, 0]); // только в LOGIC
+    place(&mut core, LOGIC_DOMAIN, 2, [30, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 2, [30, 0, 0]);
+
+    let o = (0, 0, 0);
+    let r = core.knn_multi(&[(LOGIC_DOMAIN, 1.0, o), (MAP_DOMAIN, 1.0, o)], 50, 5);
+    // sutra 1: (0 + 2500)/2 = 1250; sutra 2: (900 + 900)/2 = 900
+    assert_eq!(r.iter().map(|r| r.0).collect::<Vec<_>>(), vec![2, 1]);
+    assert!((r[1].1 - 1250.0).abs() < 1e-3);
+    assert!(core.knn_multi(&[(LOGIC_DOMAIN, 1.0, o)], 50, 0).is_empty());
+}
+
+#[test]
+fn test_knn_finds_neighbours_beyond_any_radius() {
+    let mut core = AshtiCore::new(1);
+    place(&mut core, MAP_DOMAIN, 1, [20000, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 2, [-5000, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 3, [0, 9000, 0]);
+
+    let nearest = core.knn(MAP_DOMAIN, (0, 0, 0), 2).unwrap();
+    assert_eq!(nearest, vec![(2, 25_000_000), (3, 81_000_000)]);
+    assert!(core.knn(999, (0, 0, 0), 2).is_none());
+
+    // Новый токен без перестройки grid: дерево видит изменившееся число токенов
+    let _ = core.inject_token(MAP_DOMAIN, Token::new(4, MAP_DOMAIN, [10, 0, 0], 1));
+    assert_eq!(core.knn(MAP_DOMAIN, (0, 0, 0), 1).unwrap(), vec![(4, 100)]);
+}
+
+#[test]
+fn test_range_and_box_queries_return_sutra_ids() {
+    let mut core = AshtiCore::new(1);
+    place(&mut core, MAP_DOMAIN, 7, [10, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 3, [0, -20, 0]);
+    place(&mut core, MAP_DOMAIN, 5, [500, 500, 500]);
+
+    let near = core.range_query(MAP_DOMAIN, (0, 0, 0), 30).unwrap();
+    assert_eq!(near, vec![(7, 100), (3, 400)]);
+    let boxed = core.box_query(MAP_DOMAIN, (0, -20, 0), (600, 600, 600)).unwrap();
+    assert_eq!(boxed, vec![3, 5, 7]);
+    assert!(core.range_query(999, (0, 0, 0), 30).is_none());
+}
+
+#[test]
+fn test_fit_domain_transform_maps_shared_tokens() {
+    let mut core = AshtiCore::new(1);
+    let points = [[0, 0, 0], [100, 0, 0], [0, 100, 0], [0, 0, 100], [40, 40, 40]];
+    for (id, p) in points.iter().enumerate() {
+        let _ = core.inject_token(LOGIC_DOMAIN, Token::new(id as u32 + 1, LOGIC_DOMAIN, *p, 1));
+        let shifted = [p[0] * 2 + 5, p[1] * 2, p[2] * 2 - 5];
+        let _ = core.inject_token(MAP_DOMAIN, Token::new(id as u32 + 1, MAP_DOMAIN, shifted, 1));
+    }
+    let t = core.fit_domain_transform(LOGIC_DOMAIN, MAP_DOMAIN).unwrap();
+    assert_eq!(t.apply((10, 20, 30)), (25, 40, 55));
+    assert_eq!(t.inverse().unwrap().apply((25, 40, 55)), (10, 20, 30));
+    assert!(core.fit_domain_transform(LOGIC_DOMAIN, 999).is_none());
+    assert!(core.fit_domain_transform(LOGIC_DOMAIN, 109).is_none()); // общих токенов нет
+}
+
+#[test]
+fn test_grid_occupancy_counts_tokens_per_cell() {
+    let mut core = AshtiCore::new(1);
+    place(&mut core, MAP_DOMAIN, 1, [10, 0, 0]);
+    place(&mut core, MAP_DOMAIN, 2, [200, 100, 0]);
+    place(&mut core, MAP_DOMAIN, 3, [-10, 0, 0]);
+
+    let stats = core.grid_occupancy(MAP_DOMAIN).unwrap();
+    assert_eq!(stats.tokens, 3);
+    assert_eq!(stats.occupied_cells(), 2);
+    assert_eq!(stats.densest(1)[0].cell, (0, 0, 0));
+    assert_eq!(stats.max_occupancy(), 2);
+    assert!(core.grid_occupancy(999).is_none());
+}
+
+// ─── TokenBatchBuilder ───────────────────────────────────────────────────────
+
+#[test]
+fn test_inject_batch_applies_shared_defaults() {
+    let mut core = AshtiCore::new(1);
+    let mut batch = TokenBatchBuilder::new(LOGIC_DOMAIN, 1).with_mass(42).with_valence(1);
+    batch.push(1, [0, 0, 0]).push(2, [10, 0, 0]);
+
+    let indices = core.inject_batch(&batch).unwrap();
+    assert_eq!(indices, vec![0, 1]);
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 2);
+    let t = core.find_token_by_sutra_id(LOGIC_DOMAIN, 2).unwrap();
+    assert_eq!((t.mass, t.valence), (42, 1));
+}
+
+#[test]
+fn test_inject_batch_is_all_or_nothing() {
+    let mut core = AshtiCore::new(1);
+    let mut batch = TokenBatchBuilder::new(LOGIC_DOMAIN, 1);
+    batch.push(1, [0, 0, 0]).push(0, [1, 0, 0]);
+    assert!(matches!(
+        core.inject_batch(&batch),
+        Err(TokenBatchError::InvalidToken { index: 1, .. })
+    ));
+
+    let mut dup = TokenBatchBuilder::new(LOGIC_DOMAIN, 1);
+    dup.push(5, [0, 0, 0]).push(5, [1, 0, 0]);
+    assert_eq!(core.inject_batch(&dup), Err(TokenBatchError::DuplicateSutraId(5)));
+
+    let mut foreign = TokenBatchBuilder::new(LOGIC_DOMAIN, 1);
+    foreign.push_token(Token::new(7, 105, [0, 0, 0], 1));
+    assert!(matches!(
+        core.inject_batch(&foreign),
+        Err(TokenBatchError::DomainMismatch { domain_id: 105, .. })
+    ));
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 0);
+
+    let capacity = core.state(6).unwrap().token_capacity();
+    let mut big = TokenBatchBuilder::new(LOGIC_DOMAIN, 1);
+    for id in 1..=capacity as u32 + 1 {
+        big.push(id, [0, 0, 0]);
+    }
+    assert!(matches!(core.inject_batch(&big), Err(TokenBatchError::Capacity { .. })));
+    assert_eq!(core.token_count(LOGIC_DOMAIN), 0);
+    assert_eq!(
+        core.inject_batch(&TokenBatchBuilder::new(999, 1)),
+        Err(TokenBatchError::UnknownDomain(999))
+    );
+}
+
+// --- dedup_tokens ---
+
+#[test]
+fn test_dedup_merges_close_tokens_and_rewrites_connections() {
+    let mut core = AshtiCore::new(1);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    for (id, x) in [(1u32, 0i16), (2, 2), (3, 500)] {
+        core.inject_token(LOGIC_DOMAIN, Token::new(id, LOGIC_DOMAIN, [x, 0, 0], 1)).unwrap();
+    }
+    let state = core.state_mut(idx).unwrap();
+    state.add_connection(Connection::new(1, 3, LOGIC_DOMAIN, 1)).unwrap();
+    state.add_connection(Connection::new(2, 3, LOGIC_DOMAIN, 1)).unwrap();
+    state.add_connection(Connection::new(1, 2, LOGIC_DOMAIN, 1)).unwrap();
+
+    let report = core.dedup_tokens(LOGIC_DOMAIN, 4, MergeStrategy::KeepPrimary).unwrap();
+    assert_eq!(report.merged, vec![(1, 2)]);
+    assert_eq!(report.connections_rewritten, 2);
+    assert_eq!(report.connections_dropped, 2, "петля 1→1 и дубликат 1→3");
+
+    let state = core.state(idx).unwrap();
+    assert_eq!(state.token_count(), 2);
+    assert_eq!(state.connections.len(), 1);
+    assert_eq!(
+        (state.connections[0].source_id, state.connections[0].target_id),
+        (1, 3)
+    );
+}
+
+#[test]
+fn test_dedup_keeps_live_duplicate_over_tombstone() {
+    let mut core = AshtiCore::new(1);
+    let idx = core.index_of(LOGIC_DOMAIN).unwrap();
+    for (id, x) in [(1u32, 0i16), (2, 2), (3, 500)] {
+        core.inject_token(LOGIC_DOMAIN, Token::new(id, LOGIC_DOMAIN, [x, 0, 0], 1)).unwrap();
+    }
+    let edge = |source: u32, link_type: u16, strength: f32| {
+        let mut c = Connection::new(source, 3, LOGIC_DOMAIN, 1);
+        (c.link_type, c.strength) = (link_type, strength);
+        c
+    };
+    // Удалённая связь 1→3 идёт первой: раньше при схлопывании побеждала она
+    core.state_mut(idx).unwrap().add_connection(edge(1, 0, 0.9)).unwrap();
+    let _ = core.remove_connection(LOGIC_DOMAIN, (1, 3, 0), |_| true);
+    for c in [edge(1, 0, 0.3), edge(2, 0, 0.4), edge(1, 7, 0.2)] {
+        core.state_mut(idx).unwrap().add_connection(c).unwrap();
+    }
+
+    let report = core.dedup_tokens(LOGIC_DOMAIN, 4, MergeStrategy::KeepPrimary).unwrap();
+    assert_eq!(report.merged, vec![(1, 2)]);
+    let state = core.state(idx).unwrap();
+    assert_eq!(state.tombstone_count(), 0);
+    // Максимум по живым дубликатам: 0.3 (1→3) и 0.4 (2→3 → 1→3)
+    assert_eq!(state.connection(1, 3, 0).unwrap().strength, 0.4);
+    assert_eq!(state.connection(1, 3, 7).unwrap().strength, 0.2);
+    assert_eq!(state.connections.len(), 2);
+}
+
+#[test]
+fn test_dedup_unknown_domain_is_none() {
+    let mut core = AshtiCore::new(1);
+    assert!(core.dedup_tokens(999, 4, MergeStrategy::default()).is_none());
+}
+
+#[test]
+fn test_export_import_connections_without_tokens() {
+    let mut source = AshtiCore::new(1);
+    source.inject_connection(LOGIC_DOMAIN, Connection::new(1, 2, LOGIC_DOMAIN, 5)).unwrap();
+    source.inject_connection(110, Connection::new(3, 4, 110, 6)).unwrap();
+    let mut buf = Vec::new();
+    assert_eq!(source.export_connections(&mut buf).unwrap(), 2);
+
+    let mut target = AshtiCore::new(1);
+    assert_eq!(target.import_connections(&mut buf.as_slice()).unwrap(), 2);
+    let logic = target.state(target.index_of(LOGIC_DOMAIN).unwrap()).unwrap();
+    assert_eq!((logic.connections[0].source_id, logic.connections[0].created_at), (1, 5));
+    assert_eq!(logic.token_count(), 0);
+    let maya = target.state(target.index_of(110).unwrap()).unwrap();
+    assert_eq!(maya.connections[0].target_id, 4);
+}
+
+#[test]
+fn test_import_connections_unknown_domain_changes_nothing() {
+    let mut buf = Vec::new();
+    let conns = [Connection::new(1, 2, LOGIC_DOMAIN, 1), Connection::new(1, 2, 999, 1)];
+    Connection::write_batch(&conns, &mut buf).unwrap();
+
+    let mut core = AshtiCore::new(1);
+    let err = core.import_connections(&mut buf.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let logic = core.state(core.index_of(LOGIC_DOMAIN).unwrap()).unwrap();
+    assert!(logic.connections.is_empty());
+}
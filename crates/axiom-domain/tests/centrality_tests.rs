@@ -0,0 +1,70 @@
+// Тесты структурной важности токенов (PageRank, betweenness, степени)
+
+use axiom_core::{Connection, FLAG_INHIBITED};
+use axiom_domain::{
+    betweenness_centrality, centrality, degree_centrality, pagerank, PageRankConfig,
+};
+
+fn edge(source: u32, target: u32, strength: f32) -> Connection {
+    let mut c = Connection::new(source, target, 106, 1);
+    c.strength = strength;
+    c
+}
+
+// Звезда: 2, 3, 4 ссылаются на 1, 1 ссылается на 2
+fn star() -> Vec<Connection> {
+    vec![edge(2, 1, 1.0), edge(3, 1, 1.0), edge(4, 1, 1.0), edge(1, 2, 1.0)]
+}
+
+#[test]
+fn test_pagerank_hub_ranks_highest_and_sums_to_one() {
+    let ranks = pagerank(&star(), &PageRankConfig::default(), None);
+    let total: f32 = ranks.scores.values().sum();
+    assert!((total - 1.0).abs() < 1e-4);
+    let hub = ranks.scores[&1];
+    assert!(ranks.scores.iter().all(|(&id, &r)| id == 1 || r < hub));
+    assert!(ranks.scores[&2] > ranks.scores[&3]);
+    assert!(pagerank(&[], &PageRankConfig::default(), None).scores.is_empty());
+}
+
+#[test]
+fn test_pagerank_warm_start_converges_faster() {
+    let config = PageRankConfig::default();
+    let cold = pagerank(&star(), &config, None);
+    let mut grown = star();
+    grown.push(edge(5, 1, 1.0));
+    let warm = pagerank(&grown, &config, Some(&cold.scores));
+    let fresh = pagerank(&grown, &config, None);
+    assert!(warm.iterations <= fresh.iterations);
+    for (id, r) in &fresh.scores {
+        assert!((warm.scores[id] - r).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_inhibited_connection_does_not_vote() {
+    let mut conns = vec![edge(1, 2, 1.0), edge(1, 3, 1.0)];
+    conns[1].flags |= FLAG_INHIBITED;
+    let ranks = pagerank(&conns, &PageRankConfig::default(), None).scores;
+    assert!(ranks[&2] > ranks[&3]);
+}
+
+#[test]
+fn test_degree_and_betweenness() {
+    // Цепочка 1 → 2 → 3: все пути 1 → 3 идут через 2
+    let chain = vec![edge(1, 2, 0.5), edge(2, 3, 0.5)];
+    let degrees = degree_centrality(&chain);
+    assert_eq!((degrees[&2].incoming, degrees[&2].outgoing), (1, 1));
+    let b = betweenness_centrality(&chain);
+    assert_eq!(b[&2], 1.0);
+    assert_eq!(b[&1], 0.0);
+    assert_eq!(b[&3], 0.0);
+}
+
+#[test]
+fn test_centrality_sorted_by_pagerank() {
+    let nodes = centrality(&star(), &PageRankConfig::default());
+    assert_eq!(nodes[0].sutra_id, 1);
+    assert_eq!(nodes[0].degree.incoming, 3);
+    assert!(nodes.windows(2).all(|w| w[0].pagerank >= w[1].pagerank));
+}
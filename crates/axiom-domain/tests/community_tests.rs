@@ -0,0 +1,62 @@
+// Тесты поиска сообществ в графе связей (label propagation)
+
+use axiom_core::Connection;
+use axiom_domain::{label_propagation, TokenLabels};
+
+fn edge(source: u32, target: u32, strength: f32) -> Connection {
+    let mut c = Connection::new(source, target, 106, 1);
+    c.strength = strength;
+    c
+}
+
+// Два плотных треугольника {1,2,3} и {10,11,12}, между ними слабый мост 3 → 10
+fn two_clusters() -> Vec<Connection> {
+    vec![
+        edge(1, 2, 0.9),
+        edge(2, 3, 0.9),
+        edge(3, 1, 0.9),
+        edge(10, 11, 0.9),
+        edge(11, 12, 0.9),
+        edge(12, 10, 0.9),
+        edge(3, 10, 0.1),
+    ]
+}
+
+#[test]
+fn test_two_clusters_separated() {
+    let communities = label_propagation(&two_clusters(), 20);
+    assert_eq!(communities.count(), 2);
+    assert_eq!(communities.members(1), vec![1, 2, 3]);
+    assert_eq!(communities.members(10), vec![10, 11, 12]);
+    assert_eq!(communities.community_of(12), Some(10));
+    assert_eq!(communities.community_of(99), None);
+}
+
+#[test]
+fn test_deterministic_and_converges() {
+    let conns = two_clusters();
+    let a = label_propagation(&conns, 20);
+    let b = label_propagation(&conns, 20);
+    assert_eq!(a, b);
+    assert!(a.iterations < 20);
+}
+
+#[test]
+fn test_zero_iterations_keeps_singletons() {
+    let communities = label_propagation(&two_clusters(), 0);
+    assert_eq!(communities.count(), 6);
+    assert_eq!(communities.iterations, 0);
+    assert!(label_propagation(&[], 10).iter().next().is_none());
+}
+
+#[test]
+fn test_write_labels_replaces_previous() {
+    let mut labels = TokenLabels::new();
+    labels.add(1, "apple");
+    labels.add(1, "community:42");
+    let communities = label_propagation(&two_clusters(), 20);
+    assert_eq!(communities.write_labels(&mut labels, "community:"), 6);
+    assert_eq!(labels.labels(1), ["apple".to_string(), "community:1".to_string()]);
+    assert!(labels.find_by_label("community:42").is_empty());
+    assert_eq!(labels.find_by_label("community:10"), &[10, 11, 12]);
+}
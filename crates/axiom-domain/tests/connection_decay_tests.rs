@@ -0,0 +1,56 @@
+// Тесты ConnectionDecay — затухание простаивающих связей
+
+use axiom_core::{Connection, FLAG_CRITICAL, FLAG_PRUNE_CANDIDATE};
+use axiom_domain::ConnectionDecay;
+
+fn conn(last_event_id: u64, strength: f32) -> Connection {
+    let mut c = Connection::new(1, 2, 106, 1);
+    c.last_event_id = last_event_id;
+    c.strength = strength;
+    c
+}
+
+fn policy() -> ConnectionDecay {
+    ConnectionDecay { factor: 0.5, idle_after: 100, prune_below: 0.2 }
+}
+
+#[test]
+fn test_default_policy_is_noop() {
+    let mut conns = vec![conn(1, 1.0)];
+    let report = ConnectionDecay::default().apply(&mut conns, 1_000_000);
+    assert_eq!(report.decayed, 0);
+    assert_eq!(conns[0].strength, 1.0);
+}
+
+#[test]
+fn test_only_idle_connections_decay() {
+    let mut conns = vec![conn(10, 1.0), conn(950, 1.0)];
+    let report = policy().apply(&mut conns, 1_000);
+    assert_eq!(report.decayed, 1);
+    assert_eq!(conns[0].strength, 0.5);
+    assert_eq!(conns[1].strength, 1.0);
+}
+
+#[test]
+fn test_weak_idle_connection_flagged_then_cleared_on_activity() {
+    let mut conns = vec![conn(10, 0.3)];
+    let report = policy().apply(&mut conns, 1_000);
+    assert_eq!(report.flagged, 1);
+    assert_ne!(conns[0].flags & FLAG_PRUNE_CANDIDATE, 0);
+
+    // Повторный проход не считает уже помеченную связь заново
+    assert_eq!(policy().apply(&mut conns, 1_000).flagged, 0);
+
+    conns[0].last_event_id = 1_000;
+    policy().apply(&mut conns, 1_001);
+    assert_eq!(conns[0].flags & FLAG_PRUNE_CANDIDATE, 0);
+}
+
+#[test]
+fn test_critical_connections_do_not_decay() {
+    let mut c = conn(10, 1.0);
+    c.flags |= FLAG_CRITICAL;
+    let mut conns = vec![c];
+    assert_eq!(policy().apply(&mut conns, 1_000).decayed, 0);
+    assert_eq!(conns[0].strength, 1.0);
+}
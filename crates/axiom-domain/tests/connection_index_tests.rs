@@ -0,0 +1,55 @@
+// Тесты ConnectionTypeIndex — вторичный индекс связей по link_type
+
+use axiom_core::Connection;
+use axiom_domain::ConnectionTypeIndex;
+
+fn conn(source: u32, target: u32, link_type: u16) -> Connection {
+    let mut c = Connection::new(source, target, 110, 1);
+    c.link_type = link_type;
+    c
+}
+
+fn sample() -> Vec<Connection> {
+    vec![
+        conn(1, 2, 0x0810),
+        conn(1, 3, 0x0B01),
+        conn(2, 3, 0x0810),
+        conn(1, 4, 0x0820),
+        conn(1, 5, 0x0810),
+    ]
+}
+
+#[test]
+fn test_of_type_and_count() {
+    let index = ConnectionTypeIndex::build(&sample());
+    assert_eq!(index.of_type(0x0810), &[0, 2, 4]);
+    assert_eq!(index.count(0x0B01), 1);
+    assert_eq!(index.count(0x0999), 0);
+    assert_eq!(index.types().count(), 3);
+}
+
+#[test]
+fn test_from_source() {
+    let index = ConnectionTypeIndex::build(&sample());
+    assert_eq!(index.from_source(1, 0x0810), &[0, 4]);
+    assert_eq!(index.from_source(2, 0x0810), &[2]);
+    assert!(index.from_source(3, 0x0810).is_empty());
+}
+
+#[test]
+fn test_category_queries_keep_scan_order() {
+    let conns = sample();
+    let index = ConnectionTypeIndex::build(&conns);
+    let scan: Vec<usize> =
+        (0..conns.len()).filter(|&i| conns[i].link_type >> 8 == 0x08).collect();
+    assert_eq!(index.of_category(0x08), scan);
+    assert_eq!(index.from_source_in_category(1, 0x08), vec![0, 3, 4]);
+    assert_eq!(index.from_source_in_category(1, 0x0B), vec![1]);
+}
+
+#[test]
+fn test_empty_index() {
+    let index = ConnectionTypeIndex::build(&[]);
+    assert!(index.of_category(0x08).is_empty());
+    assert_eq!(index.types().count(), 0);
+}
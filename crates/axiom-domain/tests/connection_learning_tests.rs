@@ -0,0 +1,96 @@
+// Тесты ConnectionLearningRule — пластичность связей по времени активации концов
+
+use axiom_core::{Connection, Token, FLAG_CRITICAL};
+use axiom_domain::{AshtiCore, ConnectionLearningRule, MAX_LEARNED_STRENGTH};
+
+fn tokens(source_at: u64, target_at: u64) -> Vec<Token> {
+    vec![Token::new(1, 106, [0, 0, 0], source_at), Token::new(2, 106, [5, 0, 0], target_at)]
+}
+
+fn link(strength: f32) -> Connection {
+    let mut c = Connection::new(1, 2, 106, 1);
+    c.strength = strength;
+    c
+}
+
+#[test]
+fn test_default_rule_is_noop() {
+    let rule = ConnectionLearningRule::default();
+    assert!(rule.is_noop());
+    let mut conns = vec![link(0.5)];
+    let report = rule.apply(&mut conns, &tokens(10, 11));
+    assert_eq!(report.strengthened + report.weakened, 0);
+    assert_eq!(conns[0].strength, 0.5);
+}
+
+#[test]
+fn test_simple_rule_depends_on_window() {
+    let rule = ConnectionLearningRule::Simple { rate: 0.1, window: 5 };
+    assert_eq!(rule.delta(10, 14), 0.1);
+    assert_eq!(rule.delta(14, 10), 0.1);
+    assert_eq!(rule.delta(10, 30), -0.1);
+}
+
+#[test]
+fn test_hebbian_decreases_with_distance() {
+    let rule = ConnectionLearningRule::Hebbian { rate: 0.4, window: 10 };
+    assert_eq!(rule.delta(10, 10), 0.4);
+    assert!((rule.delta(10, 15) - 0.2).abs() < 1e-6);
+    assert_eq!(rule.delta(10, 25), 0.0);
+}
+
+#[test]
+fn test_stdp_sign_follows_order() {
+    let rule = ConnectionLearningRule::Stdp { a_plus: 0.2, a_minus: 0.1, tau: 5.0 };
+    assert!(rule.delta(10, 12) > 0.0);
+    assert!(rule.delta(12, 10) < 0.0);
+    assert_eq!(rule.delta(10, 10), 0.0);
+    assert!(rule.delta(10, 11) > rule.delta(10, 20));
+}
+
+#[test]
+fn test_apply_learns_once_per_activation() {
+    let rule = ConnectionLearningRule::Stdp { a_plus: 0.2, a_minus: 0.1, tau: 5.0 };
+    let toks = tokens(10, 12);
+    let mut conns = vec![link(0.5)];
+
+    let report = rule.apply(&mut conns, &toks);
+    assert_eq!(report.strengthened, 1);
+    assert!(conns[0].strength > 0.5);
+    assert_eq!(conns[0].last_event_id, 12);
+
+    let learned = conns[0].strength;
+    let again = rule.apply(&mut conns, &toks);
+    assert_eq!(again.strengthened + again.weakened, 0);
+    assert_eq!(conns[0].strength, learned);
+}
+
+#[test]
+fn test_apply_bounds_and_skips() {
+    let rule = ConnectionLearningRule::Simple { rate: 0.5, window: 5 };
+    let mut critical = link(0.5);
+    critical.flags |= FLAG_CRITICAL;
+    let mut orphan = link(0.5);
+    orphan.target_id = 99;
+    let mut conns = vec![link(0.9), critical, orphan];
+
+    let report = rule.apply(&mut conns, &tokens(10, 11));
+    assert_eq!(report.strengthened, 1);
+    assert_eq!(conns[0].strength, MAX_LEARNED_STRENGTH);
+    assert_eq!(conns[1].strength, 0.5);
+    assert_eq!(conns[2].strength, 0.5);
+}
+
+#[test]
+fn test_ashti_learn_connections() {
+    let mut core = AshtiCore::new(1);
+    for t in tokens(10, 40) {
+        core.inject_token(106, t).unwrap();
+    }
+    core.inject_connection(106, link(0.5)).unwrap();
+    let rule = ConnectionLearningRule::Simple { rate: 0.1, window: 5 };
+    let report = core.learn_connections(&rule);
+    assert_eq!(report.weakened, 1);
+    let idx = core.index_of(106).unwrap();
+    assert!((core.state(idx).unwrap().connections[0].strength - 0.4).abs() < 1e-6);
+}
@@ -0,0 +1,64 @@
+// Тесты управляемых пар связей — FLAG_BIDIRECTIONAL + SymmetryPolicy
+
+use axiom_core::{Connection, FLAG_BIDIRECTIONAL};
+use axiom_domain::connection_pair::{make_pair, sync_pairs};
+use axiom_domain::{AshtiCore, SymmetryPolicy};
+
+fn half(source: u32, target: u32, strength: f32, last_event_id: u64) -> Connection {
+    let mut c = Connection::new(source, target, 106, 1);
+    c.flags |= FLAG_BIDIRECTIONAL;
+    c.strength = strength;
+    c.last_event_id = last_event_id;
+    c
+}
+
+#[test]
+fn test_make_pair_reverses_and_flags() {
+    let mut forward = Connection::new(1, 2, 106, 5);
+    forward.link_type = 0x0B01;
+    let (f, b) = make_pair(forward);
+    assert_ne!(f.flags & FLAG_BIDIRECTIONAL, 0);
+    assert_ne!(b.flags & FLAG_BIDIRECTIONAL, 0);
+    assert_eq!((b.source_id, b.target_id, b.link_type), (2, 1, 0x0B01));
+}
+
+#[test]
+fn test_policies() {
+    let a = half(1, 2, 0.2, 10);
+    let b = half(2, 1, 0.6, 20);
+    assert_eq!(SymmetryPolicy::LatestWins.resolve(&a, &b), 0.6);
+    assert_eq!(SymmetryPolicy::Max.resolve(&a, &b), 0.6);
+    assert_eq!(SymmetryPolicy::Min.resolve(&a, &b), 0.2);
+    assert!((SymmetryPolicy::Mean.resolve(&a, &b) - 0.4).abs() < 1e-6);
+}
+
+#[test]
+fn test_sync_pairs_aligns_halves_only() {
+    let mut plain = Connection::new(1, 3, 106, 1);
+    plain.strength = 0.9;
+    let mut conns = vec![half(1, 2, 0.2, 10), plain, half(2, 1, 0.6, 20), half(4, 5, 0.3, 1)];
+
+    assert_eq!(sync_pairs(&mut conns, SymmetryPolicy::Min), 1);
+    assert_eq!((conns[0].strength, conns[2].strength), (0.2, 0.2));
+    assert_eq!((conns[0].last_event_id, conns[2].last_event_id), (20, 20));
+    assert_eq!(conns[1].strength, 0.9, "обычная связь не трогается");
+    assert_eq!(conns[3].strength, 0.3, "половина без пары не трогается");
+
+    assert_eq!(sync_pairs(&mut conns, SymmetryPolicy::Min), 0, "уже согласованы");
+}
+
+#[test]
+fn test_inject_bidirectional_connection() {
+    let mut core = AshtiCore::new(1);
+    let (f, b) = core.inject_bidirectional_connection(106, Connection::new(1, 2, 106, 1)).unwrap();
+    let idx = core.index_of(106).unwrap();
+    let state = core.state_mut(idx).unwrap();
+    assert_eq!((state.connections[f].source_id, state.connections[b].source_id), (1, 2));
+
+    state.connections[f].strength = 0.1;
+    state.connections[f].last_event_id = 50;
+    assert_eq!(core.sync_bidirectional(SymmetryPolicy::LatestWins), 1);
+    let state = core.state(idx).unwrap();
+    assert_eq!(state.connections[b].strength, 0.1);
+    assert!(core.inject_bidirectional_connection(999, Connection::new(1, 2, 999, 1)).is_err());
+}
@@ -0,0 +1,78 @@
+// Тесты ConnectionPruner — забывание связей по политике
+
+use axiom_core::{Connection, FLAG_CRITICAL, FLAG_PRUNE_CANDIDATE};
+use axiom_domain::{AshtiCore, ConnectionPruner, PruneReason};
+use std::collections::HashMap;
+
+fn conn(target: u32, link_type: u16, strength: f32, last_event_id: u64) -> Connection {
+    let mut c = Connection::new(1, target, 106, 1);
+    c.link_type = link_type;
+    c.strength = strength;
+    c.last_event_id = last_event_id;
+    c
+}
+
+#[test]
+fn test_default_pruner_is_noop() {
+    let conns = vec![conn(2, 0, 0.0001, 1)];
+    assert!(ConnectionPruner::default().select(&conns, 1_000_000).is_empty());
+}
+
+#[test]
+fn test_rules_select_with_reasons() {
+    let mut flagged = conn(4, 0, 1.0, 900);
+    flagged.flags |= FLAG_PRUNE_CANDIDATE;
+    let mut critical = conn(5, 0, 0.01, 1);
+    critical.flags |= FLAG_CRITICAL;
+    let conns = vec![
+        conn(2, 0, 0.01, 900),
+        conn(3, 0, 1.0, 1),
+        flagged,
+        critical,
+        conn(6, 0, 1.0, 900),
+    ];
+
+    let pruner = ConnectionPruner {
+        min_strength: 0.1,
+        max_idle: 500,
+        prune_flagged: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        pruner.select(&conns, 1_000),
+        vec![(0, PruneReason::BelowStrength), (1, PruneReason::Idle), (2, PruneReason::Flagged)]
+    );
+}
+
+#[test]
+fn test_cap_keeps_strongest_per_source_and_type() {
+    let conns = vec![
+        conn(2, 7, 0.5, 1),
+        conn(3, 7, 0.9, 1),
+        conn(4, 7, 0.1, 1),
+        conn(5, 8, 0.1, 1),
+    ];
+    let pruner = ConnectionPruner {
+        per_link_type_cap: HashMap::from([(7, 2)]),
+        ..Default::default()
+    };
+    assert_eq!(pruner.select(&conns, 1), vec![(2, PruneReason::OverCap)]);
+}
+
+#[test]
+fn test_ashti_prune_removes_and_reports() {
+    let mut core = AshtiCore::new(1);
+    let idx = core.index_of(106).unwrap();
+    let state = core.state_mut(idx).unwrap();
+    state.connections.push(conn(2, 0, 0.01, 1));
+    state.connections.push(conn(3, 0, 1.0, 1));
+
+    let pruner = ConnectionPruner { min_strength: 0.1, ..Default::default() };
+    let report = core.prune_connections(&pruner, 10);
+    assert_eq!(report.removed.len(), 1);
+    assert_eq!(report.count(PruneReason::BelowStrength), 1);
+    assert_eq!(report.removed[0].0, 106);
+    let left = &core.state(idx).unwrap().connections;
+    assert_eq!(left.len(), 1);
+    assert_eq!(left[0].target_id, 3);
+}
@@ -0,0 +1,86 @@
+// Тесты атомарных транзакций над связями домена
+
+use axiom_core::Connection;
+use axiom_domain::{AshtiCore, ConnectionTransaction, TransactionError, TransactionOp};
+
+const LOGIC: u16 = 106;
+
+fn core_with(edges: &[(u32, u32)]) -> AshtiCore {
+    let mut core = AshtiCore::new(1);
+    for &(s, t) in edges {
+        core.inject_connection(LOGIC, Connection::new(s, t, LOGIC, 1)).unwrap();
+    }
+    core
+}
+
+fn targets(core: &AshtiCore) -> Vec<(u32, u32, f32)> {
+    let idx = core.index_of(LOGIC).unwrap();
+    let state = core.state(idx).unwrap();
+    state.connections.iter().map(|c| (c.source_id, c.target_id, c.strength)).collect()
+}
+
+#[test]
+fn test_transaction_applies_all_ops() {
+    let mut core = core_with(&[(1, 2), (2, 3)]);
+    let mut tx = ConnectionTransaction::new(LOGIC);
+    tx.add(Connection::new(3, 4, LOGIC, 1)).set_strength((1, 2, 0), 0.25).remove((2, 3, 0));
+
+    let report = core.apply_transaction(&tx, |_, _| true).unwrap();
+    assert_eq!(report.added, 1);
+    assert_eq!(report.updated, 1);
+    assert_eq!(report.removed.len(), 1);
+    assert_eq!(targets(&core), vec![(1, 2, 0.25), (3, 4, 1.0)]);
+    let idx = core.index_of(LOGIC).unwrap();
+    assert_eq!(core.domain(idx).unwrap().active_connections, 2);
+}
+
+#[test]
+fn test_failed_op_rolls_back_earlier_ops() {
+    let mut core = core_with(&[(1, 2)]);
+    let before = targets(&core);
+    let mut tx = ConnectionTransaction::new(LOGIC);
+    tx.set_strength((1, 2, 0), 0.1).add(Connection::new(5, 6, LOGIC, 1)).remove((7, 8, 0));
+
+    let err = core.apply_transaction(&tx, |_, _| true).unwrap_err();
+    assert_eq!(err, TransactionError::Missing { index: 2, key: (7, 8, 0) });
+    assert_eq!(targets(&core), before);
+}
+
+#[test]
+fn test_review_veto_rolls_back() {
+    let mut core = core_with(&[(1, 2), (2, 3)]);
+    let before = targets(&core);
+    let mut tx = ConnectionTransaction::new(LOGIC);
+    tx.remove((1, 2, 0)).remove((2, 3, 0));
+
+    let err = core
+        .apply_transaction(&tx, |op, existing| {
+            !matches!(op, TransactionOp::Remove(_)) || existing.is_some_and(|c| c.source_id != 2)
+        })
+        .unwrap_err();
+    assert_eq!(err, TransactionError::Vetoed { index: 1 });
+    assert_eq!(targets(&core), before);
+}
+
+#[test]
+fn test_transaction_validation_errors() {
+    let mut core = core_with(&[(1, 2)]);
+    let mut tx = ConnectionTransaction::new(LOGIC);
+    tx.add(Connection::new(1, 2, LOGIC, 1));
+    let err = core.apply_transaction(&tx, |_, _| true).unwrap_err();
+    assert_eq!(err, TransactionError::Duplicate { index: 0, key: (1, 2, 0) });
+
+    let mut tx = ConnectionTransaction::new(LOGIC);
+    tx.set_strength((1, 2, 0), f32::NAN);
+    let err = core.apply_transaction(&tx, |_, _| true).unwrap_err();
+    assert_eq!(err, TransactionError::InvalidStrength { index: 0 });
+
+    let mut tx = ConnectionTransaction::new(LOGIC);
+    tx.add(Connection::new(3, 4, 109, 1));
+    let err = core.apply_transaction(&tx, |_, _| true).unwrap_err();
+    assert_eq!(err, TransactionError::DomainMismatch { index: 0, domain_id: 109 });
+
+    let tx = ConnectionTransaction::new(999);
+    let err = core.apply_transaction(&tx, |_, _| true).unwrap_err();
+    assert_eq!(err, TransactionError::UnknownDomain(999));
+}
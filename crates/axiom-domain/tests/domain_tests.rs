@@ -887,3 +887,47 @@ fn test_process_frontier_no_collision_when_far_apart() {
         .iter().any(|e| e.event_type == EventType::TokenCollision as u16);
     assert!(!has_collision, "Should not have collision when far apart");
 }
+
+// ============================================================
+// Параллельные связи разных link_type между одной парой
+// ============================================================
+
+fn typed(link_type: u16, strength: f32) -> Connection {
+    let mut c = Connection::new(1, 2, 106, 1);
+    c.link_type = link_type;
+    c.strength = strength;
+    c
+}
+
+#[test]
+fn test_parallel_typed_connections_are_independent() {
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 0));
+    state.add_connection(typed(0x0100, 0.3)).unwrap();
+    state.add_connection(typed(0x0200, 0.8)).unwrap();
+    state.add_connection(Connection::new(2, 1, 106, 1)).unwrap();
+
+    assert_eq!(state.connections_between(1, 2).count(), 2);
+    state.connection_mut(1, 2, 0x0100).unwrap().strength = 0.5;
+    assert_eq!(state.connection(1, 2, 0x0100).unwrap().strength, 0.5);
+    assert_eq!(state.connection(1, 2, 0x0200).unwrap().strength, 0.8);
+    assert!(state.connection(1, 2, 0x0300).is_none());
+}
+
+#[test]
+fn test_pair_strength_aggregates() {
+    use axiom_domain::PairAggregate;
+    use std::collections::HashMap;
+
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 0));
+    state.add_connection(typed(0x0100, 0.25)).unwrap();
+    state.add_connection(typed(0x0200, 0.5)).unwrap();
+
+    assert_eq!(state.pair_strength(1, 2, &PairAggregate::Max), Some(0.5));
+    assert_eq!(state.pair_strength(1, 2, &PairAggregate::Sum), Some(0.75));
+    let weighted = PairAggregate::WeightedSum {
+        weights: HashMap::from([(0x0100, 2.0)]),
+        default: 0.0,
+    };
+    assert_eq!(state.pair_strength(1, 2, &weighted), Some(0.5));
+    assert_eq!(state.pair_strength(2, 1, &PairAggregate::Max), None);
+}
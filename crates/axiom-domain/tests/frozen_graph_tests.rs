@@ -0,0 +1,56 @@
+// Тесты CSR-раскладки связей (FrozenGraph)
+
+use axiom_config::DomainConfig;
+use axiom_core::Connection;
+use axiom_domain::{DomainState, FrozenGraph};
+
+fn targets<'a>(it: impl Iterator<Item = &'a Connection>) -> Vec<u32> {
+    it.map(|c| c.target_id).collect()
+}
+
+fn graph() -> FrozenGraph {
+    let conns = [
+        Connection::new(3, 1, 106, 1),
+        Connection::new(1, 2, 106, 2),
+        Connection::new(3, 4, 106, 3),
+        Connection::new(1, 5, 106, 4),
+    ];
+    FrozenGraph::from_connections(&conns)
+}
+
+#[test]
+fn test_outgoing_is_contiguous_per_source() {
+    let g = graph();
+    assert_eq!(g.source_count(), 2);
+    assert_eq!(targets(g.outgoing(1)), vec![2, 5]);
+    assert_eq!(targets(g.outgoing(3)), vec![1, 4]);
+    assert_eq!(g.out_degree(2), 0);
+    let sources: Vec<u32> = g.iter().map(|c| c.source_id).collect();
+    assert_eq!(sources, vec![1, 1, 3, 3]);
+}
+
+#[test]
+fn test_overlay_visible_before_compact() {
+    let mut g = graph();
+    g.insert(Connection::new(1, 9, 106, 5));
+    g.insert(Connection::new(7, 1, 106, 6));
+    assert_eq!(g.overlay_len(), 2);
+    assert_eq!(targets(g.outgoing(1)), vec![2, 5, 9]);
+    assert_eq!(targets(g.outgoing(7)), vec![1]);
+
+    g.compact();
+    assert_eq!(g.overlay_len(), 0);
+    assert_eq!(g.edge_count(), 6);
+    assert_eq!(g.source_count(), 3);
+    assert_eq!(targets(g.outgoing(1)), vec![2, 5, 9]);
+}
+
+#[test]
+fn test_domain_state_freeze() {
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 1));
+    state.add_connection(Connection::new(2, 1, 106, 1)).unwrap();
+    state.add_connection(Connection::new(1, 3, 106, 2)).unwrap();
+    let g = state.freeze();
+    assert_eq!(g.edge_count(), 2);
+    assert_eq!(targets(g.outgoing(2)), vec![1]);
+}
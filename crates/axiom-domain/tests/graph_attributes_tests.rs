@@ -0,0 +1,81 @@
+// Тесты атрибутов токенов и связей (GraphAttributes)
+
+use axiom_core::Connection;
+use axiom_domain::{edge_id, AttrValue, GraphAttributes};
+
+#[test]
+fn test_node_attrs_set_get_replace() {
+    let mut attrs = GraphAttributes::new();
+    assert!(attrs.is_empty());
+    assert_eq!(attrs.set_node_attr(1, "doc", "paper-42"), None);
+    attrs.set_node_attr(1, "score", 0.5);
+    let previous = attrs.set_node_attr(1, "score", 0.9);
+    assert_eq!(previous, Some(AttrValue::Float(0.5)));
+    assert_eq!(attrs.node_attr(1, "doc").and_then(AttrValue::as_str), Some("paper-42"));
+    assert_eq!(attrs.node_attr(1, "score").and_then(AttrValue::as_float), Some(0.9));
+    let keys: Vec<&str> = attrs.node_attrs(1).into_iter().map(|(k, _)| k).collect();
+    assert_eq!(keys, vec!["doc", "score"]);
+    assert_eq!(attrs.node_attr(2, "doc"), None);
+}
+
+#[test]
+fn test_nodes_where_queries_one_column() {
+    let mut attrs = GraphAttributes::new();
+    attrs.set_node_attr(3, "score", 0.8);
+    attrs.set_node_attr(1, "score", 0.75);
+    attrs.set_node_attr(2, "score", 0.2);
+    attrs.set_node_attr(4, "score", 1_i64);
+    attrs.set_node_attr(5, "score", "high");
+    let strong = attrs.nodes_where("score", |v| v.as_float().is_some_and(|s| s > 0.7));
+    assert_eq!(strong, vec![1, 3, 4]);
+    assert!(attrs.nodes_where("missing", |_| true).is_empty());
+}
+
+#[test]
+fn test_remove_node_and_attr() {
+    let mut attrs = GraphAttributes::new();
+    attrs.set_node_attr(1, "a", true);
+    attrs.set_node_attr(1, "b", 2_i64);
+    attrs.set_node_attr(2, "a", false);
+    assert_eq!(attrs.remove_node_attr(1, "a"), Some(AttrValue::Bool(true)));
+    assert_eq!(attrs.remove_node(1), 1);
+    assert_eq!(attrs.node_attrs(1).len(), 0);
+    assert_eq!(attrs.nodes_where("a", |_| true), vec![2]);
+}
+
+#[test]
+fn test_edge_attrs_by_edge_id() {
+    let mut attrs = GraphAttributes::new();
+    let a = Connection::new(1, 2, 106, 1);
+    let mut b = Connection::new(1, 2, 106, 1);
+    b.link_type = 0x0801;
+    attrs.set_edge_attr(edge_id(&a), "origin", "import");
+    attrs.set_edge_attr(edge_id(&b), "origin", "dream");
+    assert_eq!(edge_id(&b), (106, 1, 2, 0x0801));
+    assert_eq!(
+        attrs.edges_where("origin", |v| v.as_str() == Some("dream")),
+        vec![edge_id(&b)]
+    );
+    assert_eq!(attrs.remove_edge(edge_id(&a)), 1);
+    assert_eq!(attrs.edge_attr(edge_id(&a), "origin"), None);
+    assert!(!attrs.is_empty());
+}
+
+#[test]
+fn test_merge_node_moves_missing_attrs_and_rewires_edges() {
+    let mut attrs = GraphAttributes::new();
+    attrs.set_node_attr(1, "score", 0.9);
+    attrs.set_node_attr(2, "score", 0.1);
+    attrs.set_node_attr(2, "doc", "paper-7");
+    attrs.set_edge_attr((106, 2, 3, 0), "w", 1_i64);
+    attrs.set_edge_attr((106, 1, 2, 0), "w", 2_i64);
+    attrs.set_edge_attr((105, 2, 3, 0), "w", 3_i64);
+
+    assert_eq!(attrs.merge_node(106, 1, 2), 1);
+    assert_eq!(attrs.node_attr(1, "score").and_then(AttrValue::as_float), Some(0.9));
+    assert_eq!(attrs.node_attr(1, "doc").and_then(AttrValue::as_str), Some("paper-7"));
+    assert!(attrs.node_attrs(2).is_empty());
+    assert_eq!(attrs.edge_attr((106, 1, 3, 0), "w"), Some(&AttrValue::Int(1)));
+    // 1→2 стала петлёй 1→1 и снята; другой домен не тронут
+    assert_eq!(attrs.edges_where("w", |_| true), vec![(105, 2, 3, 0), (106, 1, 3, 0)]);
+}
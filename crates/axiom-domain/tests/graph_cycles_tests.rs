@@ -0,0 +1,46 @@
+// Тесты поиска циклов и сильно связных компонент
+
+use axiom_core::{Connection, FLAG_TOMBSTONE};
+use axiom_domain::{find_cycles, strongly_connected_components};
+
+fn edge(source: u32, target: u32) -> Connection {
+    Connection::new(source, target, 106, 1)
+}
+
+#[test]
+fn test_scc_groups_cycle_members() {
+    // Кольцо 1 → 2 → 3 → 1, хвост 3 → 4, отдельное кольцо 5 ⇄ 6
+    let conns = vec![edge(1, 2), edge(2, 3), edge(3, 1), edge(3, 4), edge(5, 6), edge(6, 5)];
+    let components = strongly_connected_components(&conns);
+    assert_eq!(components, vec![vec![1, 2, 3], vec![4], vec![5, 6]]);
+}
+
+#[test]
+fn test_scc_of_dag_is_singletons() {
+    let conns = vec![edge(1, 2), edge(2, 3), edge(1, 3)];
+    assert_eq!(strongly_connected_components(&conns), vec![vec![1], vec![2], vec![3]]);
+}
+
+#[test]
+fn test_find_cycles_once_each_from_smallest() {
+    let conns = vec![edge(2, 3), edge(3, 1), edge(1, 2), edge(3, 2), edge(7, 7)];
+    let cycles = find_cycles(&conns, 8);
+    assert_eq!(cycles, vec![vec![1, 2, 3], vec![2, 3], vec![7]]);
+}
+
+#[test]
+fn test_find_cycles_respects_max_len() {
+    let conns = vec![edge(1, 2), edge(2, 3), edge(3, 4), edge(4, 1), edge(1, 5), edge(5, 1)];
+    assert_eq!(find_cycles(&conns, 2), vec![vec![1, 5]]);
+    assert_eq!(find_cycles(&conns, 4).len(), 2);
+    assert!(find_cycles(&conns, 0).is_empty());
+}
+
+#[test]
+fn test_tombstoned_connection_breaks_cycle() {
+    let mut back = edge(2, 1);
+    back.flags |= FLAG_TOMBSTONE;
+    let conns = vec![edge(1, 2), back];
+    assert!(find_cycles(&conns, 4).is_empty());
+    assert_eq!(strongly_connected_components(&conns).len(), 2);
+}
@@ -0,0 +1,57 @@
+// Тесты разницы двух снимков графа домена (GraphDiff)
+
+use axiom_config::DomainConfig;
+use axiom_core::{Connection, Token};
+use axiom_domain::{DomainState, GraphDiff, Subgraph};
+
+fn state() -> DomainState {
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 1));
+    for id in [1, 2, 3] {
+        state.add_token(Token::new(id, 106, [0, 0, 0], 1)).unwrap();
+    }
+    state.add_connection(Connection::new(1, 2, 106, 1)).unwrap();
+    state.add_connection(Connection::new(2, 3, 106, 1)).unwrap();
+    state
+}
+
+#[test]
+fn test_identical_snapshots_empty_diff() {
+    let s = state();
+    let diff = GraphDiff::between(&Subgraph::whole(&s), &Subgraph::whole(&s));
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn test_diff_reports_added_removed_changed() {
+    let mut s = state();
+    let before = Subgraph::whole(&s);
+
+    s.tokens[0].mass = 200;
+    s.remove_tokens(&[3]);
+    s.add_token(Token::new(4, 106, [0, 0, 0], 1)).unwrap();
+    s.connections[0].strength = 0.25;
+    s.add_connection(Connection::new(1, 4, 106, 2)).unwrap();
+    let after = Subgraph::whole(&s);
+
+    let diff = GraphDiff::between(&before, &after);
+    assert_eq!(diff.tokens_added, vec![4]);
+    assert_eq!(diff.tokens_removed, vec![3]);
+    assert_eq!(diff.tokens_changed.len(), 1);
+    assert_eq!(diff.tokens_changed[0].sutra_id, 1);
+    assert_eq!(diff.tokens_changed[0].fields, vec!["mass"]);
+    assert_eq!(diff.connections_added, vec![(1, 4, 0)]);
+    assert_eq!(diff.connections_removed, vec![(2, 3, 0)]);
+    assert_eq!(diff.connections_changed.len(), 1);
+    assert_eq!(diff.connections_changed[0].key, (1, 2, 0));
+    assert!((diff.connections_changed[0].strength_delta() + 0.75).abs() < 1e-6);
+}
+
+#[test]
+fn test_diff_serializes_to_json() {
+    let s = state();
+    let mut empty = Subgraph::whole(&s);
+    empty.connections.clear();
+    let json = serde_json::to_value(GraphDiff::between(&empty, &Subgraph::whole(&s))).unwrap();
+    assert_eq!(json["connections_added"][0], serde_json::json!([1, 2, 0]));
+    assert!(json["tokens_changed"].as_array().unwrap().is_empty());
+}
@@ -0,0 +1,62 @@
+// Тесты экспорта фрагмента графа в DOT и GraphML
+
+use axiom_core::{Connection, Token, FLAG_INHIBITED};
+use axiom_domain::{ExportFilter, Subgraph, TokenLabels};
+
+fn sample() -> Subgraph {
+    let mut weak = Connection::new(2, 3, 106, 1);
+    weak.strength = 0.1;
+    let mut strong = Connection::new(1, 2, 106, 1);
+    strong.strength = 0.9;
+    strong.link_type = 0x0801;
+    Subgraph {
+        tokens: vec![Token::new(1, 106, [0, 0, 0], 1), Token::new(2, 106, [0, 0, 0], 1)],
+        connections: vec![strong, weak],
+    }
+}
+
+fn render(f: impl FnOnce(&mut Vec<u8>) -> std::io::Result<()>) -> String {
+    let mut buf = Vec::new();
+    f(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_dot_uses_labels_and_edge_attributes() {
+    let mut labels = TokenLabels::new();
+    labels.add(1, "say \"hi\"");
+    let sub = sample();
+    let dot = render(|w| sub.to_dot(Some(&labels), &ExportFilter::default(), w));
+    assert!(dot.starts_with("digraph axiom {"));
+    assert!(dot.contains(r#"1 [label="say \"hi\""];"#));
+    assert!(dot.contains(r#"3 [label="3"];"#), "конец связи без токена — тоже узел");
+    assert!(dot.contains(r#"1 -> 2 [link_type="0x0801", weight=0.9];"#));
+    assert!(dot.contains("2 -> 3"));
+}
+
+#[test]
+fn test_filter_drops_weak_and_inhibited_edges() {
+    let mut sub = sample();
+    let filter = ExportFilter { min_strength: 0.5, ..Default::default() };
+    let dot = render(|w| sub.to_dot(None, &filter, w));
+    assert!(dot.contains("1 -> 2"));
+    assert!(!dot.contains("2 -> 3"));
+    assert!(dot.contains("3 [label"), "узлы не выбрасываются");
+
+    sub.connections[0].flags |= FLAG_INHIBITED;
+    let filter = ExportFilter { skip_inhibited: true, ..Default::default() };
+    assert!(!render(|w| sub.to_dot(None, &filter, w)).contains("1 -> 2"));
+}
+
+#[test]
+fn test_graphml_escapes_and_lists_edges() {
+    let mut labels = TokenLabels::new();
+    labels.add(2, "a<b & c");
+    let sub = sample();
+    let xml = render(|w| sub.to_graphml(Some(&labels), &ExportFilter::default(), w));
+    assert!(xml.contains(r#"<graph id="axiom" edgedefault="directed">"#));
+    assert!(xml.contains(r#"<node id="n2"><data key="label">a&lt;b &amp; c</data></node>"#));
+    assert!(xml.contains(r#"<edge source="n1" target="n2"><data key="link_type">2049</data>"#));
+    assert_eq!(xml.matches("<edge ").count(), 2);
+    assert!(xml.trim_end().ends_with("</graphml>"));
+}
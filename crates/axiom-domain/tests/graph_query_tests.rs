@@ -0,0 +1,91 @@
+// Тесты декларативных запросов к графу домена (GraphQuery)
+
+use axiom_config::DomainConfig;
+use axiom_core::{Connection, Token, TOKEN_FLAG_GOAL};
+use axiom_domain::{DomainState, GraphQuery, GraphQueryError, QueryDirection};
+
+fn edge(source: u32, target: u32, link_type: u16, strength: f32) -> Connection {
+    let mut c = Connection::new(source, target, 106, 1);
+    c.link_type = link_type;
+    c.strength = strength;
+    c
+}
+
+// 1 →(0x0801) 2 →(0x0801) 3 →(0x0801) 4, 1 →(0x0B01) 5, 6 →(0x0801, слабая) 1
+fn state() -> DomainState {
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 1));
+    for id in 1..=6 {
+        let mut t = Token::new(id, 106, [0, 0, 0], 1);
+        if id % 2 == 1 {
+            t.type_flags |= TOKEN_FLAG_GOAL;
+        }
+        state.add_token(t).unwrap();
+    }
+    for c in [
+        edge(1, 2, 0x0801, 0.9),
+        edge(2, 3, 0x0801, 0.9),
+        edge(3, 4, 0x0801, 0.9),
+        edge(1, 5, 0x0B01, 0.9),
+        edge(6, 1, 0x0801, 0.2),
+    ] {
+        state.add_connection(c).unwrap();
+    }
+    state
+}
+
+#[test]
+fn test_hops_and_link_type() {
+    let s = state();
+    assert_eq!(GraphQuery::near(&[1]).run(&s), vec![2, 5, 6]);
+    let typed = GraphQuery::near(&[1]).with_hops(2).with_link_type(0x0801);
+    assert_eq!(typed.run(&s), vec![2, 3, 6]);
+    let category = GraphQuery::near(&[1]).with_hops(3).with_category(0x08);
+    assert_eq!(category.run(&s), vec![2, 3, 4, 6]);
+}
+
+#[test]
+fn test_strength_direction_and_type_flags() {
+    let s = state();
+    let strong = GraphQuery::near(&[1]).with_min_strength(0.5);
+    assert_eq!(strong.run(&s), vec![2, 5]);
+    let incoming = GraphQuery::near(&[1]).with_direction(QueryDirection::Incoming);
+    assert_eq!(incoming.run(&s), vec![6]);
+    let goals = GraphQuery::near(&[1]).with_hops(3).with_type_flags(TOKEN_FLAG_GOAL);
+    assert_eq!(goals.run(&s), vec![3, 5]);
+}
+
+#[test]
+fn test_parse_query_string() {
+    let q: GraphQuery =
+        "near=1,2 hops=2 link_type=0x0801 min_strength=0.7 type_flags=1 direction=out"
+            .parse()
+            .unwrap();
+    let expected = GraphQuery::near(&[1, 2])
+        .with_hops(2)
+        .with_link_type(0x0801)
+        .with_min_strength(0.7)
+        .with_type_flags(1)
+        .with_direction(QueryDirection::Outgoing);
+    assert_eq!(q, expected);
+    assert_eq!(q.run(&state()), vec![3]);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!("hops=2".parse::<GraphQuery>(), Err(GraphQueryError::MissingNear));
+    let unknown = "near=1 color=red".parse::<GraphQuery>();
+    assert!(matches!(unknown, Err(GraphQueryError::UnknownKey(_))));
+    assert!(matches!("near=1 hops".parse::<GraphQuery>(), Err(GraphQueryError::Malformed(_))));
+    let err = "near=1 category=0x1FF".parse::<GraphQuery>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid value '0x1FF' for 'category'");
+}
+
+#[test]
+fn test_as_of_limits_traversal() {
+    let mut s = state();
+    s.connections[0].set_valid_to(5);
+    let q: GraphQuery = "near=1 as_of=10".parse().unwrap();
+    assert_eq!(q, GraphQuery::near(&[1]).with_as_of(10));
+    assert_eq!(q.run(&s), vec![5, 6]);
+    assert_eq!(GraphQuery::near(&[1]).with_as_of(3).run(&s), vec![2, 5, 6]);
+}
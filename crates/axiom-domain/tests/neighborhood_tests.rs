@@ -0,0 +1,65 @@
+// Тесты ленивого обхода окрестности токена
+
+use axiom_core::Connection;
+use axiom_domain::{DomainConfig, DomainState, NeighborhoodConfig, QueryDirection};
+
+fn state_with(edges: &[(u32, u32, u16)]) -> DomainState {
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 1));
+    for &(s, t, link_type) in edges {
+        let mut c = Connection::new(s, t, 106, 1);
+        c.link_type = link_type;
+        state.add_connection(c).unwrap();
+    }
+    state
+}
+
+#[test]
+fn test_neighborhood_bfs_order_with_depth() {
+    // 1 → 2 → 3 → 4, 5 → 1
+    let state = state_with(&[(1, 2, 0), (2, 3, 0), (3, 4, 0), (5, 1, 0)]);
+    let found: Vec<(u32, usize)> =
+        state.neighborhood(1, 2, NeighborhoodConfig::default()).collect();
+    assert_eq!(found, vec![(2, 1), (5, 1), (3, 2)]);
+}
+
+#[test]
+fn test_neighborhood_direction_and_type_filters() {
+    let state = state_with(&[(1, 2, 0x0101), (1, 3, 0x0202), (4, 1, 0x0101)]);
+    let outgoing = NeighborhoodConfig { direction: QueryDirection::Outgoing, ..Default::default() };
+    let ids: Vec<u32> = state.neighborhood(1, 1, outgoing).map(|(id, _)| id).collect();
+    assert_eq!(ids, vec![2, 3]);
+
+    let typed = NeighborhoodConfig { link_type: Some(0x0101), ..Default::default() };
+    let ids: Vec<u32> = state.neighborhood(1, 1, typed).map(|(id, _)| id).collect();
+    assert_eq!(ids, vec![2, 4]);
+}
+
+#[test]
+fn test_neighborhood_budgets_stop_hub_walk() {
+    let edges: Vec<(u32, u32, u16)> = (2..100).map(|t| (1, t, 0)).collect();
+    let state = state_with(&edges);
+
+    let config = NeighborhoodConfig { max_nodes: 5, ..Default::default() };
+    let mut walk = state.neighborhood(1, 3, config);
+    assert_eq!(walk.by_ref().count(), 5);
+    assert!(walk.truncated());
+
+    let config = NeighborhoodConfig { max_edges: 10, ..Default::default() };
+    let mut walk = state.neighborhood(1, 3, config);
+    assert_eq!(walk.by_ref().count(), 10);
+    assert!(walk.truncated());
+    assert_eq!(walk.edges_visited(), 10);
+}
+
+#[test]
+fn test_neighborhood_is_lazy() {
+    // Цепочка 1 → 2 → … → 50: первый сосед не требует обхода всей цепочки
+    let edges: Vec<(u32, u32, u16)> = (1..50).map(|s| (s, s + 1, 0)).collect();
+    let state = state_with(&edges);
+    let config = NeighborhoodConfig { direction: QueryDirection::Outgoing, ..Default::default() };
+    let mut walk = state.neighborhood(1, 100, config);
+    assert_eq!(walk.next(), Some((2, 1)));
+    assert_eq!(walk.edges_visited(), 1);
+    assert!(!walk.truncated());
+    assert_eq!(walk.count(), 48);
+}
@@ -0,0 +1,68 @@
+// Тесты взвешенного поиска пути по связям (Dijkstra / A*)
+
+use axiom_core::{Connection, Token, FLAG_INHIBITED};
+use axiom_domain::{a_star, position_heuristic, shortest_path, strength_cost};
+
+fn edge(source: u32, target: u32, strength: f32) -> Connection {
+    let mut c = Connection::new(source, target, 106, 1);
+    c.strength = strength;
+    c
+}
+
+// 1 → 2 → 4 сильные (стоимость 0.1 + 0.1), 1 → 4 напрямую слабая (0.9),
+// 1 → 3 → 4 средние (0.5 + 0.5)
+fn graph() -> Vec<Connection> {
+    vec![edge(1, 2, 0.9), edge(2, 4, 0.9), edge(1, 4, 0.1), edge(1, 3, 0.5), edge(3, 4, 0.5)]
+}
+
+#[test]
+fn test_dijkstra_prefers_strong_chain() {
+    let conns = graph();
+    let path = shortest_path(&conns, 1, 4, strength_cost).unwrap();
+    assert_eq!(path.nodes, vec![1, 2, 4]);
+    assert_eq!(path.connections, vec![0, 1]);
+    assert!((path.cost - 0.2).abs() < 1e-5);
+}
+
+#[test]
+fn test_trivial_and_missing_paths() {
+    let conns = graph();
+    let same = shortest_path(&conns, 3, 3, strength_cost).unwrap();
+    assert_eq!(same.nodes, vec![3]);
+    assert_eq!(same.cost, 0.0);
+    assert!(shortest_path(&conns, 4, 1, strength_cost).is_none(), "связи направленные");
+}
+
+#[test]
+fn test_impassable_edges_are_skipped() {
+    let mut conns = graph();
+    conns[1].flags |= FLAG_INHIBITED;
+    let path = shortest_path(&conns, 1, 4, strength_cost).unwrap();
+    assert_eq!(path.nodes, vec![1, 4]);
+}
+
+#[test]
+fn test_a_star_matches_dijkstra_with_admissible_heuristic() {
+    let conns = graph();
+    let tokens: Vec<Token> = [(1, 0), (2, 10), (3, 5), (4, 20)]
+        .into_iter()
+        .map(|(id, x)| Token::new(id, 106, [x, 0, 0], 1))
+        .collect();
+    // Минимальная стоимость на единицу расстояния: 0.1 / 10 = 0.01
+    let h = position_heuristic(&tokens, 4, 0.01);
+    assert!((h(1) - 0.2).abs() < 1e-5);
+    assert_eq!(h(99), 0.0);
+
+    let a = a_star(&conns, 1, 4, strength_cost, h).unwrap();
+    let d = shortest_path(&conns, 1, 4, strength_cost).unwrap();
+    assert_eq!(a.nodes, d.nodes);
+    assert!((a.cost - d.cost).abs() < 1e-5);
+}
+
+#[test]
+fn test_custom_cost_counts_hops() {
+    let conns = graph();
+    let path = shortest_path(&conns, 1, 4, |_| Some(1.0)).unwrap();
+    assert_eq!(path.nodes, vec![1, 4]);
+    assert_eq!(path.cost, 1.0);
+}
@@ -0,0 +1,97 @@
+// Тесты StrengthNormalization — гомеостаз исходящих связей
+
+use axiom_core::Connection;
+use axiom_domain::strength_norm::MIN_NORMALIZED_STRENGTH;
+use axiom_domain::{NormalizationMode, StrengthNormalization};
+
+fn conn(source: u32, target: u32, link_type: u16, strength: f32) -> Connection {
+    let mut c = Connection::new(source, target, 106, 1);
+    c.link_type = link_type;
+    c.strength = strength;
+    c
+}
+
+fn sum_from(conns: &[Connection], source: u32) -> f32 {
+    conns
+        .iter()
+        .filter(|c| c.source_id == source)
+        .map(|c| c.strength)
+        .sum()
+}
+
+#[test]
+fn test_empty_policy_is_noop() {
+    let mut conns = vec![conn(1, 2, 0, 5.0), conn(1, 3, 0, 5.0)];
+    assert_eq!(StrengthNormalization::default().apply(&mut conns), 0);
+    assert_eq!(conns[0].strength, 5.0);
+}
+
+#[test]
+fn test_sum_to_cap_preserves_ratios() {
+    let mut conns = vec![conn(1, 2, 0, 6.0), conn(1, 3, 0, 2.0)];
+    let policy = StrengthNormalization::uniform(NormalizationMode::SumToCap { cap: 2.0 });
+    assert_eq!(policy.apply(&mut conns), 1);
+    assert!((sum_from(&conns, 1) - 2.0).abs() < 1e-5);
+    assert!((conns[0].strength / conns[1].strength - 3.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_group_under_cap_untouched() {
+    let mut conns = vec![conn(1, 2, 0, 0.3), conn(1, 3, 0, 0.2)];
+    let policy = StrengthNormalization::uniform(NormalizationMode::SumToCap { cap: 1.0 });
+    assert_eq!(policy.apply(&mut conns), 0);
+    assert_eq!(conns[0].strength, 0.3);
+    assert_eq!(conns[1].strength, 0.2);
+}
+
+#[test]
+fn test_groups_split_by_source() {
+    let mut conns = vec![conn(1, 2, 0, 4.0), conn(1, 3, 0, 4.0), conn(7, 2, 0, 0.5)];
+    let policy = StrengthNormalization::uniform(NormalizationMode::SumToCap { cap: 1.0 });
+    assert_eq!(policy.apply(&mut conns), 1);
+    assert_eq!(conns[2].strength, 0.5, "источник 7 не насыщен");
+}
+
+#[test]
+fn test_softmax_distributes_cap() {
+    let mut conns = vec![conn(1, 2, 0, 3.0), conn(1, 3, 0, 1.0), conn(1, 4, 0, 1.0)];
+    let policy = StrengthNormalization::uniform(NormalizationMode::Softmax {
+        cap: 1.0,
+        temperature: 1.0,
+    });
+    policy.apply(&mut conns);
+    assert!((sum_from(&conns, 1) - 1.0).abs() < 1e-5);
+    assert!(conns[0].strength > conns[1].strength);
+    assert!((conns[1].strength - conns[2].strength).abs() < 1e-6);
+}
+
+#[test]
+fn test_per_link_type_override() {
+    let mut conns = vec![
+        conn(1, 2, 0x08, 4.0),
+        conn(1, 3, 0x08, 4.0),
+        conn(1, 4, 0x09, 4.0),
+        conn(1, 5, 0x09, 4.0),
+    ];
+    let mut policy = StrengthNormalization::default();
+    policy
+        .per_link_type
+        .insert(0x08, NormalizationMode::SumToCap { cap: 1.0 });
+    assert_eq!(policy.apply(&mut conns), 1);
+    assert!((conns[0].strength + conns[1].strength - 1.0).abs() < 1e-5);
+    assert_eq!(
+        conns[2].strength, 4.0,
+        "link_type без политики не трогается"
+    );
+}
+
+#[test]
+fn test_strength_stays_positive() {
+    let mut conns = vec![conn(1, 2, 0, 100.0), conn(1, 3, 0, 1e-3)];
+    let policy = StrengthNormalization::uniform(NormalizationMode::Softmax {
+        cap: 1.0,
+        temperature: 0.01,
+    });
+    policy.apply(&mut conns);
+    assert!(conns[1].strength >= MIN_NORMALIZED_STRENGTH);
+}
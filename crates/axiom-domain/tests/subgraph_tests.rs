@@ -0,0 +1,108 @@
+// Тесты извлечения и слияния фрагментов графа (Subgraph)
+
+use axiom_core::{Connection, Token};
+use axiom_domain::{AshtiCore, EdgeConflict, NodeMapping, Subgraph};
+
+const LOGIC: u16 = 106;
+const MAYA: u16 = 110;
+
+fn edge(source: u32, target: u32, domain_id: u16, strength: f32) -> Connection {
+    let mut c = Connection::new(source, target, domain_id, 1);
+    c.strength = strength;
+    c
+}
+
+// Цепочка 1 — 2 — 3 — 4 плюс отдельная пара 10 → 11
+fn chain_core() -> AshtiCore {
+    let mut core = AshtiCore::new(1);
+    for id in [1, 2, 3, 4, 10, 11] {
+        core.inject_token(LOGIC, Token::new(id, LOGIC, [id as i16, 0, 0], 1)).unwrap();
+    }
+    for (s, t) in [(1, 2), (3, 2), (3, 4), (10, 11)] {
+        core.inject_connection(LOGIC, edge(s, t, LOGIC, 0.5)).unwrap();
+    }
+    core
+}
+
+fn ids(sub: &Subgraph) -> Vec<u32> {
+    sub.tokens.iter().map(|t| t.sutra_id).collect()
+}
+
+#[test]
+fn test_extract_k_hop_ignores_direction() {
+    let core = chain_core();
+    let one = core.extract_subgraph(LOGIC, &[1], 1).unwrap();
+    assert_eq!(ids(&one), vec![1, 2]);
+    assert_eq!(one.connections.len(), 1);
+
+    let two = core.extract_subgraph(LOGIC, &[1], 2).unwrap();
+    assert_eq!(ids(&two), vec![1, 2, 3]);
+    assert_eq!(two.connections.len(), 2, "3 → 4 выходит за окрестность");
+
+    assert_eq!(ids(&core.extract_subgraph(LOGIC, &[1], 0).unwrap()), vec![1]);
+    assert!(core.extract_subgraph(999, &[1], 1).is_none());
+}
+
+#[test]
+fn test_merge_fresh_remaps_ids_into_other_runtime() {
+    let sub = chain_core().extract_subgraph(LOGIC, &[1], 2).unwrap();
+    let mut buf = Vec::new();
+    sub.write(&mut buf).unwrap();
+    let sub = Subgraph::read(&mut buf.as_slice()).unwrap();
+
+    let mut target = AshtiCore::new(1);
+    target.inject_token(MAYA, Token::new(7, MAYA, [0, 0, 0], 1)).unwrap();
+    let report = target.merge_subgraph(MAYA, &sub, NodeMapping::Fresh, EdgeConflict::default());
+    let report = report.unwrap();
+    assert_eq!(report.tokens_added, 3);
+    assert_eq!(report.connections_added, 2);
+    assert_eq!((report.id_map[&1], report.id_map[&2], report.id_map[&3]), (8, 9, 10));
+
+    let state = target.state(target.index_of(MAYA).unwrap()).unwrap();
+    assert_eq!(state.token_count(), 4);
+    assert!(state.tokens.iter().all(|t| t.domain_id == MAYA));
+    let c = state.connection(10, 9, 0).unwrap();
+    assert_eq!(c.domain_id, MAYA);
+}
+
+#[test]
+fn test_merge_identity_resolves_edge_conflicts() {
+    let mut sub = chain_core().extract_subgraph(LOGIC, &[1], 1).unwrap();
+    sub.connections[0].strength = 0.9;
+
+    let mut keep = chain_core();
+    let report =
+        keep.merge_subgraph(LOGIC, &sub, NodeMapping::Identity, EdgeConflict::KeepExisting);
+    let report = report.unwrap();
+    assert_eq!((report.tokens_added, report.connections_updated), (0, 0));
+    let state = keep.state(keep.index_of(LOGIC).unwrap()).unwrap();
+    assert_eq!(state.connection(1, 2, 0).unwrap().strength, 0.5);
+
+    let mut strongest = chain_core();
+    let report = strongest
+        .merge_subgraph(LOGIC, &sub, NodeMapping::Identity, EdgeConflict::Strongest)
+        .unwrap();
+    assert_eq!(report.connections_updated, 1);
+    let state = strongest.state(strongest.index_of(LOGIC).unwrap()).unwrap();
+    assert_eq!(state.connection(1, 2, 0).unwrap().strength, 0.9);
+    assert_eq!(state.connection_count(), 4);
+}
+
+#[test]
+fn test_as_of_keeps_connections_valid_at_moment() {
+    let mut closed = edge(1, 2, LOGIC, 0.5);
+    closed.set_valid_to(50);
+    let late = Connection::new(2, 3, LOGIC, 80);
+    let sub = Subgraph { tokens: Vec::new(), connections: vec![closed, late] };
+
+    let ends = |s: &Subgraph| s.connections.iter().map(|c| c.target_id).collect::<Vec<_>>();
+    assert_eq!(ends(&sub.as_of(10)), vec![2]);
+    assert!(ends(&sub.as_of(60)).is_empty());
+    assert_eq!(ends(&sub.as_of(90)), vec![3]);
+
+    let mut core = AshtiCore::new(1);
+    core.inject_connection(LOGIC, closed).unwrap();
+    core.inject_connection(LOGIC, late).unwrap();
+    let state = core.state(core.index_of(LOGIC).unwrap()).unwrap();
+    assert_eq!(state.connections_as_of(90).count(), 1);
+}
@@ -0,0 +1,51 @@
+use axiom_core::Token;
+use axiom_domain::TokenHistory;
+
+#[test]
+fn test_history_is_bounded_ring() {
+    let mut history = TokenHistory::new(2);
+    let mut t = Token::new(1, 100, [0, 0, 0], 1);
+    for x in 1..=3 {
+        t.position[0] = x;
+        assert!(history.record(&t));
+    }
+    let xs: Vec<i16> = history.generations(100, 1).map(|g| g.position[0]).collect();
+    assert_eq!(xs, vec![2, 3]);
+    assert_eq!(history.previous(100, 1, 0).unwrap().position[0], 3);
+    assert_eq!(history.previous(100, 1, 1).unwrap().position[0], 2);
+    assert!(history.previous(100, 1, 2).is_none());
+}
+
+#[test]
+fn test_unchanged_token_not_recorded_twice() {
+    let mut history = TokenHistory::new(4);
+    let t = Token::new(1, 100, [0, 0, 0], 1);
+    assert!(history.record(&t));
+    assert!(!history.record(&t));
+    assert_eq!(history.generations(100, 1).count(), 1);
+
+    history.forget(100, 1);
+    assert_eq!(history.tracked(), 0);
+}
+
+#[test]
+fn test_absorb_prepends_generations_of_merged_token() {
+    let mut history = TokenHistory::new(3);
+    let mut kept = Token::new(1, 100, [0, 0, 0], 1);
+    let mut absorbed = Token::new(2, 100, [0, 0, 0], 1);
+    for x in [10, 20] {
+        absorbed.position[0] = x;
+        history.record(&absorbed);
+    }
+    for x in [1, 2] {
+        kept.position[0] = x;
+        history.record(&kept);
+    }
+    history.absorb(100, 2, 1);
+
+    let xs: Vec<i16> = history.generations(100, 1).map(|g| g.position[0]).collect();
+    assert_eq!(xs, vec![20, 1, 2], "старейшее поколение поглощённого отброшено");
+    assert!(history.generations(100, 1).all(|g| g.sutra_id == 1));
+    assert_eq!(history.generations(100, 2).count(), 0);
+    assert_eq!(history.tracked(), 1);
+}
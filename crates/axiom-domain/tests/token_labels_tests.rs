@@ -0,0 +1,53 @@
+use axiom_domain::TokenLabels;
+
+#[test]
+fn test_labels_roundtrip_and_inverted_index() {
+    let mut labels = TokenLabels::new();
+    assert!(labels.add(1, "кот"));
+    assert!(labels.add(1, "кошка"));
+    assert!(!labels.add(1, "кот"), "повтор метки не добавляется");
+    assert!(labels.add(2, "кошка"));
+
+    assert_eq!(labels.labels(1), ["кот", "кошка"]);
+    assert_eq!(labels.primary(1), Some("кот"));
+    assert_eq!(labels.find_by_label("кошка"), [1, 2]);
+    assert!(labels.find_by_label("пёс").is_empty());
+}
+
+#[test]
+fn test_remove_token_cleans_index() {
+    let mut labels = TokenLabels::new();
+    labels.add(1, "кот");
+    labels.add(2, "кот");
+    assert_eq!(labels.remove_token(1), 1);
+    assert_eq!(labels.find_by_label("кот"), [2]);
+    labels.remove_token(2);
+    assert!(labels.find_by_label("кот").is_empty());
+    assert!(labels.is_empty());
+}
+
+#[test]
+fn test_remove_single_label() {
+    let mut labels = TokenLabels::new();
+    labels.add(1, "apple");
+    labels.add(1, "fruit");
+    assert!(labels.remove_label(1, "apple"));
+    assert!(!labels.remove_label(1, "apple"));
+    assert_eq!(labels.labels(1), ["fruit".to_string()]);
+    assert!(labels.find_by_label("apple").is_empty());
+    assert!(labels.remove_label(1, "fruit"));
+    assert!(labels.is_empty());
+}
+
+#[test]
+fn test_merge_token_moves_labels() {
+    let mut labels = TokenLabels::new();
+    labels.add(1, "кот");
+    labels.add(2, "кот");
+    labels.add(2, "котик");
+    assert_eq!(labels.merge_token(1, 2), 1, "«кот» у 1 уже есть");
+    assert_eq!(labels.labels(1), ["кот", "котик"]);
+    assert!(labels.labels(2).is_empty());
+    assert_eq!(labels.find_by_label("кот"), [1]);
+    assert_eq!(labels.merge_token(1, 1), 0);
+}
@@ -10,6 +10,7 @@ mod http;
 mod lab;
 mod shutdown;
 mod startup;
+mod systemd;
 mod tick;
 
 use anyhow::{Context, Result};
@@ -20,6 +21,7 @@ use axiom_broadcasting::{BroadcastingConfig, BroadcastServer};
 
 use crate::config::NodeConfig;
 use crate::shutdown::ShutdownSignal;
+use crate::systemd::SdNotify;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -68,7 +70,7 @@ async fn main() -> Result<()> {
     let shutdown = ShutdownSignal::new();
     shutdown.spawn_listener();
 
-    // 5. Tick loop (blocks until shutdown)
+    // 5. Tick loop (blocks until shutdown); READY/WATCHDOG/STOPPING → systemd
     tick::run(
         state.engine,
         state.auto_saver,
@@ -77,6 +79,7 @@ async fn main() -> Result<()> {
         &cfg,
         shutdown,
         cmd_rx,
+        SdNotify::from_env(),
     )
     .await;
 
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// systemd — sd_notify без libsystemd.
+//
+// Под `Type=notify` systemd передаёт путь датаграммного сокета в NOTIFY_SOCKET
+// и, если задан WatchdogSec=, интервал в WATCHDOG_USEC. Узел сообщает:
+//   READY=1     — движок собран, серверы подняты, tick loop стартует;
+//   WATCHDOG=1  — из tick loop: зависший цикл перестаёт слать пинг и
+//                 systemd перезапускает сервис;
+//   STOPPING=1  — начат graceful shutdown (сохранение состояния).
+// Без NOTIFY_SOCKET все вызовы — no-op. Если WATCHDOG_PID задан и не
+// совпадает с pid процесса, watchdog адресован не нам и выключен.
+//
+// Пример unit:
+//   [Service]
+//   Type=notify
+//   ExecStart=/usr/local/bin/axiom-node --data-dir /var/lib/axiom
+//   WatchdogSec=30
+//   KillSignal=SIGTERM
+
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+pub struct SdNotify {
+    #[cfg(unix)]
+    socket: Option<(std::os::unix::net::UnixDatagram, String)>,
+    /// Период пинга watchdog (половина WATCHDOG_USEC); None — watchdog выключен
+    watchdog_period: Option<Duration>,
+    last_ping: Instant,
+}
+
+impl SdNotify {
+    /// Прочитать NOTIFY_SOCKET / WATCHDOG_USEC / WATCHDOG_PID из окружения.
+    pub fn from_env() -> Self {
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let watchdog_for_us = var("WATCHDOG_PID")
+            .is_none_or(|pid| pid.parse::<u32>().ok() == Some(std::process::id()));
+        let watchdog_period = var("WATCHDOG_USEC")
+            .filter(|_| watchdog_for_us)
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|&us| us > 0)
+            .map(|us| Duration::from_micros(us / 2));
+
+        Self {
+            #[cfg(unix)]
+            socket: var("NOTIFY_SOCKET").and_then(|path| {
+                std::os::unix::net::UnixDatagram::unbound()
+                    .map(|s| (s, path))
+                    .map_err(|e| warn!("sd_notify: cannot create socket: {e}"))
+                    .ok()
+            }),
+            watchdog_period,
+            last_ping: Instant::now(),
+        }
+    }
+
+    pub fn ready(&self) {
+        self.send("READY=1");
+    }
+
+    pub fn stopping(&self) {
+        self.send("STOPPING=1");
+    }
+
+    /// Вызывается каждый тик; пингует watchdog не чаще watchdog_period.
+    pub fn watchdog_tick(&mut self) {
+        let Some(period) = self.watchdog_period else {
+            return;
+        };
+        if self.last_ping.elapsed() >= period {
+            self.send("WATCHDOG=1");
+            self.last_ping = Instant::now();
+        }
+    }
+
+    #[cfg(unix)]
+    fn send(&self, state: &str) {
+        let Some((socket, path)) = &self.socket else {
+            return;
+        };
+        let result = match path.strip_prefix('@') {
+            #[cfg(target_os = "linux")]
+            Some(name) => {
+                use std::os::linux::net::SocketAddrExt;
+                std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())
+                    .and_then(|addr| socket.send_to_addr(state.as_bytes(), &addr))
+            }
+            _ => socket.send_to(state.as_bytes(), path),
+        };
+        if let Err(e) = result {
+            warn!("sd_notify {state}: {e}");
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn send(&self, _state: &str) {}
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixDatagram;
+
+    fn recv(socket: &UnixDatagram) -> String {
+        let mut buf = [0u8; 64];
+        let n = socket.recv(&mut buf).unwrap();
+        String::from_utf8_lossy(&buf[..n]).into_owned()
+    }
+
+    #[test]
+    fn test_notify_socket_receives_ready_watchdog_stopping() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notify.sock");
+        let server = UnixDatagram::bind(&path).unwrap();
+        server.set_read_timeout(Some(Duration::from_secs(1))).unwrap();
+
+        std::env::set_var("NOTIFY_SOCKET", &path);
+        std::env::set_var("WATCHDOG_USEC", "2");
+        std::env::set_var("WATCHDOG_PID", std::process::id().to_string());
+        let mut notify = SdNotify::from_env();
+        std::env::remove_var("NOTIFY_SOCKET");
+        std::env::remove_var("WATCHDOG_USEC");
+        std::env::remove_var("WATCHDOG_PID");
+
+        notify.ready();
+        assert_eq!(recv(&server), "READY=1");
+        std::thread::sleep(Duration::from_millis(1));
+        notify.watchdog_tick();
+        assert_eq!(recv(&server), "WATCHDOG=1");
+        notify.stopping();
+        assert_eq!(recv(&server), "STOPPING=1");
+    }
+
+    #[test]
+    fn test_watchdog_pid_of_other_process_disables_watchdog() {
+        let own = std::process::id().to_string();
+        let other = (std::process::id() + 1).to_string();
+        let vars = |pid: Option<String>| {
+            move |key: &str| match key {
+                "WATCHDOG_USEC" => Some("30000000".to_string()),
+                "WATCHDOG_PID" => pid.clone(),
+                _ => None,
+            }
+        };
+        assert!(SdNotify::from_vars(vars(Some(other))).watchdog_period.is_none());
+        assert_eq!(
+            SdNotify::from_vars(vars(Some(own))).watchdog_period,
+            Some(Duration::from_secs(15))
+        );
+        assert!(SdNotify::from_vars(vars(None)).watchdog_period.is_some());
+    }
+
+    #[test]
+    fn test_without_notify_socket_calls_are_noop() {
+        let mut notify = SdNotify::from_vars(|_| None);
+        assert!(notify.socket.is_none());
+        notify.ready();
+        notify.watchdog_tick();
+        notify.stopping();
+    }
+}
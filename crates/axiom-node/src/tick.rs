@@ -23,6 +23,7 @@ use crate::commands::handle_engine_command;
 use crate::config::NodeConfig;
 use crate::http::NodeCmd;
 use crate::shutdown::ShutdownSignal;
+use crate::systemd::SdNotify;
 
 struct NodePerfTracker {
     start: std::time::Instant,
@@ -76,6 +77,7 @@ impl NodePerfTracker {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     mut engine: AxiomEngine,
     mut auto_saver: AutoSaver,
@@ -84,6 +86,7 @@ pub async fn run(
     cfg: &NodeConfig,
     shutdown: ShutdownSignal,
     mut cmd_rx: tokio::sync::mpsc::UnboundedReceiver<NodeCmd>,
+    mut notify: SdNotify,
 ) {
     let tick_cmd = UclCommand::new(OpCode::TickForward, 0, 100, 0);
     let base_tick_ms = 1000u64 / cfg.tick_hz.max(1) as u64;
@@ -101,11 +104,13 @@ pub async fn run(
         tick_hz = cfg.tick_hz,
         addr = cfg.addr,
     );
+    notify.ready();
 
     loop {
         // Выход по сигналу
         if shutdown.is_triggered() {
             info!("shutdown signal received — saving state");
+            notify.stopping();
             let _ = auto_saver.force_save(&engine, Path::new(&cfg.data_dir));
             break;
         }
@@ -117,6 +122,7 @@ pub async fn run(
             base_tick_ms
         };
         tokio::time::sleep(Duration::from_millis(sleep_ms)).await;
+        notify.watchdog_tick();
 
         // 1. Drain EngineCommand от Workstation (binary WS)
         let mut had_commands = false;
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// TokenArena — токены в mmap-файле в нативной 64-байтовой раскладке.
+//
+// bincode-загрузка 10M+ токенов держит в памяти и закодированный буфер,
+// и декодированный Vec<Token> — пиковое потребление вдвое выше рабочего.
+// Arena-файл хранит Token как есть (repr(C, align(64)), без padding, все поля —
+// целые), поэтому отображённые страницы читаются как &[Token] без копирования,
+// а ОС подгружает их по мере обращения.
+//
+// Формат: заголовок 64 байта (MAGIC, ARENA_VERSION, count), затем count × 64 байта
+// little-endian. Заголовок ровно в одну кеш-линию — данные выровнены на 64
+// относительно page-aligned начала отображения.
+
+use crate::error::PersistError;
+use axiom_core::Token;
+use memmap2::MmapMut;
+use std::fs::OpenOptions;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Сигнатура arena-файла.
+pub const ARENA_MAGIC: [u8; 8] = *b"AXTOKARN";
+
+/// Версия формата arena-файла.
+pub const ARENA_VERSION: u32 = 1;
+
+const HEADER_LEN: usize = 64;
+const TOKEN_LEN: usize = std::mem::size_of::<Token>();
+
+const _: () = assert!(TOKEN_LEN == 64 && std::mem::align_of::<Token>() == HEADER_LEN);
+
+/// Отображённый в память массив токенов.
+pub struct TokenArena {
+    map: MmapMut,
+    len: usize,
+}
+
+impl TokenArena {
+    /// Записать токены в новый arena-файл и открыть его.
+    pub fn create(path: &Path, tokens: &[Token]) -> Result<Self, PersistError> {
+        let file = std::fs::File::create(path)?;
+        let mut w = BufWriter::new(file);
+        let mut header = [0u8; HEADER_LEN];
+        header[0..8].copy_from_slice(&ARENA_MAGIC);
+        header[8..12].copy_from_slice(&ARENA_VERSION.to_le_bytes());
+        header[16..24].copy_from_slice(&(tokens.len() as u64).to_le_bytes());
+        w.write_all(&header)?;
+        for t in tokens {
+            w.write_all(&t.to_le_bytes())?;
+        }
+        w.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        Self::open(path)
+    }
+
+    /// Открыть существующий arena-файл (чтение и запись на месте).
+    pub fn open(path: &Path) -> Result<Self, PersistError> {
+        if !path.exists() {
+            return Err(PersistError::NotFound(path.display().to_string()));
+        }
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        // SAFETY: файл открыт этим процессом; внешнее изменение файла во время
+        // работы arena не поддерживается (как и для остальных файлов хранилища).
+        let map = unsafe { MmapMut::map_mut(&file)? };
+
+        if map.len() < HEADER_LEN || map[0..8] != ARENA_MAGIC {
+            return Err(PersistError::Decode("token arena: bad magic".into()));
+        }
+        let version = u32::from_le_bytes(map[8..12].try_into().unwrap());
+        if version != ARENA_VERSION {
+            return Err(PersistError::Decode(format!(
+                "token arena: version {version}, expected {ARENA_VERSION}"
+            )));
+        }
+        let len = u64::from_le_bytes(map[16..24].try_into().unwrap()) as usize;
+        let expected = len.checked_mul(TOKEN_LEN).and_then(|b| b.checked_add(HEADER_LEN));
+        if expected != Some(map.len()) {
+            return Err(PersistError::Decode(format!(
+                "token arena: {} bytes for {len} tokens",
+                map.len()
+            )));
+        }
+        Ok(Self { map, len })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Все токены без копирования.
+    pub fn as_slice(&self) -> &[Token] {
+        // SAFETY: размер проверен в open(); начало отображения выровнено на
+        // страницу, заголовок — 64 байта, значит данные выровнены под Token.
+        // Token — repr(C) без padding из одних целых: любой битовый образ валиден.
+        unsafe { std::slice::from_raw_parts(self.map[HEADER_LEN..].as_ptr().cast(), self.len) }
+    }
+
+    /// Все токены для изменения на месте (сохраняются через `flush`).
+    pub fn as_mut_slice(&mut self) -> &mut [Token] {
+        // SAFETY: см. as_slice; &mut self исключает другие ссылки на отображение.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.map[HEADER_LEN..].as_mut_ptr().cast(),
+                self.len,
+            )
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Token> {
+        self.as_slice().get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Token> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Сбросить изменения на диск.
+    pub fn flush(&self) -> Result<(), PersistError> {
+        self.map.flush()?;
+        Ok(())
+    }
+}
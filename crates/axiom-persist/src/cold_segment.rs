@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ColdSegment — сжатый ярус событий между EventRing и архивом.
+//
+// Большое горячее окно упирается в память: 64 байта на событие. События,
+// вытесненные из EventRing, копятся в блоки по `block_events` штук и сжимаются
+// deflate — у Event много нулевых полей, и блок ужимается в разы. Доступ по
+// порядковому номеру события сохраняется: читается и распаковывается только
+// нужный блок. Ярус живёт в памяти и ограничен `max_blocks` блоками: сверх
+// лимита старейший блок отбрасывается. Блоки, переданные в архив раньше,
+// снимаются через `drop_before`.
+//
+// Блок сжимается при записи следующего за ним события и до того, как событие
+// принято: ошибка сжатия возвращается вызывающему, ярус не меняется.
+//
+// TieredEvents склеивает оба яруса: горячее окно в mmap-файле и холодный
+// сегмент перед ним, с общей нумерацией событий. TieredEventsObserver пишет
+// в него события Event Bus.
+
+use crate::error::PersistError;
+use crate::event_ring::EventRing;
+use axiom_core::Event;
+use axiom_runtime::EventObserver;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::rc::Rc;
+
+/// Событий в блоке холодного яруса по умолчанию.
+pub const DEFAULT_BLOCK_EVENTS: usize = 256;
+
+/// Сжатых блоков в холодном ярусе по умолчанию (1M событий при 256 в блоке).
+pub const DEFAULT_MAX_COLD_BLOCKS: usize = 4096;
+
+const EVENT_LEN: usize = std::mem::size_of::<Event>();
+
+/// Сжатый блок подряд идущих событий.
+struct ColdBlock {
+    first_seq: u64,
+    len: usize,
+    bytes: Vec<u8>,
+}
+
+/// Холодный ярус: сжатые блоки и несжатый хвост, ещё не набравший блок.
+pub struct ColdSegment {
+    block_events: usize,
+    max_blocks: usize,
+    first_seq: u64,
+    blocks: VecDeque<ColdBlock>,
+    pending: Vec<Event>,
+    evicted: u64,
+}
+
+impl ColdSegment {
+    /// Пустой ярус, первое событие которого будет иметь номер `first_seq`;
+    /// не больше DEFAULT_MAX_COLD_BLOCKS блоков.
+    pub fn new(first_seq: u64, block_events: usize) -> Self {
+        let block_events = block_events.max(1);
+        let pending = Vec::with_capacity(block_events);
+        Self {
+            block_events,
+            max_blocks: DEFAULT_MAX_COLD_BLOCKS,
+            first_seq,
+            blocks: VecDeque::new(),
+            pending,
+            evicted: 0,
+        }
+    }
+
+    /// Ограничить ярус `max_blocks` сжатыми блоками (не меньше одного).
+    pub fn with_max_blocks(mut self, max_blocks: usize) -> Self {
+        self.max_blocks = max_blocks.max(1);
+        self
+    }
+
+    /// Добавить следующее по номеру событие. Полный хвост сначала сжимается в
+    /// блок; при ошибке сжатия событие не принимается.
+    pub fn push(&mut self, event: &Event) -> Result<(), PersistError> {
+        self.make_room()?;
+        self.pending.push(*event);
+        Ok(())
+    }
+
+    /// Номер самого старого события яруса.
+    pub fn first_seq(&self) -> u64 {
+        self.first_seq
+    }
+
+    /// Номер, который получит следующее событие.
+    pub fn end_seq(&self) -> u64 {
+        self.first_seq + self.len() as u64
+    }
+
+    /// Событий, отброшенных сверх лимита `max_blocks`.
+    pub fn evicted(&self) -> u64 {
+        self.evicted
+    }
+
+    /// Сжатых блоков в ярусе.
+    pub fn block_count(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Событий в ярусе.
+    pub fn len(&self) -> usize {
+        self.blocks.iter().map(|b| b.len).sum::<usize>() + self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty() && self.pending.is_empty()
+    }
+
+    /// Событие с номером `seq`. Ok(None) — номер вне яруса.
+    pub fn get(&self, seq: u64) -> Result<Option<Event>, PersistError> {
+        if seq < self.first_seq || seq >= self.end_seq() {
+            return Ok(None);
+        }
+        let pending_seq = self.end_seq() - self.pending.len() as u64;
+        if seq >= pending_seq {
+            return Ok(Some(self.pending[(seq - pending_seq) as usize]));
+        }
+        let i = self.blocks.partition_point(|b| b.first_seq + b.len as u64 <= seq);
+        let block = &self.blocks[i];
+        let events = decode(&block.bytes, block.len)?;
+        Ok(Some(events[(seq - block.first_seq) as usize]))
+    }
+
+    /// Отбросить сжатые блоки, целиком лежащие до `seq` (переданные в архив).
+    /// Возвращает число отброшенных событий.
+    pub fn drop_before(&mut self, seq: u64) -> usize {
+        let n = self.blocks.partition_point(|b| b.first_seq + b.len as u64 <= seq);
+        let dropped: usize = self.blocks.drain(..n).map(|b| b.len).sum();
+        self.first_seq += dropped as u64;
+        dropped
+    }
+
+    /// Сжать полный хвост в блок, чтобы следующее событие поместилось.
+    fn make_room(&mut self) -> Result<(), PersistError> {
+        if self.pending.len() >= self.block_events {
+            self.seal()?;
+        }
+        Ok(())
+    }
+
+    /// Занято сжатыми блоками и хвостом, байт.
+    pub fn stored_bytes(&self) -> usize {
+        self.blocks.iter().map(|b| b.bytes.len()).sum::<usize>() + self.pending.len() * EVENT_LEN
+    }
+
+    /// Заняли бы те же события без сжатия, байт.
+    pub fn raw_bytes(&self) -> usize {
+        self.len() * EVENT_LEN
+    }
+
+    fn seal(&mut self) -> Result<(), PersistError> {
+        let first_seq = self.end_seq() - self.pending.len() as u64;
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::fast());
+        for event in &self.pending {
+            encoder.write_all(event_bytes(event))?;
+        }
+        let bytes = encoder.finish()?;
+        self.blocks.push_back(ColdBlock { first_seq, len: self.pending.len(), bytes });
+        self.pending.clear();
+        if self.blocks.len() > self.max_blocks {
+            let oldest = self.blocks.pop_front().map_or(0, |b| b.len);
+            self.first_seq += oldest as u64;
+            self.evicted += oldest as u64;
+        }
+        Ok(())
+    }
+}
+
+/// Горячее окно EventRing плюс холодный сжатый ярус перед ним.
+pub struct TieredEvents {
+    hot: EventRing,
+    cold: ColdSegment,
+    lost: u64,
+}
+
+impl TieredEvents {
+    /// Холодный ярус начинается с первого события горячего окна: вытесненное
+    /// из окна переходит в него.
+    pub fn new(hot: EventRing, block_events: usize) -> Self {
+        let cold = ColdSegment::new(hot.first_seq(), block_events);
+        Self { hot, cold, lost: 0 }
+    }
+
+    /// Ограничить холодный ярус `max_blocks` блоками (см. `ColdSegment::with_max_blocks`).
+    pub fn with_max_cold_blocks(mut self, max_blocks: usize) -> Self {
+        self.cold.max_blocks = max_blocks.max(1);
+        self
+    }
+
+    /// Записать событие; вытесненное из окна уходит в холодный ярус. Место в
+    /// холодном ярусе готовится до записи: при ошибке сжатия не меняется ни
+    /// один ярус, и событие остаётся у вызывающего.
+    pub fn push(&mut self, event: &Event) -> Result<(), PersistError> {
+        if self.hot.len() == self.hot.capacity() {
+            self.cold.make_room()?;
+        }
+        if let Some(evicted) = self.hot.push(event) {
+            self.cold.pending.push(evicted);
+        }
+        Ok(())
+    }
+
+    /// События Event Bus, не записанные наблюдателем из-за ошибки сжатия.
+    pub fn lost(&self) -> u64 {
+        self.lost
+    }
+
+    /// Событие с номером `seq` из любого яруса.
+    pub fn get(&self, seq: u64) -> Result<Option<Event>, PersistError> {
+        if seq >= self.hot.first_seq() {
+            return Ok(self.hot.get((seq - self.hot.first_seq()) as usize).copied());
+        }
+        self.cold.get(seq)
+    }
+
+    /// Номер самого старого доступного события.
+    pub fn first_seq(&self) -> u64 {
+        self.cold.first_seq().min(self.hot.first_seq())
+    }
+
+    /// Номер, который получит следующее событие.
+    pub fn end_seq(&self) -> u64 {
+        self.hot.total_written()
+    }
+
+    pub fn hot(&self) -> &EventRing {
+        &self.hot
+    }
+
+    /// Сбросить горячее окно на диск (см. `EventRing::sync`).
+    pub fn sync(&self) -> Result<(), PersistError> {
+        self.hot.sync()
+    }
+
+    pub fn cold(&self) -> &ColdSegment {
+        &self.cold
+    }
+
+    pub fn cold_mut(&mut self) -> &mut ColdSegment {
+        &mut self.cold
+    }
+}
+
+/// Наблюдатель Event Bus, пишущий события в общий TieredEvents.
+///
+/// `Gateway::register_observer(Box::new(TieredEventsObserver(Rc::clone(&events))))`
+pub struct TieredEventsObserver(pub Rc<RefCell<TieredEvents>>);
+
+impl EventObserver for TieredEventsObserver {
+    fn on_event(&self, event: &Event) {
+        let mut events = self.0.borrow_mut();
+        if events.push(event).is_err() {
+            events.lost += 1;
+        }
+    }
+}
+
+fn event_bytes(event: &Event) -> &[u8] {
+    // SAFETY: Event — repr(C) без padding из одних целых (см. event_ring),
+    // все EVENT_LEN байт инициализированы.
+    unsafe { std::slice::from_raw_parts((event as *const Event).cast::<u8>(), EVENT_LEN) }
+}
+
+fn decode(bytes: &[u8], len: usize) -> Result<Vec<Event>, PersistError> {
+    let mut raw = Vec::with_capacity(len * EVENT_LEN);
+    DeflateDecoder::new(bytes).read_to_end(&mut raw)?;
+    if raw.len() != len * EVENT_LEN {
+        return Err(PersistError::Decode(format!(
+            "cold segment: {} bytes for {len} events",
+            raw.len()
+        )));
+    }
+    Ok(raw
+        .chunks_exact(EVENT_LEN)
+        // SAFETY: чанк — ровно EVENT_LEN байт; любой битовый образ Event валиден
+        .map(|chunk| unsafe { chunk.as_ptr().cast::<Event>().read_unaligned() })
+        .collect())
+}
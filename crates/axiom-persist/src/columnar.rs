@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Колоночный экспорт событий и следов опыта (Arrow / Parquet).
+//
+// Бинарные форматы хранилища (bincode, arena, event ring) читаются только
+// этим крейтом. Для анализа в pandas, polars или DuckDB события и следы
+// раскладываются по колонкам Arrow — по колонке на поле, без вложенных
+// типов — и пишутся в Parquet. Позиция паттерна — три колонки x/y/z,
+// inline payload события — FixedSizeBinary(8).
+//
+// Модуль собирается с feature "arrow".
+
+use crate::error::PersistError;
+use arrow_array::{
+    ArrayRef, FixedSizeBinaryArray, Float32Array, Int16Array, Int8Array, RecordBatch,
+    UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+};
+use arrow_schema::{Field, Schema};
+use axiom_arbiter::ExperienceTrace;
+use axiom_core::Event;
+use axiom_runtime::AxiomEngine;
+use parquet::arrow::ArrowWriter;
+use std::path::Path;
+use std::sync::Arc;
+
+/// События как RecordBatch: по колонке на поле Event.
+pub fn events_to_record_batch<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+) -> Result<RecordBatch, PersistError> {
+    let events: Vec<&Event> = events.into_iter().collect();
+    let u64s = |f: fn(&Event) -> u64| -> ArrayRef {
+        Arc::new(UInt64Array::from_iter_values(events.iter().map(|e| f(e))))
+    };
+    let u32s = |f: fn(&Event) -> u32| -> ArrayRef {
+        Arc::new(UInt32Array::from_iter_values(events.iter().map(|e| f(e))))
+    };
+    let u16s = |f: fn(&Event) -> u16| -> ArrayRef {
+        Arc::new(UInt16Array::from_iter_values(events.iter().map(|e| f(e))))
+    };
+    let u8s = |f: fn(&Event) -> u8| -> ArrayRef {
+        Arc::new(UInt8Array::from_iter_values(events.iter().map(|e| f(e))))
+    };
+    let payloads = events.iter().map(|e| Some(e.payload));
+    let payload = FixedSizeBinaryArray::try_from_sparse_iter_with_size(payloads, 8)
+        .map_err(|e| PersistError::Encode(e.to_string()))?;
+
+    let columns: Vec<(&str, ArrayRef)> = vec![
+        ("event_id", u64s(|e| e.event_id)),
+        ("parent_event_id", u64s(|e| e.parent_event_id)),
+        ("payload_hash", u64s(|e| e.payload_hash)),
+        ("target_id", u32s(|e| e.target_id)),
+        ("source_id", u32s(|e| e.source_id)),
+        ("domain_id", u16s(|e| e.domain_id)),
+        ("event_type", u16s(|e| e.event_type)),
+        ("payload_size", u16s(|e| e.payload_size)),
+        ("priority", u8s(|e| e.priority)),
+        ("flags", u8s(|e| e.flags)),
+        ("pulse_id", u64s(|e| e.pulse_id)),
+        ("source_domain", u16s(|e| e.source_domain)),
+        ("event_subtype", u16s(|e| e.event_subtype)),
+        ("snapshot_event_id", u32s(|e| e.snapshot_event_id)),
+        ("payload", Arc::new(payload)),
+    ];
+    record_batch(columns)
+}
+
+/// Следы опыта как RecordBatch: метаданные следа и поля паттерна.
+pub fn traces_to_record_batch(traces: &[ExperienceTrace]) -> Result<RecordBatch, PersistError> {
+    let u64s = |f: fn(&ExperienceTrace) -> u64| -> ArrayRef {
+        Arc::new(UInt64Array::from_iter_values(traces.iter().map(f)))
+    };
+    let u32s = |f: fn(&ExperienceTrace) -> u32| -> ArrayRef {
+        Arc::new(UInt32Array::from_iter_values(traces.iter().map(f)))
+    };
+    let u16s = |f: fn(&ExperienceTrace) -> u16| -> ArrayRef {
+        Arc::new(UInt16Array::from_iter_values(traces.iter().map(f)))
+    };
+    let i16s = |f: fn(&ExperienceTrace) -> i16| -> ArrayRef {
+        Arc::new(Int16Array::from_iter_values(traces.iter().map(f)))
+    };
+    let u8s = |f: fn(&ExperienceTrace) -> u8| -> ArrayRef {
+        Arc::new(UInt8Array::from_iter_values(traces.iter().map(f)))
+    };
+    let weight = Float32Array::from_iter_values(traces.iter().map(|t| t.weight));
+    let valence = Int8Array::from_iter_values(traces.iter().map(|t| t.pattern.valence));
+
+    let columns: Vec<(&str, ArrayRef)> = vec![
+        ("created_at", u64s(|t| t.created_at)),
+        ("last_used", u64s(|t| t.last_used)),
+        ("weight", Arc::new(weight)),
+        ("success_count", u32s(|t| t.success_count)),
+        ("pattern_hash", u64s(|t| t.pattern_hash)),
+        ("sutra_id", u32s(|t| t.pattern.sutra_id)),
+        ("domain_id", u16s(|t| t.pattern.domain_id)),
+        ("type_flags", u16s(|t| t.pattern.type_flags)),
+        ("x", i16s(|t| t.pattern.position[0])),
+        ("y", i16s(|t| t.pattern.position[1])),
+        ("z", i16s(|t| t.pattern.position[2])),
+        ("valence", Arc::new(valence)),
+        ("mass", u8s(|t| t.pattern.mass)),
+        ("temperature", u8s(|t| t.pattern.temperature)),
+    ];
+    record_batch(columns)
+}
+
+/// Экспортировать следы с weight ≥ threshold в Parquet. Возвращает число строк.
+pub fn export_traces_parquet(
+    engine: &AxiomEngine,
+    path: &Path,
+    weight_threshold: f32,
+) -> Result<usize, PersistError> {
+    let traces: Vec<ExperienceTrace> = engine
+        .ashti
+        .experience()
+        .traces()
+        .iter()
+        .filter(|t| t.weight >= weight_threshold)
+        .cloned()
+        .collect();
+    write_parquet(path, &traces_to_record_batch(&traces)?)?;
+    Ok(traces.len())
+}
+
+/// Записать RecordBatch в Parquet-файл.
+pub fn write_parquet(path: &Path, batch: &RecordBatch) -> Result<(), PersistError> {
+    let file = std::fs::File::create(path)?;
+    let encode = |e: parquet::errors::ParquetError| PersistError::Encode(e.to_string());
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).map_err(encode)?;
+    writer.write(batch).map_err(encode)?;
+    writer.close().map_err(encode)?;
+    Ok(())
+}
+
+fn record_batch(columns: Vec<(&str, ArrayRef)>) -> Result<RecordBatch, PersistError> {
+    let fields: Vec<Field> = columns
+        .iter()
+        .map(|(name, array)| Field::new(*name, array.data_type().clone(), false))
+        .collect();
+    let arrays = columns.into_iter().map(|(_, array)| array).collect();
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+        .map_err(|e| PersistError::Encode(e.to_string()))
+}
@@ -0,0 +1,303 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// EventRing — кольцевой буфер последних событий в mmap-файле.
+//
+// Снапшот (save/AutoSaver) пишется раз в interval_ticks, и события между
+// снапшотами при перезапуске теряются. EventRing держит окно последних
+// `capacity` событий в файле: запись — копия 64-байтового Event в слот
+// отображения, без кодирования, а окно может быть больше оперативной памяти —
+// ОС подгружает страницы по мере чтения.
+//
+// Формат: заголовок 64 байта (MAGIC, EVENT_RING_VERSION, capacity, head),
+// затем (capacity + 1) слотов по 64 байта в нативной раскладке Event.
+// head — число событий, записанных за всё время; событие n лежит в слоте
+// n % (capacity + 1). Лишний слот — зазор: пишется всегда слот вне видимого
+// окна, а head увеличивается после записи, поэтому падение процесса посреди
+// push не портит ни одного видимого события. Устойчивость к сбою питания —
+// до последнего `sync`: он сбрасывает слоты раньше заголовка.
+//
+// Файлы старых версий формата (другая раскладка и размер слота) открываются
+// через `open_migrating` или `open_or_create_migrating`: каждая версия
+// регистрирует в EventMigrations размер своего слота и функцию перевода слота
+// в текущий Event. Файл переписывается целиком во временный и атомарно
+// подменяется, номера событий и head сохраняются.
+
+use crate::error::PersistError;
+use axiom_core::Event;
+use axiom_runtime::EventObserver;
+use memmap2::MmapMut;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Read;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Сигнатура файла кольцевого буфера событий.
+pub const EVENT_RING_MAGIC: [u8; 8] = *b"AXEVRING";
+
+/// Версия формата кольцевого буфера событий.
+pub const EVENT_RING_VERSION: u32 = 1;
+
+const HEADER_LEN: usize = 64;
+const EVENT_LEN: usize = std::mem::size_of::<Event>();
+const HEAD_OFFSET: usize = 24;
+
+const _: () = assert!(EVENT_LEN == 64 && std::mem::align_of::<Event>() == HEADER_LEN);
+
+/// Перевод слота старой версии формата (`slot_len` байт) в текущий Event.
+pub type EventMigration = fn(&[u8]) -> Event;
+
+/// Зарегистрированные миграции: версия формата → (размер слота, перевод).
+#[derive(Debug, Clone, Default)]
+pub struct EventMigrations {
+    steps: HashMap<u32, (usize, EventMigration)>,
+}
+
+impl EventMigrations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Зарегистрировать перевод слотов версии `version` размером `slot_len`.
+    pub fn register(
+        &mut self,
+        version: u32,
+        slot_len: usize,
+        migrate: EventMigration,
+    ) -> &mut Self {
+        self.steps.insert(version, (slot_len, migrate));
+        self
+    }
+
+    /// Есть ли миграция для версии.
+    pub fn supports(&self, version: u32) -> bool {
+        self.steps.contains_key(&version)
+    }
+}
+
+/// Отображённое в память окно последних событий.
+pub struct EventRing {
+    map: MmapMut,
+    capacity: usize,
+    head: u64,
+}
+
+impl EventRing {
+    /// Создать пустой буфер на `capacity` событий (не меньше одного).
+    pub fn create(path: &Path, capacity: usize) -> Result<Self, PersistError> {
+        let capacity = capacity.max(1);
+        let Some(file_len) = ring_file_len(capacity, EVENT_LEN) else {
+            return Err(PersistError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("event ring: capacity {capacity} overflows file size"),
+            )));
+        };
+        let file =
+            OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+        file.set_len(file_len as u64)?;
+        // SAFETY: см. open()
+        let mut map = unsafe { MmapMut::map_mut(&file)? };
+        map[0..8].copy_from_slice(&EVENT_RING_MAGIC);
+        map[8..12].copy_from_slice(&EVENT_RING_VERSION.to_le_bytes());
+        map[16..24].copy_from_slice(&(capacity as u64).to_le_bytes());
+        map.flush()?;
+        Ok(Self { map, capacity, head: 0 })
+    }
+
+    /// Открыть существующий буфер: окно событий — как до закрытия.
+    pub fn open(path: &Path) -> Result<Self, PersistError> {
+        if !path.exists() {
+            return Err(PersistError::NotFound(path.display().to_string()));
+        }
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        // SAFETY: файл открыт этим процессом; внешнее изменение файла во время
+        // работы буфера не поддерживается (как и для остальных файлов хранилища).
+        let map = unsafe { MmapMut::map_mut(&file)? };
+
+        if map.len() < HEADER_LEN || map[0..8] != EVENT_RING_MAGIC {
+            return Err(PersistError::Decode("event ring: bad magic".into()));
+        }
+        let version = u32::from_le_bytes(map[8..12].try_into().unwrap());
+        if version != EVENT_RING_VERSION {
+            return Err(PersistError::Decode(format!(
+                "event ring: version {version}, expected {EVENT_RING_VERSION}"
+            )));
+        }
+        let capacity = u64::from_le_bytes(map[16..24].try_into().unwrap()) as usize;
+        if capacity == 0 || ring_file_len(capacity, EVENT_LEN) != Some(map.len()) {
+            return Err(PersistError::Decode(format!(
+                "event ring: {} bytes for capacity {capacity}",
+                map.len()
+            )));
+        }
+        let head = u64::from_le_bytes(map[HEAD_OFFSET..HEAD_OFFSET + 8].try_into().unwrap());
+        Ok(Self { map, capacity, head })
+    }
+
+    /// Открыть буфер; файл зарегистрированной старой версии сначала
+    /// переводится в текущую версию (на месте, через временный файл).
+    pub fn open_migrating(path: &Path, migrations: &EventMigrations) -> Result<Self, PersistError> {
+        let not_found = |e: std::io::Error| match e.kind() {
+            std::io::ErrorKind::NotFound => PersistError::NotFound(path.display().to_string()),
+            _ => PersistError::Io(e),
+        };
+        // Версия — по заголовку; весь файл читается, только если нужна миграция
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        std::fs::File::open(path)
+            .and_then(|f| f.take(HEADER_LEN as u64).read_to_end(&mut header))
+            .map_err(not_found)?;
+        if header.len() < HEADER_LEN || header[0..8] != EVENT_RING_MAGIC {
+            return Err(PersistError::Decode("event ring: bad magic".into()));
+        }
+        let version = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        // Текущая или незарегистрированная версия — обычное открытие
+        // (во втором случае — ошибка версии)
+        let step = migrations.steps.get(&version).filter(|_| version != EVENT_RING_VERSION);
+        let Some(&(slot_len, migrate)) = step else {
+            return Self::open(path);
+        };
+        let capacity = u64::from_le_bytes(header[16..24].try_into().unwrap()) as usize;
+        let head = u64::from_le_bytes(header[HEAD_OFFSET..HEAD_OFFSET + 8].try_into().unwrap());
+        let bytes = std::fs::read(path).map_err(not_found)?;
+        let expected = ring_file_len(capacity, slot_len);
+        if capacity == 0 || slot_len == 0 || expected != Some(bytes.len()) {
+            return Err(PersistError::Decode(format!(
+                "event ring v{version}: {} bytes for capacity {capacity}",
+                bytes.len()
+            )));
+        }
+
+        // Слоты переводятся на те же места: номера событий не меняются
+        let tmp = path.with_extension("migrating");
+        let mut ring = Self::create(&tmp, capacity)?;
+        let old_slots = bytes[HEADER_LEN..].chunks_exact(slot_len);
+        for (slot, old) in ring.slots_mut().iter_mut().zip(old_slots) {
+            *slot = migrate(old);
+        }
+        ring.head = head;
+        ring.map[HEAD_OFFSET..HEAD_OFFSET + 8].copy_from_slice(&head.to_le_bytes());
+        ring.sync()?;
+        drop(ring);
+        std::fs::rename(&tmp, path)?;
+        Self::open(path)
+    }
+
+    /// Открыть буфер, если файл есть, иначе создать. Ёмкость существующего
+    /// файла сохраняется. Файл старой версии отклоняется — для него
+    /// `open_or_create_migrating`.
+    pub fn open_or_create(path: &Path, capacity: usize) -> Result<Self, PersistError> {
+        Self::open_or_create_migrating(path, capacity, &EventMigrations::default())
+    }
+
+    /// Как `open_or_create`, но существующий файл открывается через
+    /// `open_migrating`.
+    pub fn open_or_create_migrating(
+        path: &Path,
+        capacity: usize,
+        migrations: &EventMigrations,
+    ) -> Result<Self, PersistError> {
+        if path.exists() {
+            Self::open_migrating(path, migrations)
+        } else {
+            Self::create(path, capacity)
+        }
+    }
+
+    /// Записать событие; при заполненном окне вытесняется самое старое —
+    /// оно и возвращается (для холодного яруса, см. TieredEvents).
+    pub fn push(&mut self, event: &Event) -> Option<Event> {
+        let evicted = (self.len() == self.capacity).then(|| *self.get(0).unwrap());
+        let slot = (self.head % self.slots() as u64) as usize;
+        self.slots_mut()[slot] = *event;
+        self.head += 1;
+        self.map[HEAD_OFFSET..HEAD_OFFSET + 8].copy_from_slice(&self.head.to_le_bytes());
+        evicted
+    }
+
+    /// Сбросить буфер на диск: сначала слоты, затем заголовок с head.
+    pub fn sync(&self) -> Result<(), PersistError> {
+        self.map.flush_range(HEADER_LEN, self.map.len() - HEADER_LEN)?;
+        self.map.flush_range(0, HEADER_LEN)?;
+        Ok(())
+    }
+
+    /// Ёмкость окна.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Событий в окне.
+    pub fn len(&self) -> usize {
+        self.head.min(self.capacity as u64) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head == 0
+    }
+
+    /// Событий записано за всё время (включая вытесненные).
+    pub fn total_written(&self) -> u64 {
+        self.head
+    }
+
+    /// Порядковый номер самого старого события окна (номер события —
+    /// его позиция среди всех записанных, от нуля).
+    pub fn first_seq(&self) -> u64 {
+        self.head - self.len() as u64
+    }
+
+    /// `index`-е событие окна, от самого старого.
+    pub fn get(&self, index: usize) -> Option<&Event> {
+        if index >= self.len() {
+            return None;
+        }
+        let n = self.first_seq() + index as u64;
+        Some(&self.slots_ref()[(n % self.slots() as u64) as usize])
+    }
+
+    /// События окна от самого старого к самому новому.
+    pub fn iter(&self) -> impl Iterator<Item = &Event> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// Последние `n` событий, от старого к новому.
+    pub fn recent(&self, n: usize) -> impl Iterator<Item = &Event> + '_ {
+        self.iter().skip(self.len().saturating_sub(n))
+    }
+
+    fn slots(&self) -> usize {
+        self.capacity + 1
+    }
+
+    fn slots_ref(&self) -> &[Event] {
+        // SAFETY: размер проверен в open()/create(); начало отображения выровнено
+        // на страницу, заголовок — 64 байта, значит слоты выровнены под Event.
+        // Event — repr(C) без padding из одних целых: любой битовый образ валиден.
+        unsafe { std::slice::from_raw_parts(self.map[HEADER_LEN..].as_ptr().cast(), self.slots()) }
+    }
+
+    fn slots_mut(&mut self) -> &mut [Event] {
+        let slots = self.slots();
+        // SAFETY: см. slots_ref; &mut self исключает другие ссылки на отображение.
+        unsafe { std::slice::from_raw_parts_mut(self.map[HEADER_LEN..].as_mut_ptr().cast(), slots) }
+    }
+}
+
+/// Размер файла буфера на `capacity` событий со слотами по `slot_len` байт;
+/// None — размер не помещается в usize.
+fn ring_file_len(capacity: usize, slot_len: usize) -> Option<usize> {
+    capacity.checked_add(1)?.checked_mul(slot_len)?.checked_add(HEADER_LEN)
+}
+
+/// Наблюдатель Event Bus, пишущий события в общий EventRing.
+///
+/// `Gateway::register_observer(Box::new(EventRingObserver(Rc::clone(&ring))))`
+pub struct EventRingObserver(pub Rc<RefCell<EventRing>>);
+
+impl EventObserver for EventRingObserver {
+    fn on_event(&self, event: &Event) {
+        self.0.borrow_mut().push(event);
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Fuzz-харнессы бинарных декодеров persist (feature "fuzzing").
+//
+// Входы — произвольные байты, как если бы engine_state.bin или exchange-пакет
+// были повреждены на диске или подменены. Харнесс не должен паниковать:
+// любая ошибка формата обязана вернуться как PersistError::Decode.
+//
+//     fuzz_target!(|data: &[u8]| axiom_persist::fuzz::fuzz_engine_state(data));
+
+use crate::exchange::{SkillPackage, TracePackage};
+use crate::loader::{decode_state, state_to_snapshot};
+use axiom_runtime::AxiomEngine;
+
+/// Харнесс: engine_state.bin → StoredEngineState → восстановление Engine.
+///
+/// Проходит тот же путь, что и `load()`, кроме чтения manifest с диска.
+pub fn fuzz_engine_state(data: &[u8]) {
+    let Ok(state) = decode_state(data) else {
+        return;
+    };
+    let snapshot = state_to_snapshot(&state);
+    let _ = AxiomEngine::restore_from(&snapshot);
+}
+
+/// Харнесс: декодирование exchange-пакетов (traces и skills).
+pub fn fuzz_exchange_package(data: &[u8]) {
+    let _ = bincode::serde::decode_from_slice::<TracePackage, _>(data, bincode::config::standard());
+    let _ = bincode::serde::decode_from_slice::<SkillPackage, _>(data, bincode::config::standard());
+}
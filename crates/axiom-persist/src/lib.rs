@@ -8,16 +8,43 @@
 //
 // Спецификация: docs/spec/Memory_Persistence_V1_0.md
 
+// Нативная раскладка файла совпадает с памятью только на little-endian
+#[cfg(target_endian = "little")]
+pub mod arena;
 pub mod auto;
+#[cfg(target_endian = "little")]
+pub mod cold_segment;
+#[cfg(feature = "arrow")]
+pub mod columnar;
 pub mod error;
+#[cfg(target_endian = "little")]
+pub mod event_ring;
 pub mod exchange;
 pub mod format;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
 pub mod loader;
 pub mod manifest;
 pub mod writer;
 
+#[cfg(target_endian = "little")]
+pub use arena::{TokenArena, ARENA_MAGIC, ARENA_VERSION};
 pub use auto::{AutoSaver, PersistenceConfig};
+#[cfg(target_endian = "little")]
+pub use cold_segment::{
+    ColdSegment, TieredEvents, TieredEventsObserver, DEFAULT_BLOCK_EVENTS,
+    DEFAULT_MAX_COLD_BLOCKS,
+};
+#[cfg(feature = "arrow")]
+pub use columnar::{
+    events_to_record_batch, export_traces_parquet, traces_to_record_batch, write_parquet,
+};
 pub use error::PersistError;
+#[cfg(target_endian = "little")]
+pub use event_ring::{
+    EventMigration, EventMigrations, EventRing, EventRingObserver, EVENT_RING_MAGIC,
+    EVENT_RING_VERSION,
+};
 pub use exchange::{
     export_skills, export_traces, import_skills, import_traces, ExchangeKind, ExportReport,
     ImportReport,
@@ -45,9 +45,7 @@ pub fn load(dir: &Path) -> Result<LoadResult, PersistError> {
         return Err(PersistError::NotFound(state_path.display().to_string()));
     }
     let bytes = std::fs::read(&state_path)?;
-    let (state, _): (StoredEngineState, _) =
-        bincode::serde::decode_from_slice(&bytes, bincode::config::standard())
-            .map_err(|e| PersistError::Decode(e.to_string()))?;
+    let state = decode_state(&bytes)?;
 
     // 3. Восстановить токены/связи через EngineSnapshot
     let snapshot = state_to_snapshot(&state);
@@ -107,8 +105,16 @@ pub fn load(dir: &Path) -> Result<LoadResult, PersistError> {
     })
 }
 
+/// Декодировать содержимое engine_state.bin.
+pub(crate) fn decode_state(bytes: &[u8]) -> Result<StoredEngineState, PersistError> {
+    let (state, _): (StoredEngineState, _) =
+        bincode::serde::decode_from_slice(bytes, bincode::config::standard())
+            .map_err(|e| PersistError::Decode(e.to_string()))?;
+    Ok(state)
+}
+
 /// Конвертировать StoredEngineState → EngineSnapshot (без traces).
-fn state_to_snapshot(state: &StoredEngineState) -> axiom_runtime::EngineSnapshot {
+pub(crate) fn state_to_snapshot(state: &StoredEngineState) -> axiom_runtime::EngineSnapshot {
     use axiom_config::DomainConfig;
     use axiom_runtime::DomainSnapshot;
 
@@ -0,0 +1,89 @@
+// Тесты TokenArena — mmap-хранилище токенов в нативной раскладке
+
+use axiom_core::Token;
+use axiom_persist::{PersistError, TokenArena};
+use std::path::PathBuf;
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join("axiom-persist-arena-test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn sample(n: u32) -> Vec<Token> {
+    (1..=n)
+        .map(|i| {
+            let mut t = Token::new(i, 100 + (i % 11) as u16, [i as i16, -(i as i16), 7], i as u64);
+            t.velocity = [1, -2, 3];
+            t.valence = -5;
+            t.lineage_hash = 0xDEAD_BEEF_0000_0000 | i as u64;
+            t.momentum = [-1, 100_000, i as i32];
+            t.resonance = 440;
+            t
+        })
+        .collect()
+}
+
+fn same(a: &Token, b: &Token) -> bool {
+    format!("{a:?}") == format!("{b:?}")
+}
+
+#[test]
+fn test_arena_roundtrip_is_field_exact() {
+    let path = temp_file("roundtrip.arena");
+    let tokens = sample(1000);
+    let arena = TokenArena::create(&path, &tokens).unwrap();
+    assert_eq!(arena.len(), 1000);
+    assert!(arena.as_slice().iter().zip(&tokens).all(|(a, b)| same(a, b)));
+
+    let reopened = TokenArena::open(&path).unwrap();
+    assert!(same(reopened.get(999).unwrap(), &tokens[999]));
+    assert!(reopened.get(1000).is_none());
+}
+
+#[test]
+fn test_arena_in_place_update_persists() {
+    let path = temp_file("update.arena");
+    let mut arena = TokenArena::create(&path, &sample(3)).unwrap();
+    arena.get_mut(1).unwrap().mass = 9;
+    arena.flush().unwrap();
+    drop(arena);
+
+    let arena = TokenArena::open(&path).unwrap();
+    assert_eq!(arena.get(1).unwrap().mass, 9);
+}
+
+#[test]
+fn test_arena_rejects_bad_files() {
+    let path = temp_file("bad.arena");
+    std::fs::write(&path, b"not an arena").unwrap();
+    assert!(matches!(TokenArena::open(&path), Err(PersistError::Decode(_))));
+
+    let truncated = temp_file("truncated.arena");
+    TokenArena::create(&truncated, &sample(2)).unwrap();
+    let bytes = std::fs::read(&truncated).unwrap();
+    std::fs::write(&truncated, &bytes[..bytes.len() - 1]).unwrap();
+    assert!(matches!(TokenArena::open(&truncated), Err(PersistError::Decode(_))));
+
+    assert!(matches!(
+        TokenArena::open(&temp_file("missing.arena")),
+        Err(PersistError::NotFound(_))
+    ));
+}
+
+#[test]
+fn test_arena_rejects_overflowing_count() {
+    // 64 + (2^58 + 1) · 64 переполняет usize и без проверки совпал бы со 128 байтами
+    let path = temp_file("overflow.arena");
+    TokenArena::create(&path, &sample(1)).unwrap();
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[16..24].copy_from_slice(&((1u64 << 58) + 1).to_le_bytes());
+    std::fs::write(&path, &bytes).unwrap();
+    assert!(matches!(TokenArena::open(&path), Err(PersistError::Decode(_))));
+
+    bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    std::fs::write(&path, &bytes).unwrap();
+    assert!(matches!(TokenArena::open(&path), Err(PersistError::Decode(_))));
+}
@@ -0,0 +1,93 @@
+// Тесты ColdSegment и TieredEvents — сжатый ярус событий за EventRing
+
+use axiom_core::{Event, EventPriority, EventType};
+use axiom_persist::{ColdSegment, EventRing, TieredEvents, TieredEventsObserver};
+use axiom_runtime::EventObserver;
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join("axiom-persist-cold-segment-test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn event(id: u64) -> Event {
+    let mut e = Event::new(id, 100, EventType::TokenCreate, EventPriority::Normal, id * 7, 1, 2, 0);
+    e.payload = (id as u32).to_le_bytes().repeat(2).try_into().unwrap();
+    e
+}
+
+#[test]
+fn test_cold_segment_compresses_and_reads_by_seq() {
+    let mut cold = ColdSegment::new(10, 16);
+    for id in 0..40 {
+        cold.push(&event(id)).unwrap();
+    }
+    assert_eq!((cold.first_seq(), cold.end_seq(), cold.len()), (10, 50, 40));
+    assert!(cold.stored_bytes() < cold.raw_bytes() / 2, "{}", cold.stored_bytes());
+
+    // Из сжатого блока и из несжатого хвоста
+    assert_eq!(cold.get(10).unwrap().unwrap().event_id, 0);
+    assert_eq!(cold.get(27).unwrap().unwrap().payload, event(17).payload);
+    assert_eq!(cold.get(49).unwrap().unwrap().event_id, 39);
+    assert!(cold.get(9).unwrap().is_none());
+    assert!(cold.get(50).unwrap().is_none());
+
+    // Хвост не отбрасывается, блоки — только целиком
+    assert_eq!(cold.drop_before(30), 16);
+    assert_eq!(cold.first_seq(), 26);
+    assert_eq!(cold.get(26).unwrap().unwrap().event_id, 16);
+    assert_eq!(cold.drop_before(u64::MAX), 16);
+    assert_eq!(cold.len(), 8);
+}
+
+#[test]
+fn test_tiered_events_span_hot_and_cold() {
+    let hot = EventRing::create(&temp_file("tiered.ring"), 4).unwrap();
+    let mut tiered = TieredEvents::new(hot, 3);
+    for id in 0..20 {
+        tiered.push(&event(id)).unwrap();
+    }
+    assert_eq!((tiered.first_seq(), tiered.end_seq()), (0, 20));
+    assert_eq!(tiered.hot().len(), 4);
+    assert_eq!(tiered.cold().len(), 16);
+    let all: Vec<u64> = (0..20).map(|s| tiered.get(s).unwrap().unwrap().event_id).collect();
+    assert_eq!(all, (0..20).collect::<Vec<_>>());
+    assert!(tiered.get(20).unwrap().is_none());
+}
+
+#[test]
+fn test_cold_segment_is_bounded_by_max_blocks() {
+    let mut cold = ColdSegment::new(0, 4).with_max_blocks(2);
+    for id in 0..20 {
+        cold.push(&event(id)).unwrap();
+    }
+    // 4 полных блока сжаты, в ярусе остаются 2 последних и хвост
+    assert_eq!(cold.block_count(), 2);
+    assert_eq!(cold.evicted(), 8);
+    assert_eq!((cold.first_seq(), cold.end_seq()), (8, 20));
+    assert!(cold.get(7).unwrap().is_none());
+    assert_eq!(cold.get(8).unwrap().unwrap().event_id, 8);
+    assert_eq!(cold.get(19).unwrap().unwrap().event_id, 19);
+}
+
+#[test]
+fn test_tiered_events_observer_records_bus_events() {
+    let hot = EventRing::create(&temp_file("tiered-observer.ring"), 2).unwrap();
+    let tiered = Rc::new(RefCell::new(TieredEvents::new(hot, 2).with_max_cold_blocks(1)));
+    let observer = TieredEventsObserver(Rc::clone(&tiered));
+    for id in 0..9 {
+        observer.on_event(&event(id));
+    }
+    let tiered = tiered.borrow();
+    assert_eq!(tiered.lost(), 0);
+    // Холодный ярус: один блок [4, 5] и хвост [6]; горячее окно — [7, 8]
+    assert_eq!((tiered.first_seq(), tiered.end_seq()), (4, 9));
+    assert_eq!(tiered.cold().evicted(), 4);
+    let ids: Vec<u64> = (4..9).map(|s| tiered.get(s).unwrap().unwrap().event_id).collect();
+    assert_eq!(ids, (4..9).collect::<Vec<_>>());
+}
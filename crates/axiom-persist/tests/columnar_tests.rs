@@ -0,0 +1,68 @@
+// Тесты колоночного экспорта (feature "arrow")
+#![cfg(feature = "arrow")]
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float32Type, Int16Type, UInt16Type, UInt64Type};
+use axiom_arbiter::ExperienceModule as Experience;
+use axiom_core::{Event, EventPriority, EventType, Token};
+use axiom_persist::{events_to_record_batch, traces_to_record_batch, write_parquet};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use std::path::PathBuf;
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join("axiom-persist-columnar-test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn events(n: u64) -> Vec<Event> {
+    (1..=n)
+        .map(|id| {
+            let (kind, priority) = (EventType::TokenMove, EventPriority::Normal);
+            let mut e = Event::new(id, 105, kind, priority, id, 7, 8, id - 1);
+            e.payload = [id as u8, 0, 0, 0, 0, 0, 0, 1];
+            e
+        })
+        .collect()
+}
+
+#[test]
+fn test_events_record_batch_has_column_per_field() {
+    let evs = events(5);
+    let batch = events_to_record_batch(&evs).unwrap();
+    assert_eq!((batch.num_rows(), batch.num_columns()), (5, 15));
+    let ids = batch.column_by_name("event_id").unwrap().as_primitive::<UInt64Type>();
+    assert_eq!(ids.values().to_vec(), vec![1, 2, 3, 4, 5]);
+    let domains = batch.column_by_name("domain_id").unwrap().as_primitive::<UInt16Type>();
+    assert!(domains.values().iter().all(|&d| d == 105));
+    let payload = batch.column_by_name("payload").unwrap().as_fixed_size_binary();
+    assert_eq!(payload.value(2), &[3, 0, 0, 0, 0, 0, 0, 1]);
+
+    let empty = events_to_record_batch(&[]).unwrap();
+    assert_eq!((empty.num_rows(), empty.num_columns()), (0, 15));
+}
+
+#[test]
+fn test_traces_roundtrip_through_parquet() {
+    let mut exp = Experience::new();
+    for i in 1..=4u32 {
+        let mut t = Token::new(i, 109, [i as i16 * 10, -(i as i16), 3], 1);
+        t.temperature = (i * 50) as u8;
+        exp.add_trace(t, i as f32 / 4.0, i as u64);
+    }
+    let batch = traces_to_record_batch(exp.traces()).unwrap();
+    let path = temp_file("traces.parquet");
+    write_parquet(&path, &batch).unwrap();
+
+    let file = std::fs::File::open(&path).unwrap();
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap().build().unwrap();
+    let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+    assert_eq!(read.len(), 1);
+    assert_eq!(read[0].schema(), batch.schema());
+    let weights = read[0].column_by_name("weight").unwrap().as_primitive::<Float32Type>();
+    assert_eq!(weights.values().to_vec(), vec![0.25, 0.5, 0.75, 1.0]);
+    let x = read[0].column_by_name("x").unwrap().as_primitive::<Int16Type>();
+    assert_eq!(x.values().to_vec(), vec![10, 20, 30, 40]);
+}
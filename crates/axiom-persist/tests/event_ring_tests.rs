@@ -0,0 +1,197 @@
+// Тесты EventRing — mmap-окно последних событий
+
+use axiom_core::{Event, EventPriority, EventType};
+use axiom_persist::{EventMigrations, EventRing, EventRingObserver, PersistError};
+use axiom_runtime::EventObserver;
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+fn temp_file(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join("axiom-persist-event-ring-test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn event(id: u64) -> Event {
+    let mut e = Event::new(id, 100, EventType::TokenCreate, EventPriority::Normal, id * 7, 1, 2, 0);
+    e.payload = (id as u32).to_le_bytes().repeat(2).try_into().unwrap();
+    e
+}
+
+fn ids(ring: &EventRing) -> Vec<u64> {
+    ring.iter().map(|e| e.event_id).collect()
+}
+
+#[test]
+fn test_event_ring_keeps_latest_window() {
+    let path = temp_file("window.ring");
+    let mut ring = EventRing::create(&path, 4).unwrap();
+    assert!(ring.is_empty());
+    for id in 1..=3 {
+        ring.push(&event(id));
+    }
+    assert_eq!(ids(&ring), vec![1, 2, 3]);
+
+    for id in 4..=10 {
+        ring.push(&event(id));
+    }
+    assert_eq!((ring.len(), ring.capacity(), ring.total_written()), (4, 4, 10));
+    assert_eq!(ids(&ring), vec![7, 8, 9, 10]);
+    assert_eq!(ring.recent(2).map(|e| e.event_id).collect::<Vec<_>>(), vec![9, 10]);
+    assert_eq!(ring.get(0).unwrap().payload_hash, 49);
+    assert!(ring.get(4).is_none());
+}
+
+#[test]
+fn test_event_ring_survives_reopen() {
+    let path = temp_file("reopen.ring");
+    let mut ring = EventRing::create(&path, 3).unwrap();
+    for id in 1..=5 {
+        ring.push(&event(id));
+    }
+    ring.sync().unwrap();
+    drop(ring);
+
+    let mut ring = EventRing::open_or_create(&path, 100).unwrap();
+    assert_eq!(ring.capacity(), 3);
+    assert_eq!(ids(&ring), vec![3, 4, 5]);
+    assert_eq!(ring.get(2).unwrap().payload, event(5).payload);
+
+    ring.push(&event(6));
+    drop(ring);
+    // Без sync — изменения в отображении всё равно видны после переоткрытия
+    assert_eq!(ids(&EventRing::open(&path).unwrap()), vec![4, 5, 6]);
+}
+
+#[test]
+fn test_event_ring_rejects_bad_files() {
+    let path = temp_file("bad.ring");
+    std::fs::write(&path, b"not a ring").unwrap();
+    assert!(matches!(EventRing::open(&path), Err(PersistError::Decode(_))));
+
+    let truncated = temp_file("truncated.ring");
+    EventRing::create(&truncated, 2).unwrap();
+    let bytes = std::fs::read(&truncated).unwrap();
+    std::fs::write(&truncated, &bytes[..bytes.len() - 1]).unwrap();
+    assert!(matches!(EventRing::open(&truncated), Err(PersistError::Decode(_))));
+
+    assert!(matches!(
+        EventRing::open(&temp_file("missing.ring")),
+        Err(PersistError::NotFound(_))
+    ));
+}
+
+#[test]
+fn test_event_ring_observer_records_bus_events() {
+    let path = temp_file("observer.ring");
+    let ring = Rc::new(RefCell::new(EventRing::create(&path, 8).unwrap()));
+    let observer = EventRingObserver(Rc::clone(&ring));
+    observer.on_event(&event(42));
+    observer.on_event(&event(43));
+    assert_eq!(ids(&ring.borrow()), vec![42, 43]);
+    assert_eq!(ids(&EventRing::open(&path).unwrap()), vec![42, 43]);
+}
+
+#[test]
+fn test_event_ring_push_returns_evicted() {
+    let mut ring = EventRing::create(&temp_file("evict.ring"), 2).unwrap();
+    assert!(ring.push(&event(1)).is_none());
+    assert!(ring.push(&event(2)).is_none());
+    assert_eq!(ring.push(&event(3)).map(|e| e.event_id), Some(1));
+    assert_eq!(ring.first_seq(), 1);
+}
+
+/// Файл «версии 0»: слот 16 байт — event_id и target_id, little-endian.
+fn write_v0_ring(path: &std::path::Path, capacity: usize, ids: &[u64]) {
+    let mut bytes = vec![0u8; 64 + (capacity + 1) * 16];
+    bytes[0..8].copy_from_slice(b"AXEVRING");
+    bytes[16..24].copy_from_slice(&(capacity as u64).to_le_bytes());
+    bytes[24..32].copy_from_slice(&(ids.len() as u64).to_le_bytes());
+    for (n, &id) in ids.iter().enumerate() {
+        let slot = 64 + (n % (capacity + 1)) * 16;
+        bytes[slot..slot + 8].copy_from_slice(&id.to_le_bytes());
+        bytes[slot + 8..slot + 12].copy_from_slice(&(id as u32 * 10).to_le_bytes());
+    }
+    std::fs::write(path, bytes).unwrap();
+}
+
+fn migrate_v0(slot: &[u8]) -> Event {
+    let id = u64::from_le_bytes(slot[0..8].try_into().unwrap());
+    let target = u32::from_le_bytes(slot[8..12].try_into().unwrap());
+    Event::new(id, 100, EventType::TokenCreate, EventPriority::Normal, 0, target, 0, 0)
+}
+
+#[test]
+fn test_event_ring_migrates_old_version() {
+    let path = temp_file("v0.ring");
+    write_v0_ring(&path, 3, &[1, 2, 3, 4, 5]);
+    // Без миграции — ошибка версии
+    assert!(matches!(EventRing::open(&path), Err(PersistError::Decode(_))));
+    let none = EventMigrations::new();
+    assert!(matches!(EventRing::open_migrating(&path, &none), Err(PersistError::Decode(_))));
+
+    let mut migrations = EventMigrations::new();
+    migrations.register(0, 16, migrate_v0);
+    assert!(migrations.supports(0) && !migrations.supports(2));
+    let ring = EventRing::open_migrating(&path, &migrations).unwrap();
+    assert_eq!((ring.capacity(), ring.total_written()), (3, 5));
+    assert_eq!(ids(&ring), vec![3, 4, 5]);
+    assert_eq!(ring.get(2).unwrap().target_id, 50);
+    drop(ring);
+
+    // Файл переписан в текущей версии
+    assert_eq!(ids(&EventRing::open(&path).unwrap()), vec![3, 4, 5]);
+    assert_eq!(ids(&EventRing::open_migrating(&path, &migrations).unwrap()), vec![3, 4, 5]);
+}
+
+#[test]
+fn test_event_ring_rejects_overflowing_capacity() {
+    // Заголовок без слотов: 64 + 2^58 · 64 без проверки переполнения = 64 байта
+    let path = temp_file("overflow.ring");
+    EventRing::create(&path, 1).unwrap();
+    let mut header = std::fs::read(&path).unwrap()[..64].to_vec();
+    header[16..24].copy_from_slice(&((1u64 << 58) - 1).to_le_bytes());
+    std::fs::write(&path, &header).unwrap();
+    assert!(matches!(EventRing::open(&path), Err(PersistError::Decode(_))));
+
+    // capacity + 1 == 0: слотов было бы ноль, push делил бы на ноль
+    header[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+    std::fs::write(&path, &header).unwrap();
+    assert!(matches!(EventRing::open(&path), Err(PersistError::Decode(_))));
+
+    let huge = temp_file("huge.ring");
+    assert!(matches!(EventRing::create(&huge, usize::MAX / 2), Err(PersistError::Io(_))));
+}
+
+#[test]
+fn test_event_ring_open_or_create_migrating() {
+    let path = temp_file("v0-or-create.ring");
+    write_v0_ring(&path, 2, &[7, 8, 9]);
+    assert!(matches!(EventRing::open_or_create(&path, 16), Err(PersistError::Decode(_))));
+
+    let mut migrations = EventMigrations::new();
+    migrations.register(0, 16, migrate_v0);
+    let ring = EventRing::open_or_create_migrating(&path, 16, &migrations).unwrap();
+    assert_eq!((ring.capacity(), ids(&ring)), (2, vec![8, 9]));
+
+    let fresh = temp_file("fresh-migrating.ring");
+    let ring = EventRing::open_or_create_migrating(&fresh, 16, &migrations).unwrap();
+    assert_eq!((ring.capacity(), ring.len()), (16, 0));
+}
+
+#[test]
+fn test_event_ring_migration_rejects_overflowing_capacity() {
+    // (2^59 - 1 + 1) · 32 = 2^64: без проверки файл из одного заголовка прошёл бы
+    let path = temp_file("v0-overflow.ring");
+    let mut header = vec![0u8; 64];
+    header[0..8].copy_from_slice(b"AXEVRING");
+    header[16..24].copy_from_slice(&((1u64 << 59) - 1).to_le_bytes());
+    std::fs::write(&path, &header).unwrap();
+
+    let mut migrations = EventMigrations::new();
+    migrations.register(0, 32, migrate_v0);
+    assert!(matches!(EventRing::open_migrating(&path, &migrations), Err(PersistError::Decode(_))));
+}
@@ -0,0 +1,31 @@
+// Smoke-тесты fuzz-харнессов persist: повреждённые входы не паникуют.
+// Запуск: cargo test -p axiom-persist --features fuzzing
+#![cfg(feature = "fuzzing")]
+
+use axiom_persist::fuzz::{fuzz_engine_state, fuzz_exchange_package};
+
+#[test]
+fn test_fuzz_engine_state_garbage() {
+    fuzz_engine_state(&[]);
+    fuzz_engine_state(&[0xFF; 64]);
+    fuzz_engine_state(&[0x00; 256]);
+}
+
+#[test]
+fn test_fuzz_engine_state_truncated_valid_file() {
+    let engine = axiom_runtime::AxiomEngine::new();
+    let dir = std::env::temp_dir().join("axiom_fuzz_truncated");
+    let _ = std::fs::remove_dir_all(&dir);
+    axiom_persist::save(&engine, &dir, &axiom_persist::WriteOptions::default()).unwrap();
+    let bytes = std::fs::read(dir.join("engine_state.bin")).unwrap();
+    for cut in [0, 1, bytes.len() / 2, bytes.len().saturating_sub(1), bytes.len()] {
+        fuzz_engine_state(&bytes[..cut]);
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_fuzz_exchange_package_garbage() {
+    fuzz_exchange_package(&[]);
+    fuzz_exchange_package(&[0xAB; 128]);
+}
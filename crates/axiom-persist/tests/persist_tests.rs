@@ -287,3 +287,19 @@ fn test_trace_threshold_filtering() {
     // Сохранилось не больше чем всего traces
     assert!(manifest.contents.traces <= all_traces as u32);
 }
+
+// ─── serde: Event (axiom-core feature "serde") ──────────────────────────────
+
+#[test]
+fn test_event_serde_roundtrip() {
+    use axiom_core::{Event, EventPriority, EventType};
+    let mut event = Event::new(42, 100, EventType::TokenMove, EventPriority::High, 7, 3, 4, 41);
+    event.payload = [1, 0, 2, 0, 3, 0, 0, 0];
+    let bytes = bincode::serde::encode_to_vec(event, bincode::config::standard()).unwrap();
+    let (back, _): (Event, usize) =
+        bincode::serde::decode_from_slice(&bytes, bincode::config::standard()).unwrap();
+    assert_eq!(back.event_id, 42);
+    assert_eq!(back.parent_event_id, 41);
+    assert_eq!(back.event_type, EventType::TokenMove as u16);
+    assert_eq!(back.payload, event.payload);
+}
@@ -0,0 +1,158 @@
+/* SPDX-License-Identifier: AGPL-3.0-only */
+/* Copyright (C) 2024-2026 Chernov Denys */
+/* Do not edit: regenerate with `just ffi-header` (cbindgen). */
+
+#ifndef AXIOM_H
+#define AXIOM_H
+
+#include <stdint.h>
+
+#define AXIOM_ALIGNED(n) __attribute__((aligned(n)))
+
+/* Успех. */
+#define AXIOM_OK 0
+
+/* Нулевой указатель в аргументах. */
+#define AXIOM_ERR_NULL -1
+
+/* Домен или токен не найден. */
+#define AXIOM_ERR_NOT_FOUND -2
+
+/* Runtime отклонил операцию (ёмкость домена, дубликат, вето GUARDIAN). */
+#define AXIOM_ERR_REJECTED -3
+
+/* Непрозрачный хэндл runtime. */
+typedef struct AxiomHandle AxiomHandle;
+
+/* Основная структура команды - 64 байта */
+typedef struct AXIOM_ALIGNED(64) UclCommand {
+  uint8_t payload[48];
+  uint64_t command_id;
+  uint32_t target_id;
+  uint16_t opcode;
+  uint8_t priority;
+  uint8_t flags;
+} UclCommand;
+
+/* Ответ ядра - 32 байта */
+typedef struct AXIOM_ALIGNED(32) UclResult {
+  uint64_t command_id;
+  uint32_t execution_time_us;
+  float consumed_energy;
+  uint16_t error_code;
+  uint16_t events_generated;
+  uint8_t status;
+  uint8_t reserved[7];
+} UclResult;
+
+/* Token — 64 байта */
+typedef struct AXIOM_ALIGNED(64) Token {
+  uint32_t sutra_id;
+  uint16_t domain_id;
+  uint16_t type_flags;
+  int16_t position[3];
+  int16_t velocity[3];
+  int16_t target[3];
+  uint16_t origin;
+  int8_t valence;
+  uint8_t mass;
+  uint8_t temperature;
+  uint8_t state;
+  uint64_t lineage_hash;
+  int32_t momentum[3];
+  uint32_t resonance;
+  uint64_t last_event_id;
+} Token;
+
+/* Connection — связь между двумя токенами, 64 байта */
+typedef struct AXIOM_ALIGNED(64) Connection {
+  uint32_t source_id;
+  uint32_t target_id;
+  uint16_t domain_id;
+  uint16_t link_type;
+  uint32_t flags;
+  float strength;
+  float current_stress;
+  float ideal_dist;
+  float elasticity;
+  uint8_t density_gate;
+  uint8_t thermal_gate;
+  uint8_t reserved_gate[14];
+  uint64_t created_at;
+  uint64_t last_event_id;
+} Connection;
+
+/* Event — событие в причинном порядке, 64 байта */
+typedef struct AXIOM_ALIGNED(64) Event {
+  uint64_t event_id;
+  uint64_t parent_event_id;
+  uint64_t payload_hash;
+  uint32_t target_id;
+  uint32_t source_id;
+  uint16_t domain_id;
+  uint16_t event_type;
+  uint16_t payload_size;
+  uint8_t priority;
+  uint8_t flags;
+  uint64_t pulse_id;
+  uint16_t source_domain;
+  uint16_t event_subtype;
+  uint32_t snapshot_event_id;
+  uint8_t payload[8];
+} Event;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/* Создать runtime с конфигурацией по умолчанию. Освобождать — `axiom_free`. */
+AxiomHandle *axiom_new(void);
+
+/* `handle` — результат `axiom_new` либо NULL; после вызова недействителен. */
+void axiom_free(AxiomHandle *handle);
+
+/*
+ Отправить UCL-команду через Gateway; результат — в `result`.
+ События команды попадают в очередь `axiom_poll_event`.
+ */
+int32_t axiom_submit(AxiomHandle *handle, const UclCommand *command, UclResult *result);
+
+/* Забрать следующее событие. 1 — событие записано в `event`, 0 — очередь пуста. */
+int32_t axiom_poll_event(AxiomHandle *handle, Event *event);
+
+/*
+ Записать токен в домен `token.domain_id` напрямую, минуя UCL
+ (см. `AxiomEngine::inject_token_direct`).
+ */
+int32_t axiom_inject_token(AxiomHandle *handle, const Token *token);
+
+/* Прочитать токен `sutra_id` домена `domain_id` в `token`. */
+int32_t axiom_read_token(const AxiomHandle *handle,
+                         uint16_t domain_id,
+                         uint32_t sutra_id,
+                         Token *token);
+
+/*
+ Добавить связь в домен `connection.domain_id`
+ (см. `AxiomEngine::apply_connection_transaction`).
+ */
+int32_t axiom_add_connection(AxiomHandle *handle, const Connection *connection);
+
+/*
+ Снять связь `source_id → target_id` типа `link_type` в домене `domain_id`
+ (см. `AxiomEngine::remove_connection`); ConnectionDelete — в очереди событий.
+ */
+int32_t axiom_remove_connection(AxiomHandle *handle,
+                                uint16_t domain_id,
+                                uint32_t source_id,
+                                uint32_t target_id,
+                                uint16_t link_type);
+
+/* Число токенов в домене; отрицательное — код ошибки. */
+int64_t axiom_token_count(const AxiomHandle *handle, uint16_t domain_id);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AXIOM_H */
@@ -0,0 +1,229 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// C FFI — встраивание runtime в не-Python хосты (C, C++, Go через cgo)
+//
+// Хост держит непрозрачный AxiomHandle: Gateway плюс очередь событий,
+// которую наполняет наблюдатель Event Bus. Token, Connection, Event,
+// UclCommand и UclResult — repr(C) записи фиксированного размера, и через
+// границу они передаются как есть; описания для C — в include/axiom.h
+// (генерируется cbindgen по cbindgen.toml, см. `just ffi-header`).
+// Ошибки возвращаются кодами AXIOM_*; паника внутри runtime не раскручивается
+// через extern "C", а завершает процесс.
+// Хэндл не потокобезопасен: вызовы с одним хэндлом — из одного потока.
+
+#![allow(unsafe_code)]
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use axiom_core::{Connection, Event, Token};
+use axiom_domain::{ConnectionTransaction, Removal, TransactionError};
+use axiom_ucl::{UclCommand, UclResult};
+
+use crate::adapters::EventObserver;
+use crate::gateway::Gateway;
+
+/// Успех.
+pub const AXIOM_OK: i32 = 0;
+/// Нулевой указатель в аргументах.
+pub const AXIOM_ERR_NULL: i32 = -1;
+/// Домен или токен не найден.
+pub const AXIOM_ERR_NOT_FOUND: i32 = -2;
+/// Runtime отклонил операцию (ёмкость домена, дубликат, вето GUARDIAN).
+pub const AXIOM_ERR_REJECTED: i32 = -3;
+
+/// Непрозрачный хэндл runtime.
+pub struct AxiomHandle {
+    gateway: Gateway,
+    events: Rc<RefCell<VecDeque<Event>>>,
+}
+
+/// Наблюдатель, складывающий события в очередь хэндла.
+struct EventQueue(Rc<RefCell<VecDeque<Event>>>);
+
+impl EventObserver for EventQueue {
+    fn on_event(&self, event: &Event) {
+        self.0.borrow_mut().push_back(*event);
+    }
+}
+
+/// Создать runtime с конфигурацией по умолчанию. Освобождать — `axiom_free`.
+#[no_mangle]
+pub extern "C" fn axiom_new() -> *mut AxiomHandle {
+    let events = Rc::new(RefCell::new(VecDeque::new()));
+    let mut gateway = Gateway::with_default_engine();
+    gateway.register_observer(Box::new(EventQueue(Rc::clone(&events))));
+    Box::into_raw(Box::new(AxiomHandle { gateway, events }))
+}
+
+/// # Safety
+/// `handle` — результат `axiom_new` либо NULL; после вызова недействителен.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_free(handle: *mut AxiomHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Отправить UCL-команду через Gateway; результат — в `result`.
+/// События команды попадают в очередь `axiom_poll_event`.
+///
+/// # Safety
+/// `handle` — живой хэндл; `command` и `result` указывают на UclCommand и
+/// UclResult (выравнивание не требуется).
+#[no_mangle]
+pub unsafe extern "C" fn axiom_submit(
+    handle: *mut AxiomHandle,
+    command: *const UclCommand,
+    result: *mut UclResult,
+) -> i32 {
+    let Some(h) = handle.as_mut() else {
+        return AXIOM_ERR_NULL;
+    };
+    if command.is_null() || result.is_null() {
+        return AXIOM_ERR_NULL;
+    }
+    let outcome = h.gateway.process(&command.read_unaligned());
+    result.write_unaligned(outcome);
+    AXIOM_OK
+}
+
+/// Забрать следующее событие. 1 — событие записано в `event`, 0 — очередь пуста.
+///
+/// # Safety
+/// `handle` — живой хэндл; `event` указывает на место под Event.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_poll_event(handle: *mut AxiomHandle, event: *mut Event) -> i32 {
+    let Some(h) = handle.as_mut() else {
+        return AXIOM_ERR_NULL;
+    };
+    if event.is_null() {
+        return AXIOM_ERR_NULL;
+    }
+    h.gateway.drain_and_notify();
+    match h.events.borrow_mut().pop_front() {
+        Some(next) => {
+            event.write_unaligned(next);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Записать токен в домен `token.domain_id` напрямую, минуя UCL
+/// (см. `AxiomEngine::inject_token_direct`).
+///
+/// # Safety
+/// `handle` — живой хэндл; `token` указывает на Token.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_inject_token(handle: *mut AxiomHandle, token: *const Token) -> i32 {
+    let Some(h) = handle.as_mut() else {
+        return AXIOM_ERR_NULL;
+    };
+    if token.is_null() {
+        return AXIOM_ERR_NULL;
+    }
+    let token = token.read_unaligned();
+    let engine = h.gateway.engine_mut();
+    if engine.ashti.index_of(token.domain_id).is_none() {
+        return AXIOM_ERR_NOT_FOUND;
+    }
+    match engine.inject_token_direct(token.domain_id, token) {
+        Ok(_) => AXIOM_OK,
+        Err(_) => AXIOM_ERR_REJECTED,
+    }
+}
+
+/// Прочитать токен `sutra_id` домена `domain_id` в `token`.
+///
+/// # Safety
+/// `handle` — живой хэндл; `token` указывает на место под Token.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_read_token(
+    handle: *const AxiomHandle,
+    domain_id: u16,
+    sutra_id: u32,
+    token: *mut Token,
+) -> i32 {
+    let Some(h) = handle.as_ref() else {
+        return AXIOM_ERR_NULL;
+    };
+    if token.is_null() {
+        return AXIOM_ERR_NULL;
+    }
+    match h.gateway.engine().ashti.find_token_by_sutra_id(domain_id, sutra_id) {
+        Some(found) => {
+            token.write_unaligned(found);
+            AXIOM_OK
+        }
+        None => AXIOM_ERR_NOT_FOUND,
+    }
+}
+
+/// Добавить связь в домен `connection.domain_id`
+/// (см. `AxiomEngine::apply_connection_transaction`).
+///
+/// # Safety
+/// `handle` — живой хэндл; `connection` указывает на Connection.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_add_connection(
+    handle: *mut AxiomHandle,
+    connection: *const Connection,
+) -> i32 {
+    let Some(h) = handle.as_mut() else {
+        return AXIOM_ERR_NULL;
+    };
+    if connection.is_null() {
+        return AXIOM_ERR_NULL;
+    }
+    let connection = connection.read_unaligned();
+    let mut tx = ConnectionTransaction::new(connection.domain_id);
+    tx.add(connection);
+    match h.gateway.engine_mut().apply_connection_transaction(&tx) {
+        Ok(_) => AXIOM_OK,
+        Err(TransactionError::UnknownDomain(_)) => AXIOM_ERR_NOT_FOUND,
+        Err(_) => AXIOM_ERR_REJECTED,
+    }
+}
+
+/// Снять связь `source_id → target_id` типа `link_type` в домене `domain_id`
+/// (см. `AxiomEngine::remove_connection`); ConnectionDelete — в очереди событий.
+///
+/// # Safety
+/// `handle` — живой хэндл.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_remove_connection(
+    handle: *mut AxiomHandle,
+    domain_id: u16,
+    source_id: u32,
+    target_id: u32,
+    link_type: u16,
+) -> i32 {
+    let Some(h) = handle.as_mut() else {
+        return AXIOM_ERR_NULL;
+    };
+    let edge = (source_id, target_id, link_type);
+    match h.gateway.engine_mut().remove_connection(domain_id, edge) {
+        Removal::Removed(_) => AXIOM_OK,
+        Removal::Vetoed => AXIOM_ERR_REJECTED,
+        Removal::NotFound => AXIOM_ERR_NOT_FOUND,
+    }
+}
+
+/// Число токенов в домене; отрицательное — код ошибки.
+///
+/// # Safety
+/// `handle` — живой хэндл.
+#[no_mangle]
+pub unsafe extern "C" fn axiom_token_count(handle: *const AxiomHandle, domain_id: u16) -> i64 {
+    let Some(h) = handle.as_ref() else {
+        return i64::from(AXIOM_ERR_NULL);
+    };
+    let engine = h.gateway.engine();
+    if engine.ashti.index_of(domain_id).is_none() {
+        return i64::from(AXIOM_ERR_NOT_FOUND);
+    }
+    engine.token_count(domain_id) as i64
+}
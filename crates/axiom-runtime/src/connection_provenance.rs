@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// ConnectionProvenance — происхождение связей вне 64-байтового Connection.
+//
+// Connection хранит только created_at (event_id) и origin_domain/role_id
+// в reserved_gate; по нему не видно, какой модуль создал ребро и на каком тике.
+// Sidecar-карта записывает это в handle_bond_tokens. Ключ — (domain_id,
+// source_id, target_id, link_type): Connection не имеет собственного id.
+
+use axiom_genome::ModuleId;
+use std::collections::HashMap;
+
+/// Ключ связи: (domain_id, source_id, target_id, link_type).
+pub type EdgeKey = (u16, u32, u32, u16);
+
+/// Происхождение одной связи.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeProvenance {
+    /// Модуль, выдавший BondTokens. None — внешняя команда (адаптер, Gateway).
+    pub module: Option<ModuleId>,
+    /// COM event_id создания (совпадает с Connection::created_at)
+    pub event_id: u64,
+    /// Тик движка на момент создания
+    pub tick: u64,
+}
+
+/// Происхождение связей по ключу ребра.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionProvenance {
+    edges: HashMap<EdgeKey, EdgeProvenance>,
+}
+
+impl ConnectionProvenance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Записать происхождение. Повторная связь с тем же ключом не затирает
+    /// первую запись — хранится момент, когда ребро появилось впервые.
+    pub fn record(&mut self, key: EdgeKey, provenance: EdgeProvenance) {
+        self.edges.entry(key).or_insert(provenance);
+    }
+
+    /// Происхождение связи.
+    pub fn get(&self, key: &EdgeKey) -> Option<&EdgeProvenance> {
+        self.edges.get(key)
+    }
+
+    /// Забыть связь (после удаления). Возвращает её запись.
+    pub fn forget(&mut self, key: &EdgeKey) -> Option<EdgeProvenance> {
+        self.edges.remove(key)
+    }
+
+    /// Перенести связи токена `from` домена `domain_id` на `into` (слияние
+    /// токенов). Ставшие петлями записи забываются; при совпадении ключа
+    /// остаётся более ранняя запись. Возвращает число перенесённых записей.
+    pub fn merge_node(&mut self, domain_id: u16, from: u32, into: u32) -> usize {
+        if from == into {
+            return 0;
+        }
+        let moved: Vec<EdgeKey> = self
+            .edges
+            .keys()
+            .filter(|&&(d, s, t, _)| d == domain_id && (s == from || t == from))
+            .copied()
+            .collect();
+        let rename = |id: u32| if id == from { into } else { id };
+        let mut merged = 0;
+        for key in moved {
+            let provenance = self.edges.remove(&key).expect("key collected above");
+            let (source, target) = (rename(key.1), rename(key.2));
+            if source == target {
+                continue;
+            }
+            let slot = self.edges.entry((domain_id, source, target, key.3)).or_insert(provenance);
+            if provenance.event_id < slot.event_id {
+                *slot = provenance;
+            }
+            merged += 1;
+        }
+        merged
+    }
+
+    /// Связи, созданные модулем `module` (None — внешними командами).
+    pub fn created_by(&self, module: Option<ModuleId>) -> impl Iterator<Item = &EdgeKey> {
+        self.edges.iter().filter(move |(_, p)| p.module == module).map(|(k, _)| k)
+    }
+
+    /// Число записанных связей.
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
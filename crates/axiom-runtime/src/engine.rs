@@ -9,22 +9,29 @@
 //     └── Guardian  (CODEX-валидация рефлексов)
 
 use crate::adaptive::AdaptiveTickRate;
-use crate::guardian::{Guardian, GuardianConfig, RoleStats};
+use crate::connection_provenance::{ConnectionProvenance, EdgeProvenance};
+use crate::guardian::{Guardian, GuardianConfig, InhibitAction, RoleStats};
 use crate::orchestrator;
 use crate::over_domain::{
     cluster_emergent_primitives, restore_frame_from_anchor, AdvisorySource, AxialEvaluator,
     ContextRecognizer, DreamCycle, DreamPhaseState, DreamPhaseStats, DreamProposalKind,
     DreamScheduler, FatigueSnapshot, FrameWeaver, GatewayPriority, NeuralAdvisor,
-    OverDomainArbiter, OverDomainComponent, Sensorium, SensoriumView, SleepDecision, SleepTrigger,
+    OverDomainArbiter, OverDomainComponent, OverDomainModuleInfo, Sensorium, SensoriumView, SleepDecision, SleepTrigger,
     SleepTriggerKind, SubsystemCandidateStore, WakeReason, Waves, WavesView,
     WAVES_TICK_INTERVAL, WeaverId,
 };
 use crate::snapshot::{DomainSnapshot, EngineSnapshot};
 use axiom_config::DomainConfig;
-use axiom_core::{Connection, Event, Token, FLAG_ACTIVE};
-use axiom_domain::AshtiCore;
+use axiom_core::{Connection, Event, Token, FLAG_ACTIVE, FLAG_BIDIRECTIONAL};
+use axiom_domain::{
+    AshtiCore, ConnectionDecay, ConnectionExpiryReport, ConnectionLearningRule,
+    ConnectionPruneReport, ConnectionPruner, ConnectionTransaction, GraphAttributes, GraphQuery,
+    GraphQueryError, NodeCentrality, OrphanCriteria, OrphanGcReport, PageRankConfig, Removal,
+    StrengthNormalization, SymmetryPolicy, TokenHistory, TokenLabels, TransactionError,
+    TransactionOp, TransactionReport,
+};
 use axiom_experience::SubsystemId;
-use axiom_genome::Genome;
+use axiom_genome::{Genome, ModuleId};
 use axiom_ucl::{
     flags as ucl_flags, ucl_preset_to_structural_role, BondTokensPayload, CommandStatus,
     InjectFrameAnchorPayload, InjectTokenPayload, OpCode, ReinforceFramePayload,
@@ -105,9 +112,30 @@ pub struct TickSchedule {
     pub goal_check_interval: u32,
     /// Shell reconcile (default: 200)
     pub reconcile_interval: u32,
+    /// Нормализация исходящих связей по `AxiomEngine::strength_normalization` (default: 100).
+    /// 0 = отключено. При пустой политике проход — no-op.
+    pub strength_norm_interval: u32,
+    /// Затухание простаивающих связей по `AxiomEngine::connection_decay` (default: 100).
+    /// 0 = отключено. При политике по умолчанию (factor = 1.0) проход — no-op.
+    pub connection_decay_interval: u32,
+    /// Шаг пластичности связей по `AxiomEngine::connection_learning` (default: 0 = отключено).
+    pub connection_learning_interval: u32,
+    /// GC связей с истёкшим TTL, через review GUARDIAN (default: 100). 0 = отключено.
+    /// Затрагивает только связи с TTL (`Connection::set_ttl`).
+    pub connection_ttl_interval: u32,
+    /// Удаление связей по `AxiomEngine::connection_pruner` (default: 0 = отключено).
+    /// Каждое удаление оставляет событие ConnectionDelete.
+    pub connection_prune_interval: u32,
+    /// Компакция связей, удалённых `AxiomEngine::remove_connection` (default: 1).
+    /// Домен без новых tombstone пропускается за O(1), поэтому проход дешёв
+    /// и на каждом тике. 0 = отключено: tombstone копятся до прохода TTL или pruner.
+    pub connection_compact_interval: u32,
     /// Subsystem gravity pass: Values pull/push + Abstractions pull (default: 500).
     /// 0 = отключено. Медленное смысловое смещение — не каждый тик.
     pub subsystem_gravity_interval: u32,
+    /// GC осиротевших токенов по `AxiomEngine::orphan_criteria` (default: 0 = отключено).
+    /// Каждое удаление проходит review GUARDIAN и оставляет TokenDelete-tombstone.
+    pub orphan_gc_interval: u32,
     /// Автосохранение состояния на диск (default: 0 = отключено).
     /// При ненулевом значении — сохраняет каждые N тиков.
     pub persist_check_interval: u32,
@@ -142,7 +170,14 @@ impl Default for TickSchedule {
             tension_check_interval: 10,
             goal_check_interval: 10,
             reconcile_interval: 200,
+            strength_norm_interval: 100,
+            connection_decay_interval: 100,
+            connection_learning_interval: 0,
+            connection_ttl_interval: 100,
+            connection_prune_interval: 0,
+            connection_compact_interval: 1,
             subsystem_gravity_interval: 500,
+            orphan_gc_interval: 0,
             persist_check_interval: 0,
             adaptive_tick: AdaptiveTickRate::default(),
             weaver_scan_intervals: HashMap::new(),
@@ -179,6 +214,25 @@ pub struct AxiomEngine {
     pub tick_schedule: TickSchedule,
     /// Параметры адаптации Guardian (скорость обучения модели)
     pub guardian_config: GuardianConfig,
+    /// Политика нормализации исходящих связей (по умолчанию пустая — no-op)
+    pub strength_normalization: StrengthNormalization,
+    /// Политика затухания связей (по умолчанию — no-op)
+    pub connection_decay: ConnectionDecay,
+    /// Правило пластичности связей (TickSchedule::connection_learning_interval)
+    pub connection_learning: ConnectionLearningRule,
+    /// Политика удаления связей (TickSchedule::connection_prune_interval)
+    pub connection_pruner: ConnectionPruner,
+    /// Как сводить strength половин пар FLAG_BIDIRECTIONAL после проходов по связям
+    pub bidirectional_symmetry: SymmetryPolicy,
+    /// Критерии периодического GC осиротевших токенов (TickSchedule::orphan_gc_interval).
+    pub orphan_criteria: OrphanCriteria,
+    /// История версий токенов, которые сдвигают обучающие циклы
+    /// (subsystem gravity, ReinforceFrame). None — выключена (по умолчанию).
+    pub token_history: Option<TokenHistory>,
+    /// Происхождение связей (модуль, event_id, тик). None — выключено (по умолчанию).
+    pub connection_provenance: Option<ConnectionProvenance>,
+    /// Модуль, чьи команды сейчас исполняются (None — внешняя команда).
+    pub(crate) command_origin: Option<ModuleId>,
     /// Число аппаратных потоков, определённых при boot (available_parallelism).
     /// Минимум 1.
     pub worker_count: usize,
@@ -235,6 +289,12 @@ pub struct AxiomEngine {
     /// Заполняется в inject_anchor_tokens шаге 4. Используется FrameWeaver для
     /// shell-proximity и ContextRecognizer для shell-energy-bonus.
     pub shell_registry: HashMap<u32, [u8; 8]>,
+    /// Человекочитаемые метки токенов (sutra_id ↔ слово якоря и его синонимы).
+    /// Заполняется в inject_anchor_tokens; обратный путь для адаптеров вывода.
+    pub token_labels: TokenLabels,
+    /// Атрибуты приложения на токенах (sutra_id) и связях (EdgeId).
+    /// Атрибуты связи снимаются вместе с ней (ConnectionDelete).
+    pub graph_attributes: GraphAttributes,
     /// Средний Shell-профиль каждой подсистемы (computed from anchor YAML).
     /// Ключ = SubsystemId, значение = среднеарифметический shell [L1..L8].
     pub subsystem_shell_templates: HashMap<SubsystemId, [u8; 8]>,
@@ -297,6 +357,15 @@ impl AxiomEngine {
             tick_count: 0,
             tick_schedule: TickSchedule::default(),
             guardian_config: GuardianConfig::default(),
+            strength_normalization: StrengthNormalization::default(),
+            connection_decay: ConnectionDecay::default(),
+            connection_learning: ConnectionLearningRule::default(),
+            connection_pruner: ConnectionPruner::default(),
+            bidirectional_symmetry: SymmetryPolicy::default(),
+            orphan_criteria: OrphanCriteria::default(),
+            token_history: None,
+            connection_provenance: None,
+            command_origin: None,
             worker_count,
             thread_pool: get_shared_pool(worker_count),
             over_domain_components: Vec::new(),
@@ -321,6 +390,8 @@ impl AxiomEngine {
             dream_started_at: 0,
             last_dream_summary: None,
             shell_registry: HashMap::new(),
+            token_labels: TokenLabels::new(),
+            graph_attributes: GraphAttributes::new(),
             subsystem_shell_templates: HashMap::new(),
             co_activation_window: HashMap::new(),
             subsystem_candidate_store: SubsystemCandidateStore::default(),
@@ -366,6 +437,47 @@ impl AxiomEngine {
         self.over_domain_arbiter.reject_pending(advisory_id);
     }
 
+    /// PageRank, betweenness и степени токенов домена, по убыванию PageRank.
+    /// None — домен не найден.
+    pub fn domain_centrality(
+        &self,
+        domain_id: u16,
+        config: &PageRankConfig,
+    ) -> Option<Vec<NodeCentrality>> {
+        let state = self.ashti.state(self.ashti.index_of(domain_id)?)?;
+        Some(axiom_domain::centrality(&state.connections, config))
+    }
+
+    /// Исполнить строку GraphQuery над доменом. None — домен не найден.
+    pub fn query_domain(
+        &self,
+        domain_id: u16,
+        query: &str,
+    ) -> Option<Result<Vec<u32>, GraphQueryError>> {
+        let state = self.ashti.state(self.ashti.index_of(domain_id)?)?;
+        Some(query.parse::<GraphQuery>().map(|q| q.run(state)))
+    }
+
+    /// Зарегистрированные Over-Domain компоненты: встроенные, затем подключённые.
+    pub fn over_domain_modules(&self) -> Vec<OverDomainModuleInfo> {
+        let builtin: [&dyn OverDomainComponent; 5] = [
+            &self.frame_weaver,
+            &self.axial_evaluator,
+            &self.context_recognizer,
+            &self.neural_advisor,
+            &self.over_domain_arbiter,
+        ];
+        builtin
+            .into_iter()
+            .map(|c| OverDomainModuleInfo::of(c, true, &self.genome))
+            .chain(
+                self.over_domain_components
+                    .iter()
+                    .map(|c| OverDomainModuleInfo::of(c.as_ref(), false, &self.genome)),
+            )
+            .collect()
+    }
+
     /// Число токенов в домене по domain_id
     pub fn token_count(&self, domain_id: u16) -> usize {
         self.ashti.token_count(domain_id)
@@ -396,6 +508,238 @@ impl AxiomEngine {
         }
     }
 
+    /// GC осиротевших токенов с review каждого удаления через Guardian.
+    ///
+    /// Для каждого удалённого токена в очередь событий кладётся TokenDelete —
+    /// tombstone-запись для подписчиков и event log; метки, атрибуты и
+    /// история токена снимаются.
+    pub fn collect_orphans(&mut self, criteria: &OrphanCriteria) -> OrphanGcReport {
+        use axiom_core::{EventPriority, EventType};
+        let now = self.com_next_id;
+        let guardian = &mut self.guardian;
+        let report =
+            self.ashti.collect_orphans(criteria, now, |token| guardian.review_tombstone(token));
+        for (domain_id, token) in &report.tombstones {
+            let event_id = self.next_event_id();
+            self.token_labels.remove_token(token.sutra_id);
+            self.graph_attributes.remove_node(token.sutra_id);
+            if let Some(history) = self.token_history.as_mut() {
+                history.forget(*domain_id, token.sutra_id);
+            }
+            self.pending_events.push(Event::new(
+                event_id,
+                *domain_id,
+                EventType::TokenDelete,
+                EventPriority::Low,
+                token.lineage_hash,
+                token.sutra_id,
+                token.sutra_id,
+                token.last_event_id,
+            ));
+        }
+        report
+    }
+
+    /// Удалить связи с истёкшим TTL. Каждое удаление проходит
+    /// `Guardian::review_connection_expiry`; одобренные оставляют ConnectionDelete.
+    pub fn expire_connections(&mut self) -> ConnectionExpiryReport {
+        let now = self.com_next_id;
+        let guardian = &mut self.guardian;
+        let report =
+            self.ashti.expire_connections(now, |c| guardian.review_connection_expiry(c));
+        for (domain_id, conn) in &report.expired {
+            self.push_connection_delete(*domain_id, conn);
+        }
+        report
+    }
+
+    /// Удалить связь домена. Удаление проходит `Guardian::review_connection_removal`;
+    /// одобренное помечает связь tombstone (буфер чистит компакция) и оставляет
+    /// ConnectionDelete.
+    pub fn remove_connection(
+        &mut self,
+        domain_id: u16,
+        edge: (u32, u32, u16),
+    ) -> Removal<Connection> {
+        let guardian = &mut self.guardian;
+        let removal = self
+            .ashti
+            .remove_connection(domain_id, edge, |c| guardian.review_connection_removal(c));
+        if let Removal::Removed(conn) = &removal {
+            self.push_connection_delete(domain_id, conn);
+        }
+        removal
+    }
+
+    /// Удалить токен домена вместе с его связями. Удаление проходит
+    /// `Guardian::review_tombstone`; одобренное оставляет TokenDelete и
+    /// ConnectionDelete для каждой снятой связи; метки, атрибуты и история
+    /// токена снимаются.
+    pub fn remove_token(
+        &mut self,
+        domain_id: u16,
+        sutra_id: u32,
+    ) -> Removal<(Token, Vec<Connection>)> {
+        use axiom_core::{EventPriority, EventType};
+        let guardian = &mut self.guardian;
+        let removal =
+            self.ashti.remove_token(domain_id, sutra_id, |t| guardian.review_tombstone(t));
+        if let Removal::Removed((token, connections)) = &removal {
+            let event_id = self.next_event_id();
+            self.graph_attributes.remove_node(sutra_id);
+            self.token_labels.remove_token(sutra_id);
+            if let Some(history) = self.token_history.as_mut() {
+                history.forget(domain_id, sutra_id);
+            }
+            self.pending_events.push(Event::new(
+                event_id,
+                domain_id,
+                EventType::TokenDelete,
+                EventPriority::Low,
+                token.lineage_hash,
+                token.sutra_id,
+                token.sutra_id,
+                token.last_event_id,
+            ));
+            for conn in connections {
+                self.push_connection_delete(domain_id, conn);
+            }
+        }
+        removal
+    }
+
+    /// Применить транзакцию связей атомарно. Снятие связи проходит
+    /// `Guardian::review_connection_removal`; вето откатывает всю транзакцию.
+    /// Снятые связи оставляют ConnectionDelete.
+    pub fn apply_connection_transaction(
+        &mut self,
+        tx: &ConnectionTransaction,
+    ) -> Result<TransactionReport, TransactionError> {
+        let guardian = &mut self.guardian;
+        let report = self.ashti.apply_transaction(tx, |op, existing| match (op, existing) {
+            (TransactionOp::Remove(_), Some(conn)) => guardian.review_connection_removal(conn),
+            _ => true,
+        })?;
+        for conn in &report.removed {
+            self.push_connection_delete(tx.domain_id(), conn);
+        }
+        Ok(report)
+    }
+
+    /// ConnectionDelete (со своим event_id) для удалённой связи; забыть её
+    /// происхождение и атрибуты.
+    fn push_connection_delete(&mut self, domain_id: u16, conn: &Connection) {
+        use axiom_core::{EventPriority, EventType};
+        let event_id = self.next_event_id();
+        let edge = (domain_id, conn.source_id, conn.target_id, conn.link_type);
+        if let Some(provenance) = self.connection_provenance.as_mut() {
+            provenance.forget(&edge);
+        }
+        self.graph_attributes.remove_edge(edge);
+        self.pending_events.push(Event::new(
+            event_id,
+            domain_id,
+            EventType::ConnectionDelete,
+            EventPriority::Low,
+            (conn.source_id as u64) << 32 | conn.target_id as u64,
+            conn.target_id,
+            conn.source_id,
+            conn.last_event_id,
+        ));
+    }
+
+    /// Удалить связи по `connection_pruner` во всех доменах.
+    ///
+    /// Для каждой удалённой связи в очередь событий кладётся ConnectionDelete
+    /// (source_id / target_id — концы связи). Отчёт — сводка по причинам.
+    pub fn prune_connections(&mut self) -> ConnectionPruneReport {
+        let now = self.com_next_id;
+        let report = self.ashti.prune_connections(&self.connection_pruner, now);
+        if report.removed.is_empty() {
+            return report;
+        }
+        for (domain_id, conn, _) in &report.removed {
+            self.push_connection_delete(*domain_id, conn);
+        }
+        report
+    }
+
+    /// Включить историю версий токенов глубиной `depth` поколений.
+    pub fn enable_token_history(&mut self, depth: usize) {
+        self.token_history = Some(TokenHistory::new(depth));
+    }
+
+    /// Включить запись происхождения связей.
+    pub fn enable_connection_provenance(&mut self) {
+        self.connection_provenance = Some(ConnectionProvenance::new());
+    }
+
+    /// Откатить токен к версии `back` поколений назад (0 — последняя записанная).
+    ///
+    /// Текущее состояние перед откатом само попадает в историю, так что откат
+    /// обратим. Возвращает восстановленную версию.
+    pub fn revert_token(
+        &mut self,
+        domain_id: u16,
+        sutra_id: u32,
+        back: usize,
+    ) -> Result<Token, String> {
+        let history = self.token_history.as_mut().ok_or("token history is disabled")?;
+        let target = *history.previous(domain_id, sutra_id, back).ok_or_else(|| {
+            format!("no generation {back} for token {sutra_id} in domain {domain_id}")
+        })?;
+        let current = self
+            .ashti
+            .replace_token(target)
+            .ok_or_else(|| format!("token {sutra_id} not found in domain {domain_id}"))?;
+        history.record(&current);
+        Ok(target)
+    }
+
+    /// Слить почти совпадающие токены домена. Для каждой пары в очередь событий
+    /// кладётся TokenMerged (target_id — оставленный, source_id — поглощённый,
+    /// parent — последнее событие поглощённого); метки, атрибуты, история и
+    /// происхождение связей поглощённого переходят к оставленному.
+    pub fn dedup_tokens(
+        &mut self,
+        domain_id: u16,
+        radius: i16,
+        strategy: axiom_core::MergeStrategy,
+    ) -> Option<axiom_domain::DedupReport> {
+        use axiom_core::{EventPriority, EventType};
+        let idx = self.ashti.index_of(domain_id)?;
+        let last_event: HashMap<u32, u64> = self
+            .ashti
+            .state(idx)?
+            .tokens
+            .iter()
+            .map(|t| (t.sutra_id, t.last_event_id))
+            .collect();
+        let report = self.ashti.dedup_tokens(domain_id, radius, strategy)?;
+        for &(kept, absorbed) in &report.merged {
+            let event_id = self.next_event_id();
+            self.token_labels.merge_token(kept, absorbed);
+            self.graph_attributes.merge_node(domain_id, kept, absorbed);
+            if let Some(history) = self.token_history.as_mut() {
+                history.absorb(domain_id, absorbed, kept);
+            }
+            if let Some(provenance) = self.connection_provenance.as_mut() {
+                provenance.merge_node(domain_id, absorbed, kept);
+            }
+            self.pending_events.push(Event::new(
+                event_id,
+                domain_id,
+                EventType::TokenMerged,
+                EventPriority::Low,
+                (kept as u64) << 32 | absorbed as u64,
+                kept,
+                absorbed,
+                last_event.get(&absorbed).copied().unwrap_or(0),
+            ));
+        }
+        Some(report)
+    }
+
     // ── DREAM Phase accessors (pub для интеграционных тестов) ─────────────────
 
     /// true — если в текущем тике был внешний ввод (InjectToken через process_and_observe).
@@ -443,6 +787,25 @@ impl AxiomEngine {
         self.ashti.inject_token(domain_id, token)
     }
 
+    /// Пакетная вставка токенов (все или ничего).
+    ///
+    /// GUARDIAN сканирует целевой домен один раз после вставки всего пакета,
+    /// а не на каждый токен. Нарушения возвращаются вместе с индексами.
+    pub fn inject_token_batch(
+        &mut self,
+        batch: &axiom_domain::TokenBatchBuilder,
+    ) -> Result<(Vec<usize>, Vec<InhibitAction>), axiom_domain::TokenBatchError> {
+        let indices = self.ashti.inject_batch(batch)?;
+        self.had_intake_this_tick = true;
+        let actions = self
+            .ashti
+            .index_of(batch.domain_id())
+            .and_then(|i| self.ashti.state(i))
+            .map(|state| self.guardian.scan_domain(state))
+            .unwrap_or_default();
+        Ok((indices, actions))
+    }
+
     // ── DREAM Phase helpers ───────────────────────────────────────────────────
 
     /// Собирает FatigueSnapshot из текущего состояния Engine.
@@ -522,8 +885,7 @@ impl AxiomEngine {
             domain_id,
             tokens,
             connections: state
-                .connections
-                .iter()
+                .live_connections()
                 .map(crate::broadcast::ConnectionSnapshot::from)
                 .collect(),
         })
@@ -552,7 +914,7 @@ impl AxiomEngine {
                     domain_id: id,
                     config,
                     tokens: state.tokens.clone(),
-                    connections: state.connections.clone(),
+                    connections: state.live_connections().copied().collect(),
                 }
             })
             .collect();
@@ -613,6 +975,17 @@ impl AxiomEngine {
         engine
     }
 
+    /// Обработать UCL-команду от внутреннего модуля `module`.
+    ///
+    /// Отличается от process_command только атрибуцией: связи, созданные
+    /// командой, записываются в connection_provenance как созданные `module`.
+    pub fn process_command_from(&mut self, module: ModuleId, cmd: &UclCommand) -> UclResult {
+        let outer = self.command_origin.replace(module);
+        let result = self.process_command(cmd);
+        self.command_origin = outer;
+        result
+    }
+
     /// Обработать UCL-команду
     pub fn process_command(&mut self, cmd: &UclCommand) -> UclResult {
         let opcode = match opcode_from_u16(cmd.opcode) {
@@ -965,8 +1338,26 @@ impl AxiomEngine {
         conn.reserved_gate[2] = (p.role_id >> 8) as u8;
         conn.reserved_gate[3] = (p.role_id & 0xFF) as u8;
 
-        match self.ashti.inject_connection(p.domain_id, conn) {
-            Ok(_) => make_result(cmd.command_id, CommandStatus::Success, error_codes::OK, 1),
+        // FLAG_BIDIRECTIONAL в conn_flags — создать управляемую пару
+        let injected = if conn.flags & FLAG_BIDIRECTIONAL != 0 {
+            self.ashti.inject_bidirectional_connection(p.domain_id, conn).map(|(f, _)| f)
+        } else {
+            self.ashti.inject_connection(p.domain_id, conn)
+        };
+        match injected {
+            Ok(_) => {
+                if let Some(provenance) = self.connection_provenance.as_mut() {
+                    provenance.record(
+                        (p.domain_id, p.source_id, p.target_id, p.link_type),
+                        EdgeProvenance {
+                            module: self.command_origin,
+                            event_id,
+                            tick: self.tick_count,
+                        },
+                    );
+                }
+                make_result(cmd.command_id, CommandStatus::Success, error_codes::OK, 1)
+            }
             Err(_) => make_result(
                 cmd.command_id,
                 CommandStatus::SystemError,
@@ -980,6 +1371,12 @@ impl AxiomEngine {
         let p = read_reinforce_frame_payload(&cmd.payload);
         // Усиливаем Frame-анкер в EXPERIENCE (domain_id=109 для level_id=1)
         let experience_domain = self.ashti.level_id() * 100 + 9;
+        if let Some(history) = self.token_history.as_mut() {
+            let anchor = self.ashti.find_token_by_sutra_id(experience_domain, p.anchor_id);
+            if let Some(anchor) = anchor {
+                history.record(&anchor);
+            }
+        }
         if self.ashti.reinforce_token(
             experience_domain,
             p.anchor_id,
@@ -1220,6 +1617,63 @@ impl AxiomEngine {
             let _ = self.ashti.reconcile_all();
         }
 
+        // Cold path: пластичность связей по времени активации концов (до нормализации —
+        // она и гасит рост вокруг хабов)
+        let mut strengths_changed = false;
+        if s.connection_learning_interval > 0
+            && t.is_multiple_of(s.connection_learning_interval as u64)
+        {
+            let _ = self.ashti.learn_connections(&self.connection_learning);
+            strengths_changed = true;
+        }
+
+        // Cold path: гомеостаз связей — гасим насыщение вокруг хабов
+        if s.strength_norm_interval > 0 && t.is_multiple_of(s.strength_norm_interval as u64) {
+            let _ = self.ashti.normalize_strengths(&self.strength_normalization);
+            strengths_changed = true;
+        }
+
+        // Cold path: затухание связей — без него ребро, которого давно не касались,
+        // остаётся таким же сильным, как при последней активации
+        if s.connection_decay_interval > 0
+            && t.is_multiple_of(s.connection_decay_interval as u64)
+        {
+            let now = self.com_next_id;
+            let _ = self.ashti.decay_connections(&self.connection_decay, now);
+            strengths_changed = true;
+        }
+
+        // Половины управляемых пар менялись независимо — свести их обратно
+        if strengths_changed {
+            let _ = self.ashti.sync_bidirectional(self.bidirectional_symmetry);
+        }
+
+        // Cold path: GC связей с истёкшим TTL — удаление только с одобрения GUARDIAN
+        if s.connection_ttl_interval > 0 && t.is_multiple_of(s.connection_ttl_interval as u64) {
+            let _ = self.expire_connections();
+        }
+
+        // Cold path: забывание связей по политике (после decay — свежие пометки учтены)
+        if s.connection_prune_interval > 0
+            && t.is_multiple_of(s.connection_prune_interval as u64)
+        {
+            let _ = self.prune_connections();
+        }
+
+        // Cold path: убрать tombstone-связи из буферов доменов
+        if s.connection_compact_interval > 0
+            && t.is_multiple_of(s.connection_compact_interval as u64)
+        {
+            let _ = self.ashti.compact_connections();
+        }
+
+        // Cold path: GC осиротевших токенов — без него долгоживущий runtime
+        // копит токены, которые уже ни с чем не связаны и давно не активировались
+        if s.orphan_gc_interval > 0 && t.is_multiple_of(s.orphan_gc_interval as u64) {
+            let criteria = self.orphan_criteria;
+            let _ = self.collect_orphans(&criteria);
+        }
+
         // Cold path: subsystem gravity (PRIM-TD-03)
         // Медленное смысловое смещение — Values pull/push + Abstractions pull.
         // НЕ в apply_gravity_batch — горячий путь не трогаем.
@@ -1231,6 +1685,9 @@ impl AxiomEngine {
             if let Some(idx) = self.ashti.index_of(maya_id) {
                 let rules = self.subsystem_gravity_rules.as_slice();
                 if let Some(state) = self.ashti.state_mut(idx) {
+                    if let Some(history) = self.token_history.as_mut() {
+                        history.record_all(&state.tokens);
+                    }
                     crate::subsystem_gravity::apply_subsystem_gravity(state, rules);
                 }
             }
@@ -1248,7 +1705,7 @@ impl AxiomEngine {
             if interval > 0 && t.is_multiple_of(interval as u64) {
                 if let Ok(cmds) = component.on_tick(t, &self.ashti) {
                     for cmd in cmds {
-                        let _ = self.process_command(&cmd);
+                        let _ = self.process_command_from(component.module_id(), &cmd);
                     }
                 }
             }
@@ -1265,7 +1722,7 @@ impl AxiomEngine {
             }
             let fw_commands = self.frame_weaver.drain_commands();
             for fw_cmd in fw_commands {
-                let _ = self.process_command(&fw_cmd);
+                let _ = self.process_command_from(ModuleId::FrameWeaver, &fw_cmd);
             }
             // Обновить окно совместной активации: текущие кандидаты → последний активный тик
             for id in self.frame_weaver.active_candidate_anchor_ids() {
@@ -1281,7 +1738,7 @@ impl AxiomEngine {
                 .sync_primary_subsystem(self.context_recognizer.profile_store().dominant_primary());
             if let Ok(cmds) = self.axial_evaluator.on_tick(t, &self.ashti) {
                 for cmd in cmds {
-                    let _ = self.process_command(&cmd);
+                    let _ = self.process_command_from(ModuleId::AxialEvaluator, &cmd);
                 }
             }
             let axial = self.axial_evaluator.storage().store().clone();
@@ -1291,7 +1748,7 @@ impl AxiomEngine {
         if t % 7 == 0 {
             if let Ok(cmds) = self.context_recognizer.on_tick(t, &self.ashti) {
                 for cmd in cmds {
-                    let _ = self.process_command(&cmd);
+                    let _ = self.process_command_from(ModuleId::ContextRecognizer, &cmd);
                 }
             }
             // После on_tick: создать TensionTrace для каждой разрешённой дилеммы.
@@ -1321,7 +1778,7 @@ impl AxiomEngine {
         if t % 11 == 0 {
             if let Ok(cmds) = self.neural_advisor.on_tick(t, &self.ashti) {
                 for cmd in cmds {
-                    let _ = self.process_command(&cmd);
+                    let _ = self.process_command_from(ModuleId::NeuralAdvisor, &cmd);
                 }
             }
         }
@@ -1370,7 +1827,7 @@ impl AxiomEngine {
             };
             let waves_cmds = self.waves.on_tick(&waves_view);
             for cmd in waves_cmds {
-                let _ = self.process_command(&cmd);
+                let _ = self.process_command_from(ModuleId::Waves, &cmd);
             }
         }
 
@@ -1606,7 +2063,7 @@ impl AxiomEngine {
     fn apply_dream_cycle_commands(&mut self) {
         let cmds = self.dream_cycle.drain_commands();
         for cmd in cmds {
-            let _ = self.process_command(&cmd);
+            let _ = self.process_command_from(ModuleId::Dream, &cmd);
         }
     }
 
@@ -1626,7 +2083,7 @@ impl AxiomEngine {
             let frame_b = u32::from_le_bytes(cmd.payload[4..8].try_into().unwrap_or([0; 4]));
             let strength = f32::from_le_bytes(cmd.payload[20..24].try_into().unwrap_or([0; 4]));
             self.pending_cross_modal_bond_events.push((frame_a, frame_b, strength));
-            let _ = self.process_command(&cmd);
+            let _ = self.process_command_from(ModuleId::ContextRecognizer, &cmd);
         }
     }
 
@@ -1889,6 +2346,7 @@ impl AxiomEngine {
                 token.state = axiom_core::STATE_LOCKED;
                 if self.ashti.inject_token(domain_id, token).is_ok() {
                     injected += 1;
+                    self.token_labels.add(token.sutra_id, &anchor.word);
                 }
             }
         }
@@ -1952,8 +2410,11 @@ impl AxiomEngine {
                 if self.ashti.inject_token(sutra_id, token).is_ok() {
                     injected += 1;
                 }
-                // Регистрируем shell по стабильному anchor sutra_id
+                // Регистрируем shell и метки по стабильному anchor sutra_id
                 self.shell_registry.insert(anchor_sutra_id, anchor.shell);
+                for label in std::iter::once(&anchor.word).chain(&anchor.aliases) {
+                    self.token_labels.add(anchor_sutra_id, label);
+                }
                 // Flat positional list для positional fallback
                 anchor_shell_refs.push((anchor.position, anchor.shell));
                 // Accumulate shell for subsystem template
@@ -4,8 +4,10 @@
 // GUARDIAN — над-доменный контроль соблюдения CODEX + GENOME правил
 
 use axiom_config::DomainConfig;
-use axiom_core::{Token, STATE_LOCKED};
-use axiom_domain::DomainState;
+use axiom_core::{
+    Connection, Token, TokenValidationError, STATE_LOCKED, TOKEN_FLAG_FRAME_ANCHOR, TOKEN_FLAG_GOAL,
+};
+use axiom_domain::{find_cycles, DomainState};
 use axiom_genome::{Genome, GenomeIndex, ModuleId, Permission, ResourceId};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -54,6 +56,18 @@ pub enum InhibitReason {
         /// Индекс токена в списке домена
         token_index: usize,
     },
+    /// Токен нарушает инвариант (`Token::validation_errors`)
+    InvalidToken {
+        /// Индекс токена в списке домена
+        token_index: usize,
+        /// Нарушенный инвариант
+        error: TokenValidationError,
+    },
+    /// Связи замкнуты в цикл (`Guardian::scan_cycles`)
+    ConnectionCycle {
+        /// sutra_id цикла, начиная с наименьшего
+        members: Vec<u32>,
+    },
 }
 
 /// Действие ингибирования для домена.
@@ -120,6 +134,18 @@ pub struct GuardianStats {
     pub dream_proposals: u64,
     /// Вето с момента последнего Wake (сбрасывается при переходе в Wake)
     pub vetoes_since_wake: u64,
+    /// Число одобренных tombstone (удалений осиротевших токенов)
+    pub tombstones_approved: u64,
+    /// Число отклонённых tombstone
+    pub tombstones_vetoed: u64,
+    /// Число одобренных удалений связей с истёкшим TTL
+    pub expiries_approved: u64,
+    /// Число отклонённых удалений связей с истёкшим TTL
+    pub expiries_vetoed: u64,
+    /// Число одобренных явных удалений связей
+    pub removals_approved: u64,
+    /// Число отклонённых явных удалений связей
+    pub removals_vetoed: u64,
 }
 
 // ============================================================================
@@ -314,6 +340,74 @@ impl Guardian {
         ReflexDecision::Allow
     }
 
+    // ============================================================
+    // Orphan GC review
+    // ============================================================
+
+    /// Рассмотреть удаление токена: осиротевшего (GC-проход) или явное
+    /// (`AxiomEngine::remove_token`).
+    ///
+    /// Вето: нет права Control на AshtiField по GENOME, токен заблокирован,
+    /// является Frame-анкером или целью, либо имеет нулевой sutra_id.
+    pub fn review_tombstone(&mut self, token: &Token) -> bool {
+        let allowed = self.genome_index.check_access(
+            ModuleId::Guardian,
+            ResourceId::AshtiField,
+            Permission::Control,
+        ) && token.state != STATE_LOCKED
+            && token.type_flags & (TOKEN_FLAG_FRAME_ANCHOR | TOKEN_FLAG_GOAL) == 0
+            && token.sutra_id != 0;
+
+        if allowed {
+            self.stats.tombstones_approved += 1;
+        } else {
+            self.stats.tombstones_vetoed += 1;
+            self.stats.vetoes_since_wake += 1;
+        }
+        allowed
+    }
+
+    /// Рассмотреть удаление связи с истёкшим TTL.
+    ///
+    /// Вето: нет права Control на AshtiField по GENOME, связь FLAG_CRITICAL
+    /// или её концы не заданы (нулевой source_id / target_id).
+    pub fn review_connection_expiry(&mut self, conn: &Connection) -> bool {
+        let allowed = self.genome_index.check_access(
+            ModuleId::Guardian,
+            ResourceId::AshtiField,
+            Permission::Control,
+        ) && !conn.is_critical()
+            && conn.source_id != 0
+            && conn.target_id != 0;
+
+        if allowed {
+            self.stats.expiries_approved += 1;
+        } else {
+            self.stats.expiries_vetoed += 1;
+            self.stats.vetoes_since_wake += 1;
+        }
+        allowed
+    }
+
+    /// Рассмотреть явное удаление связи (`AxiomEngine::remove_connection`).
+    ///
+    /// Вето: нет права Control на AshtiField по GENOME или связь FLAG_CRITICAL.
+    pub fn review_connection_removal(&mut self, conn: &Connection) -> bool {
+        let allowed = self.genome_index.check_access(
+            ModuleId::Guardian,
+            ResourceId::AshtiField,
+            Permission::Control,
+        ) && !conn.is_critical();
+
+        if allowed {
+            self.stats.removals_approved += 1;
+        } else {
+            self.stats.removals_vetoed += 1;
+            self.stats.vetoes_since_wake += 1;
+        }
+        allowed
+    }
+
     // ============================================================
     // Domain scan
     // ============================================================
@@ -337,6 +431,45 @@ impl Guardian {
         actions
     }
 
+    /// Полная проверка инвариантов токенов домена (`axiom_core::validate_all`).
+    ///
+    /// Дороже scan_domain — вызывается по требованию, а не на каждом тике.
+    pub fn scan_token_invariants(&mut self, state: &DomainState) -> Vec<InhibitAction> {
+        self.stats.domains_scanned += 1;
+        let actions: Vec<InhibitAction> = axiom_core::validate_all(&state.tokens)
+            .into_iter()
+            .map(|(token_index, error)| InhibitAction {
+                reason: InhibitReason::InvalidToken { token_index, error },
+            })
+            .collect();
+        self.violation_count += actions.len() as u32;
+        actions
+    }
+
+    /// Найти циклы длиной не больше `max_len` среди связей домена типа
+    /// `link_type` (None — все связи). Для причинных связей цикл — противоречие,
+    /// каждый найденный засчитывается как нарушение.
+    pub fn scan_cycles(
+        &mut self,
+        state: &DomainState,
+        link_type: Option<u16>,
+        max_len: usize,
+    ) -> Vec<InhibitAction> {
+        self.stats.domains_scanned += 1;
+        let connections: Vec<Connection> = state
+            .connections
+            .iter()
+            .filter(|c| link_type.is_none_or(|t| c.link_type == t))
+            .copied()
+            .collect();
+        let actions: Vec<InhibitAction> = find_cycles(&connections, max_len)
+            .into_iter()
+            .map(|members| InhibitAction { reason: InhibitReason::ConnectionCycle { members } })
+            .collect();
+        self.violation_count += actions.len() as u32;
+        actions
+    }
+
     // ============================================================
     // CODEX management
     // ============================================================
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Guardian Sandbox — оценка предложенного GENOME до ввода в действие
+//
+// Записанная история решений Guardian (рефлексы, проверки доступа и протокола,
+// tombstone) прогоняется дважды: через текущий GENOME и через предложенный.
+// Оба прогона идут на свежих экземплярах Guardian — живой Guardian, его
+// статистика и счётчик нарушений не затрагиваются.
+
+use crate::guardian::{Guardian, ReflexDecision, VetoReason};
+use axiom_core::Token;
+use axiom_genome::{Genome, GenomeError, ModuleId, Permission, ResourceId};
+use std::sync::Arc;
+
+/// Одно историческое обращение к Guardian.
+#[derive(Debug, Clone)]
+pub enum GuardianProbe {
+    /// `validate_reflex(token)`
+    Reflex(Token),
+    /// `enforce_access(module, resource, permission)`
+    Access {
+        module: ModuleId,
+        resource: ResourceId,
+        permission: Permission,
+    },
+    /// `enforce_protocol(source, target)`
+    Protocol { source: ModuleId, target: ModuleId },
+    /// `review_tombstone(token)`
+    Tombstone(Token),
+}
+
+/// Решение, изменившееся под предложенным GENOME.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbeDiff {
+    /// Индекс обращения в истории
+    pub index: usize,
+    /// Решение под текущим GENOME
+    pub current: ReflexDecision,
+    /// Решение под предложенным GENOME
+    pub proposed: ReflexDecision,
+}
+
+/// Итог прогона истории через предложенный GENOME.
+#[derive(Debug, Clone, Default)]
+pub struct SandboxReport {
+    /// Всего обращений в истории
+    pub total: usize,
+    /// Разрешено под текущим, запрещено под предложенным
+    pub newly_denied: usize,
+    /// Запрещено под текущим, разрешено под предложенным
+    pub newly_allowed: usize,
+    /// Все расхождения в порядке истории
+    pub diffs: Vec<ProbeDiff>,
+}
+
+impl SandboxReport {
+    /// True если предложенный GENOME ни в одном случае не меняет решение.
+    pub fn is_equivalent(&self) -> bool {
+        self.diffs.is_empty()
+    }
+}
+
+impl Guardian {
+    /// Прогнать `history` через текущий и предложенный GENOME и сравнить решения.
+    ///
+    /// Предложенный GENOME сначала проходит `Genome::validate()` — невалидная
+    /// конституция не оценивается.
+    pub fn simulate(
+        &self,
+        proposed: Arc<Genome>,
+        history: &[GuardianProbe],
+    ) -> Result<SandboxReport, GenomeError> {
+        proposed.validate()?;
+        let mut current = Guardian::new(Arc::new(self.genome().clone()));
+        let mut candidate = Guardian::new(proposed);
+
+        let mut report = SandboxReport { total: history.len(), ..Default::default() };
+        for (index, probe) in history.iter().enumerate() {
+            let before = current.decide(probe);
+            let after = candidate.decide(probe);
+            if before == after {
+                continue;
+            }
+            match (before.is_allowed(), after.is_allowed()) {
+                (true, false) => report.newly_denied += 1,
+                (false, true) => report.newly_allowed += 1,
+                _ => {}
+            }
+            report.diffs.push(ProbeDiff { index, current: before, proposed: after });
+        }
+        Ok(report)
+    }
+
+    /// Решение по одному обращению. Для не-рефлексных проверок причина вето
+    /// не различается — расхождение между GENOME всегда сводится к GenomeDenied.
+    fn decide(&mut self, probe: &GuardianProbe) -> ReflexDecision {
+        let allowed = match probe {
+            GuardianProbe::Reflex(token) => return self.validate_reflex(token),
+            GuardianProbe::Access { module, resource, permission } => {
+                self.enforce_access(*module, *resource, *permission)
+            }
+            GuardianProbe::Protocol { source, target } => self.enforce_protocol(*source, *target),
+            GuardianProbe::Tombstone(token) => self.review_tombstone(token),
+        };
+        if allowed {
+            ReflexDecision::Allow
+        } else {
+            ReflexDecision::Veto(VetoReason::GenomeDenied)
+        }
+    }
+}
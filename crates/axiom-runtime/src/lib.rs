@@ -18,14 +18,21 @@ pub mod adaptive;
 /// Broadcast-типы для внешних адаптеров (детальные снапшоты, domain detail).
 /// LastDreamSummary всегда доступна; DomainDetailSnapshot и связанные — при feature "adapters".
 pub mod broadcast;
+/// C FFI — extern "C" API для встраивания runtime (feature "c-ffi")
+#[cfg(feature = "c-ffi")]
+pub mod c_ffi;
 /// Channel — in-process очередь команд и событий
 pub mod channel;
+/// ConnectionProvenance — какой модуль, в каком событии и на каком тике создал связь
+pub mod connection_provenance;
 /// Engine — центральный оркестратор
 pub mod engine;
 /// Gateway — единая точка входа для внешних запросов
 pub mod gateway;
 /// Guardian — надоменный контроль CODEX-правил
 pub mod guardian;
+/// Guardian Sandbox — прогон предложенного GENOME по записанной истории решений
+pub mod guardian_sandbox;
 mod orchestrator;
 /// Over-Domain Layer: Guardians + Weavers (Over_Domain_Layer_V1_1.md)
 pub mod over_domain;
@@ -42,6 +49,7 @@ pub use broadcast::LastDreamSummary;
 #[cfg(feature = "adapters")]
 pub use broadcast::{ConnectionSnapshot, DomainDetailSnapshot, TokenSnapshot};
 pub use channel::{Channel, ChannelBatchResult};
+pub use connection_provenance::{ConnectionProvenance, EdgeKey, EdgeProvenance};
 pub use engine::{domain_name, AxiomEngine, AxiomError, TickSchedule};
 pub use subsystem_gravity::SubsystemGravityRule;
 pub use gateway::Gateway;
@@ -49,9 +57,10 @@ pub use guardian::{
     CodexAction, Guardian, GuardianConfig, GuardianError, GuardianStats, InhibitAction,
     InhibitReason, ReflexDecision, RoleStats, VetoReason,
 };
+pub use guardian_sandbox::{GuardianProbe, ProbeDiff, SandboxReport};
 pub use over_domain::{
-    CrystallizationProposal, OverDomainComponent, OverDomainError, PromotionProposal, Weaver,
-    WeaverId,
+    CrystallizationProposal, OverDomainComponent, OverDomainError, OverDomainModuleInfo,
+    PromotionProposal, Weaver, WeaverId,
 };
 pub use over_domain::{
     DreamPhaseEvent, DreamPhaseState, DreamPhaseStats, GatewayPriority, SleepTrigger, WakeReason,
@@ -4,7 +4,7 @@
 // ArbiterLog — кольцевой буфер решений OverDomainArbiter.
 // Источник: docs/architecture/OverDomainArbiter_V1_0.md §9
 
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 
 use super::source::{AdvisoryId, AdvisoryType, SourceId};
 
@@ -39,6 +39,31 @@ pub struct ArbiterLogEntry {
     pub outcome: ArbiterOutcome,
 }
 
+/// Сводка потока рекомендаций за один цикл (окно event_id) для пары
+/// (source, advisory_type): сколько пришло, чем закончилось, насколько уверенно.
+///
+/// Экспортируется построчно (JSON lines) — для графиков динамики обучения.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "adapters", derive(serde::Serialize))]
+pub struct AdvisoryFlowSummary {
+    /// Первый event_id цикла (кратен окну)
+    pub cycle_start: u64,
+    /// Последний event_id цикла (включительно)
+    pub cycle_end: u64,
+    pub source: SourceId,
+    pub advisory_type: AdvisoryType,
+    pub applied: u32,
+    pub queued: u32,
+    pub skipped: u32,
+    pub confirmed: u32,
+    pub rejected: u32,
+    pub expired: u32,
+    /// Средний confidence всех решений цикла
+    pub mean_confidence: f32,
+    /// Confirmed / (Confirmed + Rejected); None если вердиктов не было
+    pub acceptance: Option<f32>,
+}
+
 /// Кольцевой буфер последних 500 решений.
 /// Не персистируется в V1/V2 (ARB-TD-06).
 #[derive(Debug, Default)]
@@ -83,6 +108,57 @@ impl ArbiterLog {
             .count()
     }
 
+    /// Сводки по циклам длиной `window` event_id, по возрастанию цикла,
+    /// внутри цикла — по (source, advisory_type). `window = 0` трактуется как 1.
+    pub fn summarize(&self, window: u64) -> Vec<AdvisoryFlowSummary> {
+        let window = window.max(1);
+        let mut buckets: BTreeMap<(u64, SourceId, u8), (AdvisoryFlowSummary, f32, u32)> =
+            BTreeMap::new();
+        for e in &self.entries {
+            let start = e.event_id - e.event_id % window;
+            let key = (start, e.source, advisory_type_rank(e.advisory_type));
+            let (summary, conf_sum, n) = buckets.entry(key).or_insert_with(|| {
+                (
+                    AdvisoryFlowSummary {
+                        cycle_start: start,
+                        cycle_end: start + (window - 1),
+                        source: e.source,
+                        advisory_type: e.advisory_type,
+                        applied: 0,
+                        queued: 0,
+                        skipped: 0,
+                        confirmed: 0,
+                        rejected: 0,
+                        expired: 0,
+                        mean_confidence: 0.0,
+                        acceptance: None,
+                    },
+                    0.0,
+                    0,
+                )
+            });
+            match e.outcome {
+                ArbiterOutcome::Applied => summary.applied += 1,
+                ArbiterOutcome::Queued => summary.queued += 1,
+                ArbiterOutcome::Skipped => summary.skipped += 1,
+                ArbiterOutcome::Confirmed => summary.confirmed += 1,
+                ArbiterOutcome::Rejected => summary.rejected += 1,
+                ArbiterOutcome::Expired => summary.expired += 1,
+            }
+            *conf_sum += e.confidence;
+            *n += 1;
+        }
+        buckets
+            .into_values()
+            .map(|(mut s, conf_sum, n)| {
+                s.mean_confidence = conf_sum / n as f32;
+                let verdicts = s.confirmed + s.rejected;
+                s.acceptance = (verdicts > 0).then(|| s.confirmed as f32 / verdicts as f32);
+                s
+            })
+            .collect()
+    }
+
     /// V2: Доля Confirmed среди (Confirmed + Rejected) за последние `window` записей
     /// для пары (source, advisory_type). None если нет данных.
     pub fn quality_window(
@@ -107,6 +183,18 @@ impl ArbiterLog {
     }
 }
 
+/// Стабильный порядок типов в сводках.
+fn advisory_type_rank(t: AdvisoryType) -> u8 {
+    match t {
+        AdvisoryType::DepthHint => 0,
+        AdvisoryType::OctantCorrection => 1,
+        AdvisoryType::ConflictDiagnosis => 2,
+        AdvisoryType::SubsystemAttribution => 3,
+        AdvisoryType::EmergentCandidate => 4,
+        AdvisoryType::NarrativeShift => 5,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,4 +313,30 @@ mod tests {
         // Only 1 entry counted (Confirmed), quality = 1.0
         assert!((q - 1.0).abs() < 1e-5);
     }
+
+    #[test]
+    fn test_summarize_buckets_by_cycle_and_pair() {
+        let mut log = ArbiterLog::new();
+        let mut push = |event_id, advisory_type, confidence, outcome| {
+            log.push(ArbiterLogEntry {
+                event_id, advisory_id: event_id, source: 0,
+                advisory_type, subject_id: 1, confidence, outcome,
+            });
+        };
+        push(5, AdvisoryType::OctantCorrection, 0.6, ArbiterOutcome::Confirmed);
+        push(7, AdvisoryType::OctantCorrection, 0.8, ArbiterOutcome::Rejected);
+        push(8, AdvisoryType::DepthHint, 0.9, ArbiterOutcome::Applied);
+        push(12, AdvisoryType::OctantCorrection, 0.7, ArbiterOutcome::Skipped);
+
+        let s = log.summarize(10);
+        assert_eq!(s.len(), 3);
+        assert_eq!((s[0].cycle_start, s[0].advisory_type), (0, AdvisoryType::DepthHint));
+        assert_eq!(s[0].applied, 1);
+        assert_eq!(s[0].acceptance, None);
+        assert_eq!((s[1].cycle_start, s[1].cycle_end), (0, 9));
+        assert_eq!((s[1].confirmed, s[1].rejected), (1, 1));
+        assert!((s[1].mean_confidence - 0.7).abs() < 1e-5);
+        assert_eq!(s[1].acceptance, Some(0.5));
+        assert_eq!((s[2].cycle_start, s[2].skipped), (10, 1));
+    }
 }
@@ -21,7 +21,7 @@ pub mod profile;
 pub mod source;
 pub mod trust;
 
-pub use log::{ArbiterLog, ArbiterLogEntry, ArbiterOutcome};
+pub use log::{AdvisoryFlowSummary, ArbiterLog, ArbiterLogEntry, ArbiterOutcome};
 pub use profile::CognitiveProfile;
 pub use source::{Advisory, AdvisoryAction, AdvisoryId, AdvisoryOutcome, AdvisorySource,
                  AdvisoryType, SourceId};
@@ -12,6 +12,7 @@ pub type AdvisoryId = u64;
 
 /// Тип рекомендации.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "adapters", derive(serde::Serialize))]
 pub enum AdvisoryType {
     DepthHint,
     OctantCorrection,
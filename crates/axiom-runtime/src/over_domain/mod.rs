@@ -47,14 +47,14 @@ pub use neural_advisor::{
 };
 
 pub use arbiter::{
-    Advisory, AdvisoryAction, AdvisoryId, AdvisoryOutcome, AdvisorySource, AdvisoryType,
-    ArbiterLog, ArbiterLogEntry, ArbiterOutcome, OverDomainArbiter, PendingAdvisory,
+    Advisory, AdvisoryAction, AdvisoryFlowSummary, AdvisoryId, AdvisoryOutcome, AdvisorySource,
+    AdvisoryType, ArbiterLog, ArbiterLogEntry, ArbiterOutcome, OverDomainArbiter, PendingAdvisory,
     SourceId, TrustConfig, TrustEntry, TrustMode, ARBITER_TICK_INTERVAL,
 };
 
 pub use traits::{
-    CrystallizationProposal, OverDomainComponent, OverDomainError, PromotionProposal, Weaver,
-    WeaverId,
+    CrystallizationProposal, OverDomainComponent, OverDomainError, OverDomainModuleInfo,
+    PromotionProposal, Weaver, WeaverId,
 };
 
 pub use weavers::{
@@ -6,7 +6,7 @@
 
 use axiom_core::Token;
 use axiom_domain::{AshtiCore, DomainState};
-use axiom_genome::{Genome, ModuleId};
+use axiom_genome::{AccessRule, Genome, ModuleId};
 use axiom_ucl::UclCommand;
 use std::sync::Arc;
 
@@ -62,6 +62,48 @@ pub struct PromotionProposal {
     pub commands: Vec<UclCommand>,
 }
 
+/// Описание зарегистрированного Over-Domain компонента (интроспекция).
+///
+/// Возможности и связи берутся из GENOME: `access` — правила доступа модуля,
+/// `routes_to` — цели протокольных маршрутов, где модуль является источником.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "adapters", derive(serde::Serialize))]
+pub struct OverDomainModuleInfo {
+    pub name: &'static str,
+    pub module_id: ModuleId,
+    /// Интервал on_tick в тиках
+    pub tick_interval: u32,
+    /// true — компонент хранится в Engine по значению (FrameWeaver, Arbiter, …),
+    /// false — подключён через `over_domain_components`
+    pub builtin: bool,
+    pub access: Vec<AccessRule>,
+    pub routes_to: Vec<ModuleId>,
+}
+
+impl OverDomainModuleInfo {
+    pub fn of(component: &dyn OverDomainComponent, builtin: bool, genome: &Genome) -> Self {
+        let module_id = component.module_id();
+        Self {
+            name: component.name(),
+            module_id,
+            tick_interval: component.on_tick_interval(),
+            builtin,
+            access: genome
+                .access_rules
+                .iter()
+                .filter(|r| r.module == module_id)
+                .copied()
+                .collect(),
+            routes_to: genome
+                .protocol_rules
+                .iter()
+                .filter(|r| r.source == module_id)
+                .map(|r| r.target)
+                .collect(),
+        }
+    }
+}
+
 // ============================================================================
 // OverDomainComponent — базовый trait (object-safe)
 // ============================================================================
@@ -585,11 +585,16 @@ impl FrameWeaver {
     }
 
     fn scan_state(&self, maya_state: &DomainState, maya_domain_id: u16) -> Vec<FrameCandidate> {
+        // Индекс по link_type: категории 0x08 и 0x0B без полного скана на каждую голову
+        let index = maya_state.connection_index();
+        let active = |i: &usize| (maya_state.connections[*i].flags & FLAG_ACTIVE) != 0;
+
         // Фильтровать активные синтаксические связи (категория 0x08)
-        let syn_conns: Vec<&Connection> = maya_state
-            .connections
-            .iter()
-            .filter(|c| (c.link_type >> 8) == 0x08 && (c.flags & FLAG_ACTIVE) != 0)
+        let syn_conns: Vec<&Connection> = index
+            .of_category(0x08)
+            .into_iter()
+            .filter(active)
+            .map(|i| &maya_state.connections[i])
             .collect();
 
         if syn_conns.is_empty() {
@@ -639,10 +644,8 @@ impl FrameWeaver {
             // Уже существующие sutra_id не дублируются.
             let existing_ids: std::collections::HashSet<u32> =
                 participants.iter().map(|p| p.sutra_id).collect();
-            for conn in maya_state.connections.iter() {
-                if conn.source_id != source_id { continue; }
-                if (conn.flags & FLAG_ACTIVE) == 0 { continue; }
-                if (conn.link_type >> 8) != 0x0B { continue; }
+            for i in index.from_source_in_category(source_id, 0x0B).into_iter().filter(active) {
+                let conn = &maya_state.connections[i];
                 if existing_ids.contains(&conn.target_id) { continue; }
                 participants.push(Participant {
                     sutra_id: conn.target_id,
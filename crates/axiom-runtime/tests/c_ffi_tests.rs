@@ -0,0 +1,91 @@
+// C FFI: extern "C" API поверх Gateway (feature "c-ffi")
+#![cfg(feature = "c-ffi")]
+
+use axiom_core::{Connection, Event, EventType, Token};
+use axiom_runtime::c_ffi::*;
+use axiom_ucl::{OpCode, UclBuilder, UclCommand, UclResult};
+use std::mem::MaybeUninit;
+
+const LOGIC: u16 = 106;
+
+fn drain(handle: *mut AxiomHandle) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut slot = MaybeUninit::<Event>::uninit();
+    while unsafe { axiom_poll_event(handle, slot.as_mut_ptr()) } == 1 {
+        events.push(unsafe { slot.assume_init() });
+    }
+    events
+}
+
+#[test]
+fn test_ffi_tokens_and_connections() {
+    let h = axiom_new();
+    unsafe {
+        assert_eq!(axiom_inject_token(h, &Token::new(1, LOGIC, [0, 0, 0], 1)), AXIOM_OK);
+        assert_eq!(axiom_inject_token(h, &Token::new(2, LOGIC, [10, 0, 0], 1)), AXIOM_OK);
+        assert_eq!(axiom_inject_token(h, &Token::new(3, 999, [0, 0, 0], 1)), AXIOM_ERR_NOT_FOUND);
+        assert_eq!(axiom_token_count(h, LOGIC), 2);
+        assert_eq!(axiom_token_count(h, 999), i64::from(AXIOM_ERR_NOT_FOUND));
+
+        let mut token = MaybeUninit::<Token>::uninit();
+        assert_eq!(axiom_read_token(h, LOGIC, 2, token.as_mut_ptr()), AXIOM_OK);
+        assert_eq!(token.assume_init().position, [10, 0, 0]);
+        assert_eq!(axiom_read_token(h, LOGIC, 42, token.as_mut_ptr()), AXIOM_ERR_NOT_FOUND);
+
+        let conn = Connection::new(1, 2, LOGIC, 1);
+        assert_eq!(axiom_add_connection(h, &conn), AXIOM_OK);
+        assert_eq!(axiom_add_connection(h, &conn), AXIOM_ERR_REJECTED); // дубликат
+        assert_eq!(axiom_add_connection(h, &Connection::new(1, 2, 999, 1)), AXIOM_ERR_NOT_FOUND);
+        axiom_free(h);
+    }
+}
+
+#[test]
+fn test_ffi_submit_and_poll_events() {
+    let h = axiom_new();
+    let cmd = UclBuilder::inject_token(LOGIC as u32, 0, 1.0, [5.0, 0.0, 0.0]);
+    let mut result = MaybeUninit::<UclResult>::uninit();
+    unsafe {
+        assert_eq!(axiom_submit(h, &cmd, result.as_mut_ptr()), AXIOM_OK);
+        assert!(result.assume_init().is_success());
+    }
+    drain(h);
+
+    unsafe {
+        axiom_inject_token(h, &Token::new(1, LOGIC, [0, 0, 0], 1));
+        axiom_inject_token(h, &Token::new(2, LOGIC, [10, 0, 0], 1));
+        axiom_add_connection(h, &Connection::new(1, 2, LOGIC, 1));
+        assert_eq!(axiom_remove_connection(h, LOGIC, 1, 2, 0), AXIOM_OK);
+        assert_eq!(axiom_remove_connection(h, LOGIC, 1, 2, 0), AXIOM_ERR_NOT_FOUND);
+    }
+    let events = drain(h);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, EventType::ConnectionDelete as u16);
+    assert_eq!((events[0].source_id, events[0].target_id), (1, 2));
+    assert!(drain(h).is_empty());
+
+    let tick = UclCommand::new(OpCode::TickForward, 0, 100, 0);
+    unsafe {
+        assert_eq!(axiom_submit(h, &tick, result.as_mut_ptr()), AXIOM_OK);
+        axiom_free(h);
+    }
+}
+
+#[test]
+fn test_ffi_rejects_null_pointers() {
+    let h = axiom_new();
+    let mut event = MaybeUninit::<Event>::uninit();
+    unsafe {
+        assert_eq!(axiom_poll_event(std::ptr::null_mut(), event.as_mut_ptr()), AXIOM_ERR_NULL);
+        assert_eq!(axiom_poll_event(h, std::ptr::null_mut()), AXIOM_ERR_NULL);
+        assert_eq!(axiom_inject_token(h, std::ptr::null()), AXIOM_ERR_NULL);
+        assert_eq!(axiom_add_connection(std::ptr::null_mut(), std::ptr::null()), AXIOM_ERR_NULL);
+        assert_eq!(
+            axiom_submit(h, std::ptr::null(), std::ptr::null_mut()),
+            AXIOM_ERR_NULL
+        );
+        assert_eq!(axiom_token_count(std::ptr::null(), LOGIC), i64::from(AXIOM_ERR_NULL));
+        axiom_free(std::ptr::null_mut());
+        axiom_free(h);
+    }
+}
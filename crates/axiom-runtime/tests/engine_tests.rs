@@ -359,6 +359,29 @@ fn test_inject_anchor_tokens_domain() {
     assert_eq!(engine.token_count(101), before + 1);
 }
 
+#[test]
+fn test_inject_anchor_tokens_labels_subsystem_anchors() {
+    use axiom_config::{Anchor, AnchorSet};
+    let mut set = AnchorSet::empty();
+    set.subsystems.entry("logic".to_string()).or_default().push(Anchor {
+        id: "logic_cause".to_string(),
+        word: "причина".to_string(),
+        aliases: vec!["основание".to_string()],
+        tags: vec![],
+        position: [100, 200, 300],
+        shell: [0; 8],
+        description: String::new(),
+        layer: axiom_config::AnchorLayer::L1,
+    });
+    let mut engine = AxiomEngine::new();
+    engine.inject_anchor_tokens(&set);
+
+    let ids = engine.token_labels.find_by_label("основание");
+    assert_eq!(ids.len(), 1);
+    assert_eq!(engine.token_labels.primary(ids[0]), Some("причина"));
+    assert!(engine.token_count(100) > 0);
+}
+
 // ============================================================
 // UnfoldFrame (Этап 2 стабилизации FrameWeaver)
 // ============================================================
@@ -494,3 +517,244 @@ fn dream_phase_stats_total_sleeps_starts_zero() {
     assert_eq!(engine.dream_phase_stats.total_dream_ticks, 0);
     assert_eq!(engine.dream_phase_stats.interrupted_dreams, 0);
 }
+
+// ============================================================
+// inject_token_batch
+// ============================================================
+
+#[test]
+fn test_inject_token_batch_single_guardian_scan() {
+    let mut engine = AxiomEngine::new();
+    let scanned = engine.guardian.stats().domains_scanned;
+    let mut batch = axiom_domain::TokenBatchBuilder::new(LOGIC_ID, 1);
+    for id in 1..=10 {
+        batch.push(id, [id as i16, 0, 0]);
+    }
+    let (indices, actions) = engine.inject_token_batch(&batch).unwrap();
+    assert_eq!(indices.len(), 10);
+    assert!(actions.is_empty());
+    assert_eq!(engine.token_count(LOGIC_ID), 10);
+    assert_eq!(engine.guardian.stats().domains_scanned, scanned + 1);
+    assert!(engine.had_intake_this_tick());
+}
+
+#[test]
+fn test_token_history_records_reinforce_and_reverts() {
+    use axiom_ucl::ReinforceFramePayload;
+
+    let mut engine = AxiomEngine::new();
+    engine.enable_token_history(4);
+    let experience_id = 109;
+    let anchor = Token::new(77, experience_id, [5, 5, 5], 1);
+    engine.ashti.inject_token(experience_id, anchor).unwrap();
+
+    let payload = ReinforceFramePayload {
+        anchor_id: 77,
+        delta_mass: 10,
+        delta_temperature: 0,
+        reserved: [0; 42],
+    };
+    let cmd = UclCommand::new(OpCode::ReinforceFrame, 0, 10, 0).with_payload(&payload);
+    engine.process_command(&cmd);
+    let reinforced = engine.ashti.find_token_by_sutra_id(experience_id, 77).unwrap();
+    assert_eq!(reinforced.mass, anchor.mass + 10);
+
+    let history = engine.token_history.as_ref().unwrap();
+    assert_eq!(history.generations(experience_id, 77).count(), 1);
+
+    let restored = engine.revert_token(experience_id, 77, 0).unwrap();
+    assert_eq!(restored.mass, anchor.mass);
+    let current = engine.ashti.find_token_by_sutra_id(experience_id, 77).unwrap();
+    assert_eq!(current.mass, anchor.mass);
+    // Состояние до отката тоже в истории — откат обратим
+    let undo = engine.revert_token(experience_id, 77, 0).unwrap();
+    assert_eq!(undo.mass, anchor.mass + 10);
+}
+
+#[test]
+fn test_revert_token_without_history_is_error() {
+    let mut engine = AxiomEngine::new();
+    assert!(engine.revert_token(LOGIC_ID, 1, 0).is_err());
+}
+
+fn bond_cmd(source_id: u32, target_id: u32) -> UclCommand {
+    use axiom_ucl::BondTokensPayload;
+    let payload = BondTokensPayload {
+        source_id,
+        target_id,
+        domain_id: 109,
+        link_type: 0x0801,
+        strength: 1.0,
+        conn_flags: FLAG_ACTIVE,
+        origin_domain: 109,
+        role_id: 1,
+        reserved: [0; 24],
+    };
+    UclCommand::new(OpCode::BondTokens, 0, 10, 0).with_payload(&payload)
+}
+
+#[test]
+fn test_connection_provenance_records_module_event_and_tick() {
+    use axiom_genome::ModuleId;
+
+    let mut engine = AxiomEngine::new();
+    engine.enable_connection_provenance();
+    engine.tick_count = 42;
+
+    engine.process_command(&bond_cmd(1, 2));
+    engine.process_command_from(ModuleId::FrameWeaver, &bond_cmd(1, 3));
+
+    let conn = engine.ashti.state(engine.ashti.index_of(109).unwrap()).unwrap().connections[1];
+    let provenance = engine.connection_provenance.as_ref().unwrap();
+    let external = provenance.get(&(109, 1, 2, 0x0801)).unwrap();
+    assert_eq!(external.module, None);
+    assert_eq!(external.tick, 42);
+    let woven = provenance.get(&(109, 1, 3, 0x0801)).unwrap();
+    assert_eq!(woven.module, Some(ModuleId::FrameWeaver));
+    assert_eq!(woven.event_id, conn.created_at);
+    assert_eq!(provenance.created_by(Some(ModuleId::FrameWeaver)).count(), 1);
+}
+
+#[test]
+fn test_bond_tokens_with_bidirectional_flag_creates_pair() {
+    use axiom_core::FLAG_BIDIRECTIONAL;
+    use axiom_ucl::BondTokensPayload;
+
+    let mut engine = AxiomEngine::new();
+    let payload = BondTokensPayload {
+        source_id: 1,
+        target_id: 2,
+        domain_id: 109,
+        link_type: 0x0B01,
+        strength: 0.7,
+        conn_flags: FLAG_ACTIVE | FLAG_BIDIRECTIONAL,
+        origin_domain: 109,
+        role_id: 0,
+        reserved: [0; 24],
+    };
+    let cmd = UclCommand::new(OpCode::BondTokens, 0, 10, 0).with_payload(&payload);
+    engine.process_command(&cmd);
+
+    let state = engine.ashti.state(engine.ashti.index_of(109).unwrap()).unwrap();
+    let ends: Vec<(u32, u32)> =
+        state.connections.iter().map(|c| (c.source_id, c.target_id)).collect();
+    assert_eq!(ends, vec![(1, 2), (2, 1)]);
+    assert!(state.connections.iter().all(|c| c.flags & FLAG_BIDIRECTIONAL != 0));
+}
+
+#[test]
+fn test_connection_provenance_disabled_by_default() {
+    let mut engine = AxiomEngine::new();
+    engine.process_command(&bond_cmd(1, 2));
+    assert!(engine.connection_provenance.is_none());
+}
+
+#[test]
+fn test_dedup_carries_labels_history_and_provenance() {
+    let mut engine = AxiomEngine::new();
+    engine.enable_token_history(4);
+    engine.enable_connection_provenance();
+    for (id, x) in [(1u32, 0i16), (2, 2), (3, 500)] {
+        engine.ashti.inject_token(109, Token::new(id, 109, [x, 0, 0], 1)).unwrap();
+    }
+    engine.process_command(&bond_cmd(2, 3));
+    engine.token_labels.add(2, "дубль");
+    let absorbed = engine.ashti.find_token_by_sutra_id(109, 2).unwrap();
+    engine.token_history.as_mut().unwrap().record(&absorbed);
+
+    let report = engine.dedup_tokens(109, 4, axiom_core::MergeStrategy::KeepPrimary).unwrap();
+    assert_eq!(report.merged, vec![(1, 2)]);
+    assert_eq!(engine.token_labels.labels(1), ["дубль"]);
+    assert!(engine.token_labels.labels(2).is_empty());
+    let history = engine.token_history.as_ref().unwrap();
+    assert_eq!(history.generations(109, 1).count(), 1);
+    assert_eq!(history.generations(109, 2).count(), 0);
+    let provenance = engine.connection_provenance.as_ref().unwrap();
+    assert!(provenance.get(&(109, 2, 3, 0x0801)).is_none());
+    assert!(provenance.get(&(109, 1, 3, 0x0801)).is_some());
+}
+
+#[test]
+fn test_dedup_events_have_own_ids_and_parent_and_merge_attributes() {
+    use axiom_core::EventType;
+
+    let mut engine = AxiomEngine::new();
+    for (id, x, last) in [(1u32, 0i16, 5u64), (2, 2, 40), (3, 3, 41)] {
+        let mut token = Token::new(id, 109, [x, 0, 0], 1);
+        token.last_event_id = last;
+        engine.ashti.inject_token(109, token).unwrap();
+    }
+    engine.graph_attributes.set_node_attr(2, "doc", "paper-7");
+    engine.drain_events();
+
+    let report = engine.dedup_tokens(109, 4, axiom_core::MergeStrategy::KeepPrimary).unwrap();
+    assert_eq!(report.merged, vec![(1, 2), (1, 3)]);
+    let merges: Vec<_> = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::TokenMerged as u16)
+        .collect();
+    assert_eq!(merges.len(), 2);
+    assert!(merges[0].event_id < merges[1].event_id);
+    assert_eq!(merges.iter().map(|e| e.parent_event_id).collect::<Vec<_>>(), vec![40, 41]);
+    assert!(engine.graph_attributes.node_attrs(2).is_empty());
+    assert!(engine.graph_attributes.node_attr(1, "doc").is_some());
+}
+
+#[test]
+fn test_token_delete_forgets_history() {
+    use axiom_domain::OrphanCriteria;
+
+    let mut engine = AxiomEngine::new();
+    engine.enable_token_history(4);
+    let mut orphan = Token::new(2, 109, [500, 0, 0], 1);
+    orphan.mass = 1;
+    for token in [Token::new(1, 109, [0, 0, 0], 1), orphan] {
+        engine.ashti.inject_token(109, token).unwrap();
+        engine.token_history.as_mut().unwrap().record(&token);
+    }
+
+    engine.remove_token(109, 1);
+    engine.com_next_id = 1_000;
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    assert_eq!(engine.collect_orphans(&criteria).tombstones.len(), 1);
+
+    let history = engine.token_history.as_ref().unwrap();
+    assert_eq!(history.tracked(), 0);
+    assert!(history.previous(109, 1, 0).is_none());
+}
+
+#[test]
+fn test_domain_centrality_ranks_hub_first() {
+    let mut engine = AxiomEngine::new();
+    for source in [2, 3, 4] {
+        engine.process_command(&bond_cmd(source, 1));
+    }
+    let nodes = engine.domain_centrality(109, &Default::default()).unwrap();
+    assert_eq!(nodes.len(), 4);
+    assert_eq!(nodes[0].sutra_id, 1);
+    assert_eq!(nodes[0].degree.incoming, 3);
+    assert!(engine.domain_centrality(999, &Default::default()).is_none());
+}
+
+#[test]
+fn test_graph_attributes_dropped_with_expired_connection() {
+    use axiom_core::Connection;
+    use axiom_domain::edge_id;
+
+    let mut engine = AxiomEngine::new();
+    let mut temporary = Connection::new(1, 2, LOGIC_ID, 10);
+    temporary.set_ttl(5);
+    let permanent = Connection::new(1, 3, LOGIC_ID, 10);
+    for c in [temporary, permanent] {
+        engine.ashti.inject_connection(LOGIC_ID, c).unwrap();
+        engine.graph_attributes.set_edge_attr(edge_id(&c), "source", "doc-7");
+    }
+    engine.graph_attributes.set_node_attr(1, "score", 0.9);
+    engine.com_next_id = 100;
+
+    engine.expire_connections();
+    assert_eq!(engine.graph_attributes.edge_attrs(edge_id(&temporary)).len(), 0);
+    assert!(engine.graph_attributes.edge_attr(edge_id(&permanent), "source").is_some());
+    assert!(engine.graph_attributes.node_attr(1, "score").is_some());
+}
@@ -0,0 +1,84 @@
+// Tests for Guardian Sandbox — прогон предложенного GENOME по истории решений
+
+use axiom_core::Token;
+use axiom_genome::{Genome, ModuleId, Permission, ResourceId};
+use axiom_runtime::{Guardian, GuardianProbe, ReflexDecision, VetoReason};
+use std::sync::Arc;
+
+fn reflex(sutra_id: u32) -> GuardianProbe {
+    let mut t = Token::new(sutra_id, 1, [0, 0, 0], 1);
+    t.mass = 100;
+    GuardianProbe::Reflex(t)
+}
+
+/// Default genome без права Arbiter → AshtiField Execute.
+fn genome_without_arbiter_execute() -> Genome {
+    let mut g = Genome::default_ashti_core();
+    g.access_rules
+        .retain(|r| !(r.module == ModuleId::Arbiter && r.resource == ResourceId::AshtiField));
+    g
+}
+
+#[test]
+fn test_identical_genome_is_equivalent() {
+    let guardian = Guardian::with_default_genome();
+    let history = vec![reflex(1), reflex(0), reflex(2)];
+    let report = guardian
+        .simulate(Arc::new(Genome::default_ashti_core()), &history)
+        .unwrap();
+    assert_eq!(report.total, 3);
+    assert!(report.is_equivalent());
+}
+
+#[test]
+fn test_revoked_permission_reports_newly_denied() {
+    let guardian = Guardian::with_default_genome();
+    let history = vec![reflex(1), reflex(0), reflex(2)];
+    let report = guardian
+        .simulate(Arc::new(genome_without_arbiter_execute()), &history)
+        .unwrap();
+
+    // reflex(0) запрещён в обоих случаях, меняется только причина (ZeroSutraId → GenomeDenied)
+    assert_eq!(report.diffs.len(), 3);
+    assert_eq!(report.newly_denied, 2);
+    assert_eq!(report.newly_allowed, 0);
+    assert_eq!(report.diffs[0].index, 0);
+    assert_eq!(report.diffs[0].current, ReflexDecision::Allow);
+    assert_eq!(
+        report.diffs[0].proposed,
+        ReflexDecision::Veto(VetoReason::GenomeDenied)
+    );
+}
+
+#[test]
+fn test_access_probe_diff() {
+    let guardian = Guardian::with_default_genome();
+    let history = vec![GuardianProbe::Access {
+        module: ModuleId::Arbiter,
+        resource: ResourceId::AshtiField,
+        permission: Permission::Execute,
+    }];
+    let report = guardian
+        .simulate(Arc::new(genome_without_arbiter_execute()), &history)
+        .unwrap();
+    assert_eq!(report.newly_denied, 1);
+}
+
+#[test]
+fn test_simulation_does_not_touch_live_guardian() {
+    let guardian = Guardian::with_default_genome();
+    let history = vec![reflex(0), reflex(0)];
+    let _ = guardian
+        .simulate(Arc::new(genome_without_arbiter_execute()), &history)
+        .unwrap();
+    assert_eq!(guardian.violation_count(), 0);
+    assert_eq!(guardian.stats().reflex_vetoed, 0);
+}
+
+#[test]
+fn test_invalid_genome_rejected() {
+    let guardian = Guardian::with_default_genome();
+    let mut bad = Genome::default_ashti_core();
+    bad.access_rules.retain(|r| r.module != ModuleId::Guardian);
+    assert!(guardian.simulate(Arc::new(bad), &[reflex(1)]).is_err());
+}
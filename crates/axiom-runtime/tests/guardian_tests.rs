@@ -1,8 +1,10 @@
 // Integration tests for axiom-runtime Guardian
-use axiom_core::{Token, STATE_LOCKED};
+use axiom_core::{Token, TokenValidationError, STATE_LOCKED, TOKEN_FLAG_FRAME_ANCHOR};
 use axiom_domain::{DomainConfig, DomainState};
 use axiom_genome::{ModuleId, Permission, ResourceId};
-use axiom_runtime::{CodexAction, Guardian, InhibitReason, ReflexDecision, VetoReason};
+use axiom_runtime::{
+    CodexAction, Guardian, InhibitAction, InhibitReason, ReflexDecision, VetoReason,
+};
 
 fn make_token(sutra_id: u32, mass: u8, valence: i8) -> Token {
     let mut t = Token::new(sutra_id, 1, [0, 0, 0], 1);
@@ -11,6 +13,13 @@ fn make_token(sutra_id: u32, mass: u8, valence: i8) -> Token {
     t
 }
 
+/// id событий строго растут (INVARIANTS §4); событий ровно `expected`.
+fn assert_strictly_increasing(events: &[axiom_core::Event], expected: usize) {
+    let ids: Vec<u64> = events.iter().map(|e| e.event_id).collect();
+    assert_eq!(ids.len(), expected, "events: {ids:?}");
+    assert!(ids.windows(2).all(|w| w[0] < w[1]), "event ids not increasing: {ids:?}");
+}
+
 // ============================================================
 // validate_reflex
 // ============================================================
@@ -136,6 +145,29 @@ fn test_scan_domain_multiple_bad_tokens() {
     assert_eq!(guardian.violation_count(), 2);
 }
 
+#[test]
+fn test_scan_token_invariants_reports_invalid_state() {
+    let mut guardian = Guardian::with_default_genome();
+    let config = DomainConfig::factory_logic(1, 0);
+    let mut state = DomainState::new(&config);
+
+    state.add_token(make_token(1, 10, 0)).unwrap();
+    let mut odd = make_token(2, 10, 0);
+    odd.state = 0;
+    state.add_token(odd).unwrap();
+
+    let actions = guardian.scan_token_invariants(&state);
+    assert_eq!(actions.len(), 1);
+    assert_eq!(
+        actions[0].reason,
+        InhibitReason::InvalidToken {
+            token_index: 1,
+            error: TokenValidationError::UnknownState(0)
+        }
+    );
+    assert_eq!(guardian.violation_count(), 1);
+}
+
 // ============================================================
 // enforce_access / enforce_protocol (GENOME)
 // ============================================================
@@ -210,6 +242,341 @@ fn test_update_codex_add_rule() {
     assert_eq!(codex.token_count(), 1);
 }
 
+// ============================================================
+// review_tombstone (orphan GC)
+// ============================================================
+
+#[test]
+fn test_review_tombstone_approves_plain_token() {
+    let mut guardian = Guardian::with_default_genome();
+    assert!(guardian.review_tombstone(&make_token(7, 1, 0)));
+    assert_eq!(guardian.stats().tombstones_approved, 1);
+    assert_eq!(guardian.stats().tombstones_vetoed, 0);
+}
+
+#[test]
+fn test_review_tombstone_vetoes_locked_and_anchor() {
+    let mut guardian = Guardian::with_default_genome();
+    let mut locked = make_token(7, 1, 0);
+    locked.state = STATE_LOCKED;
+    let mut anchor = make_token(8, 1, 0);
+    anchor.type_flags |= TOKEN_FLAG_FRAME_ANCHOR;
+    assert!(!guardian.review_tombstone(&locked));
+    assert!(!guardian.review_tombstone(&anchor));
+    assert_eq!(guardian.stats().tombstones_vetoed, 2);
+}
+
+#[test]
+fn test_engine_collect_orphans_emits_token_delete() {
+    use axiom_core::EventType;
+    use axiom_domain::OrphanCriteria;
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    let idx = engine.ashti.index_of(106).unwrap();
+    let state = engine.ashti.state_mut(idx).unwrap();
+    state.add_token(make_token(1, 1, 0)).unwrap();
+    let mut anchor = make_token(2, 1, 0);
+    anchor.state = STATE_LOCKED;
+    state.add_token(anchor).unwrap();
+    engine.com_next_id = 1_000;
+
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    let report = engine.collect_orphans(&criteria);
+
+    assert_eq!(report.tombstones.len(), 1);
+    assert_eq!(engine.token_count(106), 1);
+    let deletes: Vec<_> = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::TokenDelete as u16)
+        .collect();
+    assert_eq!(deletes.len(), 1);
+    assert_eq!(deletes[0].target_id, 1);
+}
+
+#[test]
+fn test_engine_collect_orphans_ids_per_delete_and_no_tick_when_idle() {
+    use axiom_domain::OrphanCriteria;
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    let idx = engine.ashti.index_of(106).unwrap();
+    for id in 1..=3 {
+        engine.ashti.state_mut(idx).unwrap().add_token(make_token(id, 1, 0)).unwrap();
+    }
+    engine.com_next_id = 1_000;
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+
+    assert_eq!(engine.collect_orphans(&criteria).tombstones.len(), 3);
+    assert_strictly_increasing(&engine.drain_events(), 3);
+    assert_eq!(engine.com_next_id, 1_003);
+
+    // Пустой проход не расходует event_id
+    assert!(engine.collect_orphans(&criteria).tombstones.is_empty());
+    assert_eq!(engine.com_next_id, 1_003);
+}
+
+// ============================================================
+// review_connection_expiry (TTL GC)
+// ============================================================
+
+#[test]
+fn test_review_connection_expiry_vetoes_critical() {
+    use axiom_core::{Connection, FLAG_CRITICAL};
+
+    let mut guardian = Guardian::with_default_genome();
+    let plain = Connection::new(1, 2, 106, 1);
+    let mut critical = plain;
+    critical.flags |= FLAG_CRITICAL;
+    assert!(guardian.review_connection_expiry(&plain));
+    assert!(!guardian.review_connection_expiry(&critical));
+    assert_eq!(guardian.stats().expiries_approved, 1);
+    assert_eq!(guardian.stats().expiries_vetoed, 1);
+}
+
+#[test]
+fn test_engine_expires_connections_through_guardian() {
+    use axiom_core::{Connection, EventType, FLAG_CRITICAL};
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    let mut temporary = Connection::new(1, 2, 106, 10);
+    temporary.set_ttl(5);
+    let mut critical = Connection::new(1, 3, 106, 10);
+    critical.set_ttl(5);
+    critical.flags |= FLAG_CRITICAL;
+    let permanent = Connection::new(1, 4, 106, 10);
+    for c in [temporary, critical, permanent] {
+        engine.ashti.inject_connection(106, c).unwrap();
+    }
+    engine.com_next_id = 100;
+
+    let report = engine.expire_connections();
+    assert_eq!(report.expired.len(), 1);
+    assert_eq!(report.vetoed, 1);
+    let idx = engine.ashti.index_of(106).unwrap();
+    let targets: Vec<u32> =
+        engine.ashti.state(idx).unwrap().connections.iter().map(|c| c.target_id).collect();
+    assert_eq!(targets, vec![3, 4]);
+    let deletes = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::ConnectionDelete as u16)
+        .count();
+    assert_eq!(deletes, 1);
+}
+
+#[test]
+fn test_engine_expired_connections_get_distinct_event_ids() {
+    use axiom_core::Connection;
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    for target in 2..=4 {
+        let mut temporary = Connection::new(1, target, 106, 10);
+        temporary.set_ttl(5);
+        engine.ashti.inject_connection(106, temporary).unwrap();
+    }
+    engine.com_next_id = 100;
+
+    assert_eq!(engine.expire_connections().expired.len(), 3);
+    assert_strictly_increasing(&engine.drain_events(), 3);
+}
+
+// ============================================================
+// review_connection_removal
+// ============================================================
+
+#[test]
+fn test_review_connection_removal_vetoes_critical() {
+    use axiom_core::{Connection, FLAG_CRITICAL};
+
+    let mut guardian = Guardian::with_default_genome();
+    let plain = Connection::new(1, 2, 106, 1);
+    let mut critical = plain;
+    critical.flags |= FLAG_CRITICAL;
+    assert!(guardian.review_connection_removal(&plain));
+    assert!(!guardian.review_connection_removal(&critical));
+    assert_eq!(guardian.stats().removals_approved, 1);
+    assert_eq!(guardian.stats().removals_vetoed, 1);
+}
+
+#[test]
+fn test_engine_remove_connection_emits_delete_and_compacts_on_tick() {
+    use axiom_core::{Connection, EventType, FLAG_CRITICAL};
+    use axiom_domain::Removal;
+    use axiom_runtime::AxiomEngine;
+    use axiom_ucl::{OpCode, UclCommand};
+
+    let mut engine = AxiomEngine::new();
+    let mut critical = Connection::new(1, 3, 106, 10);
+    critical.flags |= FLAG_CRITICAL;
+    for c in [Connection::new(1, 2, 106, 10), critical] {
+        engine.ashti.inject_connection(106, c).unwrap();
+    }
+
+    assert!(matches!(engine.remove_connection(106, (1, 2, 0)), Removal::Removed(_)));
+    assert!(matches!(engine.remove_connection(106, (1, 3, 0)), Removal::Vetoed));
+    let deletes = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::ConnectionDelete as u16)
+        .count();
+    assert_eq!(deletes, 1);
+
+    let idx = engine.ashti.index_of(106).unwrap();
+    assert_eq!(engine.ashti.state(idx).unwrap().tombstone_count(), 1);
+    engine.process_command(&UclCommand::new(OpCode::TickForward, 0, 100, 0));
+    let state = engine.ashti.state(idx).unwrap();
+    assert_eq!(state.tombstone_count(), 0);
+    assert_eq!(state.connections.len(), 1);
+}
+
+#[test]
+fn test_engine_remove_token_emits_token_and_connection_deletes() {
+    use axiom_core::{Connection, EventType, Token};
+    use axiom_domain::Removal;
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    engine.ashti.inject_token(106, Token::new(1, 106, [0, 0, 0], 1)).unwrap();
+    engine.ashti.inject_token(106, Token::new(2, 106, [5, 0, 0], 1)).unwrap();
+    engine.ashti.inject_connection(106, Connection::new(1, 2, 106, 1)).unwrap();
+
+    assert!(matches!(engine.remove_token(106, 1), Removal::Removed(_)));
+    assert!(matches!(engine.remove_token(106, 1), Removal::NotFound));
+    let events = engine.drain_events();
+    let count = |t: EventType| events.iter().filter(|e| e.event_type == t as u16).count();
+    assert_eq!(count(EventType::TokenDelete), 1);
+    assert_eq!(count(EventType::ConnectionDelete), 1);
+    assert_eq!(engine.guardian.stats().tombstones_approved, 1);
+}
+
+#[test]
+fn test_engine_remove_token_event_ids_strictly_increase() {
+    use axiom_core::{Connection, EventType, Token};
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    for id in 1..=4 {
+        engine.ashti.inject_token(106, Token::new(id, 106, [id as i16, 0, 0], 1)).unwrap();
+    }
+    for target in 2..=4 {
+        engine.ashti.inject_connection(106, Connection::new(1, target, 106, 1)).unwrap();
+    }
+    engine.drain_events();
+
+    engine.remove_token(106, 1);
+    let events = engine.drain_events();
+    assert_eq!(events[0].event_type, EventType::TokenDelete as u16);
+    assert_strictly_increasing(&events, 4);
+    assert!(events[1..].iter().all(|e| e.event_type == EventType::ConnectionDelete as u16));
+}
+
+#[test]
+fn test_engine_token_delete_drops_labels_and_attributes() {
+    use axiom_core::Token;
+    use axiom_domain::OrphanCriteria;
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    engine.ashti.inject_token(106, Token::new(1, 106, [0, 0, 0], 1)).unwrap();
+    engine.ashti.inject_token(106, make_token(2, 1, 0)).unwrap();
+    for id in [1, 2] {
+        engine.token_labels.add(id, "word");
+        engine.graph_attributes.set_node_attr(id, "score", 0.5);
+    }
+
+    engine.remove_token(106, 1);
+    engine.com_next_id = 1_000;
+    let criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    assert_eq!(engine.collect_orphans(&criteria).tombstones.len(), 1);
+
+    assert!(engine.token_labels.find_by_label("word").is_empty());
+    assert!(engine.token_labels.is_empty());
+    for id in [1, 2] {
+        assert!(engine.graph_attributes.node_attr(id, "score").is_none());
+    }
+}
+
+#[test]
+fn test_engine_transaction_vetoed_removal_rolls_back() {
+    use axiom_core::{Connection, EventType, FLAG_CRITICAL};
+    use axiom_domain::{ConnectionTransaction, TransactionError};
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    let mut critical = Connection::new(1, 3, 106, 1);
+    critical.flags |= FLAG_CRITICAL;
+    for c in [Connection::new(1, 2, 106, 1), critical] {
+        engine.ashti.inject_connection(106, c).unwrap();
+    }
+
+    let mut tx = ConnectionTransaction::new(106);
+    tx.remove((1, 2, 0)).remove((1, 3, 0));
+    let err = engine.apply_connection_transaction(&tx).unwrap_err();
+    assert_eq!(err, TransactionError::Vetoed { index: 1 });
+    let idx = engine.ashti.index_of(106).unwrap();
+    assert_eq!(engine.ashti.state(idx).unwrap().connections.len(), 2);
+
+    let mut tx = ConnectionTransaction::new(106);
+    tx.remove((1, 2, 0));
+    let report = engine.apply_connection_transaction(&tx).unwrap();
+    assert_eq!(report.removed.len(), 1);
+    let deletes = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::ConnectionDelete as u16)
+        .count();
+    assert_eq!(deletes, 1);
+}
+
+#[test]
+fn test_engine_transaction_removals_get_distinct_event_ids() {
+    use axiom_core::Connection;
+    use axiom_domain::ConnectionTransaction;
+    use axiom_runtime::AxiomEngine;
+
+    let mut engine = AxiomEngine::new();
+    for target in 2..=4 {
+        engine.ashti.inject_connection(106, Connection::new(1, target, 106, 1)).unwrap();
+    }
+
+    let mut tx = ConnectionTransaction::new(106);
+    tx.remove((1, 2, 0)).remove((1, 3, 0)).remove((1, 4, 0));
+    assert_eq!(engine.apply_connection_transaction(&tx).unwrap().removed.len(), 3);
+    assert_strictly_increasing(&engine.drain_events(), 3);
+}
+
+// ============================================================
+// scan_cycles
+// ============================================================
+
+#[test]
+fn test_scan_cycles_flags_loops_of_given_link_type() {
+    use axiom_core::Connection;
+
+    let mut state = DomainState::new(&DomainConfig::factory_logic(106, 1));
+    let link = |s: u32, t: u32, link_type: u16| {
+        let mut c = Connection::new(s, t, 106, 1);
+        c.link_type = link_type;
+        c
+    };
+    for c in [link(1, 2, 0x0201), link(2, 1, 0x0201), link(2, 3, 0x0201), link(3, 2, 0x0301)] {
+        state.add_connection(c).unwrap();
+    }
+
+    let mut guardian = Guardian::with_default_genome();
+    let actions = guardian.scan_cycles(&state, Some(0x0201), 4);
+    assert_eq!(
+        actions,
+        vec![InhibitAction { reason: InhibitReason::ConnectionCycle { members: vec![1, 2] } }]
+    );
+    assert_eq!(guardian.violation_count(), 1);
+    assert_eq!(guardian.scan_cycles(&state, None, 4).len(), 2);
+}
+
 // ============================================================
 // genome accessor
 // ============================================================
@@ -51,6 +51,7 @@ fn test_tick_schedule_defaults() {
     assert_eq!(s.tension_check_interval, 10);
     assert_eq!(s.goal_check_interval, 10);
     assert_eq!(s.reconcile_interval, 200);
+    assert_eq!(s.strength_norm_interval, 100);
 }
 
 #[test]
@@ -101,3 +102,164 @@ fn test_custom_schedule_is_respected() {
     }
     assert_eq!(engine.tick_count, 10);
 }
+
+#[test]
+fn test_strength_normalization_fires_at_interval() {
+    use axiom_core::Connection;
+    use axiom_domain::{NormalizationMode, StrengthNormalization};
+
+    let mut engine = AxiomEngine::new();
+    engine.tick_schedule.strength_norm_interval = 1;
+    engine.strength_normalization =
+        StrengthNormalization::uniform(NormalizationMode::SumToCap { cap: 1.0 });
+    let idx = engine.ashti.index_of(106).unwrap();
+    for target in 2..6 {
+        let mut c = Connection::new(1, target, 106, 1);
+        c.strength = 3.0;
+        engine.ashti.state_mut(idx).unwrap().add_connection(c).unwrap();
+    }
+
+    engine.process_command(&tick_cmd());
+
+    let sum: f32 = engine.ashti.state(idx).unwrap().connections.iter().map(|c| c.strength).sum();
+    assert!((sum - 1.0).abs() < 1e-5, "сумма исходящих связей хаба = cap, got {sum}");
+}
+
+// ============================================================
+// orphan_gc_interval
+// ============================================================
+
+#[test]
+fn test_orphan_gc_disabled_by_default() {
+    assert_eq!(TickSchedule::default().orphan_gc_interval, 0);
+}
+
+#[test]
+fn test_orphan_gc_runs_on_interval() {
+    use axiom_core::Token;
+    use axiom_domain::OrphanCriteria;
+
+    let mut engine = AxiomEngine::new();
+    engine.tick_schedule.orphan_gc_interval = 2;
+    engine.orphan_criteria = OrphanCriteria { max_mass: 1, min_bond_strength: 0.1, idle_events: 10 };
+    let mut stale = Token::new(1, 106, [0, 0, 0], 1);
+    stale.mass = 1;
+    engine.inject_token_direct(106, stale).unwrap();
+    engine.com_next_id = 1_000;
+
+    engine.process_command(&tick_cmd()); // tick 1 — GC не запускается
+    assert_eq!(engine.token_count(106), 1);
+    engine.process_command(&tick_cmd()); // tick 2
+    assert_eq!(engine.token_count(106), 0);
+}
+
+// ============================================================
+// connection_decay_interval
+// ============================================================
+
+#[test]
+fn test_connection_decay_runs_on_interval() {
+    use axiom_core::{Connection, Token};
+    use axiom_domain::ConnectionDecay;
+
+    let mut engine = AxiomEngine::new();
+    engine.tick_schedule.connection_decay_interval = 2;
+    engine.connection_decay = ConnectionDecay { factor: 0.5, idle_after: 10, prune_below: 0.01 };
+    engine.inject_token_direct(106, Token::new(1, 106, [0, 0, 0], 1)).unwrap();
+    engine.inject_token_direct(106, Token::new(2, 106, [5, 0, 0], 1)).unwrap();
+    engine.ashti.inject_connection(106, Connection::new(1, 2, 106, 1)).unwrap();
+    engine.com_next_id = 1_000;
+
+    let strength = |e: &AxiomEngine| {
+        let idx = e.ashti.index_of(106).unwrap();
+        e.ashti.state(idx).unwrap().connections[0].strength
+    };
+    let before = strength(&engine);
+    engine.process_command(&tick_cmd()); // tick 1
+    assert_eq!(strength(&engine), before);
+    engine.process_command(&tick_cmd()); // tick 2
+    assert_eq!(strength(&engine), before * 0.5);
+}
+
+// ============================================================
+// connection_prune_interval
+// ============================================================
+
+#[test]
+fn test_connection_prune_emits_connection_delete() {
+    use axiom_core::{Connection, EventType, Token};
+    use axiom_domain::ConnectionPruner;
+
+    let mut engine = AxiomEngine::new();
+    assert_eq!(engine.tick_schedule.connection_prune_interval, 0);
+    engine.tick_schedule.connection_prune_interval = 1;
+    engine.connection_pruner = ConnectionPruner { min_strength: 0.5, ..Default::default() };
+    engine.inject_token_direct(106, Token::new(1, 106, [0, 0, 0], 1)).unwrap();
+    engine.inject_token_direct(106, Token::new(2, 106, [5, 0, 0], 1)).unwrap();
+    let mut weak = Connection::new(1, 2, 106, 1);
+    weak.strength = 0.1;
+    engine.ashti.inject_connection(106, weak).unwrap();
+    engine.drain_events();
+
+    engine.process_command(&tick_cmd());
+    let idx = engine.ashti.index_of(106).unwrap();
+    assert!(engine.ashti.state(idx).unwrap().connections.is_empty());
+    let deletes: Vec<_> = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::ConnectionDelete as u16)
+        .collect();
+    assert_eq!(deletes.len(), 1);
+    assert_eq!((deletes[0].source_id, deletes[0].target_id), (1, 2));
+}
+
+#[test]
+fn test_connection_prune_gives_each_delete_its_own_event_id() {
+    use axiom_core::{Connection, EventType};
+    use axiom_domain::ConnectionPruner;
+
+    let mut engine = AxiomEngine::new();
+    engine.connection_pruner = ConnectionPruner { min_strength: 0.5, ..Default::default() };
+    for target in 2..=4 {
+        let mut weak = Connection::new(1, target, 106, 1);
+        weak.strength = 0.1;
+        engine.ashti.inject_connection(106, weak).unwrap();
+    }
+    engine.drain_events();
+
+    assert_eq!(engine.prune_connections().removed.len(), 3);
+    let ids: Vec<u64> = engine
+        .drain_events()
+        .into_iter()
+        .filter(|e| e.event_type == EventType::ConnectionDelete as u16)
+        .map(|e| e.event_id)
+        .collect();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]), "event ids not increasing: {ids:?}");
+}
+
+// ============================================================
+// connection_learning_interval
+// ============================================================
+
+#[test]
+fn test_connection_learning_strengthens_co_activated_link() {
+    use axiom_core::{Connection, Token};
+    use axiom_domain::ConnectionLearningRule;
+
+    let mut engine = AxiomEngine::new();
+    assert_eq!(engine.tick_schedule.connection_learning_interval, 0);
+    engine.tick_schedule.connection_learning_interval = 1;
+    engine.connection_learning = ConnectionLearningRule::Hebbian { rate: 0.2, window: 10 };
+    engine.inject_token_direct(106, Token::new(1, 106, [0, 0, 0], 10)).unwrap();
+    engine.inject_token_direct(106, Token::new(2, 106, [5, 0, 0], 12)).unwrap();
+    let mut link = Connection::new(1, 2, 106, 1);
+    link.strength = 0.5;
+    engine.ashti.inject_connection(106, link).unwrap();
+
+    engine.process_command(&tick_cmd());
+    let idx = engine.ashti.index_of(106).unwrap();
+    let learned = engine.ashti.state(idx).unwrap().connections[0];
+    assert!(learned.strength > 0.5);
+    assert!(learned.last_event_id >= 12);
+}
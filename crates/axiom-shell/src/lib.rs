@@ -629,6 +629,14 @@ pub mod link_types {
 
     /// Текстовый токен → примитивный якорь (по результату anchor matching).
     pub const SEMANTIC_ANCHOR_BOND: u16 = 0x0B01;
+
+    /// Embedding-токен → ближайший существующий токен SUTRA (kNN grounding).
+    /// Создаётся EmbeddingPerceptor при perceive_and_ground().
+    pub const EMBEDDING_GROUNDING_BOND: u16 = 0x0B02;
+
+    /// Токен реплики → недавний токен того же собеседника (контекст разговора).
+    /// Создаётся SessionContext в tick_loop; strength затухает с давностью.
+    pub const CONTEXT_CONTINUITY_BOND: u16 = 0x0B03;
 }
 
 // ============================================================================
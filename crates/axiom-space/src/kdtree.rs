@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// KdTree — точный kNN и выборки по шару и параллелепипеду в позициях токенов.
+//
+// SpatialHashGrid отвечает на запросы «в радиусе R»: если ближайший сосед
+// дальше R, его не видно, а большой R обходит всё пространство ячеек.
+// KdTree строится по снимку позиций (медианное разбиение по осям x → y → z,
+// неявное дерево в одном массиве) и находит k ближайших за O(log N) на
+// запрос; выборки по шару и параллелепипеду отсекают поддеревья по
+// плоскостям разбиения. Дерево не обновляется при движении токенов — его
+// перестраивают вместе с grid (см. KdRefresh).
+
+use std::collections::BinaryHeap;
+
+use crate::distance2;
+
+/// Когда перестраивать KdTree домена.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KdRefresh {
+    /// Перестройка grid сбрасывает дерево; строится при первом запросе
+    #[default]
+    OnDemand,
+    /// Строится сразу при каждой перестройке grid
+    WithGrid,
+}
+
+/// Статическое kd-дерево над (id, позиция).
+#[derive(Debug, Clone, Default)]
+pub struct KdTree {
+    /// Узел поддерева [lo, hi) — элемент с индексом (lo + hi) / 2;
+    /// ось разбиения — глубина % 3
+    points: Vec<(u32, (i16, i16, i16))>,
+}
+
+impl KdTree {
+    /// Построить дерево по точкам. O(N log N).
+    pub fn build(mut points: Vec<(u32, (i16, i16, i16))>) -> Self {
+        split(&mut points, 0);
+        Self { points }
+    }
+
+    /// Число точек.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// k ближайших к `center`: `(id, dist²)` по возрастанию расстояния,
+    /// при равенстве — по id.
+    pub fn nearest(&self, center: (i16, i16, i16), k: usize) -> Vec<(u32, i64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut best: BinaryHeap<(i64, u32)> = BinaryHeap::with_capacity(k + 1);
+        self.nearest_in(0, self.points.len(), 0, center, k, &mut best);
+        let mut found: Vec<(u32, i64)> = best.into_iter().map(|(d2, id)| (id, d2)).collect();
+        found.sort_unstable_by_key(|&(id, d2)| (d2, id));
+        found
+    }
+
+    /// Точки в шаре радиуса `radius` вокруг `center`: `(id, dist²)` по
+    /// возрастанию расстояния, при равенстве — по id.
+    pub fn within_radius(&self, center: (i16, i16, i16), radius: i16) -> Vec<(u32, i64)> {
+        let radius2 = radius as i64 * radius as i64;
+        let lo = offset(center, -(radius as i32));
+        let hi = offset(center, radius as i32);
+        let mut found: Vec<(u32, i64)> = self
+            .within_box(lo, hi)
+            .into_iter()
+            .map(|(id, p)| (id, distance2(center.0, center.1, center.2, p.0, p.1, p.2)))
+            .filter(|&(_, d2)| d2 <= radius2)
+            .collect();
+        found.sort_unstable_by_key(|&(id, d2)| (d2, id));
+        found
+    }
+
+    /// Точки в параллелепипеде [min, max] (границы включительно): `(id, позиция)`
+    /// по возрастанию id.
+    pub fn within_box(
+        &self,
+        min: (i16, i16, i16),
+        max: (i16, i16, i16),
+    ) -> Vec<(u32, (i16, i16, i16))> {
+        let mut found = Vec::new();
+        self.box_in(0, self.points.len(), 0, min, max, &mut found);
+        found.sort_unstable_by_key(|&(id, _)| id);
+        found
+    }
+
+    fn box_in(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        min: (i16, i16, i16),
+        max: (i16, i16, i16),
+        found: &mut Vec<(u32, (i16, i16, i16))>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let (id, p) = self.points[mid];
+        if (min.0..=max.0).contains(&p.0)
+            && (min.1..=max.1).contains(&p.1)
+            && (min.2..=max.2).contains(&p.2)
+        {
+            found.push((id, p));
+        }
+        let split = axis(p, depth);
+        if axis(min, depth) <= split {
+            self.box_in(lo, mid, depth + 1, min, max, found);
+        }
+        if axis(max, depth) >= split {
+            self.box_in(mid + 1, hi, depth + 1, min, max, found);
+        }
+    }
+
+    fn nearest_in(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        center: (i16, i16, i16),
+        k: usize,
+        best: &mut BinaryHeap<(i64, u32)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let (id, p) = self.points[mid];
+        let candidate = (distance2(center.0, center.1, center.2, p.0, p.1, p.2), id);
+        if best.len() < k {
+            best.push(candidate);
+        } else if best.peek().is_some_and(|&worst| candidate < worst) {
+            best.pop();
+            best.push(candidate);
+        }
+
+        let diff = axis(center, depth) as i64 - axis(p, depth) as i64;
+        let (near, far) =
+            if diff < 0 { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.nearest_in(near.0, near.1, depth + 1, center, k, best);
+        if best.len() < k || best.peek().is_some_and(|&(worst, _)| diff * diff <= worst) {
+            self.nearest_in(far.0, far.1, depth + 1, center, k, best);
+        }
+    }
+}
+
+/// Сдвиг точки на `by` по всем осям с насыщением в пределах i16.
+fn offset(p: (i16, i16, i16), by: i32) -> (i16, i16, i16) {
+    let shift = |v: i16| (v as i32 + by).clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    (shift(p.0), shift(p.1), shift(p.2))
+}
+
+fn axis(p: (i16, i16, i16), depth: usize) -> i16 {
+    match depth % 3 {
+        0 => p.0,
+        1 => p.1,
+        _ => p.2,
+    }
+}
+
+/// Разложить срез в неявное дерево: медиана по оси в середине, рекурсивно.
+fn split(points: &mut [(u32, (i16, i16, i16))], depth: usize) {
+    if points.len() <= 1 {
+        return;
+    }
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by_key(mid, |&(_, p)| axis(p, depth));
+    let (left, right) = points.split_at_mut(mid);
+    split(left, depth + 1);
+    split(&mut right[1..], depth + 1);
+}
@@ -7,6 +7,27 @@
 
 use serde::{Deserialize, Serialize};
 
+pub mod batch;
+pub use batch::{cosine_distance_batch, distance2_batch, PositionBatch};
+
+pub mod kdtree;
+pub use kdtree::{KdRefresh, KdTree};
+
+pub mod metric;
+pub use metric::{Metric, ANGULAR_SCALE, WEIGHT_ONE};
+
+pub mod occupancy;
+pub use occupancy::{CellOccupancy, OccupancyStats};
+
+pub mod reduce;
+pub use reduce::{PcaReducer, DEFAULT_PCA_LEARNING_RATE};
+
+pub mod trajectory;
+pub use trajectory::{centroid, centroid_of, lerp, sample_path, slerp, trajectory, CoordinateExt};
+
+pub mod transform;
+pub use transform::{Affine3, PointPair};
+
 /// Константы пространственной модели
 ///
 /// CELL_SHIFT определяет размер ячейки как степень двойки:
@@ -352,6 +373,9 @@ pub fn integer_sqrt(n: i64) -> i64 {
     }
 }
 
+/// Перемещение токена в grid: (token_index, старая позиция, новая позиция)
+pub type GridMove = (u32, (i16, i16, i16), (i16, i16, i16));
+
 /// Запись в ячейке spatial hash grid
 /// Хранит индекс токена и ссылку на следующую запись (linked list)
 #[repr(C)]
@@ -502,6 +526,68 @@ impl SpatialHashGrid {
         }
     }
 
+    /// Перенести токен в корзину новой позиции без перестройки grid.
+    ///
+    /// Запись токена отцепляется от цепочки корзины `old` и вешается в
+    /// голову корзины `new`; массив entries не растёт. Если позиции попадают
+    /// в одну корзину — сразу true. Возвращает false, если токена нет
+    /// в корзине `old` (grid устарел — нужна перестройка).
+    pub fn update_token(
+        &mut self,
+        token_index: u32,
+        old: (i16, i16, i16),
+        new: (i16, i16, i16),
+    ) -> bool {
+        let old_key = Self::cell_key(old.0, old.1, old.2) as usize;
+        let new_key = Self::cell_key(new.0, new.1, new.2) as usize;
+        if old_key == new_key {
+            return true;
+        }
+
+        let mut prev = CellEntry::NONE;
+        let mut current = self.bucket_heads[old_key];
+        while current != CellEntry::NONE
+            && self.entries[current as usize].token_index != token_index
+        {
+            prev = current;
+            current = self.entries[current as usize].next;
+        }
+        if current == CellEntry::NONE {
+            return false;
+        }
+
+        // Отцепить от старой цепочки
+        let next = self.entries[current as usize].next;
+        if prev == CellEntry::NONE {
+            self.bucket_heads[old_key] = next;
+        } else {
+            self.entries[prev as usize].next = next;
+        }
+        // Повесить в голову новой
+        self.entries[current as usize].next = self.bucket_heads[new_key];
+        self.bucket_heads[new_key] = current;
+        true
+    }
+
+    /// Пакетный `update_token`: `(token_index, old, new)`.
+    /// Возвращает число токенов, которых не нашлось в старых корзинах.
+    pub fn apply_moves(&mut self, moves: &[GridMove]) -> usize {
+        moves
+            .iter()
+            .filter(|&&(token_index, old, new)| !self.update_token(token_index, old, new))
+            .count()
+    }
+
+    /// Заполненность ячеек по проиндексированным токенам (см. OccupancyStats).
+    pub fn occupancy<F>(&self, get_position: F) -> OccupancyStats
+    where
+        F: Fn(usize) -> (i16, i16, i16),
+    {
+        OccupancyStats::from_positions(
+            self.entries[..self.entry_count].iter().map(|e| get_position(e.token_index as usize)),
+        )
+    }
+
     /// Найти всех соседей токена в заданном радиусе
     ///
     /// Алгоритм:
@@ -572,6 +658,88 @@ impl SpatialHashGrid {
 
         neighbors
     }
+
+    /// Найти k ближайших токенов в радиусе `radius` — пакетный путь.
+    ///
+    /// Кандидаты из ячеек собираются в `batch` (SoA), расстояния считаются
+    /// одним проходом `distance2_batch`, затем top-k частичной сортировкой.
+    /// `batch` переиспользуется между вызовами. Возвращает `(token_index, dist²)`
+    /// по возрастанию расстояния; дубликаты из коллизий корзин отброшены.
+    pub fn find_k_nearest<F>(
+        &self,
+        center: (i16, i16, i16),
+        radius: i16,
+        k: usize,
+        get_position: F,
+        batch: &mut PositionBatch,
+    ) -> Vec<(u32, i64)>
+    where
+        F: Fn(u32) -> (i16, i16, i16),
+    {
+        batch.clear();
+        for token_index in self.cell_candidates(center, radius) {
+            batch.push(token_index, get_position(token_index));
+        }
+
+        batch.compute_distances(center);
+        let radius2 = (radius as i64) * (radius as i64);
+        let mut nearest: Vec<(u32, i64)> = batch
+            .ids
+            .iter()
+            .copied()
+            .zip(batch.dist2.iter().copied())
+            .filter(|&(_, d2)| d2 <= radius2)
+            .collect();
+        batch::top_k_pairs(&mut nearest, k);
+        nearest
+    }
+
+    /// Найти k ближайших по метрике `metric`.
+    ///
+    /// Кандидаты — токены из ячеек куба ±`radius` вокруг центра; отсев по
+    /// `Metric::radius_limit` (для угловых метрик отсева нет). `Metric::Euclidean`
+    /// даёт тот же результат, что `find_k_nearest`.
+    pub fn find_k_nearest_by<F>(
+        &self,
+        center: (i16, i16, i16),
+        radius: i16,
+        k: usize,
+        metric: Metric,
+        get_position: F,
+    ) -> Vec<(u32, i64)>
+    where
+        F: Fn(u32) -> (i16, i16, i16),
+    {
+        let limit = metric.radius_limit(radius);
+        let mut nearest: Vec<(u32, i64)> = self
+            .cell_candidates(center, radius)
+            .into_iter()
+            .map(|i| (i, metric.distance(center, get_position(i))))
+            .filter(|&(_, d)| limit.is_none_or(|l| d <= l))
+            .collect();
+        batch::top_k_pairs(&mut nearest, k);
+        nearest
+    }
+
+    /// Индексы токенов из ячеек куба ±radius вокруг центра, без дубликатов.
+    fn cell_candidates(&self, center: (i16, i16, i16), radius: i16) -> Vec<u32> {
+        let lo = |v: i16| (v.saturating_sub(radius) as i32) >> CELL_SHIFT;
+        let hi = |v: i16| (v.saturating_add(radius) as i32) >> CELL_SHIFT;
+        let mut candidates = Vec::new();
+        for cell_x in lo(center.0)..=hi(center.0) {
+            for cell_y in lo(center.1)..=hi(center.1) {
+                for cell_z in lo(center.2)..=hi(center.2) {
+                    let cx = ((cell_x << CELL_SHIFT) + (CELL_SIZE / 2)) as i16;
+                    let cy = ((cell_y << CELL_SHIFT) + (CELL_SIZE / 2)) as i16;
+                    let cz = ((cell_z << CELL_SHIFT) + (CELL_SIZE / 2)) as i16;
+                    candidates.extend(self.query_cell(cx, cy, cz));
+                }
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
 }
 
 /// Итератор по токенам в ячейке
@@ -644,6 +812,9 @@ pub struct SpatialConfig {
     pub bucket_count_log2: u32,
     /// Начальная ёмкость массива entries
     pub initial_capacity: u32,
+    /// Метрика kNN-запросов (`find_k_nearest_by`); по умолчанию — евклидова
+    #[serde(default)]
+    pub metric: Metric,
 }
 
 impl SpatialConfig {
@@ -655,6 +826,7 @@ impl SpatialConfig {
             cell_shift: 6,
             bucket_count_log2: 17,
             initial_capacity: 8192,
+            metric: Metric::Euclidean,
         }
     }
 
@@ -666,6 +838,7 @@ impl SpatialConfig {
             cell_shift: CELL_SHIFT,
             bucket_count_log2: BUCKET_COUNT_LOG2,
             initial_capacity: 4096,
+            metric: Metric::Euclidean,
         }
     }
 
@@ -677,6 +850,7 @@ impl SpatialConfig {
             cell_shift: 10,
             bucket_count_log2: 14,
             initial_capacity: 2048,
+            metric: Metric::Euclidean,
         }
     }
 
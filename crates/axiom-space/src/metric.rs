@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Metric — выбираемая метрика расстояния для kNN-запросов.
+//
+// distance2 (евклидов квадрат) верен для «физических» осей, но для направленных
+// семантических слоёв значимо направление вектора от начала координат, а не
+// абсолютная позиция: два токена на одном луче на разном удалении — одно и то же
+// по смыслу. Cosine / Angular сравнивают направление, Weighted — позволяет
+// ослабить ось, которая в данном домене шумит.
+//
+// Все метрики возвращают i64, «меньше = ближе» — совместимо с top_k_pairs.
+
+use serde::{Deserialize, Serialize};
+
+/// Масштаб угловых метрик: 1.0 → ANGULAR_SCALE.
+pub const ANGULAR_SCALE: f64 = 1_000_000.0;
+
+/// Единица веса оси в `Metric::Weighted` (fixed-point 8.8: 256 = 1.0).
+pub const WEIGHT_ONE: u16 = 256;
+
+/// Метрика расстояния между позициями.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    /// dx² + dy² + dz² (как distance2)
+    #[default]
+    Euclidean,
+    /// |dx| + |dy| + |dz|
+    Manhattan,
+    /// (1 − cos θ) · ANGULAR_SCALE между векторами от начала координат
+    Cosine,
+    /// θ / π · ANGULAR_SCALE — геодезическое расстояние на единичной сфере
+    Angular,
+    /// Σ wᵢ · dᵢ² / WEIGHT_ONE — евклидов квадрат с весами осей
+    Weighted { weights: [u16; 3] },
+}
+
+impl Metric {
+    /// Расстояние между `a` и `b` (меньше = ближе).
+    pub fn distance(&self, a: (i16, i16, i16), b: (i16, i16, i16)) -> i64 {
+        let d = [
+            b.0 as i64 - a.0 as i64,
+            b.1 as i64 - a.1 as i64,
+            b.2 as i64 - a.2 as i64,
+        ];
+        match self {
+            Metric::Euclidean => crate::distance2(a.0, a.1, a.2, b.0, b.1, b.2),
+            Metric::Manhattan => d.iter().map(|v| v.abs()).sum(),
+            Metric::Cosine => cosine_distance(cosine(a, b)),
+            Metric::Angular => {
+                (cosine(a, b).acos() / std::f64::consts::PI * ANGULAR_SCALE).round() as i64
+            }
+            Metric::Weighted { weights } => {
+                d.iter()
+                    .zip(weights)
+                    .map(|(v, &w)| v * v * w as i64)
+                    .sum::<i64>()
+                    / WEIGHT_ONE as i64
+            }
+        }
+    }
+
+    /// Порог расстояния, соответствующий `radius` в квантах.
+    ///
+    /// None для угловых метрик: для них radius только ограничивает область
+    /// поиска кандидатов в grid, ранжирование — по углу.
+    pub fn radius_limit(&self, radius: i16) -> Option<i64> {
+        let r = radius as i64;
+        match self {
+            Metric::Euclidean | Metric::Weighted { .. } => Some(r * r),
+            Metric::Manhattan => Some(r),
+            Metric::Cosine | Metric::Angular => None,
+        }
+    }
+}
+
+/// cos θ между векторами от начала координат. Нулевой вектор → 0 (ортогонален всему).
+fn cosine(a: (i16, i16, i16), b: (i16, i16, i16)) -> f64 {
+    let (a, b) = (as_f64(a), as_f64(b));
+    cosine_with_norm(a, norm(a), b)
+}
+
+#[inline(always)]
+pub(crate) fn as_f64(p: (i16, i16, i16)) -> [f64; 3] {
+    [p.0 as f64, p.1 as f64, p.2 as f64]
+}
+
+#[inline(always)]
+pub(crate) fn norm(v: [f64; 3]) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+/// cos θ при заранее посчитанной норме `a` — общий путь для скалярной метрики
+/// и пакетного ядра (результаты совпадают бит-в-бит).
+#[inline(always)]
+pub(crate) fn cosine_with_norm(a: [f64; 3], na: f64, b: [f64; 3]) -> f64 {
+    let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let nb = norm(b);
+    if na == 0.0 || nb == 0.0 {
+        return 0.0;
+    }
+    (dot / (na * nb)).clamp(-1.0, 1.0)
+}
+
+/// (1 − cos θ) · ANGULAR_SCALE
+#[inline(always)]
+pub(crate) fn cosine_distance(cos: f64) -> i64 {
+    ((1.0 - cos) * ANGULAR_SCALE).round() as i64
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// OccupancyStats — заполненность ячеек пространства и heatmap.
+//
+// SpatialHashGrid хранит ячейки через хеш, и по корзинам не видно, где
+// пространство плотное: разные ячейки делят корзину. Статистика считается
+// по настоящим координатам ячеек (позиция >> CELL_SHIFT): сколько ячеек
+// занято, гистограмма заполненности по степеням двойки, самые плотные
+// ячейки. Heatmap — по строке CSV на занятую ячейку; для JSON структуры
+// сериализуются через serde.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CELL_SHIFT, CELL_SIZE};
+
+/// Занятая ячейка: координаты ячейки и число токенов в ней.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellOccupancy {
+    /// Координаты ячейки (позиция >> CELL_SHIFT)
+    pub cell: (i32, i32, i32),
+    /// Токенов в ячейке
+    pub count: u32,
+}
+
+impl CellOccupancy {
+    /// Нижний угол ячейки в координатах пространства.
+    pub fn origin(&self) -> (i32, i32, i32) {
+        (self.cell.0 * CELL_SIZE, self.cell.1 * CELL_SIZE, self.cell.2 * CELL_SIZE)
+    }
+}
+
+/// Заполненность ячеек пространства.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OccupancyStats {
+    /// Всего токенов
+    pub tokens: usize,
+    /// Занятые ячейки по возрастанию координат
+    pub cells: Vec<CellOccupancy>,
+    /// histogram[b] — число ячеек с заполненностью в [2^b, 2^(b+1))
+    pub histogram: Vec<usize>,
+}
+
+impl OccupancyStats {
+    /// Статистика по позициям токенов.
+    pub fn from_positions(positions: impl IntoIterator<Item = (i16, i16, i16)>) -> Self {
+        let mut counts: BTreeMap<(i32, i32, i32), u32> = BTreeMap::new();
+        let mut tokens = 0;
+        let cell = |v: i16| (v as i32) >> CELL_SHIFT;
+        for (x, y, z) in positions {
+            *counts.entry((cell(x), cell(y), cell(z))).or_default() += 1;
+            tokens += 1;
+        }
+        let mut histogram = Vec::new();
+        for &count in counts.values() {
+            let bucket = count.ilog2() as usize;
+            if histogram.len() <= bucket {
+                histogram.resize(bucket + 1, 0);
+            }
+            histogram[bucket] += 1;
+        }
+        let cells = counts.into_iter().map(|(cell, count)| CellOccupancy { cell, count }).collect();
+        Self { tokens, cells, histogram }
+    }
+
+    /// Число занятых ячеек.
+    pub fn occupied_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Заполненность самой плотной ячейки.
+    pub fn max_occupancy(&self) -> u32 {
+        self.cells.iter().map(|c| c.count).max().unwrap_or(0)
+    }
+
+    /// Средняя заполненность занятой ячейки.
+    pub fn mean_occupancy(&self) -> f32 {
+        if self.cells.is_empty() {
+            return 0.0;
+        }
+        self.tokens as f32 / self.cells.len() as f32
+    }
+
+    /// Перекос: max / mean. 1.0 — токены распределены по ячейкам равномерно.
+    pub fn skew(&self) -> f32 {
+        let mean = self.mean_occupancy();
+        if mean == 0.0 {
+            return 0.0;
+        }
+        self.max_occupancy() as f32 / mean
+    }
+
+    /// `n` самых плотных ячеек: по убыванию заполненности, при равенстве — по координатам.
+    pub fn densest(&self, n: usize) -> Vec<CellOccupancy> {
+        let mut cells = self.cells.clone();
+        cells.sort_by(|a, b| b.count.cmp(&a.count).then(a.cell.cmp(&b.cell)));
+        cells.truncate(n);
+        cells
+    }
+
+    /// Heatmap в CSV: заголовок и строка на занятую ячейку.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("cell_x,cell_y,cell_z,origin_x,origin_y,origin_z,count\n");
+        for c in &self.cells {
+            let (ox, oy, oz) = c.origin();
+            let (cx, cy, cz) = c.cell;
+            out.push_str(&format!("{cx},{cy},{cz},{ox},{oy},{oz},{}\n", c.count));
+        }
+        out
+    }
+}
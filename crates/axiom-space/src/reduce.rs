@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// PcaReducer — проекция векторов высокой размерности в 3D позицию токена.
+//
+// Внешние эмбеддинги (CLIP, текстовые модели) живут в сотнях измерений, а
+// токену нужны три координаты. PCA оставляет три направления наибольшей
+// дисперсии выборки — расстояния вдоль них сохраняются лучше случайной
+// проекции. `fit` считает компоненты по выборке степенным методом с
+// исчерпыванием (ковариационная матрица d×d не строится: C·v = Σ (x·v)·x / n),
+// `update` уточняет их по одному вектору правилом Сэнгера (обобщённый
+// Хебб) — модель дообучается на потоке без полного пересчёта.
+// Позиция — координаты в единицах σ компоненты, умноженные на масштаб.
+
+/// Число итераций степенного метода на компоненту.
+const POWER_ITERATIONS: usize = 64;
+
+/// Скорость обучения `update` по умолчанию.
+pub const DEFAULT_PCA_LEARNING_RATE: f32 = 0.01;
+
+/// PCA-модель: среднее, три главные компоненты и их дисперсии.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcaReducer {
+    mean: Vec<f32>,
+    components: [Vec<f32>; 3],
+    variance: [f32; 3],
+    samples: u64,
+    /// Скорость обучения `update` (правило Сэнгера)
+    pub learning_rate: f32,
+}
+
+impl PcaReducer {
+    /// Необученная модель размерности `dim`: нулевое среднее, компоненты —
+    /// первые оси, единичные дисперсии. Дообучается через `update`.
+    pub fn new(dim: usize) -> Self {
+        let axis = |a: usize| (0..dim).map(|i| if i == a { 1.0 } else { 0.0 }).collect();
+        Self {
+            mean: vec![0.0; dim],
+            components: [axis(0), axis(1), axis(2)],
+            variance: [1.0; 3],
+            samples: 0,
+            learning_rate: DEFAULT_PCA_LEARNING_RATE,
+        }
+    }
+
+    /// Обучить по выборке. None — выборка пуста, размерности векторов
+    /// различаются или есть NaN/inf.
+    pub fn fit(samples: &[Vec<f32>]) -> Option<Self> {
+        let dim = samples.first()?.len();
+        if samples.iter().any(|s| s.len() != dim || s.iter().any(|v| !v.is_finite())) {
+            return None;
+        }
+        let n = samples.len() as f64;
+        let mut mean = vec![0.0f64; dim];
+        for s in samples {
+            for (m, &v) in mean.iter_mut().zip(s) {
+                *m += v as f64 / n;
+            }
+        }
+        let centered: Vec<Vec<f64>> = samples
+            .iter()
+            .map(|s| s.iter().zip(&mean).map(|(&v, m)| v as f64 - m).collect())
+            .collect();
+
+        let mut reducer = Self::new(dim);
+        let mut found: Vec<Vec<f64>> = Vec::with_capacity(3);
+        for k in 0..3.min(dim) {
+            let mut v: Vec<f64> = (0..dim).map(|i| seed(i, k)).collect();
+            let mut lambda = 0.0;
+            for _ in 0..POWER_ITERATIONS {
+                // w = C·v, затем ортогонализация к найденным компонентам
+                let mut w = vec![0.0f64; dim];
+                for x in &centered {
+                    let proj = dot(x, &v);
+                    for (wi, xi) in w.iter_mut().zip(x) {
+                        *wi += proj * xi / n;
+                    }
+                }
+                for u in &found {
+                    let p = dot(&w, u);
+                    for (wi, ui) in w.iter_mut().zip(u) {
+                        *wi -= p * ui;
+                    }
+                }
+                lambda = dot(&w, &w).sqrt();
+                if lambda <= f64::EPSILON {
+                    break;
+                }
+                v = w.iter().map(|wi| wi / lambda).collect();
+            }
+            // Знак: наибольшая по модулю координата положительна — детерминизм
+            let sign = v.iter().copied().fold(0.0f64, |a, b| if b.abs() > a.abs() { b } else { a });
+            if sign < 0.0 {
+                v.iter_mut().for_each(|vi| *vi = -*vi);
+            }
+            reducer.components[k] = v.iter().map(|&vi| vi as f32).collect();
+            reducer.variance[k] = lambda as f32;
+            found.push(v);
+        }
+        reducer.mean = mean.iter().map(|&m| m as f32).collect();
+        reducer.samples = samples.len() as u64;
+        Some(reducer)
+    }
+
+    /// Уточнить модель одним вектором. False — размерность не совпадает
+    /// или есть NaN/inf; модель не меняется.
+    pub fn update(&mut self, x: &[f32]) -> bool {
+        if x.len() != self.dim() || x.iter().any(|v| !v.is_finite()) {
+            return false;
+        }
+        self.samples += 1;
+        let n = self.samples as f32;
+        for (m, &v) in self.mean.iter_mut().zip(x) {
+            *m += (v - *m) / n;
+        }
+        let mut residual: Vec<f32> = x.iter().zip(&self.mean).map(|(&v, m)| v - m).collect();
+        let ys = self.components.each_ref().map(|c| dot32(c, &residual));
+        let rate = self.learning_rate;
+        for (k, y) in ys.into_iter().enumerate() {
+            // Сэнгер: Δw_k = η·y_k·(x − Σ_{j≤k} y_j·w_j), y_k = w_k·x
+            for (r, w) in residual.iter_mut().zip(&self.components[k]) {
+                *r -= y * w;
+            }
+            for (w, r) in self.components[k].iter_mut().zip(&residual) {
+                *w += rate * y * r;
+            }
+            let norm = dot32(&self.components[k], &self.components[k]).sqrt();
+            if norm > f32::EPSILON {
+                self.components[k].iter_mut().for_each(|w| *w /= norm);
+            }
+            self.variance[k] += rate * (y * y - self.variance[k]);
+        }
+        true
+    }
+
+    /// Размерность входных векторов.
+    pub fn dim(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Число векторов, на которых обучена модель.
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Главные компоненты (единичные векторы).
+    pub fn components(&self) -> &[Vec<f32>; 3] {
+        &self.components
+    }
+
+    /// Дисперсия выборки вдоль каждой компоненты.
+    pub fn variance(&self) -> [f32; 3] {
+        self.variance
+    }
+
+    /// Координаты вектора в базисе компонент. None — размерность не совпадает.
+    pub fn project(&self, x: &[f32]) -> Option<[f32; 3]> {
+        if x.len() != self.dim() {
+            return None;
+        }
+        let centered: Vec<f32> = x.iter().zip(&self.mean).map(|(&v, m)| v - m).collect();
+        Some(self.components.each_ref().map(|c| dot32(c, &centered)))
+    }
+
+    /// Позиция токена: координаты в единицах σ компоненты × `scale`,
+    /// с насыщением в пределах i16.
+    pub fn to_position(&self, x: &[f32], scale: f32) -> Option<(i16, i16, i16)> {
+        let coords = self.project(x)?;
+        let q = |k: usize| {
+            let sigma = self.variance[k].sqrt();
+            let units = if sigma > f32::EPSILON { coords[k] / sigma } else { 0.0 };
+            (units * scale).round().clamp(i16::MIN as f32, i16::MAX as f32) as i16
+        };
+        Some((q(0), q(1), q(2)))
+    }
+}
+
+/// Детерминированное начальное приближение k-й компоненты.
+fn seed(i: usize, k: usize) -> f64 {
+    let h = (i as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ ((k as u64 + 1) << 32);
+    (h >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn dot32(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Интерполяция позиций и траектории по цепочке токенов.
+//
+// Плавный переход между концептами — это точки между их позициями: линейно
+// (lerp), по дуге вокруг начала координат (slerp — направление поворачивается,
+// длина меняется линейно) или вдоль ломаной через несколько токенов с
+// равным шагом по длине пути. Вычисления — в f64, результат округляется и
+// насыщается в пределах i16.
+
+use axiom_core::Token;
+
+/// Линейная интерполяция: t = 0 → `a`, t = 1 → `b`.
+pub fn lerp(a: (i16, i16, i16), b: (i16, i16, i16), t: f32) -> (i16, i16, i16) {
+    let t = t as f64;
+    let mix = |a: i16, b: i16| a as f64 + (b as f64 - a as f64) * t;
+    quantize([mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2)])
+}
+
+/// Сферическая интерполяция вокруг начала координат: направление
+/// поворачивается по дуге, длина меняется линейно. Для нулевого вектора
+/// или противоположных направлений — lerp.
+pub fn slerp(a: (i16, i16, i16), b: (i16, i16, i16), t: f32) -> (i16, i16, i16) {
+    let (va, vb) = (to_f64(a), to_f64(b));
+    let (la, lb) = (norm(va), norm(vb));
+    if la == 0.0 || lb == 0.0 {
+        return lerp(a, b, t);
+    }
+    let (ua, ub) = (va.map(|v| v / la), vb.map(|v| v / lb));
+    let cos = (0..3).map(|i| ua[i] * ub[i]).sum::<f64>().clamp(-1.0, 1.0);
+    let angle = cos.acos();
+    if angle < 1e-9 || (std::f64::consts::PI - angle) < 1e-9 {
+        return lerp(a, b, t);
+    }
+    let t = t as f64;
+    let (wa, wb) = (((1.0 - t) * angle).sin() / angle.sin(), (t * angle).sin() / angle.sin());
+    let length = la + (lb - la) * t;
+    quantize([0, 1, 2].map(|i| (wa * ua[i] + wb * ub[i]) * length))
+}
+
+/// Центроид набора точек. None — набор пуст.
+pub fn centroid(points: &[(i16, i16, i16)]) -> Option<(i16, i16, i16)> {
+    if points.is_empty() {
+        return None;
+    }
+    let n = points.len() as f64;
+    let sum = points.iter().fold([0i64; 3], |s, p| {
+        [s[0] + p.0 as i64, s[1] + p.1 as i64, s[2] + p.2 as i64]
+    });
+    Some(quantize(sum.map(|v| v as f64 / n)))
+}
+
+/// `samples` точек вдоль ломаной `path` с равным шагом по длине пути,
+/// первая и последняя — концы ломаной. Пустой путь или samples = 0 — пусто;
+/// одна точка или путь нулевой длины — её повторы.
+pub fn sample_path(path: &[(i16, i16, i16)], samples: usize) -> Vec<(i16, i16, i16)> {
+    let (Some(&first), Some(&last)) = (path.first(), path.last()) else {
+        return Vec::new();
+    };
+    let lengths: Vec<f64> = path
+        .windows(2)
+        .map(|w| {
+            let (from, to) = (to_f64(w[0]), to_f64(w[1]));
+            norm([to[0] - from[0], to[1] - from[1], to[2] - from[2]])
+        })
+        .collect();
+    let total: f64 = lengths.iter().sum();
+    if samples <= 1 || total == 0.0 {
+        return vec![first; samples];
+    }
+
+    let mut out = Vec::with_capacity(samples);
+    let mut segment = 0;
+    let mut walked = 0.0;
+    for s in 0..samples {
+        let target = total * s as f64 / (samples - 1) as f64;
+        while segment + 1 < lengths.len() && walked + lengths[segment] < target {
+            walked += lengths[segment];
+            segment += 1;
+        }
+        let t = if lengths[segment] == 0.0 { 0.0 } else { (target - walked) / lengths[segment] };
+        out.push(lerp(path[segment], path[segment + 1], t.clamp(0.0, 1.0) as f32));
+    }
+    // Концы — точно, без накопленной погрешности
+    out[samples - 1] = last;
+    out
+}
+
+/// Интерполяция и траектории прямо по токенам.
+pub trait CoordinateExt {
+    /// Позиция как (x, y, z).
+    fn coords(&self) -> (i16, i16, i16);
+
+    /// Линейно от `self` к `other`.
+    fn lerp_to(&self, other: &Self, t: f32) -> (i16, i16, i16) {
+        lerp(self.coords(), other.coords(), t)
+    }
+
+    /// По дуге от `self` к `other` (см. `slerp`).
+    fn slerp_to(&self, other: &Self, t: f32) -> (i16, i16, i16) {
+        slerp(self.coords(), other.coords(), t)
+    }
+}
+
+impl CoordinateExt for Token {
+    fn coords(&self) -> (i16, i16, i16) {
+        (self.position[0], self.position[1], self.position[2])
+    }
+}
+
+/// Центроид позиций токенов. None — набор пуст.
+pub fn centroid_of<T: CoordinateExt>(items: &[T]) -> Option<(i16, i16, i16)> {
+    centroid(&items.iter().map(CoordinateExt::coords).collect::<Vec<_>>())
+}
+
+/// Траектория через позиции токенов в заданном порядке (см. `sample_path`).
+pub fn trajectory<T: CoordinateExt>(items: &[T], samples: usize) -> Vec<(i16, i16, i16)> {
+    sample_path(&items.iter().map(CoordinateExt::coords).collect::<Vec<_>>(), samples)
+}
+
+fn to_f64(p: (i16, i16, i16)) -> [f64; 3] {
+    [p.0 as f64, p.1 as f64, p.2 as f64]
+}
+
+fn norm(v: [f64; 3]) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+fn quantize(v: [f64; 3]) -> (i16, i16, i16) {
+    let q = |x: f64| x.round().clamp(i16::MIN as f64, i16::MAX as f64) as i16;
+    (q(v[0]), q(v[1]), q(v[2]))
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Affine3 — аффинное отображение между пространствами доменов.
+//
+// Координаты токена осмысленны только внутри своего домена: точка (100, 0, 0)
+// в LOGIC и в MAP — разные вещи, и сравнивать их напрямую нельзя. Affine3
+// переводит точку одного пространства в другое: p' = M·p + t. Отображение
+// задаётся явно (масштаб, сдвиг, матрица) или подбирается методом наименьших
+// квадратов по парам соответствующих точек — например, по позициям одного
+// sutra_id в двух доменах. Отображения композируются и обращаются.
+
+use serde::{Deserialize, Serialize};
+
+/// Пара соответствующих точек: (в исходном пространстве, в целевом).
+pub type PointPair = ((i16, i16, i16), (i16, i16, i16));
+
+/// Аффинное отображение 3D: `p' = matrix · p + offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Affine3 {
+    /// Линейная часть, по строкам
+    pub matrix: [[f32; 3]; 3],
+    /// Сдвиг
+    pub offset: [f32; 3],
+}
+
+impl Default for Affine3 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Affine3 {
+    /// Тождественное отображение.
+    pub fn identity() -> Self {
+        Self::scale([1.0; 3])
+    }
+
+    /// Сдвиг на `offset`.
+    pub fn translation(offset: [f32; 3]) -> Self {
+        Self { offset, ..Self::identity() }
+    }
+
+    /// Масштаб по осям.
+    pub fn scale(factors: [f32; 3]) -> Self {
+        let [sx, sy, sz] = factors;
+        Self { matrix: [[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, sz]], offset: [0.0; 3] }
+    }
+
+    /// Отображение в точных (не округлённых) координатах.
+    pub fn apply_f32(&self, p: [f32; 3]) -> [f32; 3] {
+        let row = |r: usize| {
+            let m = self.matrix[r];
+            m[0] * p[0] + m[1] * p[1] + m[2] * p[2] + self.offset[r]
+        };
+        [row(0), row(1), row(2)]
+    }
+
+    /// Отобразить позицию токена: округление и насыщение в пределах i16.
+    pub fn apply(&self, p: (i16, i16, i16)) -> (i16, i16, i16) {
+        let [x, y, z] = self.apply_f32([p.0 as f32, p.1 as f32, p.2 as f32]);
+        let quantize = |v: f32| v.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16;
+        (quantize(x), quantize(y), quantize(z))
+    }
+
+    /// Композиция: сначала `self`, затем `next`.
+    pub fn then(&self, next: &Affine3) -> Affine3 {
+        let mut matrix = [[0.0; 3]; 3];
+        for (r, row) in matrix.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| next.matrix[r][k] * self.matrix[k][c]).sum();
+            }
+        }
+        let offset = next.apply_f32(self.offset);
+        Affine3 { matrix, offset }
+    }
+
+    /// Обратное отображение. None — линейная часть вырождена.
+    pub fn inverse(&self) -> Option<Affine3> {
+        let m = self.matrix.map(|row| row.map(f64::from));
+        let cofactor = |r: usize, c: usize| {
+            let (r1, r2) = ((r + 1) % 3, (r + 2) % 3);
+            let (c1, c2) = ((c + 1) % 3, (c + 2) % 3);
+            m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+        };
+        let det: f64 = (0..3).map(|c| m[0][c] * cofactor(0, c)).sum();
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        // (M⁻¹)[r][c] = cofactor(c, r) / det
+        let mut matrix = [[0.0f32; 3]; 3];
+        for (r, row) in matrix.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                *cell = (cofactor(c, r) / det) as f32;
+            }
+        }
+        let linear = Affine3 { matrix, offset: [0.0; 3] };
+        let [x, y, z] = linear.apply_f32(self.offset);
+        Some(Affine3 { matrix, offset: [-x, -y, -z] })
+    }
+
+    /// Подобрать отображение `from → to` по парам соответствующих точек
+    /// методом наименьших квадратов. None — меньше четырёх пар или точки
+    /// лежат в одной плоскости (отображение не определено).
+    pub fn fit(pairs: &[PointPair]) -> Option<Affine3> {
+        if pairs.len() < 4 {
+            return None;
+        }
+        // Нормальные уравнения (XᵀX)·β = Xᵀy, строка X = [x, y, z, 1]
+        let mut xtx = [[0.0f64; 4]; 4];
+        let mut xty = [[0.0f64; 3]; 4];
+        for &(src, dst) in pairs {
+            let x = [src.0 as f64, src.1 as f64, src.2 as f64, 1.0];
+            let y = [dst.0 as f64, dst.1 as f64, dst.2 as f64];
+            for i in 0..4 {
+                for j in 0..4 {
+                    xtx[i][j] += x[i] * x[j];
+                }
+                for d in 0..3 {
+                    xty[i][d] += x[i] * y[d];
+                }
+            }
+        }
+        let beta = solve4(xtx, xty)?;
+        // beta[i][d] — коэффициент при x[i] для выходной оси d
+        let matrix = std::array::from_fn(|d| std::array::from_fn(|i| beta[i][d] as f32));
+        let offset = beta[3].map(|v| v as f32);
+        Some(Affine3 { matrix, offset })
+    }
+}
+
+/// Решить A·X = B (A 4×4, три правые части) методом Гаусса с выбором ведущего.
+fn solve4(mut a: [[f64; 4]; 4], mut b: [[f64; 3]; 4]) -> Option<[[f64; 3]; 4]> {
+    let scale = a.iter().flatten().fold(0.0f64, |m, v| m.max(v.abs()));
+    for col in 0..4 {
+        let pivot = (col..4).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() <= scale * 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (pivot_a, pivot_b) = (a[col], b[col]);
+        for row in col + 1..4 {
+            let factor = a[row][col] / pivot_a[col];
+            for (v, p) in a[row].iter_mut().zip(pivot_a).skip(col) {
+                *v -= factor * p;
+            }
+            for (v, p) in b[row].iter_mut().zip(pivot_b) {
+                *v -= factor * p;
+            }
+        }
+    }
+    let mut x = [[0.0f64; 3]; 4];
+    for row in (0..4).rev() {
+        for d in 0..3 {
+            let tail: f64 = (row + 1..4).map(|k| a[row][k] * x[k][d]).sum();
+            x[row][d] = (b[row][d] - tail) / a[row][row];
+        }
+    }
+    Some(x)
+}
@@ -1,3 +1,140 @@
 // SPDX-License-Identifier: AGPL-3.0-only
 // R4: тесты глобальной гравитации (apply_gravity_batch) удалены вместе с механизмом.
 // Живая семантическая гравитация — SubsystemGravity в axiom-runtime.
+
+use axiom_space::batch::top_k_pairs;
+use axiom_space::*;
+
+fn lcg_points(n: usize, seed: u64) -> Vec<(i16, i16, i16)> {
+    let mut s = seed;
+    let mut next = || {
+        s = s.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (s >> 48) as i16
+    };
+    (0..n).map(|_| (next(), next(), next())).collect()
+}
+
+#[test]
+fn test_distance2_batch_matches_scalar() {
+    let pts = lcg_points(1003, 7); // не кратно ширине вектора — проверяет хвост
+    let xs: Vec<i16> = pts.iter().map(|p| p.0).collect();
+    let ys: Vec<i16> = pts.iter().map(|p| p.1).collect();
+    let zs: Vec<i16> = pts.iter().map(|p| p.2).collect();
+    let mut out = vec![0i64; pts.len()];
+    let q = (-32768, 32767, 0);
+
+    distance2_batch(q, &xs, &ys, &zs, &mut out);
+
+    for (p, d) in pts.iter().zip(&out) {
+        assert_eq!(*d, distance2(q.0, q.1, q.2, p.0, p.1, p.2));
+    }
+}
+
+#[test]
+fn test_top_k_sorted_with_id_tiebreak() {
+    let mut batch = PositionBatch::default();
+    batch.push(5, (10, 0, 0));
+    batch.push(3, (0, 10, 0)); // та же дистанция, меньший id
+    batch.push(9, (1, 0, 0));
+    batch.push(1, (100, 0, 0));
+
+    let top = batch.top_k((0, 0, 0), 3);
+    assert_eq!(top, vec![(9, 1), (3, 100), (5, 100)]);
+}
+
+#[test]
+fn test_top_k_pairs_k_larger_than_len_and_zero() {
+    let mut pairs = vec![(2, 50), (1, 10)];
+    top_k_pairs(&mut pairs, 10);
+    assert_eq!(pairs, vec![(1, 10), (2, 50)]);
+    top_k_pairs(&mut pairs, 0);
+    assert!(pairs.is_empty());
+}
+
+#[test]
+fn test_grid_find_k_nearest_matches_brute_force() {
+    let pts: Vec<(i16, i16, i16)> = lcg_points(2000, 42)
+        .into_iter()
+        .map(|(x, y, z)| (x / 32, y / 32, z / 32))
+        .collect();
+    let mut grid = SpatialHashGrid::new();
+    grid.rebuild(pts.len(), |i| pts[i]);
+    let mut batch = PositionBatch::with_capacity(256);
+
+    let center = (0, 0, 0);
+    let radius = 400;
+    let got = grid.find_k_nearest(center, radius, 16, |i| pts[i as usize], &mut batch);
+
+    let mut expected: Vec<(u32, i64)> = pts
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i as u32, distance2(0, 0, 0, p.0, p.1, p.2)))
+        .filter(|&(_, d)| d <= (radius as i64) * (radius as i64))
+        .collect();
+    top_k_pairs(&mut expected, 16);
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn test_grid_find_k_nearest_respects_radius() {
+    let pts = [(0i16, 0i16, 0i16), (50, 0, 0), (1000, 0, 0)];
+    let mut grid = SpatialHashGrid::new();
+    grid.rebuild(pts.len(), |i| pts[i]);
+    let mut batch = PositionBatch::default();
+
+    let got = grid.find_k_nearest((0, 0, 0), 100, 10, |i| pts[i as usize], &mut batch);
+    assert_eq!(got, vec![(0, 0), (1, 2500)]);
+}
+
+#[test]
+fn test_cosine_distance_batch_matches_metric() {
+    let mut pts = lcg_points(1001, 11);
+    pts.push((0, 0, 0)); // нулевой вектор
+    let xs: Vec<i16> = pts.iter().map(|p| p.0).collect();
+    let ys: Vec<i16> = pts.iter().map(|p| p.1).collect();
+    let zs: Vec<i16> = pts.iter().map(|p| p.2).collect();
+    let mut out = vec![0i64; pts.len()];
+    let q = (1200, -300, 77);
+
+    cosine_distance_batch(q, &xs, &ys, &zs, &mut out);
+
+    for (p, d) in pts.iter().zip(&out) {
+        assert_eq!(*d, Metric::Cosine.distance(q, *p));
+    }
+}
+
+#[test]
+fn test_batch_top_k_by_metric_from_tokens() {
+    let tokens = [
+        axiom_core::Token::new(1, 100, [1000, 0, 0], 1),
+        axiom_core::Token::new(2, 100, [10, 10, 0], 1),
+        axiom_core::Token::new(3, 100, [5, 0, 0], 1),
+    ];
+    let mut batch = PositionBatch::default();
+    batch.extend_from_tokens(&tokens);
+
+    let by_l2 = batch.top_k_by((0, 0, 0), 2, Metric::Euclidean);
+    assert_eq!(by_l2, batch.top_k((0, 0, 0), 2));
+    let by_cos: Vec<u32> =
+        batch.top_k_by((1, 0, 0), 3, Metric::Cosine).iter().map(|p| p.0).collect();
+    assert_eq!(by_cos, vec![1, 3, 2]);
+    let by_l1 = batch.top_k_by((0, 0, 0), 1, Metric::Manhattan);
+    assert_eq!(by_l1, vec![(3, 5)]);
+}
+
+#[test]
+fn test_top_k_many_matches_single_queries() {
+    let mut batch = PositionBatch::default();
+    let pts = lcg_points(2 * batch::BLOCK + 37, 11); // не кратно BLOCK — проверяет хвост
+    for (id, p) in pts.into_iter().enumerate() {
+        batch.push(id as u32, p);
+    }
+    let queries = [(0, 0, 0), (500, -500, 250), (-32768, 32767, 0)];
+    let many = batch.top_k_many(&queries, 5);
+    assert_eq!(many.len(), 3);
+    for (query, found) in queries.iter().zip(&many) {
+        assert_eq!(found, &batch.top_k(*query, 5));
+    }
+    assert!(batch.top_k_many(&queries, 0).iter().all(Vec::is_empty));
+    assert!(PositionBatch::default().top_k_many(&queries, 3).iter().all(Vec::is_empty));
+}
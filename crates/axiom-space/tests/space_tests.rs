@@ -1043,3 +1043,340 @@ fn test_from_yaml_all_presets_valid() {
         assert!(cfg.validate().is_ok(), "preset {} invalid", name);
     }
 }
+
+// ============================================================================
+// Metric
+// ============================================================================
+
+#[test]
+fn test_metric_euclidean_matches_distance2() {
+    let (a, b) = ((1, -2, 3), (40, 5, -6));
+    assert_eq!(Metric::Euclidean.distance(a, b), distance2(1, -2, 3, 40, 5, -6));
+    assert_eq!(Metric::Manhattan.distance(a, b), 39 + 7 + 9);
+}
+
+#[test]
+fn test_metric_cosine_ignores_magnitude() {
+    let near = Metric::Cosine.distance((100, 0, 0), (5000, 0, 0));
+    let ortho = Metric::Cosine.distance((100, 0, 0), (0, 100, 0));
+    assert_eq!(near, 0);
+    assert_eq!(ortho, ANGULAR_SCALE as i64);
+    assert_eq!(Metric::Angular.distance((100, 0, 0), (-100, 0, 0)), ANGULAR_SCALE as i64);
+}
+
+#[test]
+fn test_metric_weighted_scales_axes() {
+    let m = Metric::Weighted { weights: [WEIGHT_ONE, WEIGHT_ONE, 0] };
+    assert_eq!(m.distance((0, 0, 0), (3, 4, 1000)), 25);
+}
+
+#[test]
+fn test_find_k_nearest_by_cosine_ranks_by_direction() {
+    let pts = [(200i16, 0i16, 0i16), (40, 40, 0), (20, 1, 0)];
+    let mut grid = SpatialHashGrid::new();
+    grid.rebuild(pts.len(), |i| pts[i]);
+
+    let by_dir = grid.find_k_nearest_by((100, 0, 0), 300, 3, Metric::Cosine, |i| pts[i as usize]);
+    assert_eq!(by_dir.iter().map(|r| r.0).collect::<Vec<_>>(), vec![0, 2, 1]);
+
+    let mut batch = PositionBatch::default();
+    let euclid = grid.find_k_nearest((100, 0, 0), 300, 3, |i| pts[i as usize], &mut batch);
+    assert_eq!(
+        grid.find_k_nearest_by((100, 0, 0), 300, 3, Metric::Euclidean, |i| pts[i as usize]),
+        euclid
+    );
+}
+
+#[test]
+fn test_spatial_config_metric_from_yaml() {
+    let yaml = "cell_shift: 8\nbucket_count_log2: 16\ninitial_capacity: 16\nmetric: cosine\n";
+    let cfg: SpatialConfig = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(cfg.metric, Metric::Cosine);
+    assert_eq!(SpatialConfig::medium().metric, Metric::Euclidean);
+}
+
+fn pseudo_points(n: u32) -> Vec<(u32, (i16, i16, i16))> {
+    let mut state = 0x2545_F491u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state % 2001) as i16 - 1000
+    };
+    (0..n).map(|id| (id, (next(), next(), next()))).collect()
+}
+
+#[test]
+fn test_kdtree_nearest_matches_brute_force() {
+    let points = pseudo_points(500);
+    let tree = KdTree::build(points.clone());
+    assert_eq!(tree.len(), 500);
+    for center in [(0, 0, 0), (900, -900, 15), (-1000, 1000, 1000)] {
+        let mut brute: Vec<(u32, i64)> = points
+            .iter()
+            .map(|&(id, p)| (id, distance2(center.0, center.1, center.2, p.0, p.1, p.2)))
+            .collect();
+        brute.sort_unstable_by_key(|&(id, d2)| (d2, id));
+        brute.truncate(7);
+        assert_eq!(tree.nearest(center, 7), brute);
+    }
+}
+
+#[test]
+fn test_kdtree_nearest_is_not_radius_bounded() {
+    let tree = KdTree::build(vec![(1, (30000, 30000, 30000)), (2, (-30000, 0, 0))]);
+    assert_eq!(tree.nearest((0, 0, 0), 1), vec![(2, 900_000_000)]);
+    assert_eq!(tree.nearest((0, 0, 0), 5).len(), 2);
+    assert!(tree.nearest((0, 0, 0), 0).is_empty());
+    assert!(KdTree::default().nearest((0, 0, 0), 3).is_empty());
+}
+
+#[test]
+fn test_kdtree_within_radius_and_box_match_brute_force() {
+    let points = pseudo_points(400);
+    let tree = KdTree::build(points.clone());
+    let center = (100, -50, 20);
+
+    let mut brute: Vec<(u32, i64)> = points
+        .iter()
+        .map(|&(id, p)| (id, distance2(center.0, center.1, center.2, p.0, p.1, p.2)))
+        .filter(|&(_, d2)| d2 <= 400 * 400)
+        .collect();
+    brute.sort_unstable_by_key(|&(id, d2)| (d2, id));
+    assert!(!brute.is_empty());
+    assert_eq!(tree.within_radius(center, 400), brute);
+
+    let (min, max) = ((-200, -200, -200), (300, 0, 500));
+    let inside = |p: (i16, i16, i16)| {
+        (min.0..=max.0).contains(&p.0)
+            && (min.1..=max.1).contains(&p.1)
+            && (min.2..=max.2).contains(&p.2)
+    };
+    let brute_box: Vec<(u32, (i16, i16, i16))> =
+        points.iter().copied().filter(|&(_, p)| inside(p)).collect();
+    assert_eq!(tree.within_box(min, max), brute_box);
+}
+
+#[test]
+fn test_kdtree_within_radius_saturates_at_space_edge() {
+    let tree = KdTree::build(vec![(1, (i16::MAX, 0, 0)), (2, (i16::MIN, 0, 0))]);
+    assert_eq!(tree.within_radius((i16::MAX - 1, 0, 0), 10), vec![(1, 1)]);
+    assert!(tree.within_radius((0, 0, 0), -1).is_empty());
+}
+
+#[test]
+fn test_occupancy_stats_histogram_and_skew() {
+    // 4 токена в ячейке (0,0,0), 1 — в (1,0,0), 1 — в (-1,-1,-1)
+    let positions =
+        [(0, 0, 0), (10, 20, 30), (255, 255, 255), (100, 0, 5), (256, 0, 0), (-1, -1, -1)];
+    let stats = OccupancyStats::from_positions(positions);
+
+    assert_eq!(stats.tokens, 6);
+    assert_eq!(stats.occupied_cells(), 3);
+    assert_eq!(stats.cells[0], CellOccupancy { cell: (-1, -1, -1), count: 1 });
+    assert_eq!(stats.cells[0].origin(), (-256, -256, -256));
+    assert_eq!(stats.histogram, vec![2, 0, 1]); // 1,1 → [1,2); 4 → [4,8)
+    assert_eq!(stats.max_occupancy(), 4);
+    assert!((stats.mean_occupancy() - 2.0).abs() < 1e-6);
+    assert!((stats.skew() - 2.0).abs() < 1e-6);
+    assert_eq!(stats.densest(2).iter().map(|c| c.count).collect::<Vec<_>>(), vec![4, 1]);
+    assert_eq!(stats.densest(2)[1].cell, (-1, -1, -1));
+
+    let csv = stats.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "cell_x,cell_y,cell_z,origin_x,origin_y,origin_z,count");
+    assert!(lines.contains(&"1,0,0,256,0,0,1"));
+
+    let empty = OccupancyStats::from_positions(std::iter::empty());
+    assert_eq!((empty.occupied_cells(), empty.skew()), (0, 0.0));
+}
+
+#[test]
+fn test_grid_occupancy_uses_indexed_tokens() {
+    let positions = [(0i16, 0i16, 0i16), (300, 0, 0), (310, 5, 5)];
+    let mut grid = SpatialHashGrid::new();
+    grid.rebuild(positions.len(), |i| positions[i]);
+    let stats = grid.occupancy(|i| positions[i]);
+    assert_eq!(stats.tokens, 3);
+    assert_eq!(stats.cells[1], CellOccupancy { cell: (1, 0, 0), count: 2 });
+}
+
+#[test]
+fn test_grid_update_token_relinks_entry() {
+    let mut positions = [(0i16, 0i16, 0i16), (10, 0, 0), (20, 0, 0)];
+    let mut grid = SpatialHashGrid::new();
+    grid.rebuild(positions.len(), |i| positions[i]);
+
+    // Середина цепочки корзины уходит в другую ячейку
+    assert!(grid.update_token(1, (10, 0, 0), (5000, 0, 0)));
+    positions[1] = (5000, 0, 0);
+    let mut origin: Vec<u32> = grid.query_cell(0, 0, 0).collect();
+    origin.sort_unstable();
+    assert_eq!(origin, vec![0, 2]);
+    assert_eq!(grid.query_cell(5000, 0, 0).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(grid.entries.len(), 3);
+
+    let found = grid.find_neighbors(5000, 0, 0, 10, |i| positions[i as usize]);
+    assert_eq!(found, vec![1]);
+
+    // Та же ячейка — ничего не меняется; токена нет в старой ячейке — false
+    assert!(grid.update_token(0, (0, 0, 0), (1, 1, 1)));
+    assert!(!grid.update_token(2, (5000, 0, 0), (0, 0, 0)));
+}
+
+#[test]
+fn test_grid_apply_moves_counts_misses() {
+    let positions = [(0i16, 0i16, 0i16), (300, 0, 0)];
+    let mut grid = SpatialHashGrid::new();
+    grid.rebuild(positions.len(), |i| positions[i]);
+
+    let moves = [(0, (0, 0, 0), (300, 0, 0)), (1, (-3000, 0, 0), (0, 0, 0))];
+    assert_eq!(grid.apply_moves(&moves), 1);
+    let mut cell: Vec<u32> = grid.query_cell(300, 0, 0).collect();
+    cell.sort_unstable();
+    assert_eq!(cell, vec![0, 1]);
+    assert_eq!(grid.query_cell(0, 0, 0).count(), 0);
+}
+
+#[test]
+fn test_affine_compose_and_inverse() {
+    let scale = Affine3::scale([2.0, 0.5, 1.0]);
+    let shift = Affine3::translation([100.0, -50.0, 7.0]);
+    let both = scale.then(&shift);
+    assert_eq!(both.apply((10, 20, 30)), (120, -40, 37));
+    assert_eq!(shift.then(&scale).apply((10, 20, 30)), (220, -15, 37));
+
+    let back = both.inverse().unwrap();
+    assert_eq!(back.apply(both.apply((-300, 400, 5))), (-300, 400, 5));
+    let round_trip = both.then(&back);
+    assert_eq!(round_trip.apply((1234, -4321, 77)), (1234, -4321, 77));
+
+    assert!(Affine3::scale([1.0, 0.0, 1.0]).inverse().is_none());
+    assert_eq!(Affine3::default().apply((1, 2, 3)), (1, 2, 3));
+    // Насыщение в пределах i16
+    assert_eq!(Affine3::scale([4.0; 3]).apply((20000, -20000, 0)), (32767, -32768, 0));
+}
+
+#[test]
+fn test_affine_fit_recovers_mapping() {
+    // Поворот на 90° вокруг z, масштаб 2, сдвиг
+    let truth = Affine3 {
+        matrix: [[0.0, -2.0, 0.0], [2.0, 0.0, 0.0], [0.0, 0.0, 2.0]],
+        offset: [10.0, -20.0, 30.0],
+    };
+    let pairs: Vec<_> = [(0, 0, 0), (100, 0, 0), (0, 100, 0), (0, 0, 100), (-50, 70, 20)]
+        .into_iter()
+        .map(|p| (p, truth.apply(p)))
+        .collect();
+    let fitted = Affine3::fit(&pairs).unwrap();
+    assert_eq!(fitted.apply((37, -12, 5)), truth.apply((37, -12, 5)));
+
+    assert!(Affine3::fit(&pairs[..3]).is_none());
+    // Все точки в плоскости z = 0 — отображение по z не определено
+    let flat: Vec<_> = [(0, 0, 0), (10, 0, 0), (0, 10, 0), (10, 10, 0)]
+        .into_iter()
+        .map(|p| (p, p))
+        .collect();
+    assert!(Affine3::fit(&flat).is_none());
+}
+
+#[test]
+fn test_lerp_and_slerp() {
+    assert_eq!(lerp((0, 0, 0), (100, -50, 10), 0.5), (50, -25, 5));
+    assert_eq!(lerp((0, 0, 0), (100, -50, 10), 0.0), (0, 0, 0));
+    assert_eq!(lerp((0, 0, 0), (100, -50, 10), 1.0), (100, -50, 10));
+
+    // Четверть окружности: середина дуги на той же длине, а не внутри хорды
+    assert_eq!(slerp((1000, 0, 0), (0, 1000, 0), 0.5), (707, 707, 0));
+    assert_eq!(slerp((1000, 0, 0), (0, 2000, 0), 1.0), (0, 2000, 0));
+    // Вырожденные случаи — как lerp
+    assert_eq!(slerp((0, 0, 0), (100, 0, 0), 0.5), (50, 0, 0));
+    assert_eq!(slerp((100, 0, 0), (-100, 0, 0), 0.25), (50, 0, 0));
+}
+
+#[test]
+fn test_centroid_and_sample_path() {
+    assert_eq!(centroid(&[(0, 0, 0), (10, 20, -30), (20, 40, 0)]), Some((10, 20, -10)));
+    assert_eq!(centroid(&[(32767, 0, 0), (32767, 0, 0)]), Some((32767, 0, 0)));
+    assert_eq!(centroid(&[]), None);
+
+    // Ломаная длиной 200: 100 по x, затем 100 по y
+    let path = [(0, 0, 0), (100, 0, 0), (100, 100, 0)];
+    assert_eq!(
+        sample_path(&path, 5),
+        vec![(0, 0, 0), (50, 0, 0), (100, 0, 0), (100, 50, 0), (100, 100, 0)]
+    );
+    assert_eq!(sample_path(&path, 1), vec![(0, 0, 0)]);
+    assert!(sample_path(&path, 0).is_empty());
+    assert!(sample_path(&[], 3).is_empty());
+    assert_eq!(sample_path(&[(5, 5, 5)], 2), vec![(5, 5, 5); 2]);
+}
+
+#[test]
+fn test_coordinate_ext_on_tokens() {
+    use axiom_core::Token;
+    let a = Token::new(1, 106, [0, 0, 0], 1);
+    let b = Token::new(2, 106, [200, 0, 0], 1);
+    let c = Token::new(3, 106, [200, 200, 0], 1);
+
+    assert_eq!(a.lerp_to(&b, 0.25), (50, 0, 0));
+    assert_eq!(b.slerp_to(&c, 1.0), (200, 200, 0));
+    assert_eq!(centroid_of(&[a, b, c]), Some((133, 67, 0)));
+    assert_eq!(trajectory(&[a, b, c], 3), vec![(0, 0, 0), (200, 0, 0), (200, 200, 0)]);
+}
+
+fn stretched_cloud(n: usize) -> Vec<Vec<f32>> {
+    // Разброс по направлениям (1,1,0,0,0)/√2 ≫ (0,0,1,0,0) ≫ (0,0,0,1,0)
+    let points = pseudo_points(n as u32);
+    points
+        .iter()
+        .map(|&(_, (a, b, c))| {
+            let (a, b, c) = (a as f32 / 100.0, b as f32 / 300.0, c as f32 / 1000.0);
+            vec![a + 5.0, a + 5.0, b, c, 1.0]
+        })
+        .collect()
+}
+
+#[test]
+fn test_pca_fit_finds_principal_axes() {
+    let samples = stretched_cloud(400);
+    let pca = PcaReducer::fit(&samples).unwrap();
+    assert_eq!((pca.dim(), pca.samples()), (5, 400));
+
+    let [c0, c1, c2] = pca.components();
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((c0[0] - h).abs() < 0.01 && (c0[1] - h).abs() < 0.01, "{c0:?}");
+    assert!((c1[2].abs() - 1.0).abs() < 0.01, "{c1:?}");
+    assert!((c2[3].abs() - 1.0).abs() < 0.01, "{c2:?}");
+    let v = pca.variance();
+    assert!(v[0] > v[1] && v[1] > v[2] && v[2] > 0.0);
+
+    // Среднее проецируется в начало координат, σ по компоненте → scale
+    let mean: Vec<f32> = (0..5)
+        .map(|d| samples.iter().map(|s| s[d]).sum::<f32>() / samples.len() as f32)
+        .collect();
+    assert_eq!(pca.to_position(&mean, 1000.0), Some((0, 0, 0)));
+    let along: Vec<f32> = mean.iter().zip(c1).map(|(m, c)| m + v[1].sqrt() * c).collect();
+    assert_eq!(pca.to_position(&along, 1000.0), Some((0, 1000, 0)));
+
+    assert_eq!(pca.project(&[1.0]), None);
+    assert!(PcaReducer::fit(&[]).is_none());
+    assert!(PcaReducer::fit(&[vec![1.0, 2.0], vec![1.0]]).is_none());
+    assert!(PcaReducer::fit(&[vec![f32::NAN]]).is_none());
+}
+
+#[test]
+fn test_pca_update_converges_on_stream() {
+    let samples = stretched_cloud(2000);
+    let mut pca = PcaReducer::new(5);
+    for s in &samples {
+        assert!(pca.update(s));
+    }
+    let c0 = &pca.components()[0];
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+    assert!((c0[0].abs() - h).abs() < 0.05 && (c0[1].abs() - h).abs() < 0.05, "{c0:?}");
+    assert_eq!(pca.samples(), 2000);
+    assert!(!pca.update(&[1.0, 2.0]));
+    assert!(!pca.update(&[f32::INFINITY, 0.0, 0.0, 0.0, 0.0]));
+}
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2024-2026 Chernov Denys
+//
+// Fuzz-харнессы UCL (feature "fuzzing").
+//
+// Точки входа принимают сырые байты от фаззера (cargo fuzz / libFuzzer / AFL)
+// и не должны паниковать ни на каком входе. Пример цели cargo-fuzz:
+//
+//     fuzz_target!(|data: &[u8]| axiom_ucl::fuzz::fuzz_command(data));
+//
+// Главная зона риска — `get_payload::<T>()`: `read_unaligned` из 48-байтового
+// буфера. Любой payload-тип длиннее 48 байт — чтение за границей кадра,
+// поэтому размеры дополнительно зафиксированы compile-time проверками ниже.
+
+use crate::*;
+use arbitrary::{Arbitrary, Unstructured};
+
+const PAYLOAD_SIZE: usize = 48;
+
+const _: () = assert!(std::mem::size_of::<SpawnDomainPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ApplyForcePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<InjectTokenPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ChangeTemperaturePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ProcessTokenPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<FinalizeComparisonPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<UnfoldFramePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<InjectFrameAnchorPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<BondTokensPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ReinforceFramePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ProposeAxialAdjustmentPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<QueryDepthDistributionPayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ResetDepthForFramePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<NotifyEmergentCandidatePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ApproveEmergentCandidatePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<NotifySubsystemCandidatePayload>() <= PAYLOAD_SIZE);
+const _: () = assert!(std::mem::size_of::<ApproveSubsystemCandidatePayload>() <= PAYLOAD_SIZE);
+
+/// Харнесс: произвольная команда → валидация → декодирование payload по opcode.
+///
+/// Повторяет путь, которым Engine разбирает входящий кадр: `is_valid()`,
+/// затем `get_payload::<T>()` для типа, соответствующего opcode/flags.
+pub fn fuzz_command(data: &[u8]) {
+    let mut u = Unstructured::new(data);
+    let Ok(cmd) = UclCommand::arbitrary(&mut u) else {
+        return;
+    };
+    let _ = cmd.is_valid();
+    decode_payload(&cmd);
+}
+
+/// Харнесс: один и тот же payload декодируется всеми известными типами.
+///
+/// Не зависит от opcode — покрывает payload-типы, до которых `fuzz_command`
+/// добирается только при совпадении opcode.
+pub fn fuzz_payload_all(data: &[u8]) {
+    let mut cmd = UclCommand::new(OpCode::TickForward, 0, 0, 0);
+    let n = data.len().min(PAYLOAD_SIZE);
+    cmd.payload[..n].copy_from_slice(&data[..n]);
+
+    let _ = cmd.get_payload::<SpawnDomainPayload>();
+    let _ = cmd.get_payload::<ApplyForcePayload>();
+    let _ = cmd.get_payload::<InjectTokenPayload>();
+    let _ = cmd.get_payload::<ChangeTemperaturePayload>();
+    let _ = cmd.get_payload::<ProcessTokenPayload>();
+    let _ = cmd.get_payload::<FinalizeComparisonPayload>();
+    let _ = cmd.get_payload::<UnfoldFramePayload>();
+    let _ = cmd.get_payload::<InjectFrameAnchorPayload>();
+    let _ = cmd.get_payload::<BondTokensPayload>();
+    let _ = cmd.get_payload::<ReinforceFramePayload>();
+    let _ = cmd.get_payload::<ProposeAxialAdjustmentPayload>();
+    let _ = cmd.get_payload::<QueryDepthDistributionPayload>();
+    let _ = cmd.get_payload::<ResetDepthForFramePayload>();
+    let _ = cmd.get_payload::<NotifyEmergentCandidatePayload>();
+    let _ = cmd.get_payload::<ApproveEmergentCandidatePayload>();
+    let _ = cmd.get_payload::<NotifySubsystemCandidatePayload>();
+    let _ = cmd.get_payload::<ApproveSubsystemCandidatePayload>();
+}
+
+/// Декодировать payload в тип, соответствующий opcode (как это делает Engine).
+fn decode_payload(cmd: &UclCommand) {
+    let is = |op: OpCode| cmd.opcode == op as u16;
+    match () {
+        _ if is(OpCode::SpawnDomain) => {
+            let p = cmd.get_payload::<SpawnDomainPayload>();
+            let _ = ucl_preset_to_structural_role(p.factory_preset);
+        }
+        _ if is(OpCode::InjectToken) && cmd.flags & flags::FRAME_ANCHOR != 0 => {
+            let _ = cmd.get_payload::<InjectFrameAnchorPayload>();
+        }
+        _ if is(OpCode::InjectToken) => {
+            let _ = cmd.get_payload::<InjectTokenPayload>();
+        }
+        _ if is(OpCode::ApplyForce) => {
+            let _ = cmd.get_payload::<ApplyForcePayload>();
+        }
+        _ if is(OpCode::BondTokens) => {
+            let _ = cmd.get_payload::<BondTokensPayload>();
+        }
+        _ if is(OpCode::ChangeTemperature) => {
+            let _ = cmd.get_payload::<ChangeTemperaturePayload>();
+        }
+        _ if is(OpCode::ProcessTokenDualPath) => {
+            let _ = cmd.get_payload::<ProcessTokenPayload>();
+        }
+        _ if is(OpCode::FinalizeComparison) => {
+            let _ = cmd.get_payload::<FinalizeComparisonPayload>();
+        }
+        _ if is(OpCode::UnfoldFrame) => {
+            let _ = cmd.get_payload::<UnfoldFramePayload>();
+        }
+        _ if is(OpCode::ReinforceFrame) => {
+            let _ = cmd.get_payload::<ReinforceFramePayload>();
+        }
+        _ if is(OpCode::ProposeAxialAdjustment) => {
+            let _ = cmd.get_payload::<ProposeAxialAdjustmentPayload>();
+        }
+        _ if is(OpCode::QueryDepthDistribution) => {
+            let _ = cmd.get_payload::<QueryDepthDistributionPayload>();
+        }
+        _ if is(OpCode::ResetDepthForFrame) => {
+            let _ = cmd.get_payload::<ResetDepthForFramePayload>();
+        }
+        _ if is(OpCode::NotifyEmergentCandidate) => {
+            let _ = cmd.get_payload::<NotifyEmergentCandidatePayload>();
+        }
+        _ if is(OpCode::ApproveEmergentCandidate) => {
+            let _ = cmd.get_payload::<ApproveEmergentCandidatePayload>();
+        }
+        _ if is(OpCode::NotifySubsystemCandidate) => {
+            let _ = cmd.get_payload::<NotifySubsystemCandidatePayload>();
+        }
+        _ if is(OpCode::ApproveSubsystemCandidate) => {
+            let _ = cmd.get_payload::<ApproveSubsystemCandidatePayload>();
+        }
+        _ => {}
+    }
+}
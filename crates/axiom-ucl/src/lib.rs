@@ -3,6 +3,10 @@
 // UCL V2.0 - Unified Command Language (Zero-Allocation FFI Frame)
 // 64 байта, repr(C, align(64))
 
+/// Fuzz-харнессы декодеров payload (feature "fuzzing")
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+
 /// Статус выполнения команды
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -82,6 +86,7 @@ pub mod flags {
 /// Основная структура команды - 64 байта
 #[repr(C, align(64))]
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
 pub struct UclCommand {
     // --- ПОЛЕЗНАЯ НАГРУЗКА (PAYLOAD) [48 байт] ---
     pub payload: [u8; 48], // 48b | Raw данные для разных команд
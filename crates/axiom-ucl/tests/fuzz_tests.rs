@@ -0,0 +1,55 @@
+// Smoke-тесты fuzz-харнессов: детерминированные входы не должны паниковать.
+// Запуск: cargo test -p axiom-ucl --features fuzzing
+#![cfg(feature = "fuzzing")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use axiom_ucl::fuzz::{fuzz_command, fuzz_payload_all};
+use axiom_ucl::*;
+
+fn pseudo_random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut x = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x as u8
+        })
+        .collect()
+}
+
+#[test]
+fn test_fuzz_command_empty_input() {
+    fuzz_command(&[]);
+    fuzz_payload_all(&[]);
+}
+
+#[test]
+fn test_fuzz_command_random_inputs() {
+    for seed in 0..256 {
+        let data = pseudo_random_bytes(seed, 128);
+        fuzz_command(&data);
+        fuzz_payload_all(&data);
+    }
+}
+
+#[test]
+fn test_arbitrary_command_roundtrip_fields() {
+    let data = pseudo_random_bytes(42, 128);
+    let mut u = Unstructured::new(&data);
+    let cmd = UclCommand::arbitrary(&mut u).unwrap();
+    assert_eq!(std::mem::size_of_val(&cmd), 64);
+}
+
+#[test]
+fn test_arbitrary_core_types() {
+    let data = pseudo_random_bytes(7, 512);
+    let mut u = Unstructured::new(&data);
+    let token = axiom_core::Token::arbitrary(&mut u).unwrap();
+    let conn = axiom_core::Connection::arbitrary(&mut u).unwrap();
+    let event = axiom_core::Event::arbitrary(&mut u).unwrap();
+    // validate() обязан отвечать Ok/Err без паники на любом содержимом
+    let _ = token.validate();
+    let _ = conn.validate();
+    let _ = event.validate();
+}